@@ -3,7 +3,7 @@
 use bevy::prelude::*;
 use bevy_bullet_dynamics::components::Projectile;
 use bevy_bullet_dynamics::resources::BallisticsEnvironment;
-use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 
 fn benchmark_rk4_integration(c: &mut Criterion) {
     let env = BallisticsEnvironment::default();
@@ -73,17 +73,16 @@ fn benchmark_spread_calculation(c: &mut Criterion) {
     use bevy_bullet_dynamics::systems::accuracy;
 
     let accuracy_preset = accuracy::presets::rifle();
+    let ctx = accuracy::AccuracyContext {
+        is_moving: true,
+        movement_speed: 3.0,
+        max_speed: 5.0,
+        ..Default::default()
+    };
 
     c.bench_function("Spread Calculation", |b| {
         b.iter(|| {
-            accuracy::calculate_total_spread(
-                &accuracy_preset,
-                false,
-                true,
-                false,
-                3.0,
-                5.0,
-            )
+            accuracy::calculate_total_spread(&accuracy_preset, &ctx)
         });
     });
 
@@ -97,5 +96,48 @@ fn benchmark_spread_calculation(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, benchmark_rk4_integration, benchmark_spread_calculation);
+/// Previous spread implementation (StdRng + Normal per call), kept as a baseline.
+fn apply_spread_std_rng(base_direction: Vec3, spread_angle: f32, seed: u64) -> Vec3 {
+    use rand::prelude::*;
+    use rand_distr::{Distribution, Normal};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let normal = Normal::new(0.0, spread_angle as f64 / 3.0).unwrap_or(Normal::new(0.0, 0.01).unwrap());
+    let angle_x = normal.sample(&mut rng) as f32;
+    let angle_y = normal.sample(&mut rng) as f32;
+    (Quat::from_euler(EulerRot::XYZ, angle_x, angle_y, 0.0) * base_direction).normalize()
+}
+
+fn benchmark_pellet_spread(c: &mut Criterion) {
+    use bevy_bullet_dynamics::systems::accuracy;
+
+    let mut group = c.benchmark_group("Spread 1000 Pellets");
+    let direction = Vec3::NEG_Z;
+    let spread_angle = 0.08;
+
+    group.bench_function("inline_rng", |b| {
+        b.iter(|| {
+            for seed in 0..1000u64 {
+                black_box(accuracy::apply_spread_to_direction(direction, spread_angle, seed));
+            }
+        });
+    });
+
+    group.bench_function("std_rng_normal", |b| {
+        b.iter(|| {
+            for seed in 0..1000u64 {
+                black_box(apply_spread_std_rng(direction, spread_angle, seed));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_rk4_integration,
+    benchmark_spread_calculation,
+    benchmark_pellet_spread
+);
 criterion_main!(benches);
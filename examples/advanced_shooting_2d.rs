@@ -34,6 +34,7 @@ fn main() {
             temperature: 20.0,
             altitude: 0.0,
             latitude: 0.0,
+            ..default()
         })
         .insert_resource(SimulationSettings {
             virtual_range: ACTUAL_WALL_DISTANCE,
@@ -48,6 +49,7 @@ fn main() {
             enable_ricochet: true,
             min_projectile_speed: 20.0,
             debug_draw: true,
+            ..default()
         })
         .insert_resource(WeaponPresets::with_defaults())
         .add_systems(Startup, setup)
@@ -79,13 +81,6 @@ struct PlayerEntity(Entity);
 #[derive(Resource)]
 struct CurrentWeapon(usize);
 
-#[derive(Resource)]
-struct PlayerStats {
-    weapon_index: usize,
-    shots_fired: u32,
-    hits: u32,
-}
-
 fn setup(mut commands: Commands) {
     // Camera setup for 2D
     commands.spawn(Camera2d);
@@ -137,11 +132,6 @@ fn setup(mut commands: Commands) {
 
     commands.insert_resource(PlayerEntity(player_entity));
     commands.insert_resource(CurrentWeapon(0));
-    commands.insert_resource(PlayerStats {
-        weapon_index: 0,
-        shots_fired: 0,
-        hits: 0,
-    });
 
     // Spawn UI (Bevy 0.18 style)
     commands.spawn((
@@ -221,24 +211,20 @@ fn player_movement(
 fn weapon_switching(
     mut current_weapon: ResMut<CurrentWeapon>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut player_stats: ResMut<PlayerStats>,
+    mut stats: ResMut<BallisticsStats>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Digit1) {
         current_weapon.0 = 0;
-        player_stats.weapon_index = 0;
     }
     if keyboard_input.just_pressed(KeyCode::Digit2) {
         current_weapon.0 = 1;
-        player_stats.weapon_index = 1;
     }
     if keyboard_input.just_pressed(KeyCode::Digit3) {
         current_weapon.0 = 2;
-        player_stats.weapon_index = 2;
     }
     
     if keyboard_input.any_just_pressed([KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3]) {
-        player_stats.shots_fired = 0;
-        player_stats.hits = 0;
+        stats.reset();
     }
 }
 
@@ -248,8 +234,8 @@ fn player_shooting(
     player_query: Query<&Transform, With<Player>>,
     player_entity: Res<PlayerEntity>,
     current_weapon: Res<CurrentWeapon>,
-    mut player_stats: ResMut<PlayerStats>,
     weapon_presets: Res<WeaponPresets>,
+    environment: Res<BallisticsEnvironment>,
     mut fire_events: MessageWriter<FireEvent>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Space) {
@@ -260,7 +246,7 @@ fn player_shooting(
             let spawn_params = ProjectileSpawnParams::new(
                 player_transform.translation + direction * 20.0,
                 direction,
-                weapon_preset.muzzle_velocity,
+                weapon_preset.muzzle_velocity_at(&environment),
             )
             .with_damage(weapon_preset.base_damage)
             .with_mass(weapon_preset.projectile_mass)
@@ -304,13 +290,10 @@ fn player_shooting(
                 ProjectileLogic::Impact,
             ));
 
-            fire_events.write(FireEvent::new(
-                spawn_params.origin,
-                rotated_direction,
-                spawn_params.velocity,
-            ).with_seed(::rand::random::<u64>()));
-
-            player_stats.shots_fired += 1;
+            fire_events.write(FireEvent {
+                direction: rotated_direction,
+                ..FireEvent::from(&spawn_params)
+            }.with_seed(::rand::random::<u64>()));
         }
     }
 }
@@ -348,7 +331,7 @@ fn update_ui(
     mut status_query: Query<&mut Text, (With<StatusText>, Without<WeaponInfoText>, Without<DistanceInfoText>)>,
     mut distance_query: Query<&mut Text, (With<DistanceInfoText>, Without<WeaponInfoText>, Without<StatusText>)>,
     current_weapon: Res<CurrentWeapon>,
-    player_stats: Res<PlayerStats>,
+    stats: Res<BallisticsStats>,
     weapon_presets: Res<WeaponPresets>,
     settings: Res<SimulationSettings>,
 ) {
@@ -357,10 +340,11 @@ fn update_ui(
     
     if let Some(mut text) = weapon_info_query.iter_mut().next() {
         text.0 = format!(
-            "Current Weapon: {} | Shots: {} | Hits: {}\n",
+            "Current Weapon: {} | Shots: {} | Hits: {} | Accuracy: {:.0}%\n",
             current_weapon_name,
-            player_stats.shots_fired,
-            player_stats.hits
+            stats.shots_fired,
+            stats.hits,
+            stats.accuracy() * 100.0
         );
     }
     
@@ -387,11 +371,8 @@ fn update_ui(
 fn handle_hits(
     mut commands: Commands,
     mut hit_events: MessageReader<HitEvent>,
-    mut stats: ResMut<PlayerStats>,
 ) {
     for event in hit_events.read() {
-        stats.hits += 1;
-        
         // Spawn small impact effect
         commands.spawn((
             Sprite {
@@ -55,6 +55,7 @@ fn setup(
             MeshMaterial3d(box_material.clone()),
             Transform::from_xyz(-8.0 + i as f32 * 4.0, 1.0, -10.0),
             SurfaceMaterial::default(),
+            ProximityTarget,
         ));
     }
 
@@ -244,16 +245,10 @@ fn handle_input(
 
     // Zeroing adjustment
     if keyboard.just_pressed(KeyCode::PageUp) {
-        let current = weapon_state.sight.current_zero;
-        if let Some(next) = weapon_state.sight.zero_presets.iter().find(|&&z| z > current) {
-            weapon_state.sight.current_zero = *next;
-        }
+        weapon_state.sight.cycle_up();
     }
     if keyboard.just_pressed(KeyCode::PageDown) {
-        let current = weapon_state.sight.current_zero;
-        if let Some(prev) = weapon_state.sight.zero_presets.iter().rev().find(|&&z| z < current) {
-            weapon_state.sight.current_zero = *prev;
-        }
+        weapon_state.sight.cycle_down();
     }
 
     if changed {
@@ -289,11 +284,10 @@ fn handle_input(
         use bevy_bullet_dynamics::systems::accuracy;
         let spread_angle = accuracy::calculate_total_spread(
             &weapon_state.accuracy,
-            false, // not aiming
-            false, // not moving
-            false, // not airborne
-            0.0,
-            5.0,
+            &accuracy::AccuracyContext {
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
 
         // Determine projectile count and damage
@@ -365,8 +359,7 @@ fn handle_input(
                     .with_mass(mass)
                     .with_drag(drag)
                     .with_diameter(diameter)
-                    .with_penetration(100.0) // Default penetration
-                    .with_reference_area(std::f32::consts::PI * (diameter / 2.0).powi(2)),
+                    .with_penetration(100.0), // Default penetration
                 Payload::Kinetic { damage },
                 ProjectileLogic::Impact,
             ));
@@ -381,13 +374,13 @@ fn handle_input(
                 entity_cmd.insert(Payload::Explosive { 
                     radius: 3.0, 
                     damage,
-                    falloff: 0.5 
+                    falloff: FalloffCurve::Power(0.5)
                 });
                 entity_cmd.insert(ProjectileLogic::Proximity { range: 1.0 });
             }
 
             if weapon_state.weapon_type == WeaponType::Laser {
-                 entity_cmd.insert(ProjectileLogic::Hitscan { range: 1000.0 });
+                 entity_cmd.insert(ProjectileLogic::Hitscan { range: 1000.0, beam_radius: 0.0 });
                  // Laser usually has Payload too (logic.rs handles damage via payload)
             }
 
@@ -47,6 +47,7 @@ fn main() {
             enable_ricochet: true,
             min_projectile_speed: 20.0,
             debug_draw: true,
+            ..default()
         })
         .add_systems(Startup, setup)
         .add_systems(
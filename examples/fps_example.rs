@@ -34,7 +34,6 @@ fn main() {
             switch_weapons,
             update_ui,
             handle_events,
-            scale_projectiles,
             grab_cursor,
             update_hit_markers,
         ))
@@ -68,12 +67,6 @@ enum WeaponType {
     Sniper,
 }
 
-#[derive(Component)]
-struct Stats {
-    hits: u32,
-    ricochets: u32,
-}
-
 #[derive(Component)]
 struct StatsText;
 
@@ -103,7 +96,6 @@ fn spawn_player(
     // Player Root
     commands.spawn((
         Player,
-        Stats { hits: 0, ricochets: 0 },
         WeaponState { 
             current_type: WeaponType::Pistol, 
             last_fire_time: 0.0 
@@ -240,7 +232,7 @@ fn spawn_ui(mut commands: Commands) {
         .with_children(|parent| {
             // Stats Top Left
             parent.spawn((
-                Text::new("Hits: 0 | Ricochets: 0"),
+                Text::new("Shots: 0 | Hits: 0 | Ricochets: 0 | Accuracy: 0%"),
                 TextFont::from_font_size(24.0),
                 TextColor(Color::WHITE),
                 StatsText,
@@ -334,6 +326,7 @@ fn handle_shooting(
     muzzle: Single<&GlobalTransform, With<Muzzle>>,
     ballistics_assets: Res<BallisticsAssets>,
     spatial_query: SpatialQuery,
+    mut fire_events: MessageWriter<FireEvent>,
 ) {
     let (player_transform, ref mut state) = *player_query;
     let now = time.elapsed_secs();
@@ -396,10 +389,18 @@ fn handle_shooting(
             MeshMaterial3d(ballistics_assets.flash_material.clone()),
             Transform::from_translation(spawn_pos).with_scale(Vec3::splat(0.01)),
             Projectile::new(shot_dir * velocity).with_previous_position(spawn_pos),
+            // Grow for visibility: from 0.05 to 1.5 over 500 meters
+            ProjectileVisualScale {
+                near: 0.05,
+                far: 1.5,
+                far_distance: 500.0,
+            },
             ProjectileLogic::Impact,
             Payload::Kinetic { damage: 50.0 },
             accuracy, 
         ));
+        // Spawned above; the event only feeds BallisticsStats
+        fire_events.write(FireEvent::new(spawn_pos, shot_dir, velocity));
 
         // Muzzle Flash
         commands.spawn((
@@ -413,24 +414,12 @@ fn handle_shooting(
     }
 }
 
-fn scale_projectiles(
-    mut query: Query<(&mut Transform, &Projectile)>,
-) {
-    for (mut transform, projectile) in query.iter_mut() {
-        // Grow even more for visibility: from 0.05 to 1.5 over 500 meters
-        let factor = (projectile.distance_travelled / 500.0).clamp(0.0, 1.0);
-        let final_scale = 0.05 + (factor * 1.45);
-        transform.scale = Vec3::splat(final_scale);
-    }
-}
-
 fn handle_events(
     mut commands: Commands,
     ballistics_assets: Res<BallisticsAssets>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut hit_events: MessageReader<HitEvent>,
     mut ricochet_events: MessageReader<RicochetEvent>,
-    mut stats: Single<&mut Stats>,
     mut projectile_query: Query<&mut MeshMaterial3d<StandardMaterial>, With<Projectile>>,
 ) {
     let red_mat = materials.add(StandardMaterial {
@@ -451,7 +440,6 @@ fn handle_events(
         if let Ok(mut mat) = projectile_query.get_mut(hit.projectile) {
             *mat = MeshMaterial3d(red_mat.clone());
         }
-        stats.hits += 1;
 
         // Spawn hit marker sphere
         commands.spawn((
@@ -468,17 +456,22 @@ fn handle_events(
         if let Ok(mut mat) = projectile_query.get_mut(rico.projectile) {
             *mat = MeshMaterial3d(green_mat.clone());
         }
-        stats.ricochets += 1;
     }
 }
 
 fn update_ui(
-    stats: Single<&Stats>,
+    stats: Res<BallisticsStats>,
     weapon: Single<&WeaponState>,
     mut stats_text: Single<&mut Text, (With<StatsText>, Without<WeaponText>)>,
     mut weapon_text: Single<&mut Text, (With<WeaponText>, Without<StatsText>)>,
 ) {
-    stats_text.0 = format!("Hits: {} | Ricochets: {}", stats.hits, stats.ricochets);
+    stats_text.0 = format!(
+        "Shots: {} | Hits: {} | Ricochets: {} | Accuracy: {:.0}%",
+        stats.shots_fired,
+        stats.hits,
+        stats.ricochets,
+        stats.accuracy() * 100.0
+    );
 
     let name = match weapon.current_type {
         WeaponType::Pistol => "Pistol (Semi)",
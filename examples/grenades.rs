@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use bevy_bullet_dynamics::prelude::*;
-use bevy_bullet_dynamics::systems::logic::presets as grenade_presets;
+use bevy_bullet_dynamics::systems::logic::{presets as grenade_presets, ExplosionCover};
 
 fn main() {
     App::new()
@@ -106,7 +106,7 @@ fn setup(
 
     // UI
     commands.spawn((
-        Text::new("Press 1-4: Select | SPACE: Throw\n\n1: Frag\n2: Flash\n3: Smoke\n4: Molotov"),
+        Text::new("Press 1-4: Select | Hold SPACE to cook, release to throw\n\n1: Frag\n2: Flash\n3: Smoke\n4: Molotov"),
         TextFont {
             font_size: 20.0,
             ..default()
@@ -123,6 +123,7 @@ fn setup(
     // Grenade type state
     commands.insert_resource(GrenadeState {
         grenade_type: GrenadeType::Frag,
+        cook_started: None,
     });
 }
 
@@ -140,6 +141,8 @@ struct UiText;
 #[derive(Resource)]
 struct GrenadeState {
     grenade_type: GrenadeType,
+    /// Time the pin was pulled, while SPACE is held
+    cook_started: Option<f32>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -173,6 +176,7 @@ impl GrenadeType {
 fn handle_input(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
     mut grenade_state: ResMut<GrenadeState>,
     thrower: Query<&Transform, With<ThrowerMarker>>,
     assets: Res<GrenadeAssets>,
@@ -195,14 +199,27 @@ fn handle_input(
         info!("Selected: Molotov");
     }
 
-    // Throw
+    // Pull the pin: the fuse starts burning while SPACE is held
     if keyboard.just_pressed(KeyCode::Space) {
+        grenade_state.cook_started = Some(time.elapsed_secs());
+    }
+
+    // Throw on release
+    if keyboard.just_released(KeyCode::Space) {
+        let Some(cook_started) = grenade_state.cook_started.take() else {
+            return;
+        };
         let Some(thrower_transform) = thrower.iter().next() else {
             warn!("No thrower marker found!");
             return;
         };
+        let cook_time = time.elapsed_secs() - cook_started;
 
-        info!("Throwing {}", grenade_state.grenade_type.name());
+        info!(
+            "Throwing {} (cooked {:.1}s)",
+            grenade_state.grenade_type.name(),
+            cook_time
+        );
 
         let origin = thrower_transform.translation;
         let direction = Vec3::new(0.0, 0.8, -1.0).normalize();
@@ -210,6 +227,7 @@ fn handle_input(
         let velocity = direction * throw_speed;
 
         let (logic, payload) = grenade_state.grenade_type.logic_and_payload();
+        let logic = logic.with_cook_time(cook_time);
 
         let material = match grenade_state.grenade_type {
             GrenadeType::Frag => assets.frag_material.clone(),
@@ -230,20 +248,31 @@ fn handle_input(
                 diameter: 0.05,
                 spin: 0.0,
                 penetration_power: 0.0,
+                gravity_scale: 1.0,
+                penetration_count: 0,
+                ricochet_count: 0,
+                is_supersonic: false,
                 previous_position: origin,
                 age: 0.0,
                 distance_travelled: 0.0,
                 owner: None,
+                no_drag: false,
+                last_hit: None,
+                drag_regime: DragRegime::Quadratic,
+                custom_reference_area: true,
             },
             logic,
             payload,
+            // Bounce and roll to a stop before going off
+            BounceOnContact,
         ));
     }
 }
 
 fn handle_explosions(
     mut explosion_events: MessageReader<ExplosionEvent>,
-    mut targets: Query<(&Transform, &mut TargetDummy)>,
+    cover: ExplosionCover,
+    mut targets: Query<(Entity, &Transform, &mut TargetDummy, Option<&Resistances>)>,
 ) {
     use bevy_bullet_dynamics::systems::logic::calculate_explosion_damage;
 
@@ -251,14 +280,16 @@ fn handle_explosions(
         info!("Explosion detected at {:?} type {:?}", explosion.center, explosion.explosion_type);
         match explosion.explosion_type {
             ExplosionType::HighExplosive | ExplosionType::Fragmentation => {
-                for (transform, mut dummy) in targets.iter_mut() {
+                for (entity, transform, mut dummy, resistances) in targets.iter_mut() {
                     let distance = transform.translation.distance(explosion.center);
+                    // Same cover check as the blast impulse
                     let damage = calculate_explosion_damage(
                         explosion.damage,
                         distance,
                         explosion.radius,
                         explosion.falloff,
-                    );
+                    ) * cover.exposure(explosion, entity, transform.translation)
+                        * resistances.map_or(1.0, |r| r.factor_for_explosion(explosion.explosion_type));
 
                     if damage > 0.0 {
                         dummy.health -= damage;
@@ -280,7 +311,7 @@ fn update_ui(
     if grenade_state.is_changed() {
         for mut text in ui_text.iter_mut() {
             text.0 = format!(
-                "Press 1-4: Select | SPACE: Throw\n\nSelected: {}\nPress SPACE to throw",
+                "Press 1-4: Select | Hold SPACE to cook, release to throw\n\nSelected: {}",
                 grenade_state.grenade_type.name()
             );
         }
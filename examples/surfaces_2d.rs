@@ -24,6 +24,7 @@ fn main() {
             temperature: 20.0,
             altitude: 0.0,
             latitude: 0.0,
+            ..default()
         })
         .insert_resource(BallisticsConfig {
             use_rk4: true,
@@ -33,6 +34,7 @@ fn main() {
             enable_ricochet: true,
             min_projectile_speed: 20.0,
             debug_draw: true,
+            ..default()
         })
         .add_systems(Startup, setup)
         .add_systems(
@@ -41,7 +43,6 @@ fn main() {
                 player_movement,
                 player_location_rotation,
                 player_shooting,
-                handle_hits,
                 update_ui,
             ),
         )
@@ -60,14 +61,6 @@ struct Obstacle;
 #[derive(Resource)]
 struct PlayerEntity(Entity);
 
-#[derive(Resource)]
-struct GameStats {
-    shots_fired: u32,
-    penetrations: u32,
-    ricochets: u32,
-    hits: u32,
-}
-
 #[derive(Clone, Copy)]
 enum MaterialType {
     Concrete,
@@ -109,12 +102,6 @@ fn setup(mut commands: Commands) {
         .id();
 
     commands.insert_resource(PlayerEntity(player_entity));
-    commands.insert_resource(GameStats {
-        shots_fired: 0,
-        penetrations: 0,
-        ricochets: 0,
-        hits: 0,
-    });
 
     // Spawn obstacles
     spawn_obstacles(&mut commands);
@@ -256,7 +243,6 @@ fn player_shooting(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     player_query: Query<&Transform, With<Player>>,
     player_entity: Res<PlayerEntity>,
-    mut game_stats: ResMut<GameStats>,
     mut fire_events: MessageWriter<FireEvent>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Space) || keyboard_input.just_pressed(KeyCode::KeyE) {
@@ -295,32 +281,13 @@ fn player_shooting(
                 spawn_params.direction,
                 spawn_params.velocity,
             ));
-
-            game_stats.shots_fired += 1;
-        }
-    }
-}
-
-fn handle_hits(
-    mut hit_events: MessageReader<HitEvent>,
-    mut game_stats: ResMut<GameStats>,
-) {
-    for event in hit_events.read() {
-        game_stats.hits += 1;
-        
-        if event.penetrated {
-            game_stats.penetrations += 1;
-        }
-        
-        if event.ricocheted {
-            game_stats.ricochets += 1;
         }
     }
 }
 
 fn update_ui(
     mut ui_query: Query<&mut Text, With<StatsText>>,
-    game_stats: Res<GameStats>,
+    game_stats: Res<BallisticsStats>,
 ) {
     if let Some(mut text) = ui_query.iter_mut().next() {
         text.0 = format!(
@@ -221,23 +221,39 @@ mod advanced_shooting_2d_tests {
             movement_penalty: 2.0,
             ads_modifier: 0.3,
             airborne_multiplier: 3.0,
+            ..Default::default()
         };
 
         // Test with no modifiers
         let spread_normal = systems::accuracy::calculate_total_spread(
-            &accuracy, false, false, false, 0.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert_eq!(spread_normal, 0.001 + 0.002); // base + bloom
 
         // Test with ADS
         let spread_ads = systems::accuracy::calculate_total_spread(
-            &accuracy, true, false, false, 0.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                is_aiming: true,
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert!(spread_ads < spread_normal); // ADS should reduce spread
 
         // Test with movement
         let spread_moving = systems::accuracy::calculate_total_spread(
-            &accuracy, false, true, false, 5.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                is_moving: true,
+                movement_speed: 5.0,
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert!(spread_moving > spread_normal); // Moving should increase spread
     }
@@ -194,7 +194,7 @@ mod basic_shooting_2d_tests {
         let explosive_payload = Payload::Explosive {
             damage: 100.0,
             radius: 5.0,
-            falloff: 1.5,
+            falloff: FalloffCurve::Power(1.5),
         };
         let incendiary_payload = Payload::Incendiary {
             duration: 5.0,
@@ -211,7 +211,7 @@ mod basic_shooting_2d_tests {
             Payload::Explosive { damage, radius, falloff } => {
                 assert_eq!(damage, 100.0);
                 assert_eq!(radius, 5.0);
-                assert_eq!(falloff, 1.5);
+                assert_eq!(falloff, FalloffCurve::Power(1.5));
             },
             _ => panic!("Expected explosive payload"),
         }
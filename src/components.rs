@@ -2,6 +2,10 @@
 
 use bevy::prelude::*;
 
+/// Relative difference between `Projectile::reference_area` and the circle of
+/// its `diameter` above which the two are reported as inconsistent.
+pub const REFERENCE_AREA_TOLERANCE: f32 = 0.5;
+
 /// Main projectile component with physical properties.
 /// 
 /// This component represents a physical projectile in the simulation with properties
@@ -13,8 +17,16 @@ use bevy::prelude::*;
 /// * `drag_coefficient` - Dimensionless drag coefficient (typically 0.2-0.5 for bullets)
 /// * `reference_area` - Cross-sectional reference area in square meters
 /// * `penetration_power` - Energy available for penetrating materials (arbitrary units)
+/// * `gravity_scale` - Multiplier on environment gravity (0.0 = no drop, 2.0 = double drop)
+/// * `penetration_count` - Number of surfaces this projectile has passed through
+/// * `ricochet_count` - Number of times this projectile has ricocheted
+/// * `is_supersonic` - Whether the projectile was faster than sound at its last step
 /// * `previous_position` - Position in the previous frame for collision detection
 /// * `owner` - Optional entity that owns this projectile (for hit detection)
+/// * `no_drag` - Skip aerodynamics entirely and fly a pure gravity parabola
+/// * `last_hit` - Entity the projectile last ricocheted off or passed through
+/// * `drag_regime` - How drag grows with speed (quadratic for bullets)
+/// * `custom_reference_area` - `reference_area` was set explicitly, so a later diameter keeps it
 /// 
 /// # Example
 /// ```
@@ -26,7 +38,7 @@ use bevy::prelude::*;
 ///     .with_drag(0.3)
 ///     .with_owner(Entity::PLACEHOLDER);
 /// ```
-#[derive(Component, Reflect, Default, Clone)]
+#[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
 pub struct Projectile {
     /// Current velocity vector (m/s)
@@ -43,6 +55,14 @@ pub struct Projectile {
     pub spin: f32,
     /// Penetration power (arbitrary units of energy)
     pub penetration_power: f32,
+    /// Gravity multiplier (1.0 = normal drop)
+    pub gravity_scale: f32,
+    /// Surfaces penetrated so far (scales damage on later hits)
+    pub penetration_count: u32,
+    /// Ricochets so far (spent once it reaches `BallisticsConfig::max_ricochets`)
+    pub ricochet_count: u8,
+    /// Faster than the environment's speed of sound at the last kinematics step
+    pub is_supersonic: bool,
     /// Previous frame position for collision detection
     pub previous_position: Vec3,
     /// Time since spawn (seconds)
@@ -51,6 +71,38 @@ pub struct Projectile {
     pub distance_travelled: f32,
     /// Owner entity (for multiplayer hit detection)
     pub owner: Option<Entity>,
+    /// No drag or wind: flies an exact parabola (see [`Projectile::no_drag`])
+    pub no_drag: bool,
+    /// Entity last ricocheted off or passed through, if any
+    pub last_hit: Option<Entity>,
+    /// How drag grows with speed (see [`DragRegime`])
+    pub drag_regime: DragRegime,
+    /// Set by [`Projectile::with_reference_area`]; [`Projectile::with_diameter`]
+    /// then leaves the area alone
+    pub custom_reference_area: bool,
+}
+
+/// How aerodynamic drag scales with a projectile's airspeed.
+/// 
+/// Both models share the drag equation's `0.5 * ρ * Cd * A`. Linear drag equals
+/// quadratic drag at the crossover speed where the Reynolds number (from
+/// `diameter` and airspeed) reaches `REYNOLDS_CROSSOVER`; below it linear drag is
+/// the stronger, so slow rounds bleed their last bit of speed much faster.
+/// See `systems::kinematics::drag_force`.
+/// 
+/// # Variants
+/// * `Quadratic` - Drag ∝ v², right for bullets, arrows and grenades
+/// * `Linear` - Drag ∝ v, for slow, draggy projectiles such as flamethrower globs
+/// * `Mixed` - Blend weighted by the Reynolds number: linear when slow and small, quadratic when fast
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DragRegime {
+    /// Drag grows with the square of the speed
+    #[default]
+    Quadratic,
+    /// Drag grows linearly with the speed
+    Linear,
+    /// Linear and quadratic drag blended by the Reynolds number
+    Mixed,
 }
 
 impl Projectile {
@@ -76,11 +128,54 @@ impl Projectile {
             diameter: 0.01,
             spin: 0.0,
             penetration_power: 100.0,
+            gravity_scale: 1.0,
+            penetration_count: 0,
+            ricochet_count: 0,
+            is_supersonic: false,
             previous_position: Vec3::ZERO,
             age: 0.0,
             distance_travelled: 0.0,
             owner: None,
+            no_drag: false,
+            last_hit: None,
+            drag_regime: DragRegime::Quadratic,
+            custom_reference_area: false,
+        }
+    }
+
+    /// Creates a new projectile like [`Projectile::new`], rejecting a non-finite velocity.
+    /// 
+    /// # Arguments
+    /// * `velocity` - Initial velocity vector in meters per second
+    /// 
+    /// # Returns
+    /// The projectile, or [`BallisticsError::NonFiniteVelocity`](crate::types::BallisticsError)
+    pub fn try_new(velocity: Vec3) -> Result<Self, crate::types::BallisticsError> {
+        let projectile = Self::new(velocity);
+        projectile.validate()?;
+        Ok(projectile)
+    }
+
+    /// Checks the values the integrator divides by or multiplies through.
+    /// 
+    /// Call after the builder chain: `new` and the `with_*` setters accept anything.
+    /// 
+    /// # Returns
+    /// `Ok` if the velocity is finite, the mass positive and finite and the
+    /// reference area non-negative and finite, otherwise the first problem found
+    pub fn validate(&self) -> Result<(), crate::types::BallisticsError> {
+        use crate::types::BallisticsError;
+
+        if !self.velocity.is_finite() {
+            return Err(BallisticsError::NonFiniteVelocity(self.velocity));
+        }
+        if !(self.mass.is_finite() && self.mass > 0.0) {
+            return Err(BallisticsError::NonPositiveMass(self.mass));
+        }
+        if !(self.reference_area.is_finite() && self.reference_area >= 0.0) {
+            return Err(BallisticsError::NegativeReferenceArea(self.reference_area));
         }
+        Ok(())
     }
 
     /// Builder pattern: set mass
@@ -132,22 +227,140 @@ impl Projectile {
     }
 
     /// Builder pattern: set reference area
+    /// 
+    /// Overrides the area derived by [`Projectile::with_diameter`], whichever is
+    /// called first. Warns (once) if the area is far from the circle of the
+    /// current diameter, see [`Projectile::reference_area_matches_diameter`].
+    /// 
+    /// # Arguments
+    /// * `area` - Cross-sectional reference area in square meters
+    /// 
+    /// # Returns
+    /// The modified Projectile instance for method chaining
     pub fn with_reference_area(mut self, area: f32) -> Self {
         self.reference_area = area;
+        self.custom_reference_area = true;
+        if !self.reference_area_matches_diameter() {
+            warn_once!(
+                "Projectile reference_area {} m² does not match diameter {} m (circle area {} m²)",
+                area,
+                self.diameter,
+                Self::circle_area(self.diameter)
+            );
+        }
         self
     }
 
     /// Builder pattern: set diameter
+    /// 
+    /// Also sets `reference_area` to the cross-section of a round of this
+    /// diameter, `π (d/2)²`, unless it was set with [`Projectile::with_reference_area`].
+    /// 
+    /// # Arguments
+    /// * `diameter` - Projectile diameter in meters
+    /// 
+    /// # Returns
+    /// The modified Projectile instance for method chaining
     pub fn with_diameter(mut self, diameter: f32) -> Self {
         self.diameter = diameter;
+        if !self.custom_reference_area {
+            self.reference_area = Self::circle_area(diameter);
+        }
         self
     }
 
+    /// Cross-sectional area of a round projectile.
+    /// 
+    /// # Arguments
+    /// * `diameter` - Projectile diameter in meters
+    /// 
+    /// # Returns
+    /// `π (d/2)²` in square meters
+    pub fn circle_area(diameter: f32) -> f32 {
+        std::f32::consts::PI * (diameter * 0.5).powi(2)
+    }
+
+    /// Whether `reference_area` is within [`REFERENCE_AREA_TOLERANCE`] of the
+    /// circle of `diameter`.
+    /// 
+    /// A zero diameter has nothing to compare against and always matches.
+    pub fn reference_area_matches_diameter(&self) -> bool {
+        let circle = Self::circle_area(self.diameter);
+        circle <= 0.0
+            || (self.reference_area - circle).abs() <= circle * REFERENCE_AREA_TOLERANCE
+    }
+
     /// Builder pattern: set penetration power
     pub fn with_penetration(mut self, power: f32) -> Self {
         self.penetration_power = power;
         self
     }
+
+    /// Builder pattern: set gravity scale
+    /// 
+    /// Scales only the gravity term, so drag and wind are unaffected.
+    /// 
+    /// # Arguments
+    /// * `scale` - Gravity multiplier (0.0 = no drop, 2.0 = double drop)
+    /// 
+    /// # Returns
+    /// The modified Projectile instance for method chaining
+    pub fn with_gravity_scale(mut self, scale: f32) -> Self {
+        self.gravity_scale = scale;
+        self
+    }
+
+    /// Builder pattern: set drag regime
+    /// 
+    /// # Arguments
+    /// * `regime` - How drag grows with speed; `Linear` or `Mixed` for slow, draggy projectiles
+    /// 
+    /// # Returns
+    /// The modified Projectile instance for method chaining
+    pub fn with_drag_regime(mut self, regime: DragRegime) -> Self {
+        self.drag_regime = regime;
+        self
+    }
+
+    /// Builder pattern: disable drag for arcade ballistics
+    /// 
+    /// Kinematics skips the drag computation entirely and steps the projectile
+    /// along the closed-form parabola `p0 + v0*t + 0.5*g*t²`. Wind has no effect,
+    /// since it only acts through drag; `gravity_scale` still applies.
+    /// 
+    /// # Returns
+    /// The modified Projectile instance for method chaining
+    pub fn no_drag(mut self) -> Self {
+        self.no_drag = true;
+        self
+    }
+}
+
+impl Default for Projectile {
+    /// Creates a zeroed projectile with normal gravity.
+    fn default() -> Self {
+        Self {
+            velocity: Vec3::ZERO,
+            mass: 0.0,
+            drag_coefficient: 0.0,
+            reference_area: 0.0,
+            diameter: 0.0,
+            spin: 0.0,
+            penetration_power: 0.0,
+            gravity_scale: 1.0,
+            penetration_count: 0,
+            ricochet_count: 0,
+            is_supersonic: false,
+            previous_position: Vec3::ZERO,
+            age: 0.0,
+            distance_travelled: 0.0,
+            owner: None,
+            no_drag: false,
+            last_hit: None,
+            drag_regime: DragRegime::Quadratic,
+            custom_reference_area: false,
+        }
+    }
 }
 
 /// Accuracy component for dynamic spread calculation.
@@ -164,6 +377,18 @@ impl Projectile {
 /// * `movement_penalty` - Multiplier applied when moving
 /// * `ads_modifier` - Modifier when aiming down sights (0.2 = 80% reduction)
 /// * `airborne_multiplier` - Multiplier when airborne
+/// * `recovery_delay` - Settle time after a shot before bloom starts recovering, in seconds
+/// * `time_since_last_shot` - Time elapsed since the last shot, in seconds
+/// * `ads_settle_time` - Time ADS must be held to reach the full `ads_modifier`, in seconds
+/// * `time_aiming` - Time ADS has been held continuously, in seconds
+/// * `release_recovery_boost` - Recovery rate multiplier right after the trigger is released
+/// * `release_boost_window` - Seconds over which the release boost fades back to 1
+/// * `continuous_fire_time` - Time the trigger has been held, in seconds
+/// * `time_since_release` - Time since the trigger was last released, in seconds
+/// 
+/// The trigger fields only change through
+/// [`track_trigger`](crate::systems::accuracy::track_trigger); weapons that never
+/// report their trigger recover at the plain `recovery_rate`.
 /// 
 /// # Example
 /// ```
@@ -191,6 +416,22 @@ pub struct Accuracy {
     pub ads_modifier: f32,
     /// Airborne penalty multiplier
     pub airborne_multiplier: f32,
+    /// Delay after a shot before bloom recovery starts (seconds)
+    pub recovery_delay: f32,
+    /// Time since the last shot (seconds)
+    pub time_since_last_shot: f32,
+    /// Time ADS must be held before `ads_modifier` fully applies (seconds, 0 = instant)
+    pub ads_settle_time: f32,
+    /// Time ADS has been held without interruption (seconds)
+    pub time_aiming: f32,
+    /// Recovery rate multiplier right after a trigger release (1.0 = no boost)
+    pub release_recovery_boost: f32,
+    /// Time for the release boost to fade back to 1.0 (seconds, 0 = no boost)
+    pub release_boost_window: f32,
+    /// Time the trigger has been held without interruption (seconds)
+    pub continuous_fire_time: f32,
+    /// Time since the trigger was last released (seconds)
+    pub time_since_release: f32,
 }
 
 impl Default for Accuracy {
@@ -204,6 +445,9 @@ impl Default for Accuracy {
     /// - 2.0x movement penalty
     /// - 0.3x ADS modifier (70% accuracy improvement)
     /// - 3.0x airborne penalty
+    /// - No recovery delay
+    /// - Instant ADS settle
+    /// - 2.0x recovery after a trigger release, fading over 0.3 s
     /// 
     /// # Returns
     /// A new Accuracy instance with default values
@@ -217,10 +461,29 @@ impl Default for Accuracy {
             movement_penalty: 2.0,
             ads_modifier: 0.3,
             airborne_multiplier: 3.0,
+            recovery_delay: 0.0,
+            time_since_last_shot: 0.0,
+            ads_settle_time: 0.0,
+            time_aiming: 0.0,
+            release_recovery_boost: 2.0,
+            release_boost_window: 0.3,
+            continuous_fire_time: 0.0,
+            time_since_release: f32::INFINITY,
         }
     }
 }
 
+impl Accuracy {
+    /// Drop accumulated bloom, e.g. when a weapon is equipped.
+    ///
+    /// Only `current_bloom` is cleared; the tunable values are left as they are.
+    /// Nothing calls this on its own: call it, or send a
+    /// [`WeaponEquippedEvent`](crate::events::WeaponEquippedEvent).
+    pub fn reset_bloom(&mut self) {
+        self.current_bloom = 0.0;
+    }
+}
+
 /// Projectile behavior logic type.
 /// 
 /// Defines how a projectile behaves when it interacts with the environment.
@@ -262,11 +525,48 @@ pub enum ProjectileLogic {
     Hitscan {
         /// Maximum range (meters)
         range: f32,
+        /// Beam radius (meters); above zero the trace is a sphere cast, so
+        /// targets within this distance of the center line are hit too
+        beam_radius: f32,
     },
     /// Arrow/bolt: sticks on impact
     Sticky,
 }
 
+impl ProjectileLogic {
+    /// Builder pattern: cook a timed fuse before the throw.
+    /// 
+    /// Starts the fuse `cook_time` seconds in, as if it had been burning while the
+    /// grenade was held. A fuse cooked past its length goes off on the first logic
+    /// step after spawning. Other variants are returned unchanged.
+    /// 
+    /// # Example
+    /// ```
+    /// use bevy_bullet_dynamics::systems::logic::presets;
+    /// 
+    /// let (logic, _payload) = presets::frag_grenade();
+    /// let cooked = logic.with_cook_time(2.0);
+    /// assert_eq!(cooked.fuse_remaining(), Some(1.0));
+    /// ```
+    pub fn with_cook_time(self, cook_time: f32) -> Self {
+        match self {
+            Self::Timed { fuse, elapsed } => Self::Timed {
+                fuse,
+                elapsed: elapsed + cook_time.max(0.0),
+            },
+            other => other,
+        }
+    }
+
+    /// Seconds left on a timed fuse (zero once it has burned down), or None for other variants.
+    pub fn fuse_remaining(&self) -> Option<f32> {
+        match self {
+            Self::Timed { fuse, elapsed } => Some((fuse - elapsed).max(0.0)),
+            _ => None,
+        }
+    }
+}
+
 impl Default for ProjectileLogic {
     /// Creates a default ProjectileLogic instance with Impact behavior.
     /// 
@@ -277,6 +577,270 @@ impl Default for ProjectileLogic {
     }
 }
 
+/// Marker for a round that was spent on impact and kept as debris or pooled.
+/// 
+/// See [`SpentProjectileBehavior`](crate::resources::SpentProjectileBehavior).
+/// 
+/// # Fields
+/// * `elapsed` - Time since the round was spent (seconds)
+/// * `pooled` - Whether the round is hidden in the pool rather than dropped as debris
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct SpentProjectile {
+    /// Time since the round was spent (seconds)
+    pub elapsed: f32,
+    /// Hidden in the pool rather than dropped as debris
+    pub pooled: bool,
+}
+
+/// Opt-in bounce-and-roll mode for thrown grenades.
+/// 
+/// On its first surface contact the projectile is handed to avian as a dynamic
+/// rigid body carrying on with its velocity, with restitution and friction taken
+/// from the surface it struck. It tumbles and settles while its fuse keeps
+/// burning, and explodes wherever it came to rest. Meant for
+/// `ProjectileLogic::Timed` rounds; the contact itself sends no `HitEvent`.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct BounceOnContact;
+
+/// Arcade piercing: the round passes through a fixed number of entities.
+/// 
+/// Hits on a round with this component skip the material penetration and
+/// ricochet checks. While `remaining` is above zero each new entity hit is
+/// passed through at full speed and counted down; the hit after that stops
+/// the round. Entities already pierced are never hit again.
+/// 
+/// # Fields
+/// * `remaining` - Entities the round can still pass through
+/// * `pierced` - Entities already passed through (managed by the collision system)
+#[derive(Component, Reflect, Clone, Default, Debug)]
+#[reflect(Component)]
+pub struct Pierce {
+    /// Entities the round can still pass through
+    pub remaining: u32,
+    /// Entities already passed through
+    pub pierced: Vec<Entity>,
+}
+
+impl Pierce {
+    /// Create a round that pierces `count` entities and stops in the next one.
+    pub fn new(count: u32) -> Self {
+        Self {
+            remaining: count,
+            pierced: Vec::new(),
+        }
+    }
+
+    /// Whether `entity` was already passed through.
+    pub fn has_pierced(&self, entity: Entity) -> bool {
+        self.pierced.contains(&entity)
+    }
+}
+
+/// Minimum flight distance before a round can trigger or stick.
+///
+/// A round that strikes something closer than this to where it was fired is a
+/// dud for that contact: it glances off without a `HitEvent`, so contact fuses
+/// and sticky rounds don't go off against the shooter's own geometry. It arms
+/// as soon as it has flown far enough, including after glancing off. Without
+/// this component (or at 0) rounds are armed from the muzzle.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct ArmingDistance(pub f32);
+
+impl ArmingDistance {
+    /// Whether a round that has flown `distance` meters is armed.
+    pub fn is_armed(&self, distance: f32) -> bool {
+        distance >= self.0
+    }
+}
+
+/// Custom logic run when a projectile strikes something.
+///
+/// An escape hatch for behavior the [`Payload`] variants don't cover (portals,
+/// healing, teleports). The collision system calls it with the `HitEvent` it is
+/// about to send, before the round is retired or despawned, so commands it
+/// queues against `hit.projectile` still find the round. Sensor crossings and
+/// dud glances (see [`ArmingDistance`]) don't call it.
+///
+/// The callback is shared behind an `Arc` and must be `Send + Sync + 'static`:
+/// capture owned or shared (`Arc`) data, never borrows. It is not reflected or
+/// replicated.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::OnHitCallback;
+///
+/// #[derive(Component)]
+/// struct Healed;
+///
+/// let heal = OnHitCallback::new(|hit, commands| {
+///     commands.entity(hit.target).insert(Healed);
+/// });
+/// ```
+#[derive(Component, Clone)]
+pub struct OnHitCallback(pub std::sync::Arc<HitCallbackFn>);
+
+/// Signature of an [`OnHitCallback`].
+pub type HitCallbackFn = dyn Fn(&crate::events::HitEvent, &mut Commands) + Send + Sync;
+
+impl OnHitCallback {
+    /// Wrap `callback`.
+    pub fn new(
+        callback: impl Fn(&crate::events::HitEvent, &mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        Self(std::sync::Arc::new(callback))
+    }
+
+    /// Run the callback for `hit`.
+    pub fn call(&self, hit: &crate::events::HitEvent, commands: &mut Commands) {
+        (self.0)(hit, commands)
+    }
+}
+
+impl std::fmt::Debug for OnHitCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OnHitCallback(..)")
+    }
+}
+
+/// Ammunition loaded for a shot, selecting the tracer look.
+///
+/// Rounds fired with [`FireEvent::with_ammo`](crate::events::FireEvent::with_ammo)
+/// carry it as a component. Without one the tracer comes from the weapon's
+/// settings alone (see `tracer_config::for_ammo`).
+///
+/// # Variants
+/// * `Ball` - Standard full metal jacket, yellow-orange trace
+/// * `ArmorPiercing` - Hardened core, thin green trace
+/// * `Tracer` - Dedicated tracer round, bright red and wide
+/// * `Incendiary` - Burning compound, orange trace; see [`AmmoType::incendiary_payload`]
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum AmmoType {
+    #[default]
+    Ball,
+    ArmorPiercing,
+    Tracer,
+    Incendiary,
+}
+
+impl AmmoType {
+    /// Tracer color for this ammunition.
+    pub fn tracer_color(&self) -> Color {
+        match self {
+            Self::Ball => Color::srgb(1.0, 0.8, 0.2),
+            Self::ArmorPiercing => Color::srgb(0.3, 1.0, 0.3),
+            Self::Tracer => Color::srgb(1.0, 0.15, 0.1),
+            Self::Incendiary => Color::srgb(1.0, 0.5, 0.0),
+        }
+    }
+
+    /// Tracer width for this ammunition, in meters.
+    pub fn tracer_width(&self) -> f32 {
+        match self {
+            Self::Ball => 0.015,
+            Self::ArmorPiercing => 0.01,
+            Self::Tracer => 0.025,
+            Self::Incendiary => 0.02,
+        }
+    }
+
+    /// Burning payload for incendiary rounds.
+    ///
+    /// Nothing attaches it automatically: insert it in place of the kinetic
+    /// payload if the round should set fire to what it hits.
+    ///
+    /// # Returns
+    /// A small `Payload::Incendiary` for `Incendiary`, None for other ammunition
+    pub fn incendiary_payload(&self) -> Option<Payload> {
+        match self {
+            Self::Incendiary => Some(Payload::Incendiary {
+                duration: 3.0,
+                damage_per_second: 5.0,
+                radius: 0.5,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// How explosion damage and impulse fall off between the center and the edge.
+/// 
+/// Every curve gives 1.0 at the center, 0.0 at or beyond the explosion radius
+/// and never increases with distance.
+/// 
+/// # Variants
+/// * `Power` - `(1 - d/r)^exponent`; 1.0 is linear, higher drops off faster
+/// * `Linear` - Straight line from full at the center to nothing at the edge
+/// * `InverseSquare` - Steep near the center and a long tail, rescaled to hit 0 at the edge
+/// * `Plateau` - Full up to `full_radius`, then linear down to nothing at
+///   `edge_radius` (a step when the two are equal)
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum FalloffCurve {
+    /// `(1 - d/r)^exponent`
+    Power(f32),
+    /// `1 - d/r`
+    Linear,
+    /// Inverse-square of distance, normalized to the radius
+    InverseSquare,
+    /// Full damage out to `full_radius`, none past `edge_radius` (meters)
+    Plateau { full_radius: f32, edge_radius: f32 },
+}
+
+impl Default for FalloffCurve {
+    /// default: linear power falloff
+    fn default() -> Self {
+        Self::Power(1.0)
+    }
+}
+
+impl FalloffCurve {
+    /// Steepness of the inverse-square curve: before rescaling it is down to 1/17 at the edge.
+    const INVERSE_SQUARE_SCALE: f32 = 4.0;
+
+    /// Fraction of full strength at `distance` from an explosion of `radius`.
+    /// 
+    /// # Arguments
+    /// * `distance` - Distance from the explosion center
+    /// * `radius` - Explosion radius
+    /// 
+    /// # Returns
+    /// A factor between 0 and 1
+    pub fn factor(&self, distance: f32, radius: f32) -> f32 {
+        if distance >= radius {
+            return 0.0;
+        }
+        let normalized = (distance / radius).max(0.0);
+        let factor = match *self {
+            Self::Power(exponent) => (1.0 - normalized).powf(exponent.max(0.0)),
+            Self::Linear => 1.0 - normalized,
+            Self::InverseSquare => {
+                let curve = |x: f32| 1.0 / (1.0 + (Self::INVERSE_SQUARE_SCALE * x).powi(2));
+                let edge = curve(1.0);
+                (curve(normalized) - edge) / (1.0 - edge)
+            }
+            Self::Plateau { full_radius, edge_radius } => {
+                if distance <= full_radius {
+                    1.0
+                } else if distance >= edge_radius {
+                    0.0
+                } else {
+                    1.0 - (distance - full_radius) / (edge_radius - full_radius)
+                }
+            }
+        };
+        factor.clamp(0.0, 1.0)
+    }
+
+    /// Damage at `distance` from an explosion of `radius` dealing `base_damage` at the center.
+    pub fn damage(&self, base_damage: f32, distance: f32, radius: f32) -> f32 {
+        base_damage * self.factor(distance, radius)
+    }
+}
+
 /// Payload type determining what happens when projectile triggers.
 /// 
 /// Defines the type of damage or effect a projectile delivers upon impact or detonation.
@@ -288,15 +852,16 @@ impl Default for ProjectileLogic {
 /// * `Incendiary` - Creates burning area that damages over time
 /// * `Flash` - Creates visual impairment effect (flashbangs)
 /// * `Smoke` - Creates obscuring smoke screen
+/// * `Cluster` - Air-bursts into submunitions carrying their own payload
 /// 
 /// # Example
 /// ```
-/// use bevy_bullet_dynamics::components::Payload;
+/// use bevy_bullet_dynamics::components::{FalloffCurve, Payload};
 /// 
 /// let explosive_payload = Payload::Explosive {
 ///     damage: 100.0,
 ///     radius: 5.0,
-///     falloff: 1.5,
+///     falloff: FalloffCurve::Power(1.5),
 /// };
 /// ```
 #[derive(Component, Reflect, Clone)]
@@ -308,7 +873,7 @@ pub enum Payload {
     Explosive {
         damage: f32,
         radius: f32,
-        falloff: f32,
+        falloff: FalloffCurve,
     },
     /// Incendiary: creates burning area
     Incendiary {
@@ -327,8 +892,201 @@ pub enum Payload {
         duration: f32,
         radius: f32,
     },
+    /// Cluster: releases submunitions when descending below `deploy_altitude`
+    /// (or when its fuse fires). Submunitions use `Timed` logic when
+    /// `submunition_fuse` is set, `Impact` otherwise.
+    Cluster {
+        submunition_count: u32,
+        #[reflect(ignore)]
+        submunition: Box<Payload>,
+        deploy_altitude: f32,
+        submunition_fuse: Option<f32>,
+    },
+}
+
+impl Payload {
+    /// Radius of the area the payload affects when it goes off (meters).
+    /// 
+    /// Zero for kinetic payloads; clusters report the radius of one submunition.
+    pub fn radius(&self) -> f32 {
+        match self {
+            Self::Kinetic { .. } => 0.0,
+            Self::Explosive { radius, .. }
+            | Self::Incendiary { radius, .. }
+            | Self::Flash { radius, .. }
+            | Self::Smoke { radius, .. } => *radius,
+            Self::Cluster { submunition, .. } => submunition.radius(),
+        }
+    }
+}
+
+/// Shards thrown out when a timed or proximity-fused projectile detonates.
+///
+/// Goes next to the `Payload`. Shards start evenly spread over a sphere and
+/// are then jittered: each direction tilts by up to `direction_jitter` and each
+/// speed varies by up to ±`speed_jitter`. The jitter is drawn from the parent's
+/// `NetProjectile::spread_seed` when it has one, so every peer sees the same
+/// pattern. Exactly `count` shards are spawned, each a kinetic `Impact` round
+/// carrying a [`FragmentGrace`] for its first `grace_period` seconds.
+///
+/// # Fields
+/// * `count` - Number of shards
+/// * `speed` - Nominal shard speed in m/s
+/// * `mass` - Shard mass in kilograms
+/// * `damage` - Kinetic damage per shard
+/// * `direction_jitter` - Maximum tilt off the even pattern, in radians
+/// * `speed_jitter` - Maximum relative speed change (0.2 = ±20%)
+/// * `grace_period` - Seconds during which shards ignore each other and what the parent last hit
+///
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::Fragmentation;
+///
+/// let shards = Fragmentation::new(48, 900.0).with_jitter(0.2, 0.3);
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct Fragmentation {
+    /// Number of shards
+    pub count: u32,
+    /// Nominal shard speed (m/s)
+    pub speed: f32,
+    /// Shard mass (kg)
+    pub mass: f32,
+    /// Kinetic damage per shard
+    pub damage: f32,
+    /// Maximum tilt off the even pattern (radians)
+    pub direction_jitter: f32,
+    /// Maximum relative speed change
+    pub speed_jitter: f32,
+    /// Seconds during which shards ignore their siblings and the parent's last hit
+    pub grace_period: f32,
+}
+
+impl Default for Fragmentation {
+    /// default: 32 two-gram shards at 900 m/s, 25 damage, 0.15 rad and ±20% jitter, 50 ms grace
+    fn default() -> Self {
+        Self {
+            count: 32,
+            speed: 900.0,
+            mass: 0.002,
+            damage: 25.0,
+            direction_jitter: 0.15,
+            speed_jitter: 0.2,
+            grace_period: 0.05,
+        }
+    }
+}
+
+impl Fragmentation {
+    /// Create `count` shards flying at `speed`.
+    pub fn new(count: u32, speed: f32) -> Self {
+        Self {
+            count,
+            speed,
+            ..Default::default()
+        }
+    }
+
+    /// Set the shard mass.
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Set the kinetic damage per shard.
+    pub fn with_damage(mut self, damage: f32) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    /// Set the direction (radians) and relative speed jitter; zeros give a perfectly even sphere.
+    pub fn with_jitter(mut self, direction_jitter: f32, speed_jitter: f32) -> Self {
+        self.direction_jitter = direction_jitter;
+        self.speed_jitter = speed_jitter;
+        self
+    }
+
+    /// Set how long (seconds) shards ignore their siblings; zero turns it off.
+    pub fn with_grace_period(mut self, grace_period: f32) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+}
+
+/// Short collision grace of a freshly spawned fragmentation shard.
+///
+/// Added by `spawn_fragments`. While the shard's age is within `window`, its
+/// sweeps skip sibling shards (those with the same `burst`) and `ignore`, the
+/// entity the parent last hit, so shards with colliders don't pile up hits on
+/// each other or on the surface the parent burst against. Every other entity is
+/// hit as usual.
+///
+/// # Fields
+/// * `burst` - The detonated projectile, shared by every shard of one burst
+/// * `ignore` - Entity the parent last hit, if any
+/// * `window` - Shard age (seconds) up to which the grace applies
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct FragmentGrace {
+    /// The detonated projectile
+    pub burst: Entity,
+    /// Entity the parent last hit
+    pub ignore: Option<Entity>,
+    /// Shard age (seconds) up to which the grace applies
+    pub window: f32,
+}
+
+impl FragmentGrace {
+    /// Whether the grace still applies to a shard of this age.
+    pub fn is_active(&self, age: f32) -> bool {
+        age <= self.window
+    }
+
+    /// Whether a shard of this age skips `target`.
+    ///
+    /// # Arguments
+    /// * `age` - Age of the shard carrying this grace (seconds)
+    /// * `target` - Entity the shard's sweep touched
+    /// * `target_grace` - The target's own `FragmentGrace`, if it is a shard
+    pub fn ignores(&self, age: f32, target: Entity, target_grace: Option<&FragmentGrace>) -> bool {
+        self.is_active(age)
+            && (self.ignore == Some(target)
+                || target_grace.is_some_and(|grace| grace.burst == self.burst))
+    }
 }
 
+/// Surface a [`BounceOnContact`] round first struck before avian took it over.
+///
+/// Added by `hand_off_to_physics`, which removes the round's `Projectile` and
+/// with it `Projectile::last_hit`. When the grenade bursts, its shards'
+/// [`FragmentGrace`] ignores this entity in place of the parent's last hit.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct BounceSurface(pub Entity);
+
+/// Weapon sight with a dialable zero.
+///
+/// The sight sits above the bore, so the barrel has to be pitched up for the shot
+/// to meet the line of sight at `current_zero`. Use [`Sight::cycle_up`] /
+/// [`Sight::cycle_down`] to step through the presets and [`Sight::apply_zero`]
+/// to write the matching pitch into a [`WeaponZeroing`].
+///
+/// # Fields
+/// * `height_over_bore` - Height of the sight above the bore axis (meters)
+/// * `current_zero` - Currently dialed zero distance (meters)
+/// * `zero_presets` - Available zero distances in ascending order (meters)
+/// * `wrap_presets` - Whether cycling past the last/first preset wraps around instead of clamping
+///
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::Sight;
+///
+/// let mut sight = Sight::default().with_zero_presets(vec![300.0, 100.0, 200.0]);
+/// sight.current_zero = 100.0;
+/// sight.cycle_up();
+/// assert_eq!(sight.current_zero, 200.0);
+/// ```
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
 pub struct Sight {
@@ -336,8 +1094,10 @@ pub struct Sight {
     pub height_over_bore: f32,
     /// Current zero distance (meters)
     pub current_zero: f32,
-    /// Available zero presets (meters)
+    /// Available zero presets (meters, ascending)
     pub zero_presets: Vec<f32>,
+    /// Wrap around when cycling past either end (clamps when false)
+    pub wrap_presets: bool,
 }
 
 impl Default for Sight {
@@ -346,8 +1106,102 @@ impl Default for Sight {
             height_over_bore: 0.05, // 5cm
             current_zero: 100.0,    // 100m default
             zero_presets: vec![50.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 800.0, 1000.0],
+            wrap_presets: false,
+        }
+    }
+}
+
+impl Sight {
+    /// Replace the zero presets, sorting them and dropping duplicates.
+    pub fn with_zero_presets(mut self, mut presets: Vec<f32>) -> Self {
+        presets.sort_by(f32::total_cmp);
+        presets.dedup();
+        self.zero_presets = presets;
+        self
+    }
+
+    /// Set whether cycling wraps around at the ends of the preset list.
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap_presets = wrap;
+        self
+    }
+
+    /// Dial the next farther preset.
+    ///
+    /// At the farthest preset this stays put, or wraps to the nearest one if
+    /// `wrap_presets` is set.
+    pub fn cycle_up(&mut self) {
+        let current = self.current_zero;
+        let next = self
+            .zero_presets
+            .iter()
+            .copied()
+            .filter(|&z| z > current)
+            .min_by(f32::total_cmp);
+
+        match next {
+            Some(zero) => self.current_zero = zero,
+            None if self.wrap_presets => {
+                if let Some(first) = self.zero_presets.iter().copied().min_by(f32::total_cmp) {
+                    self.current_zero = first;
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Dial the next nearer preset.
+    ///
+    /// At the nearest preset this stays put, or wraps to the farthest one if
+    /// `wrap_presets` is set.
+    pub fn cycle_down(&mut self) {
+        let current = self.current_zero;
+        let prev = self
+            .zero_presets
+            .iter()
+            .copied()
+            .filter(|&z| z < current)
+            .max_by(f32::total_cmp);
+
+        match prev {
+            Some(zero) => self.current_zero = zero,
+            None if self.wrap_presets => {
+                if let Some(last) = self.zero_presets.iter().copied().max_by(f32::total_cmp) {
+                    self.current_zero = last;
+                }
+            }
+            None => {}
         }
     }
+
+    /// Update `zeroing` for the currently dialed zero.
+    ///
+    /// Solves the bore elevation with [`compute_zero_pitch`](crate::systems::trajectory::compute_zero_pitch).
+    /// If the round can't reach the zero distance the previous pitch is kept. The
+    /// inputs are recorded on the zeroing so it follows later environment changes.
+    ///
+    /// # Arguments
+    /// * `zeroing` - Weapon zeroing to update
+    /// * `muzzle_velocity` - Launch speed in meters per second
+    /// * `projectile` - Projectile physical properties (velocity is ignored)
+    /// * `env` - Reference to the ballistics environment
+    /// * `config` - Reference to the ballistics configuration
+    pub fn apply_zero(
+        &self,
+        zeroing: &mut WeaponZeroing,
+        muzzle_velocity: f32,
+        projectile: &Projectile,
+        env: &crate::resources::BallisticsEnvironment,
+        config: &crate::resources::BallisticsConfig,
+    ) {
+        zeroing.distance = self.current_zero;
+        zeroing.solution = Some(ZeroSolution {
+            muzzle_velocity,
+            sight_height: self.height_over_bore,
+            projectile: projectile.clone(),
+        });
+        zeroing.rezero(env, config);
+    }
 }
 
 /// Network identity for replication
@@ -384,8 +1238,9 @@ impl Default for Payload {
 /// 
 /// # Fields
 /// * `ricochet_angle` - Threshold angle in radians from surface normal for ricochet
-/// * `penetration_loss` - Amount of energy lost when penetrating (affects penetration chance)
-/// * `thickness` - Thickness of the material in meters (affects penetration difficulty)
+/// * `penetration_loss` - Energy lost per `REFERENCE_THICKNESS` (5 cm) of material penetrated
+/// * `thickness` - Thickness of the material in meters; resistance grows with it
+///   (see [`SurfaceMaterial::penetration_resistance`])
 /// * `hit_effect` - Type of visual effect to show on impact
 /// 
 /// # Example
@@ -404,7 +1259,7 @@ impl Default for Payload {
 pub struct SurfaceMaterial {
     /// Ricochet threshold angle (radians from normal)
     pub ricochet_angle: f32,
-    /// Penetration resistance (energy loss)
+    /// Penetration resistance (energy loss per 5 cm of material)
     pub penetration_loss: f32,
     /// Thickness (meters)
     pub thickness: f32,
@@ -433,7 +1288,181 @@ impl Default for SurfaceMaterial {
     }
 }
 
-/// Types of visual effects on hit.
+impl SurfaceMaterial {
+    /// Depth of material `penetration_loss` is quoted for (meters).
+    pub const REFERENCE_THICKNESS: f32 = 0.05;
+
+    /// Energy a round loses passing through `depth` meters of this material.
+    /// 
+    /// # Arguments
+    /// * `depth` - Distance travelled through the material (meters)
+    /// 
+    /// # Returns
+    /// `penetration_loss` scaled by `depth / REFERENCE_THICKNESS`
+    pub fn resistance_through(&self, depth: f32) -> f32 {
+        self.penetration_loss * depth.max(0.0) / Self::REFERENCE_THICKNESS
+    }
+
+    /// Energy a round needs to pass through the whole layer.
+    /// 
+    /// A 2 m wall resists a hundred times more than a 2 cm one of the same material.
+    pub fn penetration_resistance(&self) -> f32 {
+        self.resistance_through(self.thickness)
+    }
+
+    /// Bounciness of the surface for objects rolling on it (0 = dead, 1 = elastic),
+    /// inferred from its hit effect.
+    pub fn restitution(&self) -> f32 {
+        match self.hit_effect {
+            HitEffectType::Sparks => 0.45,
+            HitEffectType::Glass => 0.4,
+            HitEffectType::WoodChips => 0.35,
+            HitEffectType::Dust => 0.25,
+            HitEffectType::Blood => 0.1,
+            HitEffectType::Water => 0.0,
+        }
+    }
+
+    /// Friction coefficient of the surface for objects rolling on it,
+    /// inferred from its hit effect.
+    pub fn friction(&self) -> f32 {
+        match self.hit_effect {
+            HitEffectType::Sparks => 0.35,
+            HitEffectType::Glass => 0.2,
+            HitEffectType::WoodChips => 0.6,
+            HitEffectType::Dust => 0.8,
+            HitEffectType::Blood => 0.9,
+            HitEffectType::Water => 1.0,
+        }
+    }
+}
+
+/// Largest obliquity (radians from the normal) considered when sloping armor.
+/// Caps the `1/cos` growth of the effective rating near grazing angles.
+pub const ARMOR_MAX_OBLIQUITY: f32 = 1.4835; // 85 degrees
+
+/// Armor on a hit entity, independent of its cosmetic `SurfaceMaterial`.
+/// 
+/// When present, the penetration check uses the armor instead of the surface's
+/// `penetration_loss`, so a thin steel plate and a thick concrete wall can be
+/// tuned separately. Sloped armor presents more line-of-sight thickness: the
+/// effective rating is `rating / cos(angle)`, where `angle` is the impact angle
+/// plus `slope`.
+/// 
+/// # Fields
+/// * `rating` - Head-on resistance, in the same units as `SurfaceMaterial::penetration_loss`
+/// * `slope` - Plate slope in radians not represented by the collider, added to the impact angle
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct Armor {
+    /// Head-on penetration resistance
+    pub rating: f32,
+    /// Extra slope of the plate (radians)
+    pub slope: f32,
+}
+
+impl Armor {
+    /// Creates flat armor with the given head-on rating.
+    pub fn new(rating: f32) -> Self {
+        Self { rating, slope: 0.0 }
+    }
+
+    /// Sets the slope of the plate (radians).
+    pub fn with_slope(mut self, slope: f32) -> Self {
+        self.slope = slope;
+        self
+    }
+
+    /// Resistance offered to a round striking at `impact_angle`.
+    /// 
+    /// # Arguments
+    /// * `impact_angle` - Angle between the round and the surface normal (radians)
+    /// 
+    /// # Returns
+    /// `rating / cos(impact_angle + slope)`, with the angle capped at `ARMOR_MAX_OBLIQUITY`
+    pub fn effective_rating(&self, impact_angle: f32) -> f32 {
+        let obliquity = (impact_angle + self.slope).clamp(0.0, ARMOR_MAX_OBLIQUITY);
+        self.rating / obliquity.cos()
+    }
+}
+
+/// Per-damage-type multipliers on the damage an entity takes.
+/// 
+/// Direct hits are scaled by the multiplier matching the round's [`Payload`]
+/// before the `HitEvent` is sent, so `HitEvent::damage` is what the target
+/// should lose. Explosion damage is applied by game code, which should multiply
+/// it by [`Resistances::factor_for_explosion`]. Entities without the component
+/// take full damage.
+/// 
+/// # Fields
+/// * `kinetic` - Multiplier on bullet, shard and arrow damage (1 = full, 0 = immune)
+/// * `explosive` - Multiplier on blast damage
+/// * `incendiary` - Multiplier on fire damage
+/// 
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::Resistances;
+/// 
+/// // Body armor: stops most of a bullet, none of a blast
+/// let vest = Resistances {
+///     kinetic: 0.3,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct Resistances {
+    /// Multiplier on kinetic damage
+    pub kinetic: f32,
+    /// Multiplier on explosive damage
+    pub explosive: f32,
+    /// Multiplier on incendiary damage
+    pub incendiary: f32,
+}
+
+impl Default for Resistances {
+    /// default: every multiplier 1.0, i.e. no resistance
+    fn default() -> Self {
+        Self {
+            kinetic: 1.0,
+            explosive: 1.0,
+            incendiary: 1.0,
+        }
+    }
+}
+
+impl Resistances {
+    /// Multiplier for a direct hit by a round carrying `payload`.
+    /// 
+    /// Rounds without a payload count as kinetic, clusters use their
+    /// submunition's type, and flash and smoke payloads are not resisted.
+    pub fn factor_for_payload(&self, payload: Option<&Payload>) -> f32 {
+        match payload {
+            None | Some(Payload::Kinetic { .. }) => self.kinetic,
+            Some(Payload::Explosive { .. }) => self.explosive,
+            Some(Payload::Incendiary { .. }) => self.incendiary,
+            Some(Payload::Flash { .. } | Payload::Smoke { .. }) => 1.0,
+            Some(Payload::Cluster { submunition, .. }) => self.factor_for_payload(Some(submunition)),
+        }
+    }
+
+    /// Multiplier for damage from an explosion of this type.
+    /// 
+    /// High-explosive, fragmentation and concussion blasts use `explosive`,
+    /// incendiary ones `incendiary`; the rest are not resisted.
+    pub fn factor_for_explosion(&self, explosion_type: crate::events::ExplosionType) -> f32 {
+        use crate::events::ExplosionType;
+        match explosion_type {
+            ExplosionType::HighExplosive
+            | ExplosionType::Fragmentation
+            | ExplosionType::Concussion => self.explosive,
+            ExplosionType::Incendiary => self.incendiary,
+            ExplosionType::Flash | ExplosionType::Smoke | ExplosionType::EMP => 1.0,
+        }
+    }
+}
+
+/// Types of visual effects on hit.
 /// 
 /// Defines the type of visual effect to display when a projectile impacts a surface.
 /// Different variants represent different materials and their corresponding effects.
@@ -477,15 +1506,20 @@ pub enum HitEffectType {
 /// # Fields
 /// * `lifetime` - Remaining lifetime in seconds before the tracer disappears
 /// * `trail_length` - Length of the tracer's visual trail
+/// * `max_lifetime` - Lifetime the tracer started with, used to place it on the ramp
+/// * `ramp` - Optional color ramp applied to the tracer's own material as it burns out
 /// 
 /// # Example
 /// ```
-/// use bevy_bullet_dynamics::components::BulletTracer;
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::{BulletTracer, TracerRamp};
 /// 
 /// let tracer = BulletTracer {
 ///     lifetime: 2.0,
 ///     trail_length: 1.5,
-/// };
+///     ..Default::default()
+/// }
+/// .with_ramp(TracerRamp::burnout(LinearRgba::rgb(5.0, 3.0, 0.5)));
 /// ```
 #[derive(Component, Default)]
 pub struct BulletTracer {
@@ -493,6 +1527,60 @@ pub struct BulletTracer {
     pub lifetime: f32,
     /// Trail length
     pub trail_length: f32,
+    /// Lifetime at spawn (seconds)
+    pub max_lifetime: f32,
+    /// Color ramp over the tracer's life; None keeps the material untouched
+    pub ramp: Option<TracerRamp>,
+}
+
+impl BulletTracer {
+    /// Builder pattern: ramp the tracer's color over its life, starting from the current lifetime.
+    pub fn with_ramp(mut self, ramp: TracerRamp) -> Self {
+        self.max_lifetime = self.lifetime;
+        self.ramp = Some(ramp);
+        self
+    }
+
+    /// Fraction of the tracer's life already burned (0 at spawn, 1 when expired).
+    pub fn burned_fraction(&self) -> f32 {
+        if self.max_lifetime <= 0.0 {
+            return 0.0;
+        }
+        1.0 - (self.lifetime / self.max_lifetime).clamp(0.0, 1.0)
+    }
+
+    /// Current ramp color, or None if the tracer has no ramp.
+    pub fn ramp_color(&self) -> Option<LinearRgba> {
+        self.ramp.map(|ramp| ramp.color_at(self.burned_fraction()))
+    }
+}
+
+/// Start → end color of a burning tracer.
+/// 
+/// Applied to both the emissive and the (alpha blended) base color, so ending
+/// on transparent black makes the tracer fade out instead of leaving a dark streak.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TracerRamp {
+    /// Color at spawn
+    pub start: LinearRgba,
+    /// Color when the lifetime runs out
+    pub end: LinearRgba,
+}
+
+impl TracerRamp {
+    /// Ramp from `start` down to transparent black.
+    pub fn burnout(start: LinearRgba) -> Self {
+        Self {
+            start,
+            end: LinearRgba::NONE,
+        }
+    }
+
+    /// Color at `fraction` (0..1) of the tracer's life.
+    pub fn color_at(&self, fraction: f32) -> LinearRgba {
+        let t = fraction.clamp(0.0, 1.0);
+        LinearRgba::from_vec4(self.start.to_vec4().lerp(self.end.to_vec4(), t))
+    }
 }
 
 /// Marker component for impact decals.
@@ -502,19 +1590,28 @@ pub struct BulletTracer {
 /// 
 /// # Fields
 /// * `lifetime` - Remaining lifetime in seconds before the decal disappears
+/// * `age` - Seconds since the decal was placed, used to evict the oldest first
 /// 
 /// # Example
 /// ```
 /// use bevy_bullet_dynamics::components::ImpactDecal;
 /// 
-/// let decal = ImpactDecal {
-///     lifetime: 30.0,
-/// };
+/// let decal = ImpactDecal::new(30.0);
+/// assert_eq!(decal.age, 0.0);
 /// ```
 #[derive(Component, Default)]
 pub struct ImpactDecal {
     /// Lifetime remaining (seconds)
     pub lifetime: f32,
+    /// Time since placement (seconds)
+    pub age: f32,
+}
+
+impl ImpactDecal {
+    /// Creates a freshly placed decal that lasts `lifetime` seconds.
+    pub fn new(lifetime: f32) -> Self {
+        Self { lifetime, age: 0.0 }
+    }
 }
 
 /// Network entity marker for multiplayer synchronization.
@@ -557,6 +1654,8 @@ pub struct NetProjectile {
 /// # Fields
 /// * `distance` - The distance in meters at which the weapon is zeroed
 /// * `pitch_adjustment` - Calculated pitch adjustment in radians to compensate for bullet drop
+/// * `solution` - Inputs the pitch was solved with, so it can be re-solved when the
+///   environment changes (set by [`Sight::apply_zero`]; None for a hand-set pitch)
 /// 
 /// # Example
 /// ```
@@ -565,6 +1664,7 @@ pub struct NetProjectile {
 /// let zeroing = WeaponZeroing {
 ///     distance: 200.0,          // Zeroed at 200 meters
 ///     pitch_adjustment: 0.005,  // Small upward adjustment
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Component, Reflect, Clone)]
@@ -574,6 +1674,8 @@ pub struct WeaponZeroing {
     pub distance: f32,
     /// Calculated pitch adjustment (radians)
     pub pitch_adjustment: f32,
+    /// Solver inputs for re-zeroing
+    pub solution: Option<ZeroSolution>,
 }
 
 impl Default for WeaponZeroing {
@@ -585,10 +1687,57 @@ impl Default for WeaponZeroing {
         Self {
             distance: 100.0,
             pitch_adjustment: 0.0,
+            solution: None,
         }
     }
 }
 
+impl WeaponZeroing {
+    /// Re-solve `pitch_adjustment` for the current environment.
+    ///
+    /// Does nothing without a recorded `solution`. If the round can no longer
+    /// reach the zero distance the previous pitch is kept.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the ballistics environment
+    /// * `config` - Reference to the ballistics configuration
+    ///
+    /// # Returns
+    /// True if the pitch was recomputed
+    pub fn rezero(
+        &mut self,
+        env: &crate::resources::BallisticsEnvironment,
+        config: &crate::resources::BallisticsConfig,
+    ) -> bool {
+        let Some(solution) = &self.solution else {
+            return false;
+        };
+        let Some(pitch) = crate::systems::trajectory::compute_zero_pitch(
+            self.distance,
+            solution.muzzle_velocity,
+            solution.sight_height,
+            &solution.projectile,
+            env,
+            config,
+        ) else {
+            return false;
+        };
+        self.pitch_adjustment = pitch;
+        true
+    }
+}
+
+/// Round and sight a [`WeaponZeroing`] was solved for.
+#[derive(Reflect, Clone)]
+pub struct ZeroSolution {
+    /// Launch speed (m/s)
+    pub muzzle_velocity: f32,
+    /// Height of the sight above the bore (meters)
+    pub sight_height: f32,
+    /// Projectile physical properties (velocity is ignored)
+    pub projectile: Projectile,
+}
+
 /// Component for muzzle flash visual effects.
 /// 
 /// This component marks entities as muzzle flash effects with properties
@@ -725,6 +1874,101 @@ impl Weapon {
     }
 }
 
+/// Draw-to-release mapping for bows and crossbows.
+///
+/// Put this on the weapon and turn the time the string was held into a launch
+/// speed with [`DrawStrength::velocity_for`], or straight into the shot with
+/// [`FireEvent::with_draw`](crate::events::FireEvent::with_draw). Speed rises
+/// linearly from `min_velocity` on release to `max_velocity` at full draw;
+/// holding longer gains nothing.
+///
+/// # Fields
+/// * `min_velocity` - Launch speed of an undrawn release in m/s
+/// * `max_velocity` - Launch speed at full draw in m/s
+/// * `full_draw_time` - Seconds of holding to reach full draw
+///
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::DrawStrength;
+///
+/// let bow = DrawStrength::new(20.0, 80.0, 1.0);
+/// assert_eq!(bow.velocity_for(0.5), 50.0);
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct DrawStrength {
+    /// Launch speed of an undrawn release (m/s)
+    pub min_velocity: f32,
+    /// Launch speed at full draw (m/s)
+    pub max_velocity: f32,
+    /// Seconds of holding to reach full draw
+    pub full_draw_time: f32,
+}
+
+impl Default for DrawStrength {
+    /// default: recurve bow, 25 to 80 m/s over 1 s, matching the "Bow" preset
+    fn default() -> Self {
+        Self {
+            min_velocity: 25.0,
+            max_velocity: 80.0,
+            full_draw_time: 1.0,
+        }
+    }
+}
+
+impl DrawStrength {
+    /// Create a draw mapping.
+    ///
+    /// # Arguments
+    /// * `min_velocity` - Launch speed of an undrawn release in m/s
+    /// * `max_velocity` - Launch speed at full draw in m/s
+    /// * `full_draw_time` - Seconds of holding to reach full draw
+    pub fn new(min_velocity: f32, max_velocity: f32, full_draw_time: f32) -> Self {
+        Self {
+            min_velocity,
+            max_velocity,
+            full_draw_time,
+        }
+    }
+
+    /// How far the string is drawn after holding for `hold_time` seconds.
+    ///
+    /// # Returns
+    /// Draw fraction from 0 (released at once) to 1 (full draw); a zero
+    /// `full_draw_time` is always at full draw
+    pub fn draw_fraction(&self, hold_time: f32) -> f32 {
+        if self.full_draw_time <= 0.0 {
+            return 1.0;
+        }
+        (hold_time / self.full_draw_time).clamp(0.0, 1.0)
+    }
+
+    /// Launch speed after holding for `hold_time` seconds.
+    ///
+    /// # Arguments
+    /// * `hold_time` - Seconds the string was held before release
+    ///
+    /// # Returns
+    /// Speed in m/s, between `min_velocity` and `max_velocity`
+    pub fn velocity_for(&self, hold_time: f32) -> f32 {
+        self.min_velocity.lerp(self.max_velocity, self.draw_fraction(hold_time))
+    }
+
+    /// Damage multiplier for a release after `hold_time` seconds.
+    ///
+    /// Scales with kinetic energy, so a half-speed arrow does a quarter of the
+    /// full-draw damage. Multiply the preset or payload damage by it.
+    ///
+    /// # Returns
+    /// Multiplier from `(min_velocity / max_velocity)²` up to 1 at full draw
+    pub fn damage_scale(&self, hold_time: f32) -> f32 {
+        if self.max_velocity <= 0.0 {
+            return 1.0;
+        }
+        (self.velocity_for(hold_time) / self.max_velocity).powi(2)
+    }
+}
+
 /// Guidance component for homing projectiles (missiles).
 /// 
 /// This component enables a projectile to steer towards a target entity.
@@ -759,3 +2003,653 @@ impl Default for Guidance {
     }
 }
 
+/// Marker for entities a [`TargetLock`] can acquire (vehicles, aircraft, flares).
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct Lockable;
+
+/// Seeker that paints [`Lockable`] entities for guided projectiles.
+///
+/// Put this on the weapon (or muzzle) entity; its forward axis is the seeker's
+/// boresight. The closest-to-center `Lockable` within `cone_angle` and `range`
+/// is locked, and kept for `grace_period` seconds after it leaves the cone.
+/// Guided rounds fired by `owner` while a lock is held get it as their
+/// `Guidance::target`.
+///
+/// # Fields
+/// * `range` - Maximum lock distance in meters
+/// * `cone_angle` - Half-angle of the seeker cone in radians
+/// * `grace_period` - Seconds a lock survives outside the cone
+/// * `owner` - Shooter whose rounds receive the lock; never locked itself
+/// * `target` - Currently locked entity (managed by the system)
+/// * `time_outside` - Seconds the target has been outside the cone (managed by the system)
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+pub struct TargetLock {
+    /// Maximum lock distance in meters
+    pub range: f32,
+    /// Half-angle of the seeker cone in radians
+    pub cone_angle: f32,
+    /// Seconds a lock survives after the target leaves the cone
+    pub grace_period: f32,
+    /// Shooter whose guided rounds receive the lock
+    pub owner: Option<Entity>,
+    /// Currently locked entity
+    pub target: Option<Entity>,
+    /// Seconds the locked target has spent outside the cone
+    pub time_outside: f32,
+}
+
+impl Default for TargetLock {
+    /// default: 1 km range, 5 degree cone, 1 s grace period
+    fn default() -> Self {
+        Self {
+            range: 1000.0,
+            cone_angle: 5.0_f32.to_radians(),
+            grace_period: 1.0,
+            owner: None,
+            target: None,
+            time_outside: 0.0,
+        }
+    }
+}
+
+impl TargetLock {
+    /// Create a seeker for `owner`'s guided rounds.
+    pub fn new(owner: Entity) -> Self {
+        Self {
+            owner: Some(owner),
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum lock distance.
+    pub fn with_range(mut self, range: f32) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Set the seeker cone half-angle (radians).
+    pub fn with_cone_angle(mut self, cone_angle: f32) -> Self {
+        self.cone_angle = cone_angle;
+        self
+    }
+
+    /// Set how long a lock survives outside the cone.
+    pub fn with_grace_period(mut self, grace_period: f32) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+}
+
+/// Laser sight that projects a dot where the weapon is pointing.
+/// 
+/// Put this on the muzzle (or weapon) entity; its forward axis is the beam.
+/// The dot entity is created the first time the sight updates and then reused
+/// every frame, hidden whenever nothing is within `max_range`.
+/// 
+/// # Fields
+/// * `max_range` - Maximum beam length in meters
+/// * `color` - Color of the projected dot
+/// * `owner` - Entity the beam ignores, usually the shooter
+/// * `render_layers` - Layers the dot is drawn on (the world layer by default, so it
+///   stays visible even when the weapon itself is on a viewmodel layer)
+/// * `dot` - Dot entity owned by this sight (managed by the system)
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::LaserSight;
+/// 
+/// let laser = LaserSight {
+///     max_range: 150.0,
+///     color: Color::srgb(0.0, 1.0, 0.0),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+pub struct LaserSight {
+    /// Maximum beam length (meters)
+    pub max_range: f32,
+    /// Dot color
+    pub color: Color,
+    /// Entity ignored by the beam (usually the shooter)
+    pub owner: Option<Entity>,
+    /// Render layers for the dot
+    pub render_layers: bevy::camera::visibility::RenderLayers,
+    /// Cached dot entity
+    pub dot: Option<Entity>,
+}
+
+impl Default for LaserSight {
+    /// Creates a red 100m laser on the default render layer.
+    fn default() -> Self {
+        Self {
+            max_range: 100.0,
+            color: Color::srgb(1.0, 0.0, 0.0),
+            owner: None,
+            render_layers: bevy::camera::visibility::RenderLayers::default(),
+            dot: None,
+        }
+    }
+}
+
+/// Marker for the dot entity projected by a [`LaserSight`].
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct LaserDot;
+
+/// Marker for the on-screen text node showing live ballistics metrics.
+/// 
+/// Spawned and updated by the debug plugin while `BallisticsConfig::debug_draw`
+/// is on, and despawned when it is turned off.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct BallisticsDebugOverlay;
+
+/// Opt-in render interpolation between fixed simulation steps.
+///
+/// Projectiles move in FixedUpdate, so at a low fixed rate a fast round jumps
+/// between frames. For entities with this component the rendered `Transform`
+/// is blended between the last two fixed-step positions by the fixed-timestep
+/// overstep. The blended position is only in place from PostUpdate until the
+/// next frame's fixed loop; FixedUpdate and Update (and so collision) always
+/// see the authoritative position. Both samples are set from the `Transform`
+/// when the component is added.
+///
+/// Writes to the `Transform` made outside the fixed loop (collisions in Update,
+/// teleports, pool reuse) are kept: the samples snap to the written position
+/// instead of blending over it.
+///
+/// # Fields
+/// * `previous` - Authoritative position before the latest fixed step
+/// * `current` - Authoritative position after the latest fixed step
+/// * `rendered` - Blended position last written to the `Transform`
+#[derive(Component, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Component)]
+pub struct InterpolatedTransform {
+    /// Authoritative position before the latest fixed step
+    pub previous: Vec3,
+    /// Authoritative position after the latest fixed step
+    pub current: Vec3,
+    /// Blended position last written to the `Transform`
+    pub rendered: Vec3,
+}
+
+impl InterpolatedTransform {
+    /// Snap both samples (and the rendered position) to `position`, dropping the blend.
+    pub fn reset(&mut self, position: Vec3) {
+        self.previous = position;
+        self.current = position;
+        self.rendered = position;
+    }
+
+    /// Rendered position `fraction` of the way through the current fixed step.
+    pub fn position_at(&self, fraction: f32) -> Vec3 {
+        self.previous.lerp(self.current, fraction.clamp(0.0, 1.0))
+    }
+}
+
+/// Visual scale curve for projectiles, growing them with distance travelled.
+///
+/// Keeps distant rounds visible on screen. Only projectiles that carry this
+/// component are scaled. The scale is purely visual: collision is raycast along
+/// the flight path and never reads `Transform::scale`.
+/// 
+/// # Fields
+/// * `near` - Uniform scale at the muzzle
+/// * `far` - Uniform scale at and beyond `far_distance`
+/// * `far_distance` - Distance travelled (meters) at which `far` is reached
+/// 
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::ProjectileVisualScale;
+/// 
+/// // Grow from 5cm to 1.5m over the first 500m
+/// let scale = ProjectileVisualScale {
+///     near: 0.05,
+///     far: 1.5,
+///     far_distance: 500.0,
+/// };
+/// assert!((scale.scale_at(250.0) - 0.775).abs() < 1e-6);
+/// ```
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct ProjectileVisualScale {
+    /// Scale at the muzzle
+    pub near: f32,
+    /// Scale at `far_distance` and beyond
+    pub far: f32,
+    /// Distance over which the scale grows (meters)
+    pub far_distance: f32,
+}
+
+impl ProjectileVisualScale {
+    /// Scale for a projectile that has travelled `distance` meters.
+    ///
+    /// Interpolates linearly from `near` to `far` and clamps outside that range.
+    pub fn scale_at(&self, distance: f32) -> f32 {
+        if self.far_distance <= 0.0 {
+            return self.far;
+        }
+        let t = (distance / self.far_distance).clamp(0.0, 1.0);
+        self.near + (self.far - self.near) * t
+    }
+}
+
+impl Default for ProjectileVisualScale {
+    /// Creates a curve that keeps the projectile at its spawn size (1.0).
+    fn default() -> Self {
+        Self {
+            near: 1.0,
+            far: 1.0,
+            far_distance: 100.0,
+        }
+    }
+}
+
+/// Lingering beam left along a projectile's whole flight path (railguns, spells).
+/// 
+/// Put this on a projectile. A separate trail entity records the projectile's
+/// position every frame and draws it as a ribbon; each recorded point fades to
+/// transparent over `lifetime` seconds, so the trail outlives the projectile by
+/// at most `lifetime` and then despawns.
+/// 
+/// # Fields
+/// * `lifetime` - Seconds a recorded point stays visible
+/// * `width` - Ribbon width in meters
+/// * `color` - Color at the projectile end of the trail
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::PersistentTrail;
+/// 
+/// let rail = PersistentTrail {
+///     lifetime: 1.5,
+///     width: 0.05,
+///     color: LinearRgba::rgb(0.3, 0.6, 4.0),
+/// };
+/// ```
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct PersistentTrail {
+    /// Fade time of each point (seconds)
+    pub lifetime: f32,
+    /// Ribbon width (meters)
+    pub width: f32,
+    /// Trail color
+    pub color: LinearRgba,
+}
+
+impl Default for PersistentTrail {
+    /// Creates a white 1 second, 3cm wide trail.
+    fn default() -> Self {
+        Self {
+            lifetime: 1.0,
+            width: 0.03,
+            color: LinearRgba::WHITE,
+        }
+    }
+}
+
+/// Point of a [`TrailPolyline`] with the time since it was recorded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrailPoint {
+    /// World-space position
+    pub position: Vec3,
+    /// Seconds since the point was recorded
+    pub age: f32,
+}
+
+/// Recorded path of a [`PersistentTrail`], kept on its own entity (managed by the system).
+/// 
+/// # Fields
+/// * `source` - Projectile being followed; None once it is gone
+/// * `trail` - Settings copied from the projectile
+/// * `points` - Recorded points, oldest first
+#[derive(Component, Clone)]
+pub struct TrailPolyline {
+    /// Followed projectile
+    pub source: Option<Entity>,
+    /// Trail settings
+    pub trail: PersistentTrail,
+    /// Recorded points, oldest first
+    pub points: std::collections::VecDeque<TrailPoint>,
+}
+
+/// Entity that hears projectiles passing by (usually the player or camera).
+/// 
+/// Rounds whose flight path comes within `radius` of the listener produce a
+/// `NearMissEvent`. Rounds fired by the listener itself are ignored.
+/// 
+/// # Fields
+/// * `radius` - Near-miss detection radius in meters
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+pub struct BulletListener {
+    /// Detection radius (meters)
+    pub radius: f32,
+}
+
+impl Default for BulletListener {
+    /// Creates a listener with a 3m near-miss radius.
+    fn default() -> Self {
+        Self { radius: 3.0 }
+    }
+}
+
+/// Marker for entities that flinch when hit (players, AI with a view to kick).
+/// 
+/// Hits on these, or on any collider below them in the hierarchy (e.g. a head
+/// hitbox), produce a `FlinchEvent` for the camera or controller to apply.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct Flinchable;
+
+/// Marker for entities that set off `ProjectileLogic::Proximity` fuses.
+/// 
+/// Proximity rounds detonate when their flight path passes within `range` of
+/// one of these (vehicles, players, drones). Their owner never triggers them.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct ProximityTarget;
+
+/// Team an entity belongs to, for friendly-fire filtering.
+/// 
+/// Put it on shooters and on the things they can hit. With
+/// `BallisticsConfig::friendly_fire` disabled, projectiles pass through anything
+/// on their shooter's team. A projectile may carry its own `Team`, which wins over
+/// its owner's; the fire system copies the owner's team onto every round, so
+/// rounds stay friendly after their shooter is despawned.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct Team(pub u32);
+
+/// Collision layers a projectile can hit.
+/// 
+/// A bit mask over avian `CollisionLayers` memberships, fed into the projectile's
+/// `SpatialQueryFilter`. Colliders whose memberships share no bit with the mask are
+/// passed through (triggers, ragdolls, friendly hitboxes). Projectiles without
+/// this component use `BallisticsConfig::collision_mask`.
+/// 
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::components::ProjectileCollisionMask;
+/// 
+/// const FRIENDLY: u32 = 1 << 2;
+/// let mask = ProjectileCollisionMask::ALL.without(FRIENDLY);
+/// assert!(!mask.hits(FRIENDLY));
+/// assert!(mask.hits(1 << 0));
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct ProjectileCollisionMask(pub u32);
+
+impl ProjectileCollisionMask {
+    /// Hits every layer
+    pub const ALL: Self = Self(u32::MAX);
+    /// Hits nothing
+    pub const NONE: Self = Self(0);
+
+    /// Builder pattern: stop hitting `layers`
+    pub fn without(self, layers: u32) -> Self {
+        Self(self.0 & !layers)
+    }
+
+    /// Builder pattern: also hit `layers`
+    pub fn with(self, layers: u32) -> Self {
+        Self(self.0 | layers)
+    }
+
+    /// Whether a collider with these layer memberships can be hit.
+    pub fn hits(&self, memberships: u32) -> bool {
+        self.0 & memberships != 0
+    }
+}
+
+impl Default for ProjectileCollisionMask {
+    /// Hits every layer.
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Shape of a [`WindZone`], in the zone entity's local space.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum WindZoneShape {
+    /// Sphere centered on the entity
+    Sphere { radius: f32 },
+    /// Axis-aligned box (in local space) centered on the entity
+    Box { half_extents: Vec3 },
+    /// Upright cylinder along the local Y axis (downwash, tornado)
+    Cylinder { radius: f32, half_height: f32 },
+}
+
+impl WindZoneShape {
+    /// How far a local-space point is from the center, relative to the shape's edge.
+    ///
+    /// # Returns
+    /// 0.0 at the center, 1.0 on the boundary and greater than 1.0 outside
+    pub fn normalized_distance(&self, local: Vec3) -> f32 {
+        match *self {
+            Self::Sphere { radius } => local.length() / radius,
+            Self::Box { half_extents } => (local.abs() / half_extents).max_element(),
+            Self::Cylinder { radius, half_height } => {
+                (local.xz().length() / radius).max(local.y.abs() / half_height)
+            }
+        }
+    }
+
+    /// Radius of a sphere around the center that encloses the whole shape.
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            Self::Sphere { radius } => radius,
+            Self::Box { half_extents } => half_extents.length(),
+            Self::Cylinder { radius, half_height } => Vec2::new(radius, half_height).length(),
+        }
+    }
+}
+
+/// Localized wind attached to an entity (fan, rotor downwash, tornado).
+/// 
+/// Projectiles inside the shape feel `velocity` on top of the environment wind.
+/// The zone follows the entity's `GlobalTransform`: the shape is placed, rotated and
+/// scaled with it, and `velocity` is given in the entity's local frame so a fan blows
+/// wherever it is facing.
+/// 
+/// # Fields
+/// * `shape` - Region affected by the zone
+/// * `velocity` - Wind velocity in local space (m/s)
+/// * `falloff` - 0.0 = full strength everywhere inside, 1.0 = fades linearly to zero at the edge
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::{WindZone, WindZoneShape};
+/// 
+/// // Ventilation fan blowing along its local -Z
+/// let fan = WindZone::new(
+///     WindZoneShape::Box { half_extents: Vec3::new(1.0, 1.0, 4.0) },
+///     Vec3::new(0.0, 0.0, -15.0),
+/// )
+/// .with_falloff(0.5);
+/// ```
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct WindZone {
+    /// Affected region in local space
+    pub shape: WindZoneShape,
+    /// Wind velocity in local space (m/s)
+    pub velocity: Vec3,
+    /// Strength falloff towards the edge (0.0 - 1.0)
+    pub falloff: f32,
+}
+
+impl WindZone {
+    /// Creates a uniform wind zone.
+    pub fn new(shape: WindZoneShape, velocity: Vec3) -> Self {
+        Self {
+            shape,
+            velocity,
+            falloff: 0.0,
+        }
+    }
+
+    /// Builder pattern: set the edge falloff (clamped to 0.0 - 1.0).
+    pub fn with_falloff(mut self, falloff: f32) -> Self {
+        self.falloff = falloff.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Wind velocity (local space) at a local-space point, zero outside the shape.
+    pub fn velocity_at_local(&self, local: Vec3) -> Vec3 {
+        let distance = self.shape.normalized_distance(local);
+        if distance > 1.0 {
+            return Vec3::ZERO;
+        }
+        self.velocity * (1.0 - self.falloff * distance)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_diameter_derives_reference_area() {
+        // 7.62mm round
+        let round = Projectile::new(Vec3::ZERO).with_diameter(0.00762);
+        assert_eq!(round.diameter, 0.00762);
+        assert!((round.reference_area - 4.560_367e-5).abs() < 1e-9);
+        assert!(round.reference_area_matches_diameter());
+
+        // An explicit area wins whichever builder runs first
+        let slug = Projectile::new(Vec3::ZERO).with_diameter(0.00762).with_reference_area(2.0e-4);
+        assert_eq!(slug.reference_area, 2.0e-4);
+        assert!(!slug.reference_area_matches_diameter());
+        let slug = Projectile::new(Vec3::ZERO).with_reference_area(2.0e-4).with_diameter(0.00762);
+        assert_eq!(slug.reference_area, 2.0e-4);
+        assert_eq!(slug.diameter, 0.00762);
+    }
+
+    fn sight_at(zero: f32) -> Sight {
+        Sight {
+            current_zero: zero,
+            ..Sight::default().with_zero_presets(vec![300.0, 100.0, 200.0, 100.0])
+        }
+    }
+
+    #[test]
+    fn test_draw_time_maps_to_launch_speed() {
+        let bow = DrawStrength::new(20.0, 80.0, 1.2);
+
+        assert_eq!(bow.velocity_for(0.0), 20.0);
+        assert_eq!(bow.velocity_for(-0.1), 20.0);
+        assert!((bow.velocity_for(0.6) - 50.0).abs() < 1e-4);
+        assert_eq!(bow.velocity_for(1.2), 80.0);
+        assert_eq!(bow.velocity_for(5.0), 80.0);
+
+        assert_eq!(bow.damage_scale(1.2), 1.0);
+        assert!((bow.damage_scale(0.0) - 0.0625).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_projectiles() {
+        use crate::types::BallisticsError;
+
+        assert!(Projectile::try_new(Vec3::new(0.0, 0.0, -850.0)).is_ok());
+        assert!(Projectile::new(Vec3::ZERO).with_reference_area(0.0).validate().is_ok());
+
+        for velocity in [
+            Vec3::new(f32::NAN, 0.0, -850.0),
+            Vec3::new(0.0, f32::INFINITY, 0.0),
+            Vec3::splat(f32::NEG_INFINITY),
+        ] {
+            assert!(matches!(
+                Projectile::try_new(velocity),
+                Err(BallisticsError::NonFiniteVelocity(_))
+            ));
+        }
+
+        for mass in [0.0, -0.01, f32::NAN, f32::INFINITY] {
+            let projectile = Projectile::new(Vec3::NEG_Z).with_mass(mass);
+            assert!(matches!(
+                projectile.validate(),
+                Err(BallisticsError::NonPositiveMass(_))
+            ));
+        }
+
+        for area in [-1e-4, f32::NAN] {
+            let mut projectile = Projectile::new(Vec3::NEG_Z);
+            projectile.reference_area = area;
+            assert!(matches!(
+                projectile.validate(),
+                Err(BallisticsError::NegativeReferenceArea(_))
+            ));
+        }
+
+        let error = Projectile::new(Vec3::NEG_Z).with_mass(0.0).validate().unwrap_err();
+        assert_eq!(error.to_string(), "projectile mass 0 kg must be positive and finite");
+    }
+
+    #[test]
+    fn test_sight_presets_sorted() {
+        assert_eq!(sight_at(100.0).zero_presets, vec![100.0, 200.0, 300.0]);
+    }
+
+    #[test]
+    fn test_sight_cycle_clamps() {
+        let mut sight = sight_at(100.0);
+        sight.cycle_up();
+        assert_eq!(sight.current_zero, 200.0);
+        sight.cycle_up();
+        sight.cycle_up();
+        assert_eq!(sight.current_zero, 300.0);
+
+        sight.cycle_down();
+        sight.cycle_down();
+        sight.cycle_down();
+        assert_eq!(sight.current_zero, 100.0);
+    }
+
+    #[test]
+    fn test_sight_cycle_wraps() {
+        let mut sight = sight_at(300.0).with_wrap(true);
+        sight.cycle_up();
+        assert_eq!(sight.current_zero, 100.0);
+        sight.cycle_down();
+        assert_eq!(sight.current_zero, 300.0);
+    }
+
+    #[test]
+    fn test_sight_cycle_from_off_preset_zero() {
+        let mut sight = sight_at(150.0);
+        sight.cycle_up();
+        assert_eq!(sight.current_zero, 200.0);
+
+        let mut sight = sight_at(150.0);
+        sight.cycle_down();
+        assert_eq!(sight.current_zero, 100.0);
+    }
+
+    #[test]
+    fn test_sight_apply_zero_updates_zeroing() {
+        let env = crate::resources::BallisticsEnvironment::default();
+        let config = crate::resources::BallisticsConfig::default();
+        let projectile = Projectile::new(Vec3::ZERO).with_reference_area(0.00005);
+        let mut sight = sight_at(100.0);
+        let mut zeroing = WeaponZeroing::default();
+
+        sight.apply_zero(&mut zeroing, 800.0, &projectile, &env, &config);
+        let near = zeroing.pitch_adjustment;
+        assert!(near > 0.0);
+
+        sight.cycle_up();
+        sight.apply_zero(&mut zeroing, 800.0, &projectile, &env, &config);
+        assert_eq!(zeroing.distance, 200.0);
+        assert!(zeroing.pitch_adjustment > near);
+    }
+}
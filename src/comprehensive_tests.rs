@@ -20,10 +20,12 @@ mod all_tests {
         let bullet_tracer = BulletTracer {
             lifetime: 2.0,
             trail_length: 1.5,
+            ..Default::default()
         };
         
         let impact_decal = ImpactDecal {
             lifetime: 30.0,
+            ..Default::default()
         };
         
         let net_projectile = NetProjectile {
@@ -35,6 +37,7 @@ mod all_tests {
         let weapon_zeroing = WeaponZeroing {
             distance: 100.0,
             pitch_adjustment: 0.005,
+            ..Default::default()
         };
         
         // Verify all components were created with expected values
@@ -74,24 +77,23 @@ mod all_tests {
             .with_shooter(Entity::PLACEHOLDER)
             .with_seed(12345);
         
-        let hit_event = HitEvent {
-            projectile: Entity::PLACEHOLDER,
-            target: Entity::PLACEHOLDER,
-            impact_point: Vec3::new(10.0, 0.0, 5.0),
-            normal: Vec3::Y,
-            velocity: Vec3::X * 300.0,
-            damage: 25.0,
-            penetrated: false,
-            ricocheted: false,
-        };
+        let hit_event = HitEvent::new(
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            Vec3::new(10.0, 0.0, 5.0),
+            Vec3::Y,
+            Vec3::X * 300.0,
+            25.0,
+        );
         
         let explosion_event = ExplosionEvent {
             center: Vec3::ZERO,
             radius: 5.0,
             damage: 100.0,
-            falloff: 1.5,
+            falloff: FalloffCurve::Power(1.5),
             explosion_type: ExplosionType::HighExplosive,
             source: Some(Entity::PLACEHOLDER),
+            instigator: None,
         };
         
         let penetration_event = PenetrationEvent {
@@ -108,6 +110,7 @@ mod all_tests {
             new_direction: Vec3::Y,
             new_speed: 200.0,
             surface: Entity::PLACEHOLDER,
+            respawned: None,
         };
         
         // Verify events were created with expected values
@@ -223,12 +226,8 @@ mod all_tests {
     #[test]
     fn test_hit_result_creation() {
         // Test HitResult creation
-        let hit_result = HitResult {
-            entity: Entity::PLACEHOLDER,
-            point: Vec3::new(10.0, 5.0, 0.0),
-            normal: Vec3::Y,
-            distance: 15.0,
-        };
+        let hit_result =
+            HitResult::new(Entity::PLACEHOLDER, Vec3::new(10.0, 5.0, 0.0), Vec3::Y, 15.0);
         
         assert_eq!(hit_result.entity, Entity::PLACEHOLDER);
         assert_eq!(hit_result.point, Vec3::new(10.0, 5.0, 0.0));
@@ -248,26 +247,47 @@ mod all_tests {
             movement_penalty: 2.0,
             ads_modifier: 0.3,
             airborne_multiplier: 3.0,
+            ..Default::default()
         };
 
         // Test calculate_total_spread with different conditions
         let spread_normal = systems::accuracy::calculate_total_spread(
-            &accuracy, false, false, false, 0.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert_eq!(spread_normal, 0.001 + 0.002); // base + bloom
 
         let spread_ads = systems::accuracy::calculate_total_spread(
-            &accuracy, true, false, false, 0.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                is_aiming: true,
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert!(spread_ads < spread_normal); // ADS should reduce spread
 
         let spread_moving = systems::accuracy::calculate_total_spread(
-            &accuracy, false, true, false, 5.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                is_moving: true,
+                movement_speed: 5.0,
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert!(spread_moving > spread_normal); // Moving should increase spread
 
         let spread_airborne = systems::accuracy::calculate_total_spread(
-            &accuracy, false, false, true, 0.0, 5.0
+            &accuracy,
+            &systems::accuracy::AccuracyContext {
+                is_airborne: true,
+                max_speed: 5.0,
+                ..Default::default()
+            },
         );
         assert!(spread_airborne > spread_normal); // Airborne should increase spread
 
@@ -280,18 +300,18 @@ mod all_tests {
     #[test]
     fn test_explosion_damage_calculation() {
         // Test explosion damage calculation
-        let damage_center = systems::logic::calculate_explosion_damage(100.0, 0.0, 10.0, 1.0);
+        let damage_center = systems::logic::calculate_explosion_damage(100.0, 0.0, 10.0, FalloffCurve::Power(1.0));
         assert_eq!(damage_center, 100.0); // Full damage at center
 
-        let damage_edge = systems::logic::calculate_explosion_damage(100.0, 10.0, 10.0, 1.0);
+        let damage_edge = systems::logic::calculate_explosion_damage(100.0, 10.0, 10.0, FalloffCurve::Power(1.0));
         assert_eq!(damage_edge, 0.0); // No damage at edge
 
-        let damage_middle = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, 1.0);
+        let damage_middle = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, FalloffCurve::Power(1.0));
         assert!(damage_middle > 0.0 && damage_middle < 100.0); // Partial damage in middle
 
         // Test with different falloff values
-        let damage_linear = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, 1.0); // Linear
-        let damage_quadratic = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, 2.0); // Quadratic
+        let damage_linear = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, FalloffCurve::Power(1.0)); // Linear
+        let damage_quadratic = systems::logic::calculate_explosion_damage(100.0, 5.0, 10.0, FalloffCurve::Power(2.0)); // Quadratic
         assert!(damage_quadratic <= damage_linear); // Quadratic falls off faster
     }
 
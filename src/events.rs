@@ -5,6 +5,11 @@
 use bevy::prelude::*;
 use bevy::ecs::message::Message;
 
+use crate::components::{Accuracy, AmmoType, DrawStrength, FalloffCurve};
+use crate::resources::{BallisticsEnvironment, WeaponPreset};
+use crate::systems::accuracy::{calculate_total_spread, AccuracyContext};
+use crate::types::{ProjectileRng, ProjectileSpawnParams};
+
 /// Event fired when a weapon is discharged.
 #[derive(Message, Debug, Reflect, Clone)]
 #[reflect(Debug)]
@@ -18,6 +23,18 @@ pub struct FireEvent {
     pub timestamp: f64,
     pub projectile_count: u32,
     pub spread_angle: f32,
+    /// Muzzle velocity exceeds the speed of sound (see [`FireEvent::with_environment`])
+    pub is_supersonic: bool,
+    /// Velocity of the shooting platform, added to every round (zero unless opted in)
+    pub inherited_velocity: Vec3,
+    /// Distance ahead of `origin`, along `direction`, at which rounds spawn
+    pub spawn_offset: f32,
+    /// Ammunition loaded, attached to every round (None leaves tracers to the weapon)
+    pub ammo: Option<AmmoType>,
+    /// `muzzle_velocity` already includes the preset's temperature adjustment and
+    /// round-to-round variation (see [`FireEvent::with_preset_variation`]), so the
+    /// fire system keeps it as is
+    pub velocity_sampled: bool,
 }
 
 impl Default for FireEvent {
@@ -32,6 +49,11 @@ impl Default for FireEvent {
             timestamp: 0.0,
             projectile_count: 1,
             spread_angle: 0.0,
+            is_supersonic: false,
+            inherited_velocity: Vec3::ZERO,
+            spawn_offset: 0.0,
+            ammo: None,
+            velocity_sampled: false,
         }
     }
 }
@@ -65,6 +87,128 @@ impl FireEvent {
         self.spread_angle = angle;
         self
     }
+
+    /// Fire with the shooter's current effective spread.
+    ///
+    /// Sets `spread_angle` to [`calculate_total_spread`] for `accuracy` in `ctx`.
+    /// `accuracy` is only read: apply the shot's bloom separately (see
+    /// `apply_shot_bloom`), after building the event.
+    pub fn with_accuracy(mut self, accuracy: &Accuracy, ctx: &AccuracyContext) -> Self {
+        self.spread_angle = calculate_total_spread(accuracy, ctx);
+        self
+    }
+
+    /// Classify the shot as supersonic or not against the environment's
+    /// temperature-adjusted speed of sound.
+    pub fn with_environment(mut self, env: &BallisticsEnvironment) -> Self {
+        self.is_supersonic = env.is_supersonic(self.muzzle_velocity);
+        self
+    }
+
+    /// Carry the shooter's motion into the rounds, e.g. its `LinearVelocity`
+    /// when firing from a vehicle. Rounds otherwise ignore the platform's motion.
+    pub fn with_inherited_velocity(mut self, velocity: Vec3) -> Self {
+        self.inherited_velocity = velocity;
+        self
+    }
+
+    /// Spawn rounds `offset` meters ahead of the muzzle, clear of the shooter's collider.
+    pub fn with_spawn_offset(mut self, offset: f32) -> Self {
+        self.spawn_offset = offset;
+        self
+    }
+
+    /// Fire `ammo`; the rounds carry it as an `AmmoType` component.
+    pub fn with_ammo(mut self, ammo: AmmoType) -> Self {
+        self.ammo = Some(ammo);
+        self
+    }
+
+    /// Launch at the speed `draw` gives after holding the string `hold_time` seconds.
+    pub fn with_draw(mut self, draw: &DrawStrength, hold_time: f32) -> Self {
+        self.muzzle_velocity = draw.velocity_for(hold_time);
+        self
+    }
+
+    /// Launch at `preset`'s muzzle velocity for this shot.
+    ///
+    /// Temperature-adjusted, with the round-to-round spread drawn from
+    /// `spread_seed`, so set the seed first.
+    pub fn with_preset_velocity(mut self, preset: &WeaponPreset, env: &BallisticsEnvironment) -> Self {
+        self.muzzle_velocity = preset.sample_muzzle_velocity(env, self.spread_seed);
+        self.velocity_sampled = true;
+        self
+    }
+
+    /// Adjust `muzzle_velocity` for `preset`'s temperature sensitivity in `env`
+    /// and vary it by the round-to-round spread, drawn from `spread_seed` like
+    /// [`WeaponPreset::sample_muzzle_velocity`].
+    ///
+    /// The fire system applies this to every event, so the caller's velocity is
+    /// the nominal one at 15°C. Does nothing once the velocity was sampled, and
+    /// keeps it exact for a preset with no sensitivity and an SD of 0.
+    pub fn with_preset_variation(mut self, preset: &WeaponPreset, env: &BallisticsEnvironment) -> Self {
+        if !self.velocity_sampled {
+            let mut offset = preset.temperature_offset(env);
+            if preset.muzzle_velocity_sd > 0.0 {
+                offset += ProjectileRng::new(self.spread_seed).muzzle_velocity_offset(preset.muzzle_velocity_sd);
+            }
+            self.muzzle_velocity = (self.muzzle_velocity + offset).max(0.0);
+        }
+        self.velocity_sampled = true;
+        self
+    }
+
+    /// Position rounds spawn at: `origin` moved `spawn_offset` along `direction`.
+    pub fn spawn_origin(&self) -> Vec3 {
+        self.origin + self.direction * self.spawn_offset
+    }
+
+    /// Build spawn parameters for this shot.
+    ///
+    /// The event only carries kinematics, so mass, drag and damage come from `preset`.
+    /// The direction is already normalized and is passed through unchanged;
+    /// the origin includes `spawn_offset`.
+    pub fn to_spawn_params(&self, preset: &WeaponPreset) -> ProjectileSpawnParams {
+        ProjectileSpawnParams {
+            origin: self.spawn_origin(),
+            direction: self.direction,
+            velocity: self.muzzle_velocity,
+            mass: preset.projectile_mass,
+            drag: preset.drag_coefficient,
+            damage: preset.base_damage,
+            owner: self.shooter,
+        }
+    }
+}
+
+impl From<&ProjectileSpawnParams> for FireEvent {
+    /// Single-projectile event with no spread, mirroring the spawn parameters.
+    fn from(params: &ProjectileSpawnParams) -> Self {
+        Self {
+            origin: params.origin,
+            direction: params.direction,
+            muzzle_velocity: params.velocity,
+            shooter: params.owner,
+            ..Default::default()
+        }
+    }
+}
+
+/// Event fired when a projectile entity has been spawned.
+///
+/// Written by the built-in fire system once per projectile (so a shotgun volley
+/// produces one per pellet). Manual spawners may write it too, but nothing
+/// requires them to. Carries enough to place a muzzle flash or tracer without
+/// querying the projectile.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct ProjectileSpawnedEvent {
+    pub entity: Entity,
+    pub origin: Vec3,
+    pub direction: Vec3,
+    pub muzzle_velocity: f32,
+    pub owner: Option<Entity>,
 }
 
 /// Event fired when a projectile hits something.
@@ -79,18 +223,71 @@ pub struct HitEvent {
     pub damage: f32,
     pub penetrated: bool,
     pub ricocheted: bool,
+    /// The round was supersonic when it struck (crack rather than thump)
+    pub was_supersonic: bool,
+    /// Angle between the incoming round and the surface normal (radians):
+    /// 0 head-on, approaching π/2 for a grazing hit
+    pub impact_angle: f32,
+    /// Seconds the round had been flying (`Projectile::age`)
+    pub age: f32,
+    /// The round passed through a sensor collider and flew on
+    /// (see `BallisticsConfig::sensor_pass_through`)
+    pub sensor: bool,
+}
+
+impl HitEvent {
+    /// Creates a plain head-on hit: not penetrated, ricocheted, supersonic or
+    /// through a sensor, at age zero.
+    ///
+    /// Handy for tests and replays; set the remaining fields with struct update
+    /// syntax, e.g. `HitEvent { penetrated: true, ..HitEvent::new(..) }`.
+    ///
+    /// # Arguments
+    /// * `projectile` - Projectile that hit
+    /// * `target` - Entity that was hit
+    /// * `impact_point` - World-space impact point
+    /// * `normal` - Surface normal at the impact point
+    /// * `velocity` - Velocity of the round at impact
+    /// * `damage` - Damage dealt
+    pub fn new(
+        projectile: Entity,
+        target: Entity,
+        impact_point: Vec3,
+        normal: Vec3,
+        velocity: Vec3,
+        damage: f32,
+    ) -> Self {
+        Self {
+            projectile,
+            target,
+            impact_point,
+            normal,
+            velocity,
+            damage,
+            penetrated: false,
+            ricocheted: false,
+            was_supersonic: false,
+            impact_angle: 0.0,
+            age: 0.0,
+            sensor: false,
+        }
+    }
 }
 
 /// Event fired when an explosion occurs.
+///
+/// `source` is the detonating projectile, `instigator` the entity that fired it
+/// (see [`SelfDamageGrace`](crate::resources::SelfDamageGrace)).
 #[derive(Message, Debug, Reflect, Clone)]
 #[reflect(Debug)]
 pub struct ExplosionEvent {
     pub center: Vec3,
     pub radius: f32,
     pub damage: f32,
-    pub falloff: f32,
+    pub falloff: FalloffCurve,
     pub explosion_type: ExplosionType,
     pub source: Option<Entity>,
+    pub instigator: Option<Entity>,
 }
 
 /// Types of explosions.
@@ -107,6 +304,39 @@ pub enum ExplosionType {
     EMP,
 }
 
+/// Why a projectile was removed by `cleanup_expired_projectiles`,
+/// `despawn_orphaned_projectiles` or, at its ricochet limit, `process_hit`.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq, Eq)]
+#[reflect(Debug)]
+pub enum DespawnReason {
+    /// Exceeded `BallisticsConfig::max_projectile_lifetime`
+    Lifetime,
+    /// Exceeded `BallisticsConfig::max_projectile_distance`
+    Distance,
+    /// Slowed below `BallisticsConfig::min_projectile_speed`
+    Speed,
+    /// Owner despawned under `OrphanPolicy::DespawnWithOwner`
+    Orphaned,
+    /// Spent and despawned by a glancing hit after `BallisticsConfig::max_ricochets`
+    /// ricochets
+    RicochetLimit,
+    /// Position or velocity stopped being finite (NaN or infinite)
+    NonFinite,
+}
+
+/// Event fired when a projectile expires without hitting anything, or is
+/// spent by its ricochet limit (after that hit's `HitEvent`).
+///
+/// `age` is the round's time of flight in seconds.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct ProjectileDespawnedEvent {
+    pub projectile: Entity,
+    pub position: Vec3,
+    pub reason: DespawnReason,
+    pub age: f32,
+}
+
 /// Event for projectile penetration.
 #[derive(Message, Debug, Reflect, Clone)]
 #[reflect(Debug)]
@@ -118,7 +348,76 @@ pub struct PenetrationEvent {
     pub remaining_power: f32,
 }
 
+/// Event fired when a projectile passes close to a `BulletListener`.
+///
+/// Sent once per pass, for the whiz/crack of a round going by. `relative_velocity`
+/// is the projectile's velocity relative to the listener and `closing` tells
+/// whether the round was approaching the listener when the pass was detected
+/// (false for a round that is already past and flying away), so audio can pick
+/// the Doppler pitch direction.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct NearMissEvent {
+    pub projectile: Entity,
+    pub listener: Entity,
+    pub closest_point: Vec3,
+    pub distance: f32,
+    pub relative_velocity: Vec3,
+    pub closing: bool,
+}
+
+/// Event fired for every `BulletListener` when a shot is fired, so audio can
+/// schedule the report.
+///
+/// `delay` is the time the sound takes to reach the listener: its distance from
+/// `position` over the temperature-adjusted speed of sound.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct ShotReportEvent {
+    pub position: Vec3,
+    pub delay: f32,
+    pub listener: Entity,
+}
+
+/// Event fired when a [`Flinchable`](crate::components::Flinchable) entity is hit.
+///
+/// `magnitude` runs from 0 to 1 and saturates for heavy hits; `direction` is the
+/// way the round was travelling, i.e. the way the view should be pushed.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct FlinchEvent {
+    pub target: Entity,
+    pub magnitude: f32,
+    pub direction: Vec3,
+}
+
+/// Event fired when a [`TargetLock`](crate::components::TargetLock) acquires a new target.
+///
+/// `weapon` is the entity carrying the seeker, `owner` its shooter.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct LockAcquiredEvent {
+    pub weapon: Entity,
+    pub owner: Option<Entity>,
+    pub target: Entity,
+}
+
+/// Event sent by the game when a weapon is equipped.
+///
+/// `entity` carries the `Accuracy` in use (the shooter or the weapon). With the
+/// core plugin, `reset_bloom_on_equip` clears its bloom so recoil from the
+/// previous weapon doesn't carry over.
+#[derive(Message, Debug, Reflect, Clone)]
+#[reflect(Debug)]
+pub struct WeaponEquippedEvent {
+    pub entity: Entity,
+}
+
 /// Event for projectile ricochet.
+///
+/// `projectile` is the round that struck the surface. With
+/// [`RicochetMode::Respawn`](crate::resources::RicochetMode) it is despawned and
+/// `respawned` names the entity that carries on; otherwise `respawned` is None.
 #[derive(Message, Debug, Reflect, Clone)]
 #[reflect(Debug)]
 pub struct RicochetEvent {
@@ -127,4 +426,74 @@ pub struct RicochetEvent {
     pub new_direction: Vec3,
     pub new_speed: f32,
     pub surface: Entity,
+    pub respawned: Option<Entity>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_params_to_fire_event() {
+        let owner = Entity::PLACEHOLDER;
+        let params =
+            ProjectileSpawnParams::new(Vec3::new(1.0, 1.5, 0.0), Vec3::new(3.0, 0.0, -4.0), 850.0)
+                .with_owner(owner);
+        let event = FireEvent::from(&params);
+
+        assert_eq!(event.origin, params.origin);
+        assert_eq!(event.direction, params.direction);
+        assert!((event.direction.length() - 1.0).abs() < 1e-6);
+        assert_eq!(event.muzzle_velocity, 850.0);
+        assert_eq!(event.shooter, Some(owner));
+        assert_eq!(event.projectile_count, 1);
+        assert_eq!(event.spread_angle, 0.0);
+
+        let preset = WeaponPreset::default();
+        let back = event.to_spawn_params(&preset);
+        assert_eq!(back.origin, params.origin);
+        assert_eq!(back.direction, params.direction);
+        assert_eq!(back.velocity, params.velocity);
+        assert_eq!(back.owner, Some(owner));
+        assert_eq!(back.mass, preset.projectile_mass);
+        assert_eq!(back.drag, preset.drag_coefficient);
+        assert_eq!(back.damage, preset.base_damage);
+    }
+
+    #[test]
+    fn test_partial_draw_feeds_spawn_velocity() {
+        let bow = DrawStrength::new(20.0, 80.0, 1.0);
+        let event = FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0).with_draw(&bow, 0.5);
+
+        assert_eq!(event.muzzle_velocity, 50.0);
+        assert_eq!(event.to_spawn_params(&WeaponPreset::default()).velocity, 50.0);
+    }
+
+    #[test]
+    fn test_spread_angle_from_accuracy() {
+        use crate::systems::accuracy::{apply_shot_bloom, presets};
+
+        let mut accuracy = presets::rifle();
+        apply_shot_bloom(&mut accuracy);
+        let ctx = AccuracyContext {
+            is_moving: true,
+            movement_speed: 3.0,
+            max_speed: 6.0,
+            ..Default::default()
+        };
+        let bloom = accuracy.current_bloom;
+
+        let event = FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 900.0).with_accuracy(&accuracy, &ctx);
+        assert_eq!(event.spread_angle, calculate_total_spread(&accuracy, &ctx));
+        assert!(event.spread_angle > accuracy.base_spread);
+        // Reading the spread doesn't bloom
+        assert_eq!(accuracy.current_bloom, bloom);
+
+        let aimed = AccuracyContext {
+            is_aiming: true,
+            ..Default::default()
+        };
+        let event = event.with_accuracy(&accuracy, &aimed);
+        assert_eq!(event.spread_angle, calculate_total_spread(&accuracy, &aimed));
+    }
 }
@@ -0,0 +1,437 @@
+//! Fixed-point flight integration for lockstep netcode (`deterministic` feature).
+//!
+//! Seeded RNG keeps spread identical across peers, but float drag integration
+//! can still drift apart between CPUs and compilers. With the `deterministic`
+//! feature the kinematics system integrates drag and gravity in Q32.32 integer
+//! arithmetic instead, so the integration itself cannot drift: peers that feed
+//! it bit-identical inputs step bit-identical trajectories.
+//!
+//! Per-round constants (gravity, wind, drag factor) are converted once per step;
+//! only the integration itself, where differences would accumulate, runs on
+//! integers. Positions and velocities are still stored as `f32` between steps.
+//!
+//! The inputs are still computed in `f32`. Gravity, wind and the drag factor use
+//! only basic IEEE operations, which round the same everywhere, but air density
+//! goes through `exp` (altitude) and `powf` (humidity), whose last bit is up to
+//! each platform's math library. Peers on different platforms only stay in
+//! lockstep with dry air at altitude 0 and `BallisticsConfig::altitude_density`
+//! off, or with one platform computing the density for all of them.
+//! `no_drag` rounds keep the closed-form float parabola, which also uses basic
+//! operations only.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use bevy::prelude::*;
+
+use crate::components::{DragRegime, Projectile};
+use crate::resources::BallisticsEnvironment;
+use crate::systems::kinematics::drag_crossover_speed;
+
+/// Signed Q32.32 fixed-point number.
+///
+/// Covers about ±2.1 million km at 2.3e-10 resolution. Arithmetic saturates
+/// instead of wrapping.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(pub i64);
+
+impl Fixed {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 32;
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+    /// One.
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+
+    const SCALE: f64 = (1u64 << Self::FRAC_BITS) as f64;
+
+    /// Convert from `f32`, rounding to the nearest representable value.
+    pub fn from_f32(value: f32) -> Self {
+        // f32 -> f64 and the power-of-two scale are exact; `as` saturates
+        Self((f64::from(value) * Self::SCALE).round() as i64)
+    }
+
+    /// Convert to the nearest `f32`.
+    pub fn to_f32(self) -> f32 {
+        (self.0 as f64 / Self::SCALE) as f32
+    }
+
+    /// Square root (zero for negative values).
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        Self(saturate(((self.0 as u128) << Self::FRAC_BITS).isqrt() as i128))
+    }
+}
+
+/// Clamp a widened intermediate back into `i64`.
+fn saturate(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(saturate((self.0 as i128 * rhs.0 as i128) >> Self::FRAC_BITS))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    /// Division; dividing by zero saturates toward the dividend's sign.
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return Self(if self.0 < 0 { i64::MIN } else { i64::MAX });
+        }
+        Self(saturate(((self.0 as i128) << Self::FRAC_BITS) / rhs.0 as i128))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+/// Three [`Fixed`] components.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FixedVec3 {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+impl FixedVec3 {
+    /// All zeroes.
+    pub const ZERO: Self = Self {
+        x: Fixed::ZERO,
+        y: Fixed::ZERO,
+        z: Fixed::ZERO,
+    };
+
+    /// Convert from a `Vec3`.
+    pub fn from_vec3(value: Vec3) -> Self {
+        Self {
+            x: Fixed::from_f32(value.x),
+            y: Fixed::from_f32(value.y),
+            z: Fixed::from_f32(value.z),
+        }
+    }
+
+    /// Convert to the nearest `Vec3`.
+    pub fn to_vec3(self) -> Vec3 {
+        Vec3::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+
+    /// Squared length.
+    pub fn length_squared(self) -> Fixed {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Length.
+    pub fn length(self) -> Fixed {
+        self.length_squared().sqrt()
+    }
+}
+
+impl Add for FixedVec3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for FixedVec3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Mul<Fixed> for FixedVec3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Fixed) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+/// Forces on one round for one step, in fixed point.
+///
+/// Mirrors the float acceleration model in the kinematics system: gravity
+/// (scaled by `gravity_scale`, fading above the gravity cutoff) minus drag on
+/// the air-relative velocity, in the round's [`DragRegime`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedFlight {
+    /// Gravity acceleration, already scaled by the round's `gravity_scale`
+    pub gravity: FixedVec3,
+    /// Wind velocity at the round's position
+    pub wind: FixedVec3,
+    /// `0.5 * ρ * Cd * A / m`; quadratic drag acceleration is this times speed times velocity
+    pub drag_factor: Fixed,
+    /// How drag scales with airspeed
+    pub regime: DragRegime,
+    /// Airspeed where linear and quadratic drag are equal (`None` for always quadratic)
+    pub crossover_speed: Option<Fixed>,
+    /// Speed above which gravity fades out (`None` for never)
+    pub gravity_cutoff: Option<Fixed>,
+}
+
+impl FixedFlight {
+    /// Forces on `bullet` for one step.
+    ///
+    /// Converting is exact and platform-independent given the same arguments; see
+    /// the [module docs](self) for which of them are.
+    ///
+    /// # Arguments
+    /// * `bullet` - Projectile being integrated
+    /// * `env` - Reference to the ballistics environment
+    /// * `air_density` - Air density at the projectile's position
+    /// * `wind` - Wind velocity at the projectile's position
+    /// * `gravity_cutoff` - Speed above which gravity fades out (`f32::INFINITY` for never)
+    pub fn new(
+        bullet: &Projectile,
+        env: &BallisticsEnvironment,
+        air_density: f32,
+        wind: Vec3,
+        gravity_cutoff: f32,
+    ) -> Self {
+        // Plain IEEE multiplies and a divide: reproducible before conversion
+        let drag_factor = if bullet.no_drag {
+            0.0
+        } else {
+            0.5 * air_density * bullet.drag_coefficient * bullet.reference_area / bullet.mass
+        };
+        Self {
+            gravity: FixedVec3::from_vec3(env.gravity * bullet.gravity_scale),
+            wind: FixedVec3::from_vec3(wind),
+            drag_factor: Fixed::from_f32(drag_factor),
+            regime: bullet.drag_regime,
+            crossover_speed: drag_crossover_speed(bullet.diameter, air_density).map(Fixed::from_f32),
+            gravity_cutoff: gravity_cutoff.is_finite().then(|| Fixed::from_f32(gravity_cutoff)),
+        }
+    }
+
+    /// Acceleration at `velocity`.
+    pub fn acceleration(&self, velocity: FixedVec3) -> FixedVec3 {
+        let gravity = match self.gravity_cutoff {
+            Some(cutoff) => {
+                let speed = velocity.length();
+                if speed > cutoff {
+                    let ratio = cutoff / speed;
+                    self.gravity * (ratio * ratio)
+                } else {
+                    self.gravity
+                }
+            }
+            None => self.gravity,
+        };
+        let relative = velocity - self.wind;
+        let speed = relative.length();
+        // Effective speed the drag factor is scaled by; Mixed is the float
+        // blend with Re / REYNOLDS_CROSSOVER written as speed / crossover
+        let drag_speed = match (self.regime, self.crossover_speed) {
+            (DragRegime::Linear, Some(crossover)) => crossover,
+            (DragRegime::Mixed, Some(crossover)) => {
+                (speed * speed + crossover * crossover) / (speed + crossover)
+            }
+            _ => speed,
+        };
+        gravity - relative * (self.drag_factor * drag_speed)
+    }
+}
+
+/// Advance a position and velocity by one step in fixed point.
+///
+/// Uses the same RK4 or semi-implicit Euler scheme as the float integrators.
+///
+/// # Arguments
+/// * `position` - Position at the start of the step
+/// * `velocity` - Velocity at the start of the step
+/// * `dt` - Time step in seconds
+/// * `flight` - Forces on the round for this step
+/// * `rk4` - Use RK4 instead of Euler
+///
+/// # Returns
+/// The position and velocity at the end of the step
+pub fn integrate(
+    position: FixedVec3,
+    velocity: FixedVec3,
+    dt: Fixed,
+    flight: &FixedFlight,
+    rk4: bool,
+) -> (FixedVec3, FixedVec3) {
+    let accel = if rk4 {
+        let half_dt = Fixed(dt.0 / 2);
+        let k1 = flight.acceleration(velocity);
+        let k2 = flight.acceleration(velocity + k1 * half_dt);
+        let k3 = flight.acceleration(velocity + k2 * half_dt);
+        let k4 = flight.acceleration(velocity + k3 * dt);
+        let sum = k1 + (k2 + k3) * Fixed::from_f32(2.0) + k4;
+        FixedVec3 {
+            x: Fixed(sum.x.0 / 6),
+            y: Fixed(sum.y.0 / 6),
+            z: Fixed(sum.z.0 / 6),
+        }
+    } else {
+        flight.acceleration(velocity)
+    };
+    let velocity = velocity + accel * dt;
+    (position + velocity * dt, velocity)
+}
+
+/// Fixed-point counterpart of the float drag integrators.
+///
+/// Converts the round's state to fixed point, integrates one step and writes
+/// the result back.
+///
+/// # Arguments
+/// * `transform` - Mutable reference to the transform component to update
+/// * `bullet` - Mutable reference to the projectile component
+/// * `dt` - Time step for the integration
+/// * `flight` - Forces on the round for this step
+/// * `rk4` - Use RK4 instead of Euler
+pub fn integrate_transform(
+    transform: &mut Transform,
+    bullet: &mut Projectile,
+    dt: f32,
+    flight: &FixedFlight,
+    rk4: bool,
+) {
+    let (position, velocity) = integrate(
+        FixedVec3::from_vec3(transform.translation),
+        FixedVec3::from_vec3(bullet.velocity),
+        Fixed::from_f32(dt),
+        flight,
+        rk4,
+    );
+    transform.translation = position.to_vec3();
+    bullet.velocity = velocity.to_vec3();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rifle_flight() -> (Projectile, FixedFlight) {
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(5.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        let bullet = Projectile::new(Vec3::new(0.0, 15.0, -850.0)).with_diameter(0.00762);
+        let flight = FixedFlight::new(
+            &bullet,
+            &env,
+            env.effective_air_density(),
+            env.wind,
+            f32::INFINITY,
+        );
+        (bullet, flight)
+    }
+
+    #[test]
+    fn test_fixed_point_runs_are_bit_identical() {
+        // Inputs exact in f32, so no platform's math library is involved
+        let flight = FixedFlight {
+            gravity: FixedVec3::from_vec3(Vec3::new(0.0, -9.8125, 0.0)),
+            wind: FixedVec3::from_vec3(Vec3::new(5.0, 0.0, 0.0)),
+            drag_factor: Fixed::from_f32(0.0009765625),
+            regime: DragRegime::Quadratic,
+            crossover_speed: None,
+            gravity_cutoff: None,
+        };
+        let run = || {
+            let mut position = FixedVec3::ZERO;
+            let mut velocity = FixedVec3::from_vec3(Vec3::new(0.0, 15.0, -850.0));
+            let dt = Fixed::from_f32(1.0 / 64.0);
+            (0..180)
+                .map(|_| {
+                    (position, velocity) = integrate(position, velocity, dt, &flight, true);
+                    position
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = run();
+        assert_eq!(first, run());
+        // Pinned bits: only integer arithmetic ran, so every peer lands here
+        let last = first.last().unwrap();
+        assert_eq!(
+            (last.x.0, last.y.0, last.z.0),
+            (29_359_004_343, -25_387_905_637, -5_276_625_424_279)
+        );
+    }
+
+    #[test]
+    fn test_fixed_point_tracks_float_trajectory() {
+        use crate::resources::BallisticsConfig;
+        use crate::systems::kinematics::apply_environment_forces;
+
+        let (bullet, flight) = rifle_flight();
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(5.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        let config = BallisticsConfig::default();
+        let dt = 1.0 / 60.0;
+        let accel = |velocity: Vec3| {
+            let round = Projectile {
+                velocity,
+                ..bullet.clone()
+            };
+            apply_environment_forces(&round, Vec3::ZERO, &env, &config)
+        };
+
+        let (mut float_position, mut float_velocity) = (Vec3::ZERO, bullet.velocity);
+        let mut position = FixedVec3::ZERO;
+        let mut velocity = FixedVec3::from_vec3(bullet.velocity);
+        for _ in 0..120 {
+            // Float RK4 reference
+            let k1 = accel(float_velocity);
+            let k2 = accel(float_velocity + k1 * (dt / 2.0));
+            let k3 = accel(float_velocity + k2 * (dt / 2.0));
+            let k4 = accel(float_velocity + k3 * dt);
+            float_velocity += (k1 + k2 * 2.0 + k3 * 2.0 + k4) / 6.0 * dt;
+            float_position += float_velocity * dt;
+
+            (position, velocity) = integrate(position, velocity, Fixed::from_f32(dt), &flight, true);
+            // Within a millimeter over ~1.3 km of flight
+            let drift = position.to_vec3().distance(float_position);
+            assert!(drift < 1e-3, "drifted {drift} m from the float path");
+        }
+    }
+}
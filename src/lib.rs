@@ -7,6 +7,7 @@
 //! - Multiple weapon types: pistols, rifles, bows, grenades
 //! - 2D and 3D support via feature flags
 //! - Client-server architecture ready
+//! - Optional fixed-point integration for lockstep determinism (`deterministic` feature)
 //! - Object pooling for performance
 //! - Dynamic accuracy and spread system
 //! - Surface interactions: ricochets, penetration, decals
@@ -33,6 +34,9 @@ pub mod types;
 #[cfg(feature = "netcode")]
 pub mod network;
 
+#[cfg(feature = "deterministic")]
+pub mod fixed_point;
+
 pub mod prelude {
     pub use crate::components::*;
     pub use crate::events::*;
@@ -40,6 +44,8 @@ pub mod prelude {
     pub use crate::types::*;
     pub use crate::BallisticsPluginGroup;
     pub use crate::{BallisticsCorePlugin, BallisticsSurfacePlugin, BallisticsVfxPlugin};
+    pub use crate::BallisticsEventLogPlugin;
+    pub use crate::{BallisticsGameplayPluginGroup, BallisticsSpawnPlugin};
 }
 
 use bevy::prelude::*;
@@ -96,9 +102,36 @@ impl PluginGroup for BallisticsPluginGroup {
 /// 
 /// # Systems
 /// - `update_bloom` - Updates accuracy bloom over time
+/// - `reset_bloom_on_equip` - Clears bloom for each `WeaponEquippedEvent` (Update)
+/// - `update_target_locks` - Acquires and holds `TargetLock` seeker locks on `Lockable` entities
+/// - `assign_locked_targets` - Gives newly fired guided rounds their owner's locked target
 /// - `update_projectiles_kinematics` - Updates projectile positions using physics
-/// - `handle_collisions` - Detects and processes projectile collisions
+/// - `rebuild_spatial_grids` - Rebuilds the proximity/near-miss target grids
+/// - `detect_near_misses` - Reports projectiles passing close to `BulletListener`s
+/// - `emit_shot_reports` - Sends each `BulletListener` the delayed report of every shot (Update)
+/// - `emit_flinch_events` - Sends a `FlinchEvent` for hits on `Flinchable` entities (Update)
+/// - `rezero_on_environment_change` - Re-solves `WeaponZeroing`s when the environment changes (Update)
+/// - `accumulate_ballistics_stats` - Counts shots, hits, penetrations and ricochets into `BallisticsStats` (Update)
+/// - `record_recent_explosions` - Buffers this frame's explosions in `RecentExplosions` (Update)
+/// - `handle_collisions` - Detects and processes projectile collisions (FixedUpdate or
+///   Update, see `BallisticsConfig::collision_schedule`)
 /// - `process_projectile_logic` - Handles timed fuses and other projectile behaviors
+/// - `cleanup_expired_projectiles` - Removes rounds past their lifetime, range or minimum
+///   speed, sending a `ProjectileDespawnedEvent`
+/// - `despawn_orphaned_projectiles` - Removes rounds whose owner was despawned, under
+///   `OrphanPolicy::DespawnWithOwner`
+/// - `update_spent_projectiles` - Expires and caps spent rounds kept as debris or pooled
+/// - `restore_interpolated_transforms` / `record_previous_positions` / `record_current_positions` /
+///   `interpolate_transforms` - Smooth rendering of `InterpolatedTransform` projectiles between
+///   fixed steps (before the fixed loop, FixedFirst, FixedLast, PostUpdate)
+/// 
+/// # Fixed timestep
+/// Leaves `Time<Fixed>` to the app unless `BallisticsConfig::fixed_timestep_hz` opts
+/// in to a rate; see `apply_fixed_timestep`.
+/// 
+/// # Observers
+/// - `index_projectile_owner` / `unindex_projectile_owner` - Maintain `ProjectilesByOwner`
+/// - `init_interpolated_transform` - Seeds `InterpolatedTransform` samples from the `Transform`
 pub struct BallisticsCorePlugin;
 
 impl Plugin for BallisticsCorePlugin {
@@ -114,23 +147,93 @@ impl Plugin for BallisticsCorePlugin {
             .register_type::<components::Accuracy>()
             .register_type::<components::ProjectileLogic>()
             .register_type::<components::Payload>()
+            .register_type::<components::DragRegime>()
             .register_type::<components::Weapon>()
             .register_type::<components::Guidance>()
+            .register_type::<components::WindZone>()
+            .register_type::<components::BulletListener>()
+            .register_type::<components::ProximityTarget>()
+            .register_type::<components::SpentProjectile>()
+            .register_type::<components::ProjectileCollisionMask>()
+            .register_type::<components::Team>()
+            .register_type::<components::BounceOnContact>()
+            .register_type::<components::Pierce>()
+            .register_type::<components::AmmoType>()
+            .register_type::<components::ArmingDistance>()
+            .register_type::<components::DrawStrength>()
+            .register_type::<components::Fragmentation>()
+            .register_type::<components::FragmentGrace>()
+            .register_type::<components::BounceSurface>()
+            .register_type::<components::Resistances>()
+            .register_type::<components::InterpolatedTransform>()
+            .register_type::<components::Flinchable>()
+            .register_type::<components::Lockable>()
+            .register_type::<components::TargetLock>()
             .init_resource::<resources::BallisticsEnvironment>()
             .init_resource::<resources::BallisticsConfig>()
+            .init_resource::<resources::SpatialGrid<components::ProximityTarget>>()
+            .init_resource::<resources::SpatialGrid<components::BulletListener>>()
+            .init_resource::<resources::SpentProjectiles>()
+            .init_resource::<resources::ProjectilesByOwner>()
+            .init_resource::<resources::ExplosionImpulseTable>()
+            .register_type::<resources::ExplosionImpulseTable>()
+            .init_resource::<resources::SelfDamageGrace>()
+            .register_type::<resources::SelfDamageGrace>()
+            .init_resource::<resources::BallisticsStats>()
+            .init_resource::<resources::RecentExplosions>()
+            .register_type::<resources::BallisticsStats>()
+            .add_observer(systems::ownership::index_projectile_owner)
+            .add_observer(systems::ownership::unindex_projectile_owner)
+            .add_observer(systems::interpolation::init_interpolated_transform)
             .add_message::<events::FireEvent>()
+            .add_message::<events::ProjectileSpawnedEvent>()
             .add_message::<events::HitEvent>()
             .add_message::<events::ExplosionEvent>()
             .add_message::<events::PenetrationEvent>()
             .add_message::<events::RicochetEvent>()
+            .add_message::<events::NearMissEvent>()
+            .add_message::<events::ShotReportEvent>()
+            .add_message::<events::FlinchEvent>()
+            .add_message::<events::LockAcquiredEvent>()
+            .add_message::<events::ProjectileDespawnedEvent>()
+            .add_message::<events::WeaponEquippedEvent>()
+            .add_systems(
+                Update,
+                (
+                    systems::accuracy::reset_bloom_on_equip,
+                    systems::near_miss::emit_shot_reports,
+                    systems::flinch::emit_flinch_events,
+                    systems::trajectory::rezero_on_environment_change,
+                    systems::event_log::accumulate_ballistics_stats,
+                    systems::logic::record_recent_explosions,
+                ),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                systems::interpolation::restore_interpolated_transforms
+                    .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+            )
+            .add_systems(FixedFirst, systems::interpolation::record_previous_positions)
+            .add_systems(FixedLast, systems::interpolation::record_current_positions)
+            .add_systems(
+                PostUpdate,
+                systems::interpolation::interpolate_transforms
+                    .before(bevy::transform::TransformSystems::Propagate),
+            )
             .add_systems(
                 FixedUpdate,
                 (
                     systems::accuracy::update_bloom,
+                    systems::targeting::update_target_locks,
+                    systems::targeting::assign_locked_targets,
                     systems::kinematics::update_guidance,
                     systems::kinematics::update_projectiles_kinematics,
+                    systems::spatial::rebuild_spatial_grids,
+                    systems::near_miss::detect_near_misses,
                     systems::logic::process_projectile_logic,
                     systems::logic::cleanup_expired_projectiles,
+                    systems::logic::despawn_orphaned_projectiles,
+                    systems::debris::update_spent_projectiles,
                 )
                     .chain(),
             );
@@ -139,14 +242,22 @@ impl Plugin for BallisticsCorePlugin {
         #[cfg(feature = "dim3")]
         {
             use avian3d::prelude::SpatialQueryPipeline;
+            use systems::collision::{collisions_in_fixed_update, collisions_in_update};
             app.add_systems(
                 FixedUpdate,
                 (
-                    systems::collision::handle_collisions,
+                    systems::collision::handle_collisions
+                        .run_if(collisions_in_fixed_update)
+                        .after(systems::near_miss::detect_near_misses),
                     systems::logic::apply_explosion_impulse,
                     systems::logic::process_hitscan,
                 )
                     .run_if(resource_exists::<SpatialQueryPipeline>),
+            )
+            .add_systems(
+                Update,
+                systems::collision::handle_collisions
+                    .run_if(resource_exists::<SpatialQueryPipeline>.and(collisions_in_update)),
             );
         }
 
@@ -154,17 +265,31 @@ impl Plugin for BallisticsCorePlugin {
         #[cfg(feature = "dim2")]
         {
             use avian2d::prelude::SpatialQueryPipeline;
+            use systems::collision::{collisions_in_fixed_update, collisions_in_update};
             app.add_systems(
                 FixedUpdate,
                 (
-                    systems::collision::handle_collisions_2d,
+                    systems::collision::handle_collisions_2d
+                        .run_if(collisions_in_fixed_update)
+                        .after(systems::near_miss::detect_near_misses),
                     systems::logic::apply_explosion_impulse_2d,
                     systems::logic::process_hitscan_2d,
                 )
                     .run_if(resource_exists::<SpatialQueryPipeline>),
+            )
+            .add_systems(
+                Update,
+                systems::collision::handle_collisions_2d
+                    .run_if(resource_exists::<SpatialQueryPipeline>.and(collisions_in_update)),
             );
         }
     }
+
+    /// Applies `BallisticsConfig::fixed_timestep_hz` once every plugin (and any
+    /// resources the app inserted after adding them) is in place.
+    fn finish(&self, app: &mut App) {
+        systems::kinematics::apply_fixed_timestep(app.world_mut());
+    }
 }
 
 /// Surface interaction plugin (ricochets, penetration, material effects).
@@ -189,6 +314,7 @@ impl Plugin for BallisticsSurfacePlugin {
     /// * `app` - Mutable reference to the Bevy App
     fn build(&self, app: &mut App) {
         app.register_type::<components::SurfaceMaterial>()
+            .register_type::<components::Armor>()
             .add_systems(FixedUpdate, systems::surface::process_surface_interactions);
     }
 }
@@ -203,8 +329,20 @@ impl Plugin for BallisticsSurfacePlugin {
 /// 
 /// # Systems
 /// - `update_tracers` - Updates tracer lifetimes and hides expired ones
-/// - `spawn_impact_effects` - Spawns visual effects at hit locations
+/// - `spawn_impact_effects` - Spawns visual effects at hit locations with the `dim3`
+///   feature (sprite decals from `spawn_impact_effects_2d` with `dim2`)
+/// - `spawn_penetration_effects` - Spawns entry/exit effects for penetrations with `dim3`
+///   (sprites from `spawn_penetration_effects_2d` with `dim2`)
+/// - `spawn_ricochet_effects` - Sprays surface debris along ricochet directions with
+///   `dim3` (sprites from `spawn_ricochet_effects_2d` with `dim2`)
+/// - `enforce_decal_budget` - Fades out the oldest decals past `BallisticsConfig::max_decals`
 /// - `cleanup_expired_effects` - Cleans up expired visual effects
+/// - `update_projectile_visual_scale` - Grows opted-in projectiles with distance
+/// - `spawn_persistent_trails` / `update_persistent_trails` / `update_trail_meshes` - Lingering flight-path trails
+/// - `update_laser_sights` - Projects laser sight dots (3D only)
+/// 
+/// # Observers
+/// - `despawn_laser_dot` - Removes a laser sight's dot along with the sight (3D only)
 pub struct BallisticsVfxPlugin;
 
 impl Plugin for BallisticsVfxPlugin {
@@ -216,7 +354,13 @@ impl Plugin for BallisticsVfxPlugin {
     /// # Arguments
     /// * `app` - Mutable reference to the Bevy App
     fn build(&self, app: &mut App) {
-        app.init_resource::<resources::TracerPool>()
+        app.register_type::<components::LaserSight>()
+            .register_type::<components::LaserDot>()
+            .register_type::<components::ProjectileVisualScale>()
+            .register_type::<components::PersistentTrail>()
+            .init_resource::<resources::BallisticsConfig>()
+            .init_resource::<resources::BallisticsEnvironment>()
+            .init_resource::<resources::TracerPool>()
             .init_resource::<resources::DecalPool>()
             .init_resource::<resources::BallisticsAssets>()
             .add_systems(Startup, setup_ballistics_assets)
@@ -224,13 +368,53 @@ impl Plugin for BallisticsVfxPlugin {
                 Update,
                 (
                     systems::vfx::update_tracers,
-                    systems::vfx::spawn_impact_effects,
-                    systems::vfx::cleanup_expired_effects,
+                    (
+                        systems::vfx::enforce_decal_budget,
+                        systems::vfx::cleanup_expired_effects,
+                    )
+                        .chain(),
                     systems::vfx::update_muzzle_flash,
                     systems::vfx::update_explosion_vfx,
                     systems::vfx::spawn_explosion_vfx_from_event,
+                    systems::vfx::update_projectile_visual_scale,
+                    (
+                        systems::vfx::spawn_persistent_trails,
+                        systems::vfx::update_persistent_trails,
+                        systems::vfx::update_trail_meshes,
+                    )
+                        .chain(),
                 ),
             );
+
+        #[cfg(feature = "dim3")]
+        app.add_systems(
+            Update,
+            (
+                systems::vfx::spawn_impact_effects,
+                systems::vfx::spawn_penetration_effects,
+                systems::vfx::spawn_ricochet_effects,
+            ),
+        );
+        #[cfg(feature = "dim2")]
+        app.add_systems(
+            Update,
+            (
+                systems::vfx::spawn_impact_effects_2d,
+                systems::vfx::spawn_penetration_effects_2d,
+                systems::vfx::spawn_ricochet_effects_2d,
+            ),
+        );
+
+        #[cfg(feature = "dim3")]
+        {
+            use avian3d::prelude::SpatialQueryPipeline;
+            app.add_systems(
+                Update,
+                systems::vfx::update_laser_sights
+                    .run_if(resource_exists::<SpatialQueryPipeline>),
+            )
+            .add_observer(systems::vfx::despawn_laser_dot);
+        }
     }
 }
 
@@ -261,6 +445,24 @@ fn setup_ballistics_assets(
         ..default()
     });
     
+    assets.wood_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.45, 0.3, 0.15),
+        ..default()
+    });
+    
+    assets.glass_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.8, 0.9, 1.0, 0.5),
+        alpha_mode: AlphaMode::Blend,
+        reflectance: 0.9,
+        ..default()
+    });
+    
+    assets.water_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.6, 0.75, 0.9, 0.6),
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    
     assets.flash_material = materials.add(StandardMaterial {
         base_color: Color::srgb(1.0, 0.9, 0.5),
         emissive: LinearRgba::rgb(5.0, 4.0, 1.0),
@@ -278,11 +480,105 @@ fn setup_ballistics_assets(
 }
 
 /// Debug plugin for ballistics visualization.
+/// 
+/// # Systems
+/// - `draw_projectile_debug` - Gizmos for projectile positions and velocities
+/// - `update_debug_overlay` - On-screen text with projectile count, average speed,
+///   pool occupancy and events/sec (only while `debug_draw` is on)
+/// - `remove_debug_overlay` - Despawns the overlay when `debug_draw` is turned off
 pub struct BallisticsDebugPlugin;
 
 impl Plugin for BallisticsDebugPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, systems::debug::draw_projectile_debug);
+        app.register_type::<components::BallisticsDebugOverlay>()
+            .add_systems(
+                Update,
+                (
+                    systems::debug::draw_projectile_debug,
+                    systems::debug::update_debug_overlay
+                        .run_if(systems::debug::debug_draw_enabled),
+                    systems::debug::remove_debug_overlay
+                        .run_if(resource_changed::<resources::BallisticsConfig>),
+                ),
+            );
+    }
+}
+
+/// Event log plugin recording fire, hit and explosion events.
+/// 
+/// Not part of [`BallisticsPluginGroup`]: add it when you need a timeline for
+/// kill replays, demos or desync debugging; games that don't need it pay nothing.
+/// Recorded events end up in the [`resources::BallisticsEventLog`] resource.
+/// 
+/// # Fields
+/// * `capacity` - Number of entries kept before the oldest are dropped
+/// 
+/// # Systems
+/// - `record_ballistics_events` - Appends each frame's events to the log (PostUpdate)
+pub struct BallisticsEventLogPlugin {
+    pub capacity: usize,
+}
+
+impl Default for BallisticsEventLogPlugin {
+    /// Keeps the last 1024 events.
+    fn default() -> Self {
+        Self { capacity: 1024 }
+    }
+}
+
+impl Plugin for BallisticsEventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(resources::BallisticsEventLog::new(self.capacity))
+            .add_systems(PostUpdate, systems::event_log::record_ballistics_events);
     }
 }
 
+/// Opt-in gameplay plugins, kept out of [`BallisticsPluginGroup`].
+/// 
+/// [`BallisticsPluginGroup`] only simulates projectiles the game spawns itself.
+/// This group adds the gameplay layer on top; each plugin also works on its own
+/// and registers only the messages it needs, so it can be combined with the
+/// default group or used without it.
+/// 
+/// # Plugins
+/// - [`BallisticsSpawnPlugin`] - Spawns projectiles from `FireEvent`s
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::prelude::*;
+/// 
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins((BallisticsPluginGroup, BallisticsGameplayPluginGroup))
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct BallisticsGameplayPluginGroup;
+
+impl PluginGroup for BallisticsGameplayPluginGroup {
+    fn build(self) -> bevy::app::PluginGroupBuilder {
+        bevy::app::PluginGroupBuilder::start::<Self>().add(BallisticsSpawnPlugin)
+    }
+}
+
+/// Fire-event-driven projectile spawning.
+/// 
+/// With this plugin, writing a `FireEvent` is enough to put rounds in the air.
+/// Games that spawn their own projectiles and only write `FireEvent` for
+/// bookkeeping should leave it out, or every shot is doubled.
+/// 
+/// # Systems
+/// - `spawn_projectiles_from_fire_events` - Spawns each event's pellets from its
+///   `WeaponPresets` entry and sends `ProjectileSpawnedEvent`s (Update)
+pub struct BallisticsSpawnPlugin;
+
+impl Plugin for BallisticsSpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<events::FireEvent>()
+            .add_message::<events::ProjectileSpawnedEvent>()
+            .add_systems(Update, systems::fire::spawn_projectiles_from_fire_events);
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy_renet2::prelude::*;
 use bevy_renet2::netcode::NetcodeClientPlugin;
 use crate::network::protocol::{Channel, PlayerInput, ServerMessage};
+use crate::network::prediction::{reconcile_spawn, update_ghost_trails, GhostTrail, GHOST_ALPHA};
 use crate::components::*;
 
 pub struct BallisticsClientPlugin;
@@ -15,10 +16,12 @@ impl Plugin for BallisticsClientPlugin {
             app.add_plugins(NetcodeClientPlugin);
         }
         
+        app.register_type::<GhostTrail>();
         app.add_systems(Update, (
             client_sync_system,
             client_input_system,
             client_csp_cleanup,
+            update_ghost_trails,
         ));
     }
 }
@@ -27,7 +30,7 @@ fn client_sync_system(
     mut client: ResMut<RenetClient>,
     mut commands: Commands,
     ballistics_assets: Res<crate::resources::BallisticsAssets>,
-    // query needed for reconciliation
+    mut ghosts: Query<(Entity, &mut GhostTrail, &Projectile)>,
 ) {
     if !client.is_connected() { return; }
         
@@ -40,8 +43,19 @@ fn client_sync_system(
                      // In real CSP, we would blend or correct prediction error.
                 }
                 ServerMessage::SpawnProjectile { id, owner_fmt: _, pos, vel, weapon_type: _ } => {
+                    // Our own shot: the predicted round becomes the server's and turns solid
+                    let candidates = ghosts
+                        .iter_mut()
+                        .map(|(entity, ghost, projectile)| (entity, ghost, projectile.velocity));
+                    if let Some(predicted) = reconcile_spawn(candidates, pos, vel) {
+                        commands
+                            .entity(predicted)
+                            .insert((NetworkId(id), Authoritative))
+                            .remove::<Predicted>();
+                        continue;
+                    }
+
                     // Spawn authoritative projectile
-                    // Ideally we check if we already have a predicted one matching this?
                      commands.spawn((
                         Mesh3d(ballistics_assets.sphere_mesh.clone()),
                         MeshMaterial3d(ballistics_assets.flash_material.clone()),
@@ -63,6 +77,7 @@ fn client_input_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
     ballistics_assets: Res<crate::resources::BallisticsAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
     if !client.is_connected() { return; }
 
@@ -82,22 +97,35 @@ fn client_input_system(
 
     // CSP: If shooting, spawn local projectile VISUAL ONLY (Predicted)
     if shoot {
+        // Own translucent material, so the ghost can fade without touching other rounds
+        let base = materials
+            .get(&ballistics_assets.spark_material)
+            .cloned()
+            .unwrap_or_default();
+        let ghost_material = materials.add(StandardMaterial {
+            base_color: base.base_color.with_alpha(GHOST_ALPHA),
+            alpha_mode: AlphaMode::Blend,
+            ..base
+        });
+        let origin = Vec3::Y * 2.0;
          commands.spawn((
             Mesh3d(ballistics_assets.sphere_mesh.clone()),
-            MeshMaterial3d(ballistics_assets.spark_material.clone()),
+            MeshMaterial3d(ghost_material),
             Projectile::new(Vec3::Z * 900.0),
-            Transform::from_translation(Vec3::Y * 2.0),
+            Transform::from_translation(origin),
             Predicted,
+            GhostTrail::new(origin),
         ));
         println!("Spawned Predicted Projectile");
     }
 }
 
 /// Simple cleanup for predicted entities to avoid double-simulation for too long
+/// (ghost-trailed ones fade out instead, see `update_ghost_trails`)
 fn client_csp_cleanup(
     mut commands: Commands,
     _time: Res<Time>,
-    query: Query<(Entity, &Projectile), With<Predicted>>,
+    query: Query<(Entity, &Projectile), (With<Predicted>, Without<GhostTrail>)>,
 ) {
     for (entity, projectile) in query.iter() {
         // If predicted projectile is alive more than 0.5s, assume server authoritative one should have arrived
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 pub mod protocol;
 pub mod server;
 pub mod client;
+pub mod prediction;
 
 pub struct BallisticsNetworkPlugin;
 
@@ -11,5 +12,10 @@ impl Plugin for BallisticsNetworkPlugin {
         // Shared configuration
         app.add_plugins(server::BallisticsServerPlugin);
         app.add_plugins(client::BallisticsClientPlugin);
+
+        // Peers must step identically, whatever bullet time the local game uses
+        app.world_mut()
+            .get_resource_or_insert_with(crate::resources::BallisticsConfig::default)
+            .ignore_time_scale = true;
     }
 }
@@ -0,0 +1,249 @@
+//! Client-side prediction visuals - ghost trails for rounds the server hasn't confirmed yet.
+
+use bevy::prelude::*;
+
+use crate::components::Projectile;
+
+/// Opacity of a predicted round until the server confirms it.
+pub const GHOST_ALPHA: f32 = 0.35;
+
+/// Opacity change per second while a ghost turns solid or fades out.
+pub const GHOST_FADE_RATE: f32 = 4.0;
+
+/// Age (seconds) after which an unconfirmed predicted round counts as mispredicted.
+pub const RECONCILE_TIMEOUT: f32 = 0.5;
+
+/// Largest distance (meters) between the predicted and server launch points that still match.
+pub const RECONCILE_POSITION_TOLERANCE: f32 = 1.0;
+
+/// Largest angle (radians) between the predicted and server launch directions that still match.
+pub const RECONCILE_ANGLE_TOLERANCE: f32 = 0.05;
+
+/// Translucent "ghost" look of a client-predicted round.
+///
+/// Put this next to `Predicted` when spawning the local round, with its own
+/// material so the fade doesn't touch other rounds. The round is drawn at
+/// [`GHOST_ALPHA`] until the server's spawn for it arrives and
+/// [`reconcile_spawn`] confirms it; it then blends to solid and the component
+/// is removed. A round the server never confirms within [`RECONCILE_TIMEOUT`]
+/// is mispredicted and fades out before being despawned.
+///
+/// # Fields
+/// * `origin` - Where the round was launched locally
+/// * `confirmed` - The server spawned a matching round
+/// * `mispredicted` - No matching round arrived in time
+/// * `alpha` - Current opacity
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct GhostTrail {
+    /// Local launch point
+    pub origin: Vec3,
+    /// The server spawned a matching round
+    pub confirmed: bool,
+    /// No matching round arrived in time
+    pub mispredicted: bool,
+    /// Current opacity
+    pub alpha: f32,
+}
+
+impl GhostTrail {
+    /// Creates a ghost for a round launched locally from `origin`.
+    pub fn new(origin: Vec3) -> Self {
+        Self {
+            origin,
+            confirmed: false,
+            mispredicted: false,
+            alpha: GHOST_ALPHA,
+        }
+    }
+
+    /// Whether a round the server launched from `origin` with `velocity`
+    /// is this prediction of it.
+    ///
+    /// # Arguments
+    /// * `predicted_velocity` - Current velocity of the predicted round
+    /// * `origin` - Server launch point
+    /// * `velocity` - Server launch velocity
+    pub fn matches(&self, predicted_velocity: Vec3, origin: Vec3, velocity: Vec3) -> bool {
+        !self.confirmed
+            && !self.mispredicted
+            && self.origin.distance(origin) <= RECONCILE_POSITION_TOLERANCE
+            && predicted_velocity.angle_between(velocity) <= RECONCILE_ANGLE_TOLERANCE
+    }
+
+    /// Opacity the ghost is heading for.
+    pub fn target_alpha(&self) -> f32 {
+        if self.confirmed {
+            1.0
+        } else if self.mispredicted {
+            0.0
+        } else {
+            GHOST_ALPHA
+        }
+    }
+
+    /// Advance the ghost by `dt` seconds for a round of the given age.
+    ///
+    /// Marks the round mispredicted once it outlives [`RECONCILE_TIMEOUT`]
+    /// unconfirmed, then moves `alpha` toward [`GhostTrail::target_alpha`] at
+    /// [`GHOST_FADE_RATE`].
+    pub fn advance(&mut self, age: f32, dt: f32) {
+        if !self.confirmed && age > RECONCILE_TIMEOUT {
+            self.mispredicted = true;
+        }
+        let target = self.target_alpha();
+        let step = GHOST_FADE_RATE * dt;
+        self.alpha = if self.alpha < target {
+            (self.alpha + step).min(target)
+        } else {
+            (self.alpha - step).max(target)
+        };
+    }
+
+    /// Confirmed and fully solid: the ghost can be dropped.
+    pub fn is_solid(&self) -> bool {
+        self.confirmed && self.alpha >= 1.0
+    }
+
+    /// Mispredicted and fully faded: the round can be despawned.
+    pub fn is_faded_out(&self) -> bool {
+        self.mispredicted && self.alpha <= 0.0
+    }
+}
+
+/// Match a server-spawned round against the local predictions.
+///
+/// The closest matching ghost (by launch point) is confirmed.
+///
+/// # Arguments
+/// * `ghosts` - Predicted rounds: entity, ghost and current velocity
+/// * `origin` - Server launch point
+/// * `velocity` - Server launch velocity
+///
+/// # Returns
+/// The predicted entity now standing in for the server round, or None to spawn it
+pub fn reconcile_spawn<'a>(
+    ghosts: impl IntoIterator<Item = (Entity, Mut<'a, GhostTrail>, Vec3)>,
+    origin: Vec3,
+    velocity: Vec3,
+) -> Option<Entity> {
+    let (entity, mut ghost, _) = ghosts
+        .into_iter()
+        .filter(|(_, ghost, predicted_velocity)| ghost.matches(*predicted_velocity, origin, velocity))
+        .min_by(|(_, a, _), (_, b, _)| {
+            a.origin.distance(origin).total_cmp(&b.origin.distance(origin))
+        })?;
+    ghost.confirmed = true;
+    Some(entity)
+}
+
+/// Fade predicted rounds toward their confirmed or mispredicted look.
+///
+/// Writes each ghost's opacity into its `StandardMaterial`. Confirmed rounds
+/// lose the `GhostTrail` once solid; mispredicted ones are despawned once
+/// invisible, so neither pops.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for removing ghosts and despawning rounds
+/// * `time` - Bevy Time resource
+/// * `materials` - Material assets holding each ghost's own material
+/// * `ghosts` - Query for predicted rounds with a ghost trail
+pub fn update_ghost_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ghosts: Query<(
+        Entity,
+        &Projectile,
+        &mut GhostTrail,
+        Option<&MeshMaterial3d<StandardMaterial>>,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (entity, projectile, mut ghost, material) in ghosts.iter_mut() {
+        ghost.advance(projectile.age, dt);
+
+        if let Some(material) = material.and_then(|handle| materials.get_mut(&handle.0)) {
+            material.base_color.set_alpha(ghost.alpha);
+            material.alpha_mode = if ghost.is_solid() {
+                AlphaMode::Opaque
+            } else {
+                AlphaMode::Blend
+            };
+        }
+
+        if ghost.is_solid() {
+            commands.entity(entity).remove::<GhostTrail>();
+        } else if ghost.is_faded_out() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_reconcile_confirms_matching_prediction() {
+        let mut world = World::new();
+        let origin = Vec3::Y * 2.0;
+        let ahead = world
+            .spawn((GhostTrail::new(origin), Projectile::new(Vec3::NEG_Z * 900.0)))
+            .id();
+        let aside = world
+            .spawn((GhostTrail::new(origin), Projectile::new(Vec3::X * 900.0)))
+            .id();
+
+        let reconcile = |world: &mut World, origin: Vec3, velocity: Vec3| {
+            world
+                .run_system_once(move |mut ghosts: Query<(Entity, &mut GhostTrail, &Projectile)>| {
+                    reconcile_spawn(
+                        ghosts
+                            .iter_mut()
+                            .map(|(entity, ghost, projectile)| (entity, ghost, projectile.velocity)),
+                        origin,
+                        velocity,
+                    )
+                })
+                .unwrap()
+        };
+
+        // The server's round, launched a little off the local muzzle
+        let matched = reconcile(&mut world, origin + Vec3::X * 0.2, Vec3::NEG_Z * 880.0);
+        assert_eq!(matched, Some(ahead));
+        assert!(world.get::<GhostTrail>(ahead).unwrap().confirmed);
+        assert!(!world.get::<GhostTrail>(aside).unwrap().confirmed);
+
+        // Already confirmed rounds and far-off launches aren't matched again
+        assert_eq!(reconcile(&mut world, origin, Vec3::NEG_Z * 900.0), None);
+        assert_eq!(reconcile(&mut world, origin + Vec3::Y * 5.0, Vec3::X * 900.0), None);
+    }
+
+    #[test]
+    fn test_ghosts_fade_instead_of_popping() {
+        let dt = 1.0 / 60.0;
+
+        // Confirmed: blends up to solid over several frames
+        let mut confirmed = GhostTrail::new(Vec3::ZERO);
+        confirmed.confirmed = true;
+        confirmed.advance(0.1, dt);
+        assert!(confirmed.alpha > GHOST_ALPHA && confirmed.alpha < 1.0);
+        for _ in 0..60 {
+            confirmed.advance(0.1, dt);
+        }
+        assert!(confirmed.is_solid());
+
+        // Never confirmed: turns mispredicted after the timeout and fades out gradually
+        let mut stray = GhostTrail::new(Vec3::ZERO);
+        stray.advance(RECONCILE_TIMEOUT * 0.5, dt);
+        assert_eq!(stray.alpha, GHOST_ALPHA);
+        stray.advance(RECONCILE_TIMEOUT + dt, dt);
+        assert!(stray.mispredicted && stray.alpha > 0.0 && !stray.is_faded_out());
+        for _ in 0..60 {
+            stray.advance(RECONCILE_TIMEOUT + dt, dt);
+        }
+        assert!(stray.is_faded_out());
+    }
+}
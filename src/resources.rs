@@ -1,6 +1,10 @@
 //! Global resources for the ballistics system.
 
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use smallvec::SmallVec;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
 
 /// Global environment settings affecting all projectiles.
 /// 
@@ -12,8 +16,12 @@ use bevy::prelude::*;
 /// * `gravity` - Gravity vector in meters per second squared
 /// * `air_density` - Air density in kg/m³ affecting drag calculations
 /// * `wind` - Wind velocity vector in meters per second
+/// * `wind_profile` - Optional wind layers as `(altitude, wind)` pairs, sorted by altitude
 /// * `temperature` - Ambient temperature in Celsius affecting air density
+/// * `humidity` - Relative humidity from 0.0 (dry) to 1.0 (saturated); humid air is less dense
 /// * `altitude` - Altitude in meters affecting air density
+/// * `latitude` - Latitude in degrees (positive North)
+/// * `up_axis` - World "up" direction; heights for wind and density are measured along it
 /// 
 /// # Example
 /// ```
@@ -26,6 +34,7 @@ use bevy::prelude::*;
 ///     wind: Vec3::new(2.0, 0.0, 0.0),
 ///     temperature: 25.0,
 ///     altitude: 100.0,
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Resource, Reflect, Clone)]
@@ -37,12 +46,19 @@ pub struct BallisticsEnvironment {
     pub air_density: f32,
     /// Wind velocity vector (m/s)
     pub wind: Vec3,
+    /// Wind layers as `(altitude, wind)` pairs sorted by ascending altitude (meters, m/s).
+    /// When empty, the constant `wind` is used everywhere.
+    pub wind_profile: Vec<(f32, Vec3)>,
     /// Temperature affecting air density calculations (Celsius)
     pub temperature: f32,
+    /// Relative humidity (0.0 = dry, 1.0 = saturated), lowering air density
+    pub humidity: f32,
     /// Altitude affecting air density (meters)
     pub altitude: f32,
     /// Latitude in degrees (positive North, negative South)
     pub latitude: f32,
+    /// Unit vector pointing "up" in world space (opposite to gravity)
+    pub up_axis: Vec3,
 }
 
 impl Default for BallisticsEnvironment {
@@ -53,7 +69,9 @@ impl Default for BallisticsEnvironment {
     /// - Air density: 1.225 kg/m³ (sea level standard)
     /// - No wind
     /// - Temperature: 20°C
+    /// - Humidity: 0% (dry air)
     /// - Altitude: Sea level (0m)
+    /// - Up axis: +Y
     /// 
     /// # Returns
     /// A new BallisticsEnvironment instance with default values
@@ -62,9 +80,12 @@ impl Default for BallisticsEnvironment {
             gravity: Vec3::new(0.0, -9.81, 0.0),
             air_density: 1.225, // Standard at sea level
             wind: Vec3::ZERO,
+            wind_profile: Vec::new(),
             temperature: 20.0,
+            humidity: 0.0,
             altitude: 0.0,
             latitude: 45.0, // Default to 45 degrees North
+            up_axis: Vec3::Y,
         }
     }
 }
@@ -72,31 +93,157 @@ impl Default for BallisticsEnvironment {
 impl BallisticsEnvironment {
     /// Creates environment for 2D (ignores Z component).
     /// 
-    /// This constructor creates an environment suitable for 2D simulations
-    /// where the Z-axis is ignored in physics calculations.
+    /// This constructor creates an environment suitable for 2D simulations in the
+    /// XY plane, the same plane the 2D collision path raycasts in. Up is +Y and
+    /// gravity pulls 9.81 m/s² toward -Y, so thrown objects arc like in a
+    /// side-scroller. Use [`Self::with_up_axis`] if your world treats -Y as up:
+    /// it re-aims gravity, and the shared flight and fuse systems measure height
+    /// along it (wind and density profiles, cluster `deploy_altitude`). The 2D
+    /// collision, explosion and effect systems work in the XY plane and never
+    /// read it. Scale `gravity` if your world units are pixels rather than meters.
     /// 
     /// # Returns
     /// A new BallisticsEnvironment instance with 2D-appropriate settings
     pub fn new_2d() -> Self {
         Self {
             gravity: Vec3::new(0.0, -9.81, 0.0),
+            up_axis: Vec3::Y,
             latitude: 0.0, // Equator for 2D? (irrelevant if Coriolis ignored in 2D)
             ..Default::default()
         }
     }
 
-    /// Calculate adjusted air density based on altitude and temperature.
+    /// Builder pattern: set the up axis.
+    ///
+    /// Gravity is re-aimed to point opposite the new axis, keeping its strength.
+    /// For 2D the axis should lie in the XY plane.
+    ///
+    /// # Arguments
+    /// * `up` - World-space "up" direction (normalized internally)
+    ///
+    /// # Returns
+    /// The modified BallisticsEnvironment instance for method chaining
+    pub fn with_up_axis(mut self, up: Vec3) -> Self {
+        self.up_axis = up.normalize_or(Vec3::Y);
+        self.gravity = -self.up_axis * self.gravity.length();
+        self
+    }
+
+    /// Height of a world position along the up axis (meters).
+    ///
+    /// # Arguments
+    /// * `position` - World-space position
+    ///
+    /// # Returns
+    /// The signed distance above the origin measured along `up_axis`
+    pub fn height_of(&self, position: Vec3) -> f32 {
+        position.dot(self.up_axis)
+    }
+
+    /// Builder pattern: set the wind profile.
+    ///
+    /// Layers are sorted by altitude so they can be sampled with [`Self::wind_at`].
+    ///
+    /// # Arguments
+    /// * `profile` - Wind layers as `(altitude, wind)` pairs
+    ///
+    /// # Returns
+    /// The modified BallisticsEnvironment instance for method chaining
+    pub fn with_wind_profile(mut self, mut profile: Vec<(f32, Vec3)>) -> Self {
+        profile.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.wind_profile = profile;
+        self
+    }
+
+    /// Sample the wind velocity at a given height.
+    ///
+    /// Linearly interpolates between the two surrounding layers of `wind_profile`,
+    /// clamping to the lowest/highest layer outside the profile. Falls back to the
+    /// constant `wind` when no profile is set.
+    ///
+    /// # Arguments
+    /// * `height` - Height (along `up_axis`) at which to sample the wind, in meters
+    ///
+    /// # Returns
+    /// The wind velocity vector at that height (m/s)
+    pub fn wind_at(&self, height: f32) -> Vec3 {
+        let Some(&(first_alt, first_wind)) = self.wind_profile.first() else {
+            return self.wind;
+        };
+        if height <= first_alt {
+            return first_wind;
+        }
+
+        for window in self.wind_profile.windows(2) {
+            let (low_alt, low_wind) = window[0];
+            let (high_alt, high_wind) = window[1];
+            if height <= high_alt {
+                let span = high_alt - low_alt;
+                if span <= f32::EPSILON {
+                    return high_wind;
+                }
+                return low_wind.lerp(high_wind, (height - low_alt) / span);
+            }
+        }
+
+        // Above the highest layer
+        self.wind_profile.last().map_or(self.wind, |layer| layer.1)
+    }
+
+    /// Calculate adjusted air density based on altitude, temperature and humidity.
     /// 
     /// Uses a simplified barometric formula to adjust air density based on
-    /// the current altitude and temperature conditions.
+    /// the current altitude and temperature conditions, then the humidity
+    /// correction from [`Self::humidity_factor`].
     /// 
     /// # Returns
-    /// The effective air density considering altitude and temperature
+    /// The effective air density considering altitude, temperature and humidity
     pub fn effective_air_density(&self) -> f32 {
+        self.air_density_at(self.altitude)
+    }
+
+    /// Calculate air density at an arbitrary altitude.
+    /// 
+    /// Applies the same simplified barometric formula as `effective_air_density`,
+    /// but for the given altitude instead of the scene-wide one.
+    /// 
+    /// # Arguments
+    /// * `altitude` - Altitude above sea level in meters
+    /// 
+    /// # Returns
+    /// The air density at that altitude considering temperature and humidity
+    pub fn air_density_at(&self, altitude: f32) -> f32 {
         // Simplified barometric formula
         let temp_kelvin = self.temperature + 273.15;
-        let pressure_ratio = (-self.altitude / 8500.0).exp();
-        self.air_density * pressure_ratio * (288.15 / temp_kelvin)
+        let pressure_ratio = (-altitude / 8500.0).exp();
+        self.air_density * pressure_ratio * (288.15 / temp_kelvin) * self.humidity_factor(pressure_ratio)
+    }
+
+    /// Density of humid air relative to dry air at the same temperature and pressure.
+    /// 
+    /// Water vapor is lighter than the air it displaces, so the density drops
+    /// by `0.378 * e / p`, where `e` is the vapor pressure (`humidity` times the
+    /// saturation pressure from the Tetens approximation) and `p` the total pressure.
+    /// The vapor can't make up more than all of the air, so `e / p` is capped at 1:
+    /// at very high altitude, where the barometric pressure falls below the vapor
+    /// pressure, the factor bottoms out at that of pure water vapor (0.622).
+    /// 
+    /// # Arguments
+    /// * `pressure_ratio` - Total pressure relative to sea level (1.0 at sea level)
+    /// 
+    /// # Returns
+    /// A factor of exactly 1.0 for dry air, falling as humidity rises, never below 0.622
+    pub fn humidity_factor(&self, pressure_ratio: f32) -> f32 {
+        let humidity = self.humidity.clamp(0.0, 1.0);
+        if humidity <= 0.0 {
+            return 1.0;
+        }
+        // Tetens saturation vapor pressure (hPa)
+        let t = self.temperature;
+        let saturation = 6.1078 * 10f32.powf(7.5 * t / (t + 237.3));
+        let vapor_pressure = humidity * saturation;
+        let pressure = 1013.25 * pressure_ratio;
+        1.0 - 0.378 * (vapor_pressure / pressure).min(1.0)
     }
 
     /// Calculate speed of sound in air based on temperature.
@@ -108,6 +255,11 @@ impl BallisticsEnvironment {
         331.3 * (1.0 + self.temperature / 273.15).sqrt()
     }
 
+    /// Whether `speed` (m/s) is faster than the temperature-adjusted speed of sound.
+    pub fn is_supersonic(&self, speed: f32) -> bool {
+        speed > self.speed_of_sound()
+    }
+
     /// Calculate Earth's angular velocity vector at the current latitude.
     /// 
     /// Assumes Z is North, X is East, Y is Up.
@@ -144,6 +296,27 @@ impl BallisticsEnvironment {
 /// * `enable_penetration` - Whether to enable projectile penetration mechanics
 /// * `enable_ricochet` - Whether to enable projectile ricochet mechanics
 /// * `debug_draw` - Whether to enable debug visualization of projectile paths
+/// * `altitude_density` - Whether air density is recomputed from each projectile's height
+/// * `damage_retained_on_penetration` - Fraction of damage kept for each surface a round has already passed through
+/// * `max_ricochets` - Number of ricochets a round may make before it is spent
+/// * `collision_schedule` - Schedule the projectile collision sweep runs in
+/// * `spatial_grid_cell_size` - Cell size of the proximity/near-miss spatial grids
+/// * `max_submunitions` - Cap on cluster submunitions and fragmentation shards spawned per step
+/// * `spent_projectile_behavior` - What happens to a round that stops on impact
+/// * `max_spent_projectiles` - Cap on debris (or pooled) spent rounds kept around
+/// * `spent_projectile_lifetime` - Seconds debris rounds stay before despawning
+/// * `collision_mask` - Layers projectiles without their own mask can hit
+/// * `friendly_fire` - Whether projectiles can hit entities on their shooter's team
+/// * `euler_first_step` - Whether a new projectile's first step is a straight Euler step
+/// * `max_decals` - Live impact decals kept before the oldest are faded out early
+/// * `gravity_velocity_cutoff` - Speed above which gravity fades out, for flat-shooting rounds
+/// * `ricochet_mode` - Whether a ricochet redirects the round or respawns it as a new entity
+/// * `sensor_pass_through` - Whether rounds fly through sensor colliders, reporting a hit
+/// * `orphan_policy` - Whether rounds outlive their despawned owner
+/// * `normal_smoothing` - Blend of the hit normal toward the average of nearby samples (0 = off)
+/// * `normal_smoothing_radius` - Offset of the extra rays that sample nearby normals
+/// * `fixed_timestep_hz` - FixedUpdate rate the core plugin sets, if any (`None` by default)
+/// * `max_substep_distance` - Longest integration substep for fast rounds (0 = one step per fixed step)
 /// 
 /// # Example
 /// ```
@@ -156,6 +329,7 @@ impl BallisticsEnvironment {
 ///     enable_penetration: true,
 ///     enable_ricochet: false,
 ///     debug_draw: true,
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Resource, Reflect, Clone)]
@@ -176,6 +350,80 @@ pub struct BallisticsConfig {
     pub min_projectile_speed: f32,
     /// Debug visualization
     pub debug_draw: bool,
+    /// Recompute air density per projectile from its current height (base altitude + Y).
+    /// When disabled, the scene-wide `effective_air_density` is used for every projectile.
+    pub altitude_density: bool,
+    /// Damage multiplier applied per surface already penetrated (1.0 = no loss).
+    /// The first target takes full damage, the second `damage * retention`,
+    /// the third `damage * retention²`, and so on.
+    pub damage_retained_on_penetration: f32,
+    /// Maximum ricochets per projectile; the next glancing hit stops the round instead.
+    /// A round despawned by it sends a `ProjectileDespawnedEvent` with
+    /// `DespawnReason::RicochetLimit`.
+    /// Defaults to 3: rounds no longer bounce for as long as they keep their speed,
+    /// so raise it for scenes that relied on that.
+    pub max_ricochets: u8,
+    /// Schedule that runs the projectile collision sweep
+    pub collision_schedule: CollisionSchedule,
+    /// Cell size (meters) of the spatial grids used for proximity and near-miss queries.
+    /// Roughly the largest trigger radius works well.
+    pub spatial_grid_cell_size: f32,
+    /// Maximum cluster submunitions and fragmentation shards spawned per fixed step,
+    /// across all bursts. Bounds the entity count of nested clusters; shards past
+    /// it are dropped.
+    pub max_submunitions: u32,
+    /// What happens to a round that stops on impact
+    pub spent_projectile_behavior: SpentProjectileBehavior,
+    /// Maximum spent rounds kept as debris (or in the pool); the oldest are despawned first
+    pub max_spent_projectiles: usize,
+    /// Time a debris round stays in the world before despawning (seconds)
+    pub spent_projectile_lifetime: f32,
+    /// Collision layers hit by projectiles without a `ProjectileCollisionMask`
+    pub collision_mask: crate::components::ProjectileCollisionMask,
+    /// Let projectiles hit entities sharing their shooter's `Team`.
+    /// When disabled, rounds pass through teammates.
+    pub friendly_fire: bool,
+    /// Simulation speed for ballistics (1.0 = real time, 0.1 = bullet time).
+    /// Scales flight, fuses, bloom recovery and effect lifetimes alike.
+    pub time_scale: f32,
+    /// Ignore `time_scale` and always step at the unscaled rate.
+    /// `BallisticsNetworkPlugin` enables this so every peer simulates identically.
+    pub ignore_time_scale: bool,
+    /// Let static cover between an explosion and an entity shield it from the
+    /// blast impulse. Damage is left to the game: scale it by
+    /// `ExplosionCover::exposure`, which also honours this flag.
+    pub explosion_occlusion: bool,
+    /// Integrate a projectile's first step with Euler even when `use_rk4` is set,
+    /// so the first swept segment runs straight out of the muzzle along the shot.
+    pub euler_first_step: bool,
+    /// Live impact decals allowed at once; past this the oldest fade out early
+    pub max_decals: usize,
+    /// Speed (m/s) above which gravity is scaled by `(cutoff / speed)²`.
+    /// Full gravity at or below the cutoff, fading smoothly toward zero above it;
+    /// `f32::INFINITY` disables the cutoff.
+    pub gravity_velocity_cutoff: f32,
+    /// How a ricocheting round continues: the same entity, or a fresh one
+    pub ricochet_mode: RicochetMode,
+    /// Rounds pass through avian `Sensor` colliders, sending a zero-damage `HitEvent`
+    /// (default true). When false, sensors stop rounds like solid colliders.
+    pub sensor_pass_through: bool,
+    /// What becomes of rounds in flight when their owner is despawned
+    pub orphan_policy: OrphanPolicy,
+    /// How far (0-1) the hit normal is blended toward the average normal of the
+    /// surface around the hit, smoothing ricochets off tessellated curves.
+    /// Costs a few extra rays per hit; 0 disables it.
+    pub normal_smoothing: f32,
+    /// Distance from the shot line of the extra smoothing rays (meters)
+    pub normal_smoothing_radius: f32,
+    /// FixedUpdate rate (Hz) `BallisticsCorePlugin` sets `Time<Fixed>` to. The rate is
+    /// app-wide (it also steps avian and every other FixedUpdate system), so the
+    /// default `None` leaves it to the app; opt in to pin the step flight accuracy
+    /// depends on, e.g. `Some(120.0)` for fast rifle rounds.
+    pub fixed_timestep_hz: Option<f64>,
+    /// Longest distance (meters) a round is integrated over in one substep.
+    /// Faster rounds split each fixed step into up to `MAX_SUBSTEPS` substeps
+    /// for accuracy; collisions still sweep the whole step. 0 disables substepping.
+    pub max_substep_distance: f32,
 }
 
 impl Default for BallisticsConfig {
@@ -189,6 +437,26 @@ impl Default for BallisticsConfig {
     /// - Ricochet enabled
     /// - 20.0 m/s minimum speed
     /// - Debug drawing disabled
+    /// - Per-projectile altitude density disabled
+    /// - No damage loss on penetration
+    /// - 3 ricochets per projectile
+    /// - Collisions checked in FixedUpdate
+    /// - 8m spatial grid cells
+    /// - 256 submunitions per step
+    /// - Spent rounds despawn (64 kept, 5 seconds, when dropped or pooled)
+    /// - Projectiles hit every collision layer
+    /// - Friendly fire enabled
+    /// - Real-time simulation (time scale 1.0)
+    /// - Explosions occluded by cover
+    /// - Euler first step
+    /// - 256 live decals
+    /// - No gravity cutoff
+    /// - Ricochets redirect the same entity
+    /// - Rounds pass through sensors
+    /// - Rounds keep flying when their owner is despawned
+    /// - No impact-normal smoothing (5 cm sample radius when enabled)
+    /// - Fixed timestep left to the app
+    /// - No substepping
     /// 
     /// # Returns
     /// A new BallisticsConfig instance with default values
@@ -201,7 +469,648 @@ impl Default for BallisticsConfig {
             enable_ricochet: true,
             min_projectile_speed: 20.0,
             debug_draw: false,
+            altitude_density: false,
+            damage_retained_on_penetration: 1.0,
+            max_ricochets: 3,
+            collision_schedule: CollisionSchedule::FixedUpdate,
+            spatial_grid_cell_size: 8.0,
+            max_submunitions: 256,
+            spent_projectile_behavior: SpentProjectileBehavior::Despawn,
+            max_spent_projectiles: 64,
+            spent_projectile_lifetime: 5.0,
+            collision_mask: crate::components::ProjectileCollisionMask::ALL,
+            friendly_fire: true,
+            time_scale: 1.0,
+            ignore_time_scale: false,
+            explosion_occlusion: true,
+            euler_first_step: true,
+            max_decals: 256,
+            gravity_velocity_cutoff: f32::INFINITY,
+            ricochet_mode: RicochetMode::Redirect,
+            sensor_pass_through: true,
+            orphan_policy: OrphanPolicy::Continue,
+            normal_smoothing: 0.0,
+            normal_smoothing_radius: 0.05,
+            fixed_timestep_hz: None,
+            max_substep_distance: 0.0,
+        }
+    }
+}
+
+impl BallisticsConfig {
+    /// Scale a time step by `time_scale`, unless `ignore_time_scale` is set.
+    /// 
+    /// # Arguments
+    /// * `dt` - Unscaled time step in seconds
+    /// 
+    /// # Returns
+    /// Time step the ballistics simulation advances by
+    pub fn scaled_delta(&self, dt: f32) -> f32 {
+        if self.ignore_time_scale {
+            dt
+        } else {
+            dt * self.time_scale.max(0.0)
+        }
+    }
+}
+
+/// Schedule in which projectile collisions are checked.
+/// 
+/// Kinematics always runs in `FixedUpdate`. Checking collisions there too keeps the
+/// simulation deterministic: every fixed step is swept exactly once, identically on
+/// client and server. Checking in `Update` reacts on the render frame instead, which
+/// feels more responsive at high frame rates, but hits then depend on frame timing
+/// and the sweep covers however many fixed steps ran since the last frame.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CollisionSchedule {
+    /// Check collisions every fixed step (deterministic, default)
+    #[default]
+    FixedUpdate,
+    /// Check collisions every render frame
+    Update,
+}
+
+/// How a projectile continues after a ricochet.
+/// 
+/// `Respawn` suits netcode that treats every leg of a bounce as its own replicated
+/// round: the ricocheting entity is despawned and a clone continues along the
+/// ricochet direction. The clone keeps every component (payload, logic, owner, team,
+/// flight state such as `distance_travelled` and `penetration_power`). Its
+/// [`NetworkId`](crate::components::NetworkId) and
+/// [`NetProjectile`](crate::components::NetProjectile) seed are fresh ones derived
+/// from the original's (see [`ProjectileRng::respawn_seed`](crate::types::ProjectileRng::respawn_seed)),
+/// so it replicates as a new round that every peer names alike.
+/// [`RicochetEvent::respawned`](crate::events::RicochetEvent) names it.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RicochetMode {
+    /// Redirect the same entity along the ricochet direction (default)
+    #[default]
+    Redirect,
+    /// Despawn the round and continue with a fresh entity
+    Respawn,
+}
+
+/// What happens to a projectile in flight when its owner is despawned.
+/// 
+/// Either way nothing looks the missing owner up with `unwrap`: rounds of a
+/// despawned owner simply stop finding its team and no longer skip it.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OrphanPolicy {
+    /// Keep flying and hitting as before (default)
+    #[default]
+    Continue,
+    /// Despawn the rounds together with their owner, sending a `ProjectileDespawnedEvent`
+    DespawnWithOwner,
+}
+
+/// What happens to a projectile that is spent on impact (neither penetrated nor ricocheted).
+/// 
+/// `Drop` and `Pool` strip the round's
+/// [`RoundComponents`](crate::systems::debris::RoundComponents) (`Projectile`,
+/// `ProjectileLogic`, `Payload`, `Pierce` and the rest), so the round no longer shows
+/// up in active projectile queries, and tag the entity with
+/// [`SpentProjectile`](crate::components::SpentProjectile).
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpentProjectileBehavior {
+    /// Despawn the round immediately (default)
+    #[default]
+    Despawn,
+    /// Turn the round into a dynamic rigid body that falls, settles and
+    /// despawns after `spent_projectile_lifetime`
+    Drop,
+    /// Hide the round and keep it in [`SpentProjectiles`] for reuse
+    Pool,
+}
+
+/// Base knockback impulse per explosion type, used by `apply_explosion_impulse`.
+/// 
+/// The impulse an entity receives is this value scaled by distance falloff,
+/// the entity's inverse mass and its exposure to the blast. Insert a modified
+/// table to retune knockback without touching the systems.
+/// 
+/// # Fields
+/// One impulse magnitude per [`ExplosionType`](crate::events::ExplosionType)
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct ExplosionImpulseTable {
+    pub high_explosive: f32,
+    pub incendiary: f32,
+    pub flash: f32,
+    pub smoke: f32,
+    pub fragmentation: f32,
+    pub concussion: f32,
+    /// EMP blasts push nothing by default
+    pub emp: f32,
+}
+
+impl Default for ExplosionImpulseTable {
+    /// Creates the stock impulse table.
+    /// 
+    /// Default values:
+    /// - High explosive: 30
+    /// - Incendiary: 5
+    /// - Flash: 2
+    /// - Smoke: 0.5
+    /// - Fragmentation: 25
+    /// - Concussion: 50
+    /// - EMP: 0
+    fn default() -> Self {
+        Self {
+            high_explosive: 30.0,
+            incendiary: 5.0,
+            flash: 2.0,
+            smoke: 0.5,
+            fragmentation: 25.0,
+            concussion: 50.0,
+            emp: 0.0,
+        }
+    }
+}
+
+impl ExplosionImpulseTable {
+    /// Base impulse for an explosion type.
+    pub fn get(&self, explosion_type: crate::events::ExplosionType) -> f32 {
+        use crate::events::ExplosionType;
+        match explosion_type {
+            ExplosionType::HighExplosive => self.high_explosive,
+            ExplosionType::Incendiary => self.incendiary,
+            ExplosionType::Flash => self.flash,
+            ExplosionType::Smoke => self.smoke,
+            ExplosionType::Fragmentation => self.fragmentation,
+            ExplosionType::Concussion => self.concussion,
+            ExplosionType::EMP => self.emp,
+        }
+    }
+}
+
+/// Self-harm protection for whoever fired an explosive.
+///
+/// Inside the grace radius of its explosion type the event's `instigator` (and,
+/// with `include_parent`, the entity it is parented to, such as a vehicle it
+/// rides) takes `damage_scale` of the damage and `impulse_scale` of the
+/// knockback. Everyone else is unaffected. All radii are zero by default, so
+/// shooters take full blast until a radius is set.
+///
+/// `apply_explosion_impulse` applies `impulse_scale` itself; damage is applied by
+/// game code, which should multiply by [`SelfDamageGrace::damage_factor`].
+///
+/// # Fields
+/// * `high_explosive` .. `emp` - Grace radius per [`ExplosionType`](crate::events::ExplosionType) (meters, 0 = none)
+/// * `damage_scale` - Fraction of damage taken inside the radius (0 = none)
+/// * `impulse_scale` - Fraction of knockback taken inside the radius (1 keeps rocket jumping)
+/// * `include_parent` - Extend the grace to the instigator's parent entity
+///
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::resources::SelfDamageGrace;
+///
+/// // No self-damage within 3m, but keep the launch for rocket jumping
+/// let grace = SelfDamageGrace {
+///     impulse_scale: 1.0,
+///     ..SelfDamageGrace::uniform(3.0)
+/// };
+/// ```
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct SelfDamageGrace {
+    pub high_explosive: f32,
+    pub incendiary: f32,
+    pub flash: f32,
+    pub smoke: f32,
+    pub fragmentation: f32,
+    pub concussion: f32,
+    pub emp: f32,
+    /// Fraction of damage the instigator takes inside the radius
+    pub damage_scale: f32,
+    /// Fraction of knockback the instigator takes inside the radius
+    pub impulse_scale: f32,
+    /// Also protect the entity the instigator is parented to
+    pub include_parent: bool,
+}
+
+impl Default for SelfDamageGrace {
+    /// Creates a disabled grace: every radius is zero.
+    fn default() -> Self {
+        Self::uniform(0.0)
+    }
+}
+
+impl SelfDamageGrace {
+    /// Same grace radius for every explosion type, with no self-damage or
+    /// knockback inside it.
+    ///
+    /// # Arguments
+    /// * `radius` - Grace radius in meters
+    pub fn uniform(radius: f32) -> Self {
+        Self {
+            high_explosive: radius,
+            incendiary: radius,
+            flash: radius,
+            smoke: radius,
+            fragmentation: radius,
+            concussion: radius,
+            emp: radius,
+            damage_scale: 0.0,
+            impulse_scale: 0.0,
+            include_parent: true,
+        }
+    }
+
+    /// Grace radius for an explosion type.
+    pub fn radius(&self, explosion_type: crate::events::ExplosionType) -> f32 {
+        use crate::events::ExplosionType;
+        match explosion_type {
+            ExplosionType::HighExplosive => self.high_explosive,
+            ExplosionType::Incendiary => self.incendiary,
+            ExplosionType::Flash => self.flash,
+            ExplosionType::Smoke => self.smoke,
+            ExplosionType::Fragmentation => self.fragmentation,
+            ExplosionType::Concussion => self.concussion,
+            ExplosionType::EMP => self.emp,
+        }
+    }
+
+    /// Whether `entity` is protected from this explosion.
+    ///
+    /// # Arguments
+    /// * `event` - The explosion
+    /// * `entity` - Entity caught in the blast
+    /// * `instigator_parent` - Parent of `event.instigator`, if it has one
+    /// * `distance` - Distance from the explosion center to `entity`
+    ///
+    /// # Returns
+    /// True if `entity` is the instigator (or its parent, with `include_parent`)
+    /// and within the grace radius
+    pub fn applies(
+        &self,
+        event: &crate::events::ExplosionEvent,
+        entity: Entity,
+        instigator_parent: Option<Entity>,
+        distance: f32,
+    ) -> bool {
+        let Some(instigator) = event.instigator else {
+            return false;
+        };
+        let own = entity == instigator
+            || (self.include_parent && instigator_parent == Some(entity));
+        own && distance <= self.radius(event.explosion_type)
+    }
+
+    /// Multiplier for the damage `entity` takes from this explosion.
+    ///
+    /// # Returns
+    /// `damage_scale` if the grace applies (see [`SelfDamageGrace::applies`]), otherwise 1.0
+    pub fn damage_factor(
+        &self,
+        event: &crate::events::ExplosionEvent,
+        entity: Entity,
+        instigator_parent: Option<Entity>,
+        distance: f32,
+    ) -> f32 {
+        if self.applies(event, entity, instigator_parent, distance) {
+            self.damage_scale
+        } else {
+            1.0
+        }
+    }
+
+    /// Multiplier for the knockback `entity` takes from this explosion.
+    ///
+    /// # Returns
+    /// `impulse_scale` if the grace applies (see [`SelfDamageGrace::applies`]), otherwise 1.0
+    pub fn impulse_factor(
+        &self,
+        event: &crate::events::ExplosionEvent,
+        entity: Entity,
+        instigator_parent: Option<Entity>,
+        distance: f32,
+    ) -> f32 {
+        if self.applies(event, entity, instigator_parent, distance) {
+            self.impulse_scale
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Bookkeeping for spent rounds kept as debris or pooled.
+/// 
+/// # Fields
+/// * `debris` - Debris rounds in the world, oldest first
+/// * `available` - Hidden pooled rounds available for reuse
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::components::{Projectile, SpentProjectile};
+/// use bevy_bullet_dynamics::resources::SpentProjectiles;
+///
+/// fn fire(mut commands: Commands, mut spent: ResMut<SpentProjectiles>) {
+///     let projectile = Projectile::new(Vec3::NEG_Z * 400.0);
+///     if let Some(entity) = spent.get() {
+///         commands
+///             .entity(entity)
+///             .remove::<SpentProjectile>()
+///             .insert((projectile, Visibility::Visible));
+///     } else {
+///         commands.spawn(projectile);
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct SpentProjectiles {
+    /// Debris rounds in the world, oldest first
+    pub debris: VecDeque<Entity>,
+    /// Pooled rounds available for reuse, oldest first
+    pub available: VecDeque<Entity>,
+}
+
+impl SpentProjectiles {
+    /// Take the most recently pooled round, or None if the pool is empty.
+    /// 
+    /// The round comes back stripped of its
+    /// [`RoundComponents`](crate::systems::debris::RoundComponents). The caller
+    /// must remove [`SpentProjectile`](crate::components::SpentProjectile) and
+    /// insert a fresh `Projectile` (and make it visible again).
+    /// 
+    /// # Returns
+    /// An Option containing a pooled Entity, or None if the pool is empty
+    pub fn get(&mut self) -> Option<Entity> {
+        self.available.pop_back()
+    }
+}
+
+/// Active projectiles indexed by owner, e.g. to despawn or count one player's
+/// rounds in flight without scanning every `Projectile`.
+/// 
+/// Kept up to date by observers on `Projectile` being added and removed, so it
+/// stays consistent however a round goes away (lifetime, distance, hit or
+/// retirement as debris). Rounds without an owner are not indexed.
+#[derive(Resource, Default, Debug)]
+pub struct ProjectilesByOwner {
+    by_owner: HashMap<Entity, SmallVec<[Entity; 8]>>,
+}
+
+impl ProjectilesByOwner {
+    /// Active projectiles fired by `owner`, in no particular order.
+    pub fn get(&self, owner: Entity) -> &[Entity] {
+        self.by_owner.get(&owner).map_or(&[], |projectiles| projectiles.as_slice())
+    }
+
+    /// Number of `owner`'s projectiles in flight.
+    pub fn count(&self, owner: Entity) -> usize {
+        self.get(owner).len()
+    }
+
+    /// Owners with at least one projectile in flight.
+    pub fn owners(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.by_owner.keys().copied()
+    }
+
+    /// Record `projectile` as fired by `owner`.
+    pub fn insert(&mut self, owner: Entity, projectile: Entity) {
+        let projectiles = self.by_owner.entry(owner).or_default();
+        if !projectiles.contains(&projectile) {
+            projectiles.push(projectile);
+        }
+    }
+
+    /// Forget `projectile`, looking under `owner` first.
+    /// 
+    /// Falls back to searching every owner, in case the round's owner was
+    /// changed after it was indexed.
+    /// 
+    /// # Returns
+    /// true if the projectile was indexed
+    pub fn remove(&mut self, owner: Option<Entity>, projectile: Entity) -> bool {
+        if let Some(owner) = owner {
+            if self.remove_from(owner, projectile) {
+                return true;
+            }
         }
+        let owner = self
+            .by_owner
+            .iter()
+            .find(|(_, projectiles)| projectiles.contains(&projectile))
+            .map(|(owner, _)| *owner);
+        owner.is_some_and(|owner| self.remove_from(owner, projectile))
+    }
+
+    fn remove_from(&mut self, owner: Entity, projectile: Entity) -> bool {
+        let Some(projectiles) = self.by_owner.get_mut(&owner) else {
+            return false;
+        };
+        let Some(index) = projectiles.iter().position(|entity| *entity == projectile) else {
+            return false;
+        };
+        projectiles.swap_remove(index);
+        if projectiles.is_empty() {
+            self.by_owner.remove(&owner);
+        }
+        true
+    }
+}
+
+/// A ballistics event captured by [`BallisticsEventLog`].
+#[derive(Debug, Clone)]
+pub enum LoggedBallisticsEvent {
+    Fire(crate::events::FireEvent),
+    Hit(crate::events::HitEvent),
+    Explosion(crate::events::ExplosionEvent),
+}
+
+/// One entry of the [`BallisticsEventLog`].
+/// 
+/// # Fields
+/// * `time` - Game time the event was recorded at (seconds since startup)
+/// * `event` - The recorded event
+#[derive(Debug, Clone)]
+pub struct BallisticsLogEntry {
+    pub time: f64,
+    pub event: LoggedBallisticsEvent,
+}
+
+/// Ring buffer of recent fire, hit and explosion events.
+/// 
+/// Filled by `BallisticsEventLogPlugin`, for kill replays, demos and tracking
+/// down desyncs. Once `capacity` entries are stored the oldest are dropped, so
+/// memory stays bounded. Entries are in recording order; events of the same
+/// frame are ordered fire, hit, explosion.
+/// 
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::events::FireEvent;
+/// use bevy_bullet_dynamics::resources::{BallisticsEventLog, LoggedBallisticsEvent};
+/// 
+/// let mut log = BallisticsEventLog::new(2);
+/// for time in [0.0, 0.5, 1.0] {
+///     log.record(time, LoggedBallisticsEvent::Fire(FireEvent::default()));
+/// }
+/// let entries = log.export();
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(entries[0].time, 0.5);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct BallisticsEventLog {
+    entries: VecDeque<BallisticsLogEntry>,
+    capacity: usize,
+}
+
+impl Default for BallisticsEventLog {
+    /// Creates a log holding the last 1024 events.
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+impl BallisticsEventLog {
+    /// Creates an empty log keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(4096)),
+            capacity,
+        }
+    }
+
+    /// Maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record an event, dropping the oldest entry if the log is full.
+    pub fn record(&mut self, time: f64, event: LoggedBallisticsEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(BallisticsLogEntry { time, event });
+    }
+
+    /// Recorded entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &BallisticsLogEntry> {
+        self.entries.iter()
+    }
+
+    /// Copy of the recorded entries, oldest first.
+    pub fn export(&self) -> Vec<BallisticsLogEntry> {
+        self.entries.iter().cloned().collect()
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Running shot and hit totals, accumulated from ballistics events.
+/// 
+/// A shot is one `FireEvent`, however many pellets it fires; `projectiles_fired`
+/// counts the pellets. A hit is a `HitEvent` that didn't glance off, so a
+/// ricochet is counted under `ricochets` only. A round that penetrates is a hit
+/// and a penetration, and scores another hit on whatever it strikes next.
+/// 
+/// # Fields
+/// * `shots_fired` - Fire events (trigger pulls)
+/// * `projectiles_fired` - Projectiles those shots spawned
+/// * `hits` - Non-ricochet hits
+/// * `penetrations` - Hits that passed through the target
+/// * `ricochets` - Hits that glanced off
+/// 
+/// # Example
+/// ```
+/// use bevy_bullet_dynamics::resources::BallisticsStats;
+/// 
+/// let stats = BallisticsStats {
+///     shots_fired: 4,
+///     projectiles_fired: 4,
+///     hits: 3,
+///     ..Default::default()
+/// };
+/// assert_eq!(stats.accuracy(), 0.75);
+/// ```
+#[derive(Resource, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Resource)]
+pub struct BallisticsStats {
+    pub shots_fired: u32,
+    pub projectiles_fired: u32,
+    pub hits: u32,
+    pub penetrations: u32,
+    pub ricochets: u32,
+}
+
+impl BallisticsStats {
+    /// Fraction of fired projectiles that hit (0 before anything is fired).
+    /// 
+    /// Capped at 1, since one penetrating round can hit several targets.
+    pub fn accuracy(&self) -> f32 {
+        if self.projectiles_fired == 0 {
+            return 0.0;
+        }
+        (self.hits as f32 / self.projectiles_fired as f32).min(1.0)
+    }
+
+    /// Clear all counters.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Explosions that went off this frame, for gameplay to ask whether a point
+/// was caught in a blast (e.g. is this cover still safe).
+/// 
+/// Refilled every frame by `record_recent_explosions` from the frame's
+/// `ExplosionEvent`s; systems reading it should run after that system.
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::resources::RecentExplosions;
+/// 
+/// fn check_cover(explosions: Res<RecentExplosions>) {
+///     if let Some(intensity) = explosions.affects(Vec3::new(0.0, 1.0, 2.0)) {
+///         info!("caught in a blast at {:.0}% strength", intensity * 100.0);
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct RecentExplosions {
+    /// This frame's explosions, in the order they were sent
+    pub explosions: Vec<crate::events::ExplosionEvent>,
+}
+
+impl RecentExplosions {
+    /// Strongest blast intensity at `point` among this frame's explosions.
+    /// 
+    /// Intensity follows each explosion's `FalloffCurve`, the same curve its
+    /// damage and impulse use: 1.0 at the center, falling to 0.0 at the radius.
+    /// Cover is not taken into account.
+    /// 
+    /// # Arguments
+    /// * `point` - World-space position to test
+    /// 
+    /// # Returns
+    /// The intensity (0..1] if `point` is inside any blast radius, otherwise None
+    pub fn affects(&self, point: Vec3) -> Option<f32> {
+        self.explosions
+            .iter()
+            .filter_map(|explosion| {
+                let distance = point.distance(explosion.center);
+                (distance < explosion.radius)
+                    .then(|| explosion.falloff.factor(distance, explosion.radius))
+            })
+            .max_by(f32::total_cmp)
     }
 }
 
@@ -361,6 +1270,140 @@ impl DecalPool {
     }
 }
 
+/// Entry stored in a [`SpatialGrid`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridEntry {
+    /// Entity the entry belongs to
+    pub entity: Entity,
+    /// World-space position at the last rebuild
+    pub position: Vec3,
+    /// Per-entry radius (e.g. a listener's near-miss radius)
+    pub radius: f32,
+}
+
+/// Uniform spatial hash of target positions, rebuilt every fixed step.
+/// 
+/// Lets proximity fuses and near-miss detection look only at targets in nearby
+/// cells instead of testing every projectile against every target. `T` tags which
+/// kind of target the grid holds (one grid per kind, e.g.
+/// `SpatialGrid<ProximityTarget>` and `SpatialGrid<BulletListener>`).
+/// 
+/// Each entity lives in exactly one cell, so a query never yields duplicates.
+/// Rebuilding is linear in the number of targets and keeps the allocations of
+/// cells that were occupied last step; cells left empty are dropped, so memory
+/// stays proportional to the number of targets.
+/// 
+/// # Fields
+/// * `cell_size` - Edge length of a cell in meters
+/// * `max_radius` - Largest entry radius, used to pad queries
+#[derive(Resource)]
+pub struct SpatialGrid<T> {
+    /// Cell edge length (meters)
+    pub cell_size: f32,
+    /// Largest entry radius currently stored
+    pub max_radius: f32,
+    cells: HashMap<IVec3, Vec<GridEntry>>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for SpatialGrid<T> {
+    fn default() -> Self {
+        Self::new(8.0)
+    }
+}
+
+impl<T> SpatialGrid<T> {
+    /// Creates an empty grid with the given cell size (meters).
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(0.01),
+            max_radius: 0.0,
+            cells: HashMap::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Cell coordinate containing `position`.
+    pub fn cell_of(&self, position: Vec3) -> IVec3 {
+        (position / self.cell_size).floor().as_ivec3()
+    }
+
+    /// Start a rebuild: empty every cell and switch to `cell_size`.
+    pub fn clear(&mut self, cell_size: f32) {
+        let cell_size = cell_size.max(0.01);
+        if cell_size != self.cell_size {
+            self.cells.clear();
+            self.cell_size = cell_size;
+        }
+        self.cells.retain(|_, entries| {
+            let occupied = !entries.is_empty();
+            entries.clear();
+            occupied
+        });
+        self.max_radius = 0.0;
+    }
+
+    /// Add an entity at `position`.
+    pub fn insert(&mut self, entity: Entity, position: Vec3, radius: f32) {
+        let cell = self.cell_of(position);
+        self.max_radius = self.max_radius.max(radius);
+        self.cells.entry(cell).or_default().push(GridEntry {
+            entity,
+            position,
+            radius,
+        });
+    }
+
+    /// Number of entries in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.values().map(Vec::len).sum()
+    }
+
+    /// Whether the grid has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.cells.values().all(Vec::is_empty)
+    }
+
+    /// Visit every entry whose cell overlaps the box `min..=max`.
+    ///
+    /// Yields candidates only; callers still do their exact distance test. Boxes
+    /// spanning more cells than are occupied scan the occupied cells instead.
+    pub fn for_each_in_aabb(&self, min: Vec3, max: Vec3, mut f: impl FnMut(&GridEntry)) {
+        let (lo, hi) = (self.cell_of(min), self.cell_of(max));
+        let span = (hi - lo + IVec3::ONE).as_i64vec3();
+        if span.x * span.y * span.z > self.cells.len() as i64 {
+            for (cell, entries) in &self.cells {
+                if cell.cmpge(lo).all() && cell.cmple(hi).all() {
+                    entries.iter().for_each(&mut f);
+                }
+            }
+            return;
+        }
+        for x in lo.x..=hi.x {
+            for y in lo.y..=hi.y {
+                for z in lo.z..=hi.z {
+                    if let Some(entries) = self.cells.get(&IVec3::new(x, y, z)) {
+                        entries.iter().for_each(&mut f);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Visit candidate entries that may lie within `radius` (plus their own
+    /// radius) of the segment `start..end`.
+    pub fn for_each_near_segment(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        radius: f32,
+        f: impl FnMut(&GridEntry),
+    ) {
+        let pad = Vec3::splat(radius + self.max_radius);
+        self.for_each_in_aabb(start.min(end) - pad, start.max(end) + pad, f);
+    }
+}
+
 /// Weapon preset definitions resource.
 /// 
 /// This resource contains predefined weapon configurations that can be used
@@ -392,6 +1435,8 @@ pub struct WeaponPresets {
 /// * `projectile_mass` - Mass of projectiles fired by this weapon (kg)
 /// * `drag_coefficient` - Drag coefficient affecting projectile flight
 /// * `base_damage` - Base damage dealt by projectiles from this weapon
+/// * `temperature_sensitivity` - Muzzle velocity change per °C away from 15°C (m/s per °C)
+/// * `muzzle_velocity_sd` - Standard deviation of the round-to-round muzzle velocity (m/s)
 /// * `accuracy` - Accuracy characteristics including spread and bloom
 /// 
 /// # Example
@@ -405,6 +1450,9 @@ pub struct WeaponPresets {
 ///     projectile_mass: 0.01,
 ///     drag_coefficient: 0.2,
 ///     base_damage: 100.0,
+///     spin: 3000.0,
+///     temperature_sensitivity: 0.0,
+///     muzzle_velocity_sd: 3.0,
 ///     accuracy: Accuracy::default(),
 /// };
 /// ```
@@ -417,6 +1465,10 @@ pub struct WeaponPreset {
     pub base_damage: f32,
     /// Spin rate in rad/s (positive = right-hand twist)
     pub spin: f32,
+    /// Muzzle velocity change per °C relative to 15°C (m/s per °C, 0 = insensitive)
+    pub temperature_sensitivity: f32,
+    /// Standard deviation of each shot's muzzle velocity (m/s, 0 = every shot identical)
+    pub muzzle_velocity_sd: f32,
     pub accuracy: crate::components::Accuracy,
 }
 
@@ -429,6 +1481,7 @@ impl Default for WeaponPreset {
     /// - Projectile mass: 10g
     /// - Drag coefficient: 0.3
     /// - Base damage: 25.0
+    /// - No muzzle velocity spread
     /// - Default accuracy settings
     /// 
     /// # Returns
@@ -441,11 +1494,61 @@ impl Default for WeaponPreset {
             drag_coefficient: 0.3,
             base_damage: 25.0,
             spin: 0.0,
+            temperature_sensitivity: 0.0,
+            muzzle_velocity_sd: 0.0,
             accuracy: crate::components::Accuracy::default(),
         }
     }
 }
 
+impl WeaponPreset {
+    /// Ambient temperature (°C) at which `muzzle_velocity` is rated.
+    pub const REFERENCE_TEMPERATURE: f32 = 15.0;
+
+    /// Calculate the muzzle velocity for the current ambient temperature.
+    ///
+    /// Propellant burns faster when hot, so a sensitive load gains velocity
+    /// above 15°C and loses it below. The fire system applies this to every
+    /// `FireEvent` (see `FireEvent::with_preset_variation`).
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the ballistics environment
+    ///
+    /// # Returns
+    /// The temperature-adjusted muzzle velocity (m/s), never negative
+    pub fn muzzle_velocity_at(&self, env: &BallisticsEnvironment) -> f32 {
+        (self.muzzle_velocity + self.temperature_offset(env)).max(0.0)
+    }
+
+    /// Muzzle velocity gained (or lost, if negative) at the environment's
+    /// temperature relative to 15°C (m/s).
+    pub fn temperature_offset(&self, env: &BallisticsEnvironment) -> f32 {
+        self.temperature_sensitivity * (env.temperature - Self::REFERENCE_TEMPERATURE)
+    }
+
+    /// Muzzle velocity of one shot, including round-to-round variation.
+    ///
+    /// Draws a normal deviation with standard deviation `muzzle_velocity_sd`
+    /// around [`Self::muzzle_velocity_at`] from the shot's seed, so every peer
+    /// holding the seed launches the round at the same speed. With an SD of 0 this
+    /// is exactly `muzzle_velocity_at`.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the ballistics environment
+    /// * `seed` - The shot's seed (`FireEvent::spread_seed`)
+    ///
+    /// # Returns
+    /// The muzzle velocity for this shot (m/s), never negative
+    pub fn sample_muzzle_velocity(&self, env: &BallisticsEnvironment, seed: u64) -> f32 {
+        let nominal = self.muzzle_velocity_at(env);
+        if self.muzzle_velocity_sd <= 0.0 {
+            return nominal;
+        }
+        let offset = crate::types::ProjectileRng::new(seed).muzzle_velocity_offset(self.muzzle_velocity_sd);
+        (nominal + offset).max(0.0)
+    }
+}
+
 /// Predefined weapon presets.
 impl WeaponPresets {
     /// Creates a WeaponPresets instance with default weapon configurations.
@@ -468,6 +1571,8 @@ impl WeaponPresets {
                     drag_coefficient: 0.35,
                     base_damage: 20.0,
                     spin: 150.0, // Low spin
+                    temperature_sensitivity: 0.0,
+                    muzzle_velocity_sd: 0.0,
                     accuracy: crate::components::Accuracy {
                         base_spread: 0.003,
                         bloom_per_shot: 0.015,
@@ -481,6 +1586,8 @@ impl WeaponPresets {
                     drag_coefficient: 0.25,
                     base_damage: 35.0,
                     spin: 2500.0, // Standard rifle spin
+                    temperature_sensitivity: 0.0,
+                    muzzle_velocity_sd: 0.0,
                     accuracy: crate::components::Accuracy {
                         base_spread: 0.001,
                         bloom_per_shot: 0.02,
@@ -494,6 +1601,8 @@ impl WeaponPresets {
                     drag_coefficient: 0.2,
                     base_damage: 100.0,
                     spin: 3000.0, // High spin for stability
+                    temperature_sensitivity: 0.0,
+                    muzzle_velocity_sd: 0.0,
                     accuracy: crate::components::Accuracy {
                         base_spread: 0.0005,
                         bloom_per_shot: 0.03,
@@ -508,6 +1617,8 @@ impl WeaponPresets {
                     drag_coefficient: 0.5,
                     base_damage: 45.0,
                     spin: 50.0, // Arrow rotation
+                    temperature_sensitivity: 0.0,
+                    muzzle_velocity_sd: 0.0,
                     accuracy: crate::components::Accuracy {
                         base_spread: 0.002,
                         bloom_per_shot: 0.0,
@@ -533,8 +1644,169 @@ pub struct BallisticsAssets {
     pub dust_material: Handle<StandardMaterial>,
     /// Material for blood effects
     pub blood_material: Handle<StandardMaterial>,
+    /// Material for wood chips
+    pub wood_material: Handle<StandardMaterial>,
+    /// Material for glass shards
+    pub glass_material: Handle<StandardMaterial>,
+    /// Material for water splashes
+    pub water_material: Handle<StandardMaterial>,
     /// Material for muzzle flashes
     pub flash_material: Handle<StandardMaterial>,
     /// Material for explosions
     pub explosion_material: Handle<StandardMaterial>,
 }
+
+impl BallisticsAssets {
+    /// Shared material for a surface's impact effect.
+    ///
+    /// Impact and ricochet effects clone this handle instead of creating a
+    /// material per hit; replace the fields to restyle every impact of a kind.
+    ///
+    /// # Arguments
+    /// * `effect` - Hit effect of the struck surface
+    ///
+    /// # Returns
+    /// Handle to the cached material for that effect
+    pub fn hit_effect_material(
+        &self,
+        effect: crate::components::HitEffectType,
+    ) -> &Handle<StandardMaterial> {
+        use crate::components::HitEffectType;
+
+        match effect {
+            HitEffectType::Sparks => &self.spark_material,
+            HitEffectType::Dust => &self.dust_material,
+            HitEffectType::Blood => &self.blood_material,
+            HitEffectType::WoodChips => &self.wood_material,
+            HitEffectType::Glass => &self.glass_material,
+            HitEffectType::Water => &self.water_material,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_muzzle_velocity_temperature_sensitivity() {
+        let preset = WeaponPreset {
+            temperature_sensitivity: 1.5,
+            ..Default::default()
+        };
+        let cold = BallisticsEnvironment {
+            temperature: -20.0,
+            ..Default::default()
+        };
+        let hot = BallisticsEnvironment {
+            temperature: 45.0,
+            ..Default::default()
+        };
+        let reference = BallisticsEnvironment {
+            temperature: WeaponPreset::REFERENCE_TEMPERATURE,
+            ..Default::default()
+        };
+
+        assert!(preset.muzzle_velocity_at(&hot) > preset.muzzle_velocity_at(&cold));
+        assert_eq!(preset.muzzle_velocity_at(&reference), preset.muzzle_velocity);
+        assert!((preset.muzzle_velocity_at(&hot) - (400.0 + 1.5 * 30.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_zero_sensitivity_ignores_temperature() {
+        let preset = WeaponPreset::default();
+        let hot = BallisticsEnvironment {
+            temperature: 45.0,
+            ..Default::default()
+        };
+        assert_eq!(preset.muzzle_velocity_at(&hot), preset.muzzle_velocity);
+    }
+
+    #[test]
+    fn test_muzzle_velocity_spread_statistics() {
+        let env = BallisticsEnvironment::default();
+        let preset = WeaponPreset {
+            muzzle_velocity: 850.0,
+            muzzle_velocity_sd: 8.0,
+            ..Default::default()
+        };
+
+        let samples: Vec<f32> = (0..10_000u64)
+            .map(|seed| preset.sample_muzzle_velocity(&env, seed))
+            .collect();
+        let count = samples.len() as f32;
+        let mean = samples.iter().sum::<f32>() / count;
+        let sd = (samples.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / count).sqrt();
+        assert!((mean - 850.0).abs() < 0.5, "mean {mean}");
+        assert!((sd - 8.0).abs() < 0.4, "sd {sd}");
+
+        // Same seed, same round on every peer
+        assert_eq!(preset.sample_muzzle_velocity(&env, 77), samples[77]);
+
+        // No spread: exactly the nominal velocity
+        let steady = WeaponPreset {
+            muzzle_velocity_sd: 0.0,
+            ..preset
+        };
+        assert!((0..100).all(|seed| steady.sample_muzzle_velocity(&env, seed) == 850.0));
+    }
+
+    #[test]
+    fn test_humid_air_is_less_dense() {
+        let dry = BallisticsEnvironment {
+            temperature: 30.0,
+            ..Default::default()
+        };
+        let humid = BallisticsEnvironment {
+            humidity: 1.0,
+            ..dry.clone()
+        };
+        let half = BallisticsEnvironment {
+            humidity: 0.5,
+            ..dry.clone()
+        };
+
+        // Dry air is exactly the uncorrected barometric value
+        let uncorrected = dry.air_density * (288.15 / (30.0 + 273.15));
+        assert_eq!(dry.effective_air_density(), uncorrected);
+
+        assert!(half.effective_air_density() < dry.effective_air_density());
+        assert!(humid.effective_air_density() < half.effective_air_density());
+        // Saturated air at 30°C is roughly 1.6% lighter
+        let ratio = humid.effective_air_density() / dry.effective_air_density();
+        assert!((ratio - 0.984).abs() < 0.002, "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_humid_air_density_stays_positive_at_extreme_altitude() {
+        let humid = BallisticsEnvironment {
+            temperature: 30.0,
+            humidity: 1.0,
+            ..Default::default()
+        };
+
+        // ~100 km up the barometric pressure is far below the vapor pressure
+        let pressure_ratio = (-100_000.0_f32 / 8500.0).exp();
+        assert!((humid.humidity_factor(pressure_ratio) - 0.622).abs() < 1e-6);
+        for altitude in [10_000.0, 30_000.0, 60_000.0, 100_000.0] {
+            let density = humid.air_density_at(altitude);
+            assert!(density > 0.0, "density {density} at {altitude} m");
+        }
+        // Sea level is untouched by the cap
+        assert!(humid.humidity_factor(1.0) > 0.95);
+    }
+
+    #[test]
+    fn test_impulse_table_defaults() {
+        use crate::events::ExplosionType;
+
+        let table = ExplosionImpulseTable::default();
+        assert_eq!(table.get(ExplosionType::HighExplosive), 30.0);
+        assert_eq!(table.get(ExplosionType::Incendiary), 5.0);
+        assert_eq!(table.get(ExplosionType::Flash), 2.0);
+        assert_eq!(table.get(ExplosionType::Smoke), 0.5);
+        assert_eq!(table.get(ExplosionType::Fragmentation), 25.0);
+        assert_eq!(table.get(ExplosionType::Concussion), 50.0);
+        assert_eq!(table.get(ExplosionType::EMP), 0.0);
+    }
+}
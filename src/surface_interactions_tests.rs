@@ -204,14 +204,8 @@ mod surfaces_2d_tests {
         
         // Simulate a hit event with penetration
         let hit_event = HitEvent {
-            projectile: Entity::PLACEHOLDER,
-            target: Entity::PLACEHOLDER,
-            impact_point: Vec3::ZERO,
-            normal: Vec3::Y,
-            velocity: Vec3::X,
-            damage: 25.0,
             penetrated: true,
-            ricocheted: false,
+            ..HitEvent::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER, Vec3::ZERO, Vec3::Y, Vec3::X, 25.0)
         };
         
         app.world.send_event(hit_event);
@@ -227,14 +221,8 @@ mod surfaces_2d_tests {
         
         // Simulate a hit event with ricochet
         let hit_event2 = HitEvent {
-            projectile: Entity::PLACEHOLDER,
-            target: Entity::PLACEHOLDER,
-            impact_point: Vec3::ONE,
-            normal: Vec3::Y,
-            velocity: Vec3::X,
-            damage: 25.0,
-            penetrated: false,
             ricocheted: true,
+            ..HitEvent::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER, Vec3::ONE, Vec3::Y, Vec3::X, 25.0)
         };
         
         app.world.send_event(hit_event2);
@@ -1,85 +1,300 @@
 //! Accuracy system - dynamic spread and bloom calculation.
 
 use bevy::prelude::*;
+use bevy::ecs::message::MessageReader;
+use smallvec::SmallVec;
 
 use crate::components::Accuracy;
+use crate::events::WeaponEquippedEvent;
+use crate::resources::BallisticsConfig;
 
 /// Update bloom recovery for all weapons with accuracy components.
 ///
-/// Runs every frame to smoothly decrease bloom over time.
+/// Runs every frame to smoothly decrease bloom over time. Recovery only starts
+/// once `recovery_delay` seconds have passed since the last shot.
 /// 
 /// # Arguments
 /// * `time` - Bevy Time resource to get delta time
+/// * `config` - Ballistics configuration resource (time scale)
 /// * `query` - Query for mutable references to Accuracy components
-pub fn update_bloom(time: Res<Time>, mut query: Query<&mut Accuracy>) {
-    let dt = time.delta_secs();
+pub fn update_bloom(
+    time: Res<Time>,
+    config: Res<BallisticsConfig>,
+    mut query: Query<&mut Accuracy>,
+) {
+    let dt = config.scaled_delta(time.delta_secs());
 
     for mut accuracy in query.iter_mut() {
-        // Recover bloom over time
-        accuracy.current_bloom -= accuracy.recovery_rate * dt;
-        accuracy.current_bloom = accuracy.current_bloom.max(0.0);
+        recover_bloom(&mut accuracy, dt);
     }
 }
 
+/// Clear the bloom of every entity named in a `WeaponEquippedEvent`.
+///
+/// Bloom is only reset when the game sends the event; swapping components
+/// around by hand leaves it alone.
+///
+/// # Arguments
+/// * `equip_events` - Message reader for weapon equip events
+/// * `query` - Query for mutable references to Accuracy components
+pub fn reset_bloom_on_equip(
+    mut equip_events: MessageReader<WeaponEquippedEvent>,
+    mut query: Query<&mut Accuracy>,
+) {
+    for event in equip_events.read() {
+        if let Ok(mut accuracy) = query.get_mut(event.entity) {
+            accuracy.reset_bloom();
+        }
+    }
+}
+
+/// Advance one weapon's bloom recovery by `dt` seconds.
+///
+/// Bloom holds while the weapon is still settling from its last shot, then
+/// decays at `recovery_rate`, sped up just after a trigger release (see [`track_trigger`]).
+///
+/// # Arguments
+/// * `accuracy` - Mutable reference to the Accuracy component
+/// * `dt` - Elapsed time in seconds
+pub fn recover_bloom(accuracy: &mut Accuracy, dt: f32) {
+    accuracy.time_since_last_shot += dt;
+    if accuracy.time_since_last_shot < accuracy.recovery_delay {
+        return;
+    }
+
+    // Recover bloom over time
+    accuracy.current_bloom -= accuracy.recovery_rate * release_multiplier(accuracy) * dt;
+    accuracy.current_bloom = accuracy.current_bloom.max(0.0);
+}
+
+/// Advance one weapon's ADS settle by `dt` seconds.
+///
+/// Call once per frame with the player's aim state. Holding ADS accumulates
+/// `time_aiming`; leaving ADS resets it, so the next aim starts from hip spread.
+///
+/// # Arguments
+/// * `accuracy` - Mutable reference to the Accuracy component
+/// * `is_aiming` - Whether the player is aiming down sights this frame
+/// * `dt` - Elapsed time in seconds
+pub fn track_aim(accuracy: &mut Accuracy, is_aiming: bool, dt: f32) {
+    if is_aiming {
+        accuracy.time_aiming += dt;
+    } else {
+        accuracy.time_aiming = 0.0;
+    }
+}
+
+/// Advance one weapon's trigger tracking by `dt` seconds.
+///
+/// Call once per frame with the trigger state to reward tap-firing. Holding the
+/// trigger accumulates `continuous_fire_time` and earns no boost; releasing it
+/// starts the `release_boost_window`, during which bloom recovers up to
+/// `release_recovery_boost` times faster, fading linearly back to normal.
+///
+/// # Arguments
+/// * `accuracy` - Mutable reference to the Accuracy component
+/// * `trigger_held` - Whether the trigger is held this frame
+/// * `dt` - Elapsed time in seconds
+pub fn track_trigger(accuracy: &mut Accuracy, trigger_held: bool, dt: f32) {
+    if trigger_held {
+        accuracy.continuous_fire_time += dt;
+        accuracy.time_since_release = f32::INFINITY;
+    } else if accuracy.continuous_fire_time > 0.0 {
+        accuracy.continuous_fire_time = 0.0;
+        accuracy.time_since_release = 0.0;
+    } else {
+        accuracy.time_since_release += dt;
+    }
+}
+
+/// Bloom recovery multiplier from a recent trigger release.
+///
+/// Fades linearly from `release_recovery_boost` to 1.0 over `release_boost_window`.
+fn release_multiplier(accuracy: &Accuracy) -> f32 {
+    if accuracy.release_boost_window <= 0.0
+        || accuracy.time_since_release >= accuracy.release_boost_window
+    {
+        return 1.0;
+    }
+    let remaining = 1.0 - accuracy.time_since_release / accuracy.release_boost_window;
+    1.0 + (accuracy.release_recovery_boost - 1.0) * remaining
+}
+
+/// Spread multiplier while aiming down sights.
+///
+/// Eases linearly from 1.0 (hip) to `ads_modifier` over `ads_settle_time`.
+fn ads_multiplier(accuracy: &Accuracy) -> f32 {
+    let settled = if accuracy.ads_settle_time > 0.0 {
+        (accuracy.time_aiming / accuracy.ads_settle_time).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    1.0 + (accuracy.ads_modifier - 1.0) * settled
+}
+
+/// Player state that modifies weapon spread.
+///
+/// Every flag defaults to off, so only the modifiers that apply need to be set:
+///
+/// ```
+/// use bevy_bullet_dynamics::systems::accuracy::AccuracyContext;
+///
+/// let ctx = AccuracyContext {
+///     is_aiming: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AccuracyContext {
+    /// Whether the player is aiming down sights
+    pub is_aiming: bool,
+    /// Whether the player is moving
+    pub is_moving: bool,
+    /// Whether the player is in the air
+    pub is_airborne: bool,
+    /// Current movement speed of the player
+    pub movement_speed: f32,
+    /// Maximum possible movement speed of the player (0 disables the movement penalty)
+    pub max_speed: f32,
+}
+
 /// Calculate total spread angle based on player state.
 ///
 /// Returns the final spread angle in radians.
 /// 
 /// # Arguments
 /// * `accuracy` - Reference to the Accuracy component
-/// * `is_aiming` - Whether the player is aiming down sights
-/// * `is_moving` - Whether the player is moving
-/// * `is_airborne` - Whether the player is in the air
-/// * `movement_speed` - Current movement speed of the player
-/// * `max_speed` - Maximum possible movement speed of the player
+/// * `ctx` - Player state modifiers (aiming, movement, airborne)
 /// 
 /// # Returns
 /// The calculated total spread angle in radians
-pub fn calculate_total_spread(
-    accuracy: &Accuracy,
-    is_aiming: bool,
-    is_moving: bool,
-    is_airborne: bool,
-    movement_speed: f32,
-    max_speed: f32,
-) -> f32 {
+pub fn calculate_total_spread(accuracy: &Accuracy, ctx: &AccuracyContext) -> f32 {
     // Start with base spread + accumulated bloom
     let mut total_spread = accuracy.base_spread + accuracy.current_bloom;
 
     // Movement penalty (scaled by movement speed)
-    if is_moving && max_speed > 0.0 {
-        let speed_ratio = (movement_speed / max_speed).min(1.0);
+    if ctx.is_moving && ctx.max_speed > 0.0 {
+        let speed_ratio = (ctx.movement_speed / ctx.max_speed).min(1.0);
         total_spread += accuracy.movement_penalty * speed_ratio * accuracy.base_spread;
     }
 
     // Airborne penalty (multiplicative)
-    if is_airborne {
+    if ctx.is_airborne {
         total_spread *= accuracy.airborne_multiplier;
     }
 
-    // ADS bonus (multiplicative reduction)
-    if is_aiming {
-        total_spread *= accuracy.ads_modifier;
+    // ADS bonus (multiplicative reduction), tightening as the aim settles
+    if ctx.is_aiming {
+        total_spread *= ads_multiplier(accuracy);
     }
 
     // Clamp to max spread
     total_spread.min(accuracy.max_spread)
 }
 
+/// Calculate total spread angle from positional player state.
+///
+/// Thin wrapper around [`calculate_total_spread`] kept for older call sites.
+/// 
+/// # Arguments
+/// * `accuracy` - Reference to the Accuracy component
+/// * `is_aiming` - Whether the player is aiming down sights
+/// * `is_moving` - Whether the player is moving
+/// * `is_airborne` - Whether the player is in the air
+/// * `movement_speed` - Current movement speed of the player
+/// * `max_speed` - Maximum possible movement speed of the player
+/// 
+/// # Returns
+/// The calculated total spread angle in radians
+#[deprecated(note = "use `calculate_total_spread` with an `AccuracyContext`")]
+pub fn calculate_total_spread_positional(
+    accuracy: &Accuracy,
+    is_aiming: bool,
+    is_moving: bool,
+    is_airborne: bool,
+    movement_speed: f32,
+    max_speed: f32,
+) -> f32 {
+    calculate_total_spread(
+        accuracy,
+        &AccuracyContext {
+            is_aiming,
+            is_moving,
+            is_airborne,
+            movement_speed,
+            max_speed,
+        },
+    )
+}
+
 /// Apply bloom increase after firing.
 /// 
 /// Increases the current bloom value based on the bloom_per_shot property,
-/// clamping to the maximum spread.
+/// clamping to the maximum spread, and restarts the recovery delay.
 /// 
 /// # Arguments
 /// * `accuracy` - Mutable reference to the Accuracy component
 pub fn apply_shot_bloom(accuracy: &mut Accuracy) {
     accuracy.current_bloom = (accuracy.current_bloom + accuracy.bloom_per_shot).min(accuracy.max_spread);
+    accuracy.time_since_last_shot = 0.0;
+}
+
+/// Small deterministic RNG for spread (SplitMix64-seeded xorshift64*).
+///
+/// Cheap enough to build for every shot. The same seed gives the same pellets
+/// with the same binary, so a client and the server running one build agree;
+/// across platforms or compilers the `f32` math may round differently.
+pub(crate) struct SpreadRng(u64);
+
+impl SpreadRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // SplitMix64 scramble so neighbouring seeds diverge and the state is never zero
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        Self(if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z })
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform sample in [0, 1).
+    pub(crate) fn next_unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform sample in [-1, 1).
+    fn next_signed(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+
+    /// Two independent standard normal samples (Marsaglia polar method).
+    pub(crate) fn next_normal_pair(&mut self) -> (f32, f32) {
+        loop {
+            let u = self.next_signed();
+            let v = self.next_signed();
+            let s = u * u + v * v;
+            if s > 0.0 && s < 1.0 {
+                let factor = (-2.0 * s.ln() / s).sqrt();
+                return (u * factor, v * factor);
+            }
+        }
+    }
 }
 
 /// Generate a random direction within the spread cone.
 ///
 /// Uses Gaussian distribution for more realistic center-weighted spread.
+/// Samples come from a small inline RNG seeded by `seed`, so the result is fully
+/// determined by the inputs and nothing is allocated per call (shotguns fire this
+/// once per pellet).
 /// 
 /// # Arguments
 /// * `base_direction` - The original direction vector before applying spread
@@ -89,17 +304,16 @@ pub fn apply_shot_bloom(accuracy: &mut Accuracy) {
 /// # Returns
 /// A new direction vector with spread applied
 pub fn apply_spread_to_direction(base_direction: Vec3, spread_angle: f32, seed: u64) -> Vec3 {
-    use rand::prelude::*;
-    use rand_distr::{Distribution, Normal};
-
     // Create seeded RNG for deterministic spread (networking)
-    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let mut rng = SpreadRng::new(seed);
 
-    // Gaussian distribution for spread (center-weighted)
-    let normal = Normal::new(0.0, spread_angle as f64 / 3.0).unwrap_or(Normal::new(0.0, 0.01).unwrap());
+    // Gaussian distribution for spread (center-weighted), ~99.7% inside the cone
+    let sigma = spread_angle / 3.0;
+    let sigma = if sigma.is_finite() && sigma >= 0.0 { sigma } else { 0.01 };
 
-    let angle_x = normal.sample(&mut rng) as f32;
-    let angle_y = normal.sample(&mut rng) as f32;
+    let (nx, ny) = rng.next_normal_pair();
+    let angle_x = nx * sigma;
+    let angle_y = ny * sigma;
 
     // Create rotation from spread angles
     let rotation = Quat::from_euler(EulerRot::XYZ, angle_x, angle_y, 0.0);
@@ -108,6 +322,60 @@ pub fn apply_spread_to_direction(base_direction: Vec3, spread_angle: f32, seed:
     (rotation * base_direction).normalize()
 }
 
+/// How the pellets of a volley are distributed around the aim direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpreadPattern {
+    /// Center-weighted random cone, as [`apply_spread_to_direction`]
+    Random {
+        /// Maximum spread angle in radians
+        spread_angle: f32,
+    },
+    /// Pellets evenly spaced on a ring around the aim direction
+    Ring {
+        /// Angle between the aim direction and each pellet, in radians
+        angle: f32,
+    },
+}
+
+/// Directions of every pellet in a volley.
+/// 
+/// Random pellets use `seed + index`, matching the fire system, so a client and
+/// the server derive the same volley. Ring pellets are fixed and ignore the seed.
+/// 
+/// # Arguments
+/// * `base_direction` - Aim direction
+/// * `pattern` - Pellet distribution
+/// * `count` - Number of pellets
+/// * `seed` - Random seed for deterministic spread
+/// 
+/// # Returns
+/// One normalized direction per pellet
+pub fn pellet_directions(
+    base_direction: Vec3,
+    pattern: SpreadPattern,
+    count: u32,
+    seed: u64,
+) -> SmallVec<[Vec3; 16]> {
+    let base_direction = base_direction.normalize();
+    match pattern {
+        SpreadPattern::Random { spread_angle } => (0..count)
+            .map(|pellet| {
+                apply_spread_to_direction(base_direction, spread_angle, seed.wrapping_add(pellet as u64))
+            })
+            .collect(),
+        SpreadPattern::Ring { angle } => {
+            let tilt_axis = base_direction.any_orthonormal_vector();
+            let tilted = Quat::from_axis_angle(tilt_axis, angle) * base_direction;
+            (0..count)
+                .map(|pellet| {
+                    let azimuth = std::f32::consts::TAU * pellet as f32 / count as f32;
+                    (Quat::from_axis_angle(base_direction, azimuth) * tilted).normalize()
+                })
+                .collect()
+        }
+    }
+}
+
 /// Create accuracy preset for different weapon types.
 pub mod presets {
     use super::*;
@@ -264,24 +532,129 @@ mod tests {
     #[test]
     fn test_spread_calculation_base() {
         let accuracy = Accuracy::default();
-        let spread = calculate_total_spread(&accuracy, false, false, false, 0.0, 5.0);
+        let spread = calculate_total_spread(&accuracy, &AccuracyContext::default());
         assert_eq!(spread, accuracy.base_spread);
     }
 
     #[test]
     fn test_spread_calculation_ads() {
         let accuracy = Accuracy::default();
-        let spread = calculate_total_spread(&accuracy, true, false, false, 0.0, 5.0);
+        let ctx = AccuracyContext {
+            is_aiming: true,
+            ..Default::default()
+        };
+        let spread = calculate_total_spread(&accuracy, &ctx);
         assert!(spread < accuracy.base_spread);
     }
 
+    #[test]
+    fn test_ads_spread_tightens_while_settling() {
+        let mut accuracy = Accuracy {
+            ads_settle_time: 0.5,
+            ..Default::default()
+        };
+        let ctx = AccuracyContext {
+            is_aiming: true,
+            ..Default::default()
+        };
+
+        // Fresh ADS starts at hip spread
+        let start = calculate_total_spread(&accuracy, &ctx);
+        assert_eq!(start, accuracy.base_spread);
+
+        let mut previous = start;
+        for _ in 0..10 {
+            track_aim(&mut accuracy, true, 0.1);
+            let spread = calculate_total_spread(&accuracy, &ctx);
+            assert!(spread <= previous);
+            previous = spread;
+        }
+        let settled = accuracy.base_spread * accuracy.ads_modifier;
+        assert!((previous - settled).abs() < 1e-6);
+        assert!(start > previous);
+
+        // Dropping out of ADS restarts the settle
+        track_aim(&mut accuracy, false, 0.1);
+        assert_eq!(accuracy.time_aiming, 0.0);
+        assert_eq!(calculate_total_spread(&accuracy, &ctx), start);
+    }
+
     #[test]
     fn test_spread_calculation_moving() {
         let accuracy = Accuracy::default();
-        let spread = calculate_total_spread(&accuracy, false, true, false, 5.0, 5.0);
+        let ctx = AccuracyContext {
+            is_moving: true,
+            movement_speed: 5.0,
+            max_speed: 5.0,
+            ..Default::default()
+        };
+        let spread = calculate_total_spread(&accuracy, &ctx);
         assert!(spread > accuracy.base_spread);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_context_matches_positional() {
+        let accuracy = Accuracy {
+            current_bloom: 0.004,
+            ..presets::rifle()
+        };
+
+        for &(is_aiming, is_moving, is_airborne, movement_speed) in &[
+            (false, false, false, 0.0),
+            (true, false, false, 0.0),
+            (false, true, false, 2.5),
+            (false, true, true, 7.0),
+            (true, true, true, 5.0),
+        ] {
+            let ctx = AccuracyContext {
+                is_aiming,
+                is_moving,
+                is_airborne,
+                movement_speed,
+                max_speed: 5.0,
+            };
+            assert_eq!(
+                calculate_total_spread(&accuracy, &ctx),
+                calculate_total_spread_positional(
+                    &accuracy,
+                    is_aiming,
+                    is_moving,
+                    is_airborne,
+                    movement_speed,
+                    5.0,
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn test_context_modifiers_stack() {
+        let accuracy = Accuracy {
+            current_bloom: 0.004,
+            ..presets::rifle()
+        };
+        let ads = ads_multiplier(&accuracy);
+
+        // base 0.001 + bloom 0.004; movement adds penalty 2.0 x speed ratio x base
+        for &(is_aiming, is_moving, is_airborne, movement_speed, expected) in &[
+            (false, false, false, 0.0, 0.005),
+            (true, false, false, 0.0, 0.005 * ads),
+            (false, true, false, 2.5, 0.006),
+            (false, true, true, 7.0, 0.007 * 3.0),
+            (true, true, true, 5.0, 0.007 * 3.0 * ads),
+        ] {
+            let ctx = AccuracyContext {
+                is_aiming,
+                is_moving,
+                is_airborne,
+                movement_speed,
+                max_speed: 5.0,
+            };
+            assert!((calculate_total_spread(&accuracy, &ctx) - expected).abs() < 1e-6);
+        }
+    }
+
     #[test]
     fn test_bloom_accumulation() {
         let mut accuracy = Accuracy::default();
@@ -293,4 +666,153 @@ mod tests {
         apply_shot_bloom(&mut accuracy);
         assert_eq!(accuracy.current_bloom, accuracy.bloom_per_shot * 2.0);
     }
+
+    #[test]
+    fn test_reset_bloom_keeps_tuning() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut accuracy = presets::rifle();
+        let tuning = (accuracy.base_spread, accuracy.max_spread, accuracy.bloom_per_shot);
+        apply_shot_bloom(&mut accuracy);
+        apply_shot_bloom(&mut accuracy);
+        assert!(accuracy.current_bloom > 0.0);
+
+        accuracy.reset_bloom();
+        assert_eq!(accuracy.current_bloom, 0.0);
+        assert_eq!((accuracy.base_spread, accuracy.max_spread, accuracy.bloom_per_shot), tuning);
+
+        // Only the equipped entity is reset
+        let mut world = World::new();
+        world.init_resource::<Messages<WeaponEquippedEvent>>();
+        let mut bloomed = presets::rifle();
+        apply_shot_bloom(&mut bloomed);
+        let equipped = world.spawn(bloomed.clone()).id();
+        let other = world.spawn(bloomed.clone()).id();
+        world.write_message(WeaponEquippedEvent { entity: equipped });
+        world.run_system_once(reset_bloom_on_equip).unwrap();
+
+        assert_eq!(world.get::<Accuracy>(equipped).unwrap().current_bloom, 0.0);
+        assert_eq!(world.get::<Accuracy>(other).unwrap().current_bloom, bloomed.current_bloom);
+    }
+
+    #[test]
+    fn test_trigger_release_speeds_up_recovery() {
+        let dt = 0.01;
+        let mut tapped = presets::rifle();
+        let mut held = presets::rifle();
+        for accuracy in [&mut tapped, &mut held] {
+            // A short burst with the trigger down
+            for _ in 0..5 {
+                track_trigger(accuracy, true, dt);
+                apply_shot_bloom(accuracy);
+            }
+        }
+
+        // A weapon that never reports its trigger recovers like the held one
+        let mut untracked = held.clone();
+
+        // Same interval: one lets go of the trigger, the other keeps holding
+        let mut previous = tapped.current_bloom;
+        let mut steps = Vec::new();
+        for _ in 0..20 {
+            track_trigger(&mut tapped, false, dt);
+            track_trigger(&mut held, true, dt);
+            recover_bloom(&mut tapped, dt);
+            recover_bloom(&mut held, dt);
+            recover_bloom(&mut untracked, dt);
+            steps.push(previous - tapped.current_bloom);
+            previous = tapped.current_bloom;
+        }
+        assert!(tapped.current_bloom < held.current_bloom);
+        assert_eq!(held.current_bloom, untracked.current_bloom);
+        assert!(held.continuous_fire_time > 0.0);
+
+        // The boost fades step by step
+        assert!(steps.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(steps[0] <= tapped.recovery_rate * tapped.release_recovery_boost * dt + 1e-6);
+
+        // Once the window has passed recovery is back to normal
+        for _ in 0..30 {
+            track_trigger(&mut tapped, false, dt);
+        }
+        assert_eq!(release_multiplier(&tapped), 1.0);
+    }
+
+    #[test]
+    fn test_bloom_recovery_delay() {
+        let mut accuracy = Accuracy {
+            recovery_delay: 0.2,
+            ..Default::default()
+        };
+        apply_shot_bloom(&mut accuracy);
+        let bloom = accuracy.current_bloom;
+
+        // Holds during the settle window
+        for _ in 0..10 {
+            recover_bloom(&mut accuracy, 0.01);
+        }
+        assert_eq!(accuracy.current_bloom, bloom);
+
+        // Another shot restarts the window
+        apply_shot_bloom(&mut accuracy);
+        let bloom = accuracy.current_bloom;
+        for _ in 0..19 {
+            recover_bloom(&mut accuracy, 0.01);
+        }
+        assert_eq!(accuracy.current_bloom, bloom);
+
+        // Then decays
+        recover_bloom(&mut accuracy, 0.01);
+        recover_bloom(&mut accuracy, 0.01);
+        assert!(accuracy.current_bloom < bloom);
+    }
+
+    #[test]
+    fn test_zero_recovery_delay_decays_immediately() {
+        let mut accuracy = Accuracy::default();
+        apply_shot_bloom(&mut accuracy);
+        recover_bloom(&mut accuracy, 0.1);
+        let expected = accuracy.bloom_per_shot - accuracy.recovery_rate * 0.1;
+        assert!((accuracy.current_bloom - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_spread_golden_values() {
+        // Captured from the reference implementation; a change here breaks
+        // client/server agreement on spread for existing seeds
+        let direction = apply_spread_to_direction(Vec3::NEG_Z, 0.05, 12345);
+        assert!(direction.abs_diff_eq(Vec3::new(0.014899845, -0.011110375, -0.9998273), 1e-6));
+        let direction = apply_spread_to_direction(Vec3::NEG_Z, 0.05, 0);
+        assert!(direction.abs_diff_eq(Vec3::new(-0.018311221, -0.00082592736, -0.9998321), 1e-6));
+
+        // Same seed, same direction
+        assert_eq!(
+            apply_spread_to_direction(Vec3::X, 0.1, 42),
+            apply_spread_to_direction(Vec3::X, 0.1, 42)
+        );
+    }
+
+    #[test]
+    fn test_spread_is_center_weighted() {
+        let spread = 0.06;
+        let samples = 4000;
+        let (mut sum, mut sum_sq, mut outside) = (0.0f32, 0.0f32, 0);
+        for seed in 0..samples {
+            let direction = apply_spread_to_direction(Vec3::NEG_Z, spread, seed);
+            let angle = direction.angle_between(Vec3::NEG_Z);
+            let x = direction.x.asin();
+            sum += x;
+            sum_sq += x * x;
+            if angle > spread * 1.5 {
+                outside += 1;
+            }
+        }
+        let mean = sum / samples as f32;
+        let std_dev = (sum_sq / samples as f32 - mean * mean).sqrt();
+
+        assert!(mean.abs() < 0.002);
+        assert!((std_dev - spread / 3.0).abs() < 0.002);
+        assert!(outside < samples / 100);
+    }
 }
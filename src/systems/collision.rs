@@ -3,36 +3,486 @@
 use bevy::prelude::*;
 use bevy::ecs::message::MessageWriter;
 
-use crate::components::{Payload, Projectile, SurfaceMaterial};
-use crate::events::HitEvent;
-use crate::resources::BallisticsConfig;
-use crate::systems::surface;
+use crate::components::{
+    Armor, NetProjectile, NetworkId, OnHitCallback, Payload, Pierce, Projectile,
+    ProjectileCollisionMask, Resistances, SurfaceMaterial, Team,
+};
+use crate::events::{
+    DespawnReason, HitEvent, PenetrationEvent, ProjectileDespawnedEvent, RicochetEvent,
+};
+use crate::resources::{BallisticsConfig, CollisionSchedule, RicochetMode, SpentProjectileBehavior};
+use crate::systems::{debris, surface};
+use crate::types::{HitResult, ProjectileRng};
+
+/// Build a [`HitResult`] for a raycast hit, looking up the surface material.
+///
+/// `collider` is the leaf collider reported by the raycast and `body` the rigid
+/// body it belongs to, if any. The collider's own SurfaceMaterial wins; otherwise
+/// the body's material is used, so a whole character can be tagged once.
+///
+/// # Arguments
+/// * `collider` - Collider entity that was hit
+/// * `body` - Rigid body owning the collider, if it's part of one
+/// * `point` - World-space hit point
+/// * `normal` - Surface normal at the hit point
+/// * `distance` - Distance from the ray origin to the hit point
+/// * `surfaces` - Query for surface material components
+///
+/// # Returns
+/// A HitResult whose `entity` is the body (or collider) and `collider_entity` the leaf
+pub fn resolve_hit(
+    collider: Entity,
+    body: Option<Entity>,
+    point: Vec3,
+    normal: Vec3,
+    distance: f32,
+    surfaces: &Query<&SurfaceMaterial>,
+) -> HitResult {
+    let root = body.unwrap_or(collider);
+    let surface = surfaces
+        .get(collider)
+        .or_else(|_| surfaces.get(root))
+        .ok()
+        .cloned();
+
+    HitResult::new(root, point, normal, distance)
+        .with_collider(collider)
+        .with_surface(surface)
+}
+
+/// Find the SurfaceMaterial of a hit entity or its nearest tagged ancestor.
+///
+/// Colliders are often children of the visual root that carries the material;
+/// this walks up the `ChildOf` hierarchy from the leaf and stops at the first
+/// entity with a SurfaceMaterial.
+///
+/// # Arguments
+/// * `entity` - Leaf entity that was hit
+/// * `surfaces` - Query for surface material components
+/// * `parents` - Query for hierarchy parents
+///
+/// # Returns
+/// The nearest SurfaceMaterial, or None if neither the entity nor any ancestor has one
+pub fn find_surface_material<'a>(
+    entity: Entity,
+    surfaces: &'a Query<&SurfaceMaterial>,
+    parents: &Query<&ChildOf>,
+) -> Option<&'a SurfaceMaterial> {
+    find_on_ancestors(entity, surfaces, parents)
+}
+
+/// Find a component on a hit entity or its nearest ancestor that has one.
+///
+/// Generic form of [`find_surface_material`], used for armor and resistances.
+///
+/// # Arguments
+/// * `entity` - Leaf entity that was hit
+/// * `components` - Query for the component
+/// * `parents` - Query for hierarchy parents
+///
+/// # Returns
+/// The nearest component, or None if neither the entity nor any ancestor has one
+pub fn find_on_ancestors<'a, T: Component>(
+    entity: Entity,
+    components: &'a Query<&T>,
+    parents: &Query<&ChildOf>,
+) -> Option<&'a T> {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find_map(|ancestor| components.get(ancestor).ok())
+}
+
+/// What the struck entity brings to a hit.
+///
+/// Built by [`HitModifierQuery::get`], which takes each part from the struck
+/// collider or its nearest ancestor that has one, so a compound body can be
+/// tagged once on its root.
+///
+/// # Fields
+/// * `surface` - Surface material; drives ricochets and penetration
+/// * `armor` - Armor; overrides the surface for penetration
+/// * `resistances` - Damage resistances, applied to the damage
+#[derive(Clone, Copy, Default)]
+pub struct HitModifiers<'a> {
+    /// Surface material of the struck entity
+    pub surface: Option<&'a SurfaceMaterial>,
+    /// Armor of the struck entity
+    pub armor: Option<&'a Armor>,
+    /// Damage resistances of the struck entity
+    pub resistances: Option<&'a Resistances>,
+}
+
+impl<'a> HitModifiers<'a> {
+    /// Set the surface material.
+    pub fn with_surface(mut self, surface: &'a SurfaceMaterial) -> Self {
+        self.surface = Some(surface);
+        self
+    }
+
+    /// Set the armor.
+    pub fn with_armor(mut self, armor: &'a Armor) -> Self {
+        self.armor = Some(armor);
+        self
+    }
+
+    /// Set the damage resistances.
+    pub fn with_resistances(mut self, resistances: &'a Resistances) -> Self {
+        self.resistances = Some(resistances);
+        self
+    }
+}
+
+/// Looks up the [`HitModifiers`] of struck entities through the `ChildOf` hierarchy.
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct HitModifierQuery<'w, 's> {
+    surfaces: Query<'w, 's, &'static SurfaceMaterial>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    armor: Query<'w, 's, &'static Armor>,
+    resistances: Query<'w, 's, &'static Resistances>,
+}
+
+impl HitModifierQuery<'_, '_> {
+    /// Surface, armor and resistances of `entity`, each from the entity or its nearest tagged ancestor.
+    pub fn get(&self, entity: Entity) -> HitModifiers<'_> {
+        HitModifiers {
+            surface: find_surface_material(entity, &self.surfaces, &self.parents),
+            armor: find_on_ancestors(entity, &self.armor, &self.parents),
+            resistances: find_on_ancestors(entity, &self.resistances, &self.parents),
+        }
+    }
+
+    /// Topmost `ChildOf` ancestor of `entity`, or the entity itself.
+    ///
+    /// Pierces are counted against this, so the colliders of one compound body
+    /// use up a single pierce between them.
+    pub fn body(&self, entity: Entity) -> Entity {
+        self.parents.root_ancestor(entity)
+    }
+}
+
+/// Message writers for everything [`process_hit`] reports.
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct HitMessages<'w> {
+    /// Every hit, including ricochets and penetrations
+    pub hits: MessageWriter<'w, HitEvent>,
+    /// Rounds glancing off a surface
+    pub ricochets: MessageWriter<'w, RicochetEvent>,
+    /// Rounds passing through a surface
+    pub penetrations: MessageWriter<'w, PenetrationEvent>,
+    /// Rounds spent by their ricochet limit
+    pub despawns: MessageWriter<'w, ProjectileDespawnedEvent>,
+}
+
+/// Trace a shot and report the first thing it hits.
+///
+/// Immediate raycast for hitscan weapons, laser sights and line-of-sight checks.
+/// Colliders belonging to `exclude` (the shooter itself or any of its child
+/// colliders) are skipped. The result names the leaf collider that was struck and
+/// carries its surface material, see [`resolve_hit`].
+///
+/// # Arguments
+/// * `spatial_query` - Avian3D spatial query
+/// * `origin` - Start of the shot in world space
+/// * `direction` - Shot direction (normalized internally)
+/// * `max_dist` - Maximum distance to trace
+/// * `exclude` - Shooter entity to ignore
+/// * `surfaces` - Query for surface material components
+/// * `colliders` - Query mapping colliders to their rigid bodies
+///
+/// # Returns
+/// The first hit, or None if nothing is within `max_dist`
+#[cfg(feature = "dim3")]
+pub fn trace_shot(
+    spatial_query: &avian3d::prelude::SpatialQuery,
+    origin: Vec3,
+    direction: Vec3,
+    max_dist: f32,
+    exclude: Option<Entity>,
+    surfaces: &Query<&SurfaceMaterial>,
+    colliders: &Query<&avian3d::prelude::ColliderOf>,
+) -> Option<HitResult> {
+    use avian3d::prelude::*;
+
+    let direction = Dir3::new(direction).ok()?;
+    let filter = SpatialQueryFilter::default().with_excluded_entities(exclude);
+    let body_of = |entity: Entity| colliders.get(entity).ok().map(|c| c.body);
+
+    let hit = spatial_query.cast_ray_predicate(
+        origin,
+        direction,
+        max_dist,
+        true,
+        &filter,
+        &|entity| exclude.is_none() || body_of(entity) != exclude,
+    )?;
+
+    Some(resolve_hit(
+        hit.entity,
+        body_of(hit.entity),
+        origin + *direction * hit.distance,
+        hit.normal,
+        hit.distance,
+        surfaces,
+    ))
+}
+
+/// Trace a shot in 2D and report the first thing it hits.
+///
+/// 2D counterpart of [`trace_shot`]. The hit point and normal are returned in
+/// 3D with `z = 0`.
+///
+/// # Arguments
+/// * `spatial_query` - Avian2D spatial query
+/// * `origin` - Start of the shot in world space
+/// * `direction` - Shot direction (normalized internally)
+/// * `max_dist` - Maximum distance to trace
+/// * `exclude` - Shooter entity to ignore
+/// * `surfaces` - Query for surface material components
+/// * `colliders` - Query mapping colliders to their rigid bodies
+///
+/// # Returns
+/// The first hit, or None if nothing is within `max_dist`
+#[cfg(feature = "dim2")]
+pub fn trace_shot_2d(
+    spatial_query: &avian2d::prelude::SpatialQuery,
+    origin: Vec2,
+    direction: Vec2,
+    max_dist: f32,
+    exclude: Option<Entity>,
+    surfaces: &Query<&SurfaceMaterial>,
+    colliders: &Query<&avian2d::prelude::ColliderOf>,
+) -> Option<HitResult> {
+    use avian2d::prelude::*;
+
+    let direction = Dir2::new(direction).ok()?;
+    let filter = SpatialQueryFilter::default().with_excluded_entities(exclude);
+    let body_of = |entity: Entity| colliders.get(entity).ok().map(|c| c.body);
+
+    let hit = spatial_query.cast_ray_predicate(
+        origin,
+        direction,
+        max_dist,
+        true,
+        &filter,
+        &|entity| exclude.is_none() || body_of(entity) != exclude,
+    )?;
+
+    Some(resolve_hit(
+        hit.entity,
+        body_of(hit.entity),
+        (origin + *direction * hit.distance).extend(0.0),
+        hit.normal.extend(0.0),
+        hit.distance,
+        surfaces,
+    ))
+}
+
+/// Collision layers a projectile can hit: its own mask, or the configured default.
+pub fn collision_mask_for(
+    config: &BallisticsConfig,
+    mask: Option<&ProjectileCollisionMask>,
+) -> ProjectileCollisionMask {
+    mask.copied().unwrap_or(config.collision_mask)
+}
+
+/// Team a projectile was fired by: its own `Team`, else its owner's.
+///
+/// A despawned owner simply has no team, so only rounds carrying their own
+/// `Team` stay friendly after their shooter is gone.
+pub fn shooter_team(teams: &Query<&Team>, projectile: Entity, owner: Option<Entity>) -> Option<Team> {
+    teams
+        .get(projectile)
+        .ok()
+        .or_else(|| owner.and_then(|owner| teams.get(owner).ok()))
+        .copied()
+}
+
+/// Whether friendly-fire rules stop `shooter`'s rounds from hitting `target`.
+///
+/// Always false while `BallisticsConfig::friendly_fire` is enabled.
+pub fn is_friendly_fire(
+    config: &BallisticsConfig,
+    teams: &Query<&Team>,
+    shooter: Option<Team>,
+    target: Entity,
+) -> bool {
+    !config.friendly_fire && shooter.is_some() && teams.get(target).ok().copied() == shooter
+}
+
+/// Blend a raw hit normal toward the average of nearby surface normals.
+///
+/// `neighbours` are normals sampled around the hit; any facing away from `raw`
+/// (another side of the object) are ignored. On a flat surface every sample
+/// equals `raw` and it comes back unchanged.
+///
+/// # Arguments
+/// * `raw` - Normal reported at the hit point
+/// * `neighbours` - Normals sampled around the hit point
+/// * `blend` - How far to move toward the average, from 0 (raw) to 1 (average)
+///
+/// # Returns
+/// The smoothed unit normal
+pub fn smooth_normal(raw: Vec3, neighbours: impl IntoIterator<Item = Vec3>, blend: f32) -> Vec3 {
+    let (sum, count) = neighbours
+        .into_iter()
+        .filter(|normal| normal.dot(raw) > 0.0)
+        .fold((raw, 1), |(sum, count), normal| (sum + normal, count + 1));
+    if count == 1 {
+        return raw;
+    }
+    let average = sum.normalize_or(raw);
+    raw.lerp(average, blend.clamp(0.0, 1.0)).normalize_or(raw)
+}
+
+/// Hit normal smoothed with `BallisticsConfig::normal_smoothing`.
+///
+/// Casts four extra rays parallel to the shot, `normal_smoothing_radius` off
+/// the shot line, and feeds the normals they find on the same entity near the
+/// hit point to [`smooth_normal`]. Returns `raw_normal` when smoothing is off.
+///
+/// # Arguments
+/// * `spatial_query` - Avian3D spatial query
+/// * `filter` - Filter of the shot's own ray
+/// * `config` - Ballistics configuration resource
+/// * `ray_origin` - Start of the shot's ray
+/// * `direction` - Direction of the shot's ray
+/// * `hit_distance` - Distance along the ray to the hit
+/// * `hit_entity` - Entity that was hit
+/// * `raw_normal` - Normal reported by the shot's ray
+#[cfg(feature = "dim3")]
+#[allow(clippy::too_many_arguments)]
+pub fn smoothed_hit_normal(
+    spatial_query: &avian3d::prelude::SpatialQuery,
+    filter: &avian3d::prelude::SpatialQueryFilter,
+    config: &BallisticsConfig,
+    ray_origin: Vec3,
+    direction: Dir3,
+    hit_distance: f32,
+    hit_entity: Entity,
+    raw_normal: Vec3,
+) -> Vec3 {
+    if config.normal_smoothing <= 0.0 {
+        return raw_normal;
+    }
+    let radius = config.normal_smoothing_radius;
+    let hit_point = ray_origin + *direction * hit_distance;
+    let (u, v) = direction.any_orthonormal_pair();
+    let neighbours = [u, -u, v, -v].into_iter().filter_map(|offset| {
+        let origin = ray_origin + offset * radius;
+        spatial_query
+            .cast_ray(origin, direction, hit_distance + 4.0 * radius, false, filter)
+            .filter(|sample| {
+                sample.entity == hit_entity
+                    && (origin + *direction * sample.distance).distance(hit_point) <= 4.0 * radius
+            })
+            .map(|sample| sample.normal)
+    });
+    smooth_normal(raw_normal, neighbours, config.normal_smoothing)
+}
+
+/// 2D version of [`smoothed_hit_normal`], sampling on both sides of the shot line.
+#[cfg(feature = "dim2")]
+#[allow(clippy::too_many_arguments)]
+pub fn smoothed_hit_normal_2d(
+    spatial_query: &avian2d::prelude::SpatialQuery,
+    filter: &avian2d::prelude::SpatialQueryFilter,
+    config: &BallisticsConfig,
+    ray_origin: Vec2,
+    direction: Dir2,
+    hit_distance: f32,
+    hit_entity: Entity,
+    raw_normal: Vec2,
+) -> Vec2 {
+    if config.normal_smoothing <= 0.0 {
+        return raw_normal;
+    }
+    let radius = config.normal_smoothing_radius;
+    let hit_point = ray_origin + *direction * hit_distance;
+    let side = direction.perp();
+    let neighbours = [side, -side].into_iter().filter_map(|offset| {
+        let origin = ray_origin + offset * radius;
+        spatial_query
+            .cast_ray(origin, direction, hit_distance + 4.0 * radius, false, filter)
+            .filter(|sample| {
+                sample.entity == hit_entity
+                    && (origin + *direction * sample.distance).distance(hit_point) <= 4.0 * radius
+            })
+            .map(|sample| sample.normal.extend(0.0))
+    });
+    smooth_normal(raw_normal.extend(0.0), neighbours, config.normal_smoothing).truncate()
+}
+
+/// Run condition: collisions are configured to run in `FixedUpdate`.
+pub fn collisions_in_fixed_update(config: Res<BallisticsConfig>) -> bool {
+    config.collision_schedule == CollisionSchedule::FixedUpdate
+}
+
+/// Run condition: collisions are configured to run in `Update`.
+pub fn collisions_in_update(config: Res<BallisticsConfig>) -> bool {
+    config.collision_schedule == CollisionSchedule::Update
+}
 
 /// Handle projectile collisions using raycasting between frames.
 ///
 /// Casts ray from previous_position to current position to catch fast projectiles.
 /// Uses avian3d SpatialQuery for actual physics-based collision detection.
+/// The ray skips the projectile's owner, any collider outside its
+/// [`ProjectileCollisionMask`] (see [`collision_mask_for`]) and, with friendly
+/// fire disabled, its shooter's teammates (see [`is_friendly_fire`]).
+/// Rounds with [`BounceOnContact`](crate::components::BounceOnContact) are handed to avian on their first contact
+/// (see [`debris::hand_off_to_physics`]) instead of being processed as a hit.
+/// Rounds with a [`Pierce`] skip the entities they already went through and
+/// are handled by [`process_pierce_hit`]. Rounds short of their
+/// [`ArmingDistance`](crate::components::ArmingDistance)
+/// glance off as duds (see [`process_unarmed_hit`]).
+/// With `BallisticsConfig::sensor_pass_through`, avian `Sensor` colliders never
+/// stop a round: every sensor crossed before the solid hit (if any) is reported
+/// by [`report_sensor_hit`].
+/// Fresh fragmentation shards skip their siblings and the parent's last hit
+/// while their [`FragmentGrace`](crate::components::FragmentGrace) lasts.
 /// 
 /// # Arguments
 /// * `mut commands` - Bevy Commands for entity manipulation
 /// * `config` - Ballistics configuration resource
 /// * `spatial_query` - Avian3D spatial query for physics-based collision detection
-/// * `mut hit_events` - Event writer for sending hit events
+/// * `mut messages` - Message writers for hit, ricochet, penetration and despawn events
 /// * `mut projectiles` - Query for projectile entities and their components
-/// * `surfaces` - Query for surface material components
+/// * `targets` - Surface, armor and resistances of struck entities, looked up through the hierarchy
+/// * `teams` - Query for teams, used for friendly-fire filtering
+/// * `sensors` - Query for sensor colliders
+/// * `fragments` - Query for fragment graces of fresh shards
 #[cfg(feature = "dim3")]
+#[allow(clippy::type_complexity)]
 pub fn handle_collisions(
     mut commands: Commands,
     config: Res<BallisticsConfig>,
     spatial_query: avian3d::prelude::SpatialQuery,
-    mut hit_events: MessageWriter<HitEvent>,
-    mut ricochet_events: MessageWriter<crate::events::RicochetEvent>,
-    mut penetration_events: MessageWriter<crate::events::PenetrationEvent>,
-    mut projectiles: Query<(Entity, &mut Transform, &mut Projectile, Option<&Payload>)>,
-    surfaces: Query<&SurfaceMaterial>,
+    mut messages: HitMessages,
+    mut projectiles: Query<(
+        Entity,
+        &mut Transform,
+        &mut Projectile,
+        Option<&Payload>,
+        Option<&ProjectileCollisionMask>,
+        Option<&crate::components::BounceOnContact>,
+        Option<&mut Pierce>,
+        Option<&crate::components::ArmingDistance>,
+        Option<&crate::components::OnHitCallback>,
+    )>,
+    targets: HitModifierQuery,
+    teams: Query<&Team>,
+    sensors: Query<(), With<avian3d::prelude::Sensor>>,
+    fragments: Query<&crate::components::FragmentGrace>,
 ) {
     use avian3d::prelude::*;
-    for (entity, mut transform, mut projectile, payload) in projectiles.iter_mut() {
+    for (entity, mut transform, mut projectile, payload, mask, bounce, mut pierce, arming, on_hit) in
+        projectiles.iter_mut()
+    {
+        if projectile.age == 0.0 {
+            // Not integrated yet: the spawn position is the true origin
+            projectile.previous_position = transform.translation;
+            continue;
+        }
         let ray_origin = projectile.previous_position;
         let ray_end = transform.translation;
         let ray_direction = ray_end - ray_origin;
@@ -51,23 +501,132 @@ pub fn handle_collisions(
             }
         };
 
-        let filter = SpatialQueryFilter::default().with_excluded_entities([entity]);
+        let filter = SpatialQueryFilter::default()
+            .with_mask(collision_mask_for(&config, mask).0)
+            .with_excluded_entities(std::iter::once(entity).chain(projectile.owner));
+        let team = shooter_team(&teams, entity, projectile.owner);
+        let grace = fragments.get(entity).ok();
 
-        if let Some(hit) = spatial_query.cast_ray(
+        let hit = spatial_query.cast_ray_predicate(
             ray_origin,
             direction,
             ray_length,
             false,
             &filter,
-        ) {
+            &|target| {
+                !is_friendly_fire(&config, &teams, team, target)
+                    && !pierce
+                        .as_ref()
+                        .is_some_and(|pierce| pierce.has_pierced(targets.body(target)))
+                    && !(config.sensor_pass_through && sensors.contains(target))
+                    && !grace.is_some_and(|grace| {
+                        grace.ignores(projectile.age, target, fragments.get(target).ok())
+                    })
+            },
+        );
+
+        if config.sensor_pass_through {
+            // Sensors entered in front of whatever stops the round (or along the whole
+            // sweep). A sweep starting inside a sensor hits it at distance zero: that
+            // sensor was reported when the round entered it.
+            let reach = hit.as_ref().map_or(ray_length, |hit| hit.distance);
+            let mut crossed = spatial_query.ray_hits(
+                ray_origin,
+                direction,
+                reach,
+                MAX_SENSOR_HITS,
+                true,
+                &filter,
+            );
+            crossed.retain(|crossed| {
+                crossed.distance > 0.0
+                    && sensors.contains(crossed.entity)
+                    && !is_friendly_fire(&config, &teams, team, crossed.entity)
+            });
+            crossed.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+            for crossed in crossed {
+                let point = ray_origin + *direction * crossed.distance;
+                report_sensor_hit(
+                    &mut messages.hits,
+                    entity,
+                    &projectile,
+                    crossed.entity,
+                    point,
+                    crossed.normal,
+                );
+            }
+        }
+
+        if let Some(hit) = hit {
             let hit_point = ray_origin + *direction * hit.distance;
-            let surface = surfaces.get(hit.entity).ok();
+            let normal = smoothed_hit_normal(
+                &spatial_query,
+                &filter,
+                &config,
+                ray_origin,
+                direction,
+                hit.distance,
+                hit.entity,
+                hit.normal,
+            );
+            let modifiers = targets.get(hit.entity);
+            let surface = modifiers.surface;
+
+            if bounce.is_some() {
+                debris::hand_off_to_physics(
+                    &mut commands,
+                    entity,
+                    &mut transform,
+                    &projectile,
+                    hit.entity,
+                    hit_point,
+                    normal,
+                    surface,
+                );
+                continue;
+            }
+
+            // Distance flown when the round reached the hit point, not the end of the step
+            let flown = projectile.distance_travelled - (ray_length - hit.distance);
+            if arming.is_some_and(|arming| !arming.is_armed(flown)) {
+                process_unarmed_hit(
+                    &mut messages.ricochets,
+                    entity,
+                    &mut transform,
+                    &mut projectile,
+                    hit.entity,
+                    hit_point,
+                    normal,
+                    surface,
+                );
+                projectile.previous_position = transform.translation;
+                continue;
+            }
+
+            if let Some(pierce) = pierce.as_deref_mut() {
+                process_pierce_hit(
+                    &mut commands,
+                    &mut messages.hits,
+                    &config,
+                    entity,
+                    &mut transform,
+                    &projectile,
+                    pierce,
+                    payload,
+                    hit.entity,
+                    targets.body(hit.entity),
+                    hit_point,
+                    normal,
+                    modifiers.resistances,
+                    on_hit,
+                );
+                projectile.previous_position = transform.translation;
+                continue;
+            }
 
             process_hit(
                 &mut commands,
-                &mut hit_events,
-                &mut ricochet_events,
-                &mut penetration_events,
+                &mut messages,
                 &config,
                 entity,
                 &mut transform,
@@ -75,8 +634,9 @@ pub fn handle_collisions(
                 payload,
                 hit.entity,
                 hit_point,
-                hit.normal,
-                surface,
+                normal,
+                modifiers,
+                on_hit,
             );
         }
 
@@ -86,18 +646,37 @@ pub fn handle_collisions(
 
 /// Handle collisions for 2D.
 #[cfg(feature = "dim2")]
+#[allow(clippy::type_complexity)]
 pub fn handle_collisions_2d(
     mut commands: Commands,
     config: Res<BallisticsConfig>,
     spatial_query: avian2d::prelude::SpatialQuery,
-    mut hit_events: MessageWriter<HitEvent>,
-    mut ricochet_events: MessageWriter<crate::events::RicochetEvent>,
-    mut penetration_events: MessageWriter<crate::events::PenetrationEvent>,
-    mut projectiles: Query<(Entity, &mut Transform, &mut Projectile, Option<&Payload>)>,
-    surfaces: Query<&SurfaceMaterial>,
+    mut messages: HitMessages,
+    mut projectiles: Query<(
+        Entity,
+        &mut Transform,
+        &mut Projectile,
+        Option<&Payload>,
+        Option<&ProjectileCollisionMask>,
+        Option<&crate::components::BounceOnContact>,
+        Option<&mut Pierce>,
+        Option<&crate::components::ArmingDistance>,
+        Option<&crate::components::OnHitCallback>,
+    )>,
+    targets: HitModifierQuery,
+    teams: Query<&Team>,
+    sensors: Query<(), With<avian2d::prelude::Sensor>>,
+    fragments: Query<&crate::components::FragmentGrace>,
 ) {
     use avian2d::prelude::*;
-    for (entity, mut transform, mut projectile, payload) in projectiles.iter_mut() {
+    for (entity, mut transform, mut projectile, payload, mask, bounce, mut pierce, arming, on_hit) in
+        projectiles.iter_mut()
+    {
+        if projectile.age == 0.0 {
+            // Not integrated yet: the spawn position is the true origin
+            projectile.previous_position = transform.translation;
+            continue;
+        }
         let ray_origin = projectile.previous_position.xy();
         let ray_end = transform.translation.xy();
         let ray_direction = ray_end - ray_origin;
@@ -116,27 +695,136 @@ pub fn handle_collisions_2d(
             }
         };
 
-        let filter = SpatialQueryFilter::default().with_excluded_entities([entity]);
+        let filter = SpatialQueryFilter::default()
+            .with_mask(collision_mask_for(&config, mask).0)
+            .with_excluded_entities(std::iter::once(entity).chain(projectile.owner));
+        let team = shooter_team(&teams, entity, projectile.owner);
+        let grace = fragments.get(entity).ok();
 
-        if let Some(hit) = spatial_query.cast_ray(
+        let hit = spatial_query.cast_ray_predicate(
             ray_origin,
             direction,
             ray_length,
             false,
             &filter,
-        ) {
+            &|target| {
+                !is_friendly_fire(&config, &teams, team, target)
+                    && !pierce
+                        .as_ref()
+                        .is_some_and(|pierce| pierce.has_pierced(targets.body(target)))
+                    && !(config.sensor_pass_through && sensors.contains(target))
+                    && !grace.is_some_and(|grace| {
+                        grace.ignores(projectile.age, target, fragments.get(target).ok())
+                    })
+            },
+        );
+
+        if config.sensor_pass_through {
+            // Sensors entered in front of whatever stops the round (or along the whole
+            // sweep). A sweep starting inside a sensor hits it at distance zero: that
+            // sensor was reported when the round entered it.
+            let reach = hit.as_ref().map_or(ray_length, |hit| hit.distance);
+            let mut crossed = spatial_query.ray_hits(
+                ray_origin,
+                direction,
+                reach,
+                MAX_SENSOR_HITS,
+                true,
+                &filter,
+            );
+            crossed.retain(|crossed| {
+                crossed.distance > 0.0
+                    && sensors.contains(crossed.entity)
+                    && !is_friendly_fire(&config, &teams, team, crossed.entity)
+            });
+            crossed.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+            for crossed in crossed {
+                let point = ray_origin + *direction * crossed.distance;
+                report_sensor_hit(
+                    &mut messages.hits,
+                    entity,
+                    &projectile,
+                    crossed.entity,
+                    Vec3::new(point.x, point.y, transform.translation.z),
+                    Vec3::new(crossed.normal.x, crossed.normal.y, 0.0),
+                );
+            }
+        }
+
+        if let Some(hit) = hit {
             let hit_point = ray_origin + *direction * hit.distance;
             // Convert 2D hit point and normal back to 3D for process_hit
             let hit_point_3d = Vec3::new(hit_point.x, hit_point.y, transform.translation.z);
-            let hit_normal_3d = Vec3::new(hit.normal.x, hit.normal.y, 0.0);
+            let normal = smoothed_hit_normal_2d(
+                &spatial_query,
+                &filter,
+                &config,
+                ray_origin,
+                direction,
+                hit.distance,
+                hit.entity,
+                hit.normal,
+            );
+            let hit_normal_3d = Vec3::new(normal.x, normal.y, 0.0);
             
-            let surface = surfaces.get(hit.entity).ok();
+            let modifiers = targets.get(hit.entity);
+            let surface = modifiers.surface;
+
+            if bounce.is_some() {
+                debris::hand_off_to_physics(
+                    &mut commands,
+                    entity,
+                    &mut transform,
+                    &projectile,
+                    hit.entity,
+                    hit_point_3d,
+                    hit_normal_3d,
+                    surface,
+                );
+                continue;
+            }
+
+            // Distance flown when the round reached the hit point, not the end of the step
+            let flown = projectile.distance_travelled - (ray_length - hit.distance);
+            if arming.is_some_and(|arming| !arming.is_armed(flown)) {
+                process_unarmed_hit(
+                    &mut messages.ricochets,
+                    entity,
+                    &mut transform,
+                    &mut projectile,
+                    hit.entity,
+                    hit_point_3d,
+                    hit_normal_3d,
+                    surface,
+                );
+                projectile.previous_position = transform.translation;
+                continue;
+            }
+
+            if let Some(pierce) = pierce.as_deref_mut() {
+                process_pierce_hit(
+                    &mut commands,
+                    &mut messages.hits,
+                    &config,
+                    entity,
+                    &mut transform,
+                    &projectile,
+                    pierce,
+                    payload,
+                    hit.entity,
+                    targets.body(hit.entity),
+                    hit_point_3d,
+                    hit_normal_3d,
+                    modifiers.resistances,
+                    on_hit,
+                );
+                projectile.previous_position = transform.translation;
+                continue;
+            }
 
             process_hit(
                 &mut commands,
-                &mut hit_events,
-                &mut ricochet_events,
-                &mut penetration_events,
+                &mut messages,
                 &config,
                 entity,
                 &mut transform,
@@ -145,7 +833,8 @@ pub fn handle_collisions_2d(
                 hit.entity,
                 hit_point_3d,
                 hit_normal_3d,
-                surface,
+                modifiers,
+                on_hit,
             );
         }
 
@@ -184,10 +873,21 @@ pub fn handle_collisions(
 /// 
 /// This function handles the logic when a projectile collides with a surface,
 /// determining if it penetrates, ricochets, or stops, and sending the appropriate event.
+/// Whether a round penetrates follows from its speed, which ricochets already
+/// bleed; `Projectile::penetration_power` is scaled with the energy kept through
+/// each ricochet or penetration so it stays in step for [`surface::can_penetrate`].
+/// A ricochet redirects the round in place, or continues it as a fresh entity
+/// under [`RicochetMode::Respawn`]. Penetration needs enough energy to cross the
+/// whole layer (see [`SurfaceMaterial::penetration_resistance`]); a round that
+/// runs out partway embeds in the material at the depth it reached. A glancing
+/// hit past `BallisticsConfig::max_ricochets` that would otherwise have bounced
+/// stops the round; when the round is despawned rather than pooled or dropped
+/// (see `SpentProjectileBehavior`) this also sends a `ProjectileDespawnedEvent`
+/// with [`DespawnReason::RicochetLimit`].
 /// 
 /// # Arguments
 /// * `commands` - Bevy Commands for entity manipulation
-/// * `hit_events` - Event writer for sending hit events
+/// * `messages` - Message writers for hit, ricochet, penetration and despawn events
 /// * `config` - Ballistics configuration resource
 /// * `projectile_entity` - Entity of the projectile that hit
 /// * `projectile` - Reference to the projectile component
@@ -195,13 +895,12 @@ pub fn handle_collisions(
 /// * `hit_entity` - Entity that was hit by the projectile
 /// * `hit_point` - World-space position where the impact occurred
 /// * `hit_normal` - Surface normal vector at the impact point
-/// * `surface` - Optional reference to the surface material component
-#[allow(dead_code)]
+/// * `modifiers` - Surface material, armor and resistances of the hit entity
+/// * `on_hit` - Optional callback of the projectile, run with the hit before the round is retired
+#[allow(clippy::too_many_arguments)]
 pub fn process_hit(
     commands: &mut Commands,
-    hit_events: &mut MessageWriter<HitEvent>,
-    ricochet_events: &mut MessageWriter<crate::events::RicochetEvent>,
-    penetration_events: &mut MessageWriter<crate::events::PenetrationEvent>,
+    messages: &mut HitMessages,
     config: &BallisticsConfig,
     projectile_entity: Entity,
     transform: &mut Transform,
@@ -210,16 +909,30 @@ pub fn process_hit(
     hit_entity: Entity,
     hit_point: Vec3,
     hit_normal: Vec3,
-    surface: Option<&SurfaceMaterial>,
+    modifiers: HitModifiers,
+    on_hit: Option<&OnHitCallback>,
 ) {
-    let damage = match payload {
-        Some(Payload::Kinetic { damage }) => *damage,
-        Some(Payload::Explosive { damage, .. }) => *damage,
-        _ => 25.0, // Default damage
-    };
+    let HitModifiers {
+        surface,
+        armor,
+        resistances,
+    } = modifiers;
+    let base_damage = hit_damage(payload, resistances);
+
+    // Every surface already passed through costs a share of the damage
+    let damage = base_damage
+        * config
+            .damage_retained_on_penetration
+            .powi(projectile.penetration_count as i32);
 
     let mut penetrated = false;
     let mut ricocheted = false;
+    let mut respawned_original = false;
+    let mut out_of_ricochets = false;
+    // Classified at the last kinematics step, before this hit slows the round
+    let impact_supersonic = projectile.is_supersonic;
+    // Incoming angle, the same one the ricochet check sees
+    let impact_angle = surface::impact_angle(projectile.velocity, hit_normal);
 
     // Early return if projectile is moving AWAY from the surface normal (exiting)
     // and penetration is already handled or not needed.
@@ -228,56 +941,114 @@ pub fn process_hit(
         return;
     }
 
-    if let Some(surface) = surface {
-        // Ricochet
-        if config.enable_ricochet && surface::should_ricochet(projectile.velocity, hit_normal, surface) {
-            let (new_dir, new_speed) = surface::calculate_ricochet(projectile.velocity, hit_normal, surface);
+    let ricochet_surface = surface.filter(|surface| {
+        config.enable_ricochet && surface::should_ricochet(projectile.velocity, hit_normal, surface)
+    });
+    // Armor governs penetration when present, otherwise the surface material does
+    let resistance = match (armor, surface) {
+        (Some(armor), _) => Some(armor.effective_rating(impact_angle)),
+        (None, Some(surface)) => Some(surface.penetration_resistance()),
+        (None, None) => None,
+    };
+
+    // Ricochet
+    if let Some(surface) = ricochet_surface {
+        let (new_dir, new_speed) = surface::calculate_ricochet(projectile.velocity, hit_normal, surface);
+        
+        // Rounds that are too slow or out of bounces are spent and stop here
+        let fast_enough = new_speed > config.min_projectile_speed;
+        out_of_ricochets = fast_enough && projectile.ricochet_count >= config.max_ricochets;
+        if fast_enough && !out_of_ricochets {
+            ricocheted = true;
+            projectile.ricochet_count += 1;
+            projectile.last_hit = Some(hit_entity);
+            projectile.penetration_power = surface::retained_penetration_power(
+                projectile.penetration_power,
+                projectile.velocity.length(),
+                new_speed,
+            );
+            projectile.velocity = new_dir * new_speed;
+            // Offset hit point slightly along normal to avoid getting stuck inside
+            transform.translation = hit_point + hit_normal * 0.05;
+
+            // The clone is taken when commands apply, after the caller's last write
+            // to this round, so it carries the redirected flight state
+            // The original is despawned once the hit callback (if any) has run
+            let respawned = (config.ricochet_mode == RicochetMode::Respawn).then(|| {
+                let clone = commands
+                    .entity(projectile_entity)
+                    .clone_and_spawn_with_opt_out(|builder| {
+                        builder.deny::<(NetworkId, NetProjectile)>();
+                    })
+                    .id();
+                commands.queue(respawn_identity(
+                    projectile_entity,
+                    clone,
+                    projectile.ricochet_count,
+                ));
+                clone
+            });
+            respawned_original = respawned.is_some();
+
+            // Fire Ricochet Event
+            messages.ricochets.write(RicochetEvent {
+                projectile: projectile_entity,
+                impact_point: hit_point,
+                new_direction: new_dir,
+                new_speed,
+                surface: hit_entity,
+                respawned,
+            });
+        }
+    } 
+    // Penetration
+    else if let Some(resistance) = resistance.filter(|_| config.enable_penetration) {
+        let speed = projectile.velocity.length();
+        let dynamic_power = 0.5 * projectile.mass * speed.powi(2) * 0.25;
+        
+        if dynamic_power > resistance {
+            let exit_vel = match (armor, surface) {
+                (None, Some(surface)) => surface::calculate_exit_velocity(
+                    projectile.velocity,
+                    dynamic_power,
+                    surface,
+                    surface.thickness,
+                ),
+                // Armor keeps the energy left over after defeating it
+                _ => projectile.velocity * ((dynamic_power - resistance) / dynamic_power).sqrt(),
+            };
             
-            if new_speed > config.min_projectile_speed {
-                ricocheted = true;
-                projectile.velocity = new_dir * new_speed;
-                // Offset hit point slightly along normal to avoid getting stuck inside
-                transform.translation = hit_point + hit_normal * 0.05;
-
-                // Fire Ricochet Event
-                ricochet_events.write(crate::events::RicochetEvent {
+            if exit_vel.length() > config.min_projectile_speed {
+                penetrated = true;
+                projectile.penetration_power = surface::retained_penetration_power(
+                    projectile.penetration_power,
+                    speed,
+                    exit_vel.length(),
+                );
+                projectile.velocity = exit_vel;
+                projectile.penetration_count += 1;
+                projectile.last_hit = Some(hit_entity);
+                // Offset transform for penetration to avoid re-hitting entry point
+                transform.translation = hit_point + projectile.velocity.normalize() * 0.05;
+
+                // Fire Penetration Event
+                messages.penetrations.write(PenetrationEvent {
                     projectile: projectile_entity,
-                    impact_point: hit_point,
-                    new_direction: new_dir,
-                    new_speed,
-                    surface: hit_entity,
+                    entry_point: hit_point,
+                    exit_point: transform.translation,
+                    target: hit_entity,
+                    remaining_power: dynamic_power - resistance,
                 });
             }
-        } 
-        // Penetration
-        else if config.enable_penetration {
-            let speed = projectile.velocity.length();
-            let dynamic_power = 0.5 * projectile.mass * speed.powi(2) * 0.25;
-            
-            if dynamic_power > surface.penetration_loss {
-                let exit_vel = surface::calculate_exit_velocity(projectile.velocity, surface, surface.thickness);
-                
-                if exit_vel.length() > config.min_projectile_speed {
-                    penetrated = true;
-                    projectile.velocity = exit_vel;
-                    // Offset transform for penetration to avoid re-hitting entry point
-                    transform.translation = hit_point + projectile.velocity.normalize() * 0.05;
-
-                    // Fire Penetration Event
-                    penetration_events.write(crate::events::PenetrationEvent {
-                        projectile: projectile_entity,
-                        entry_point: hit_point,
-                        exit_point: transform.translation,
-                        target: hit_entity,
-                        remaining_power: dynamic_power - surface.penetration_loss,
-                    });
-                }
-            }
+        } else if let (None, Some(surface)) = (armor, surface) {
+            // Ran out of energy inside the layer: embed at the depth reached
+            let depth = surface.thickness * (dynamic_power / resistance).min(1.0);
+            transform.translation = hit_point + projectile.velocity.normalize_or_zero() * depth;
         }
     }
 
     // Send hit event
-    hit_events.write(HitEvent {
+    let hit = HitEvent {
         projectile: projectile_entity,
         target: hit_entity,
         impact_point: hit_point,
@@ -286,48 +1057,1654 @@ pub fn process_hit(
         damage,
         penetrated,
         ricocheted,
-    });
+        was_supersonic: impact_supersonic,
+        impact_angle,
+        age: projectile.age,
+        sensor: false,
+    };
+    if let Some(on_hit) = on_hit {
+        on_hit.call(&hit, commands);
+    }
+    messages.hits.write(hit);
 
-    // Despawn projectile if it didn't penetrate or ricochet
-    if !penetrated && !ricocheted {
+    if respawned_original {
         commands.entity(projectile_entity).despawn();
     }
+    // Retire projectile if it didn't penetrate or ricochet
+    if !penetrated && !ricocheted {
+        // Pooled or dropped rounds live on as spent entities, so only a despawn is reported
+        if out_of_ricochets
+            && config.spent_projectile_behavior == SpentProjectileBehavior::Despawn
+        {
+            messages.despawns.write(ProjectileDespawnedEvent {
+                projectile: projectile_entity,
+                position: hit_point,
+                reason: DespawnReason::RicochetLimit,
+                age: projectile.age,
+            });
+        }
+        debris::retire_projectile(
+            commands,
+            config,
+            projectile_entity,
+            transform,
+            projectile,
+            hit_point,
+            hit_normal,
+        );
+    }
 }
 
-/// Calculate damage with distance falloff.
+/// Gives the clone of a `RicochetMode::Respawn` ricochet its own network identity.
+///
+/// The `NetworkId` and `NetProjectile` seed are derived from the original's through
+/// [`ProjectileRng::respawn_seed`], so the server and every client name the new leg
+/// alike. Rounds without them (single player) get none.
+fn respawn_identity(original: Entity, clone: Entity, ricochet: u8) -> impl FnOnce(&mut World) {
+    move |world: &mut World| {
+        let id = world.get::<NetworkId>(original).copied();
+        let net = world.get::<NetProjectile>(original).cloned();
+        let Ok(mut clone) = world.get_entity_mut(clone) else {
+            return;
+        };
+        if let Some(NetworkId(id)) = id {
+            clone.insert(NetworkId(ProjectileRng::new(id).respawn_seed(ricochet)));
+        }
+        if let Some(net) = net {
+            clone.insert(NetProjectile {
+                spread_seed: ProjectileRng::from(&net).respawn_seed(ricochet),
+                ..net
+            });
+        }
+    }
+}
+
+/// Damage a round's payload deals on a direct hit.
+fn payload_damage(payload: Option<&Payload>) -> f32 {
+    match payload {
+        Some(Payload::Kinetic { damage }) => *damage,
+        Some(Payload::Explosive { damage, .. }) => *damage,
+        _ => 25.0, // Default damage
+    }
+}
+
+/// Payload damage after the target's resistance to that payload type.
+fn hit_damage(payload: Option<&Payload>, resistances: Option<&Resistances>) -> f32 {
+    payload_damage(payload) * resistances.map_or(1.0, |r| r.factor_for_payload(payload))
+}
+
+/// Process a hit by a [`Pierce`] round.
 /// 
-/// Applies a linear falloff to damage based on distance from the origin.
-/// Damage remains constant up to falloff_start, then decreases linearly
-/// until it reaches 50% of the original damage at falloff_end.
+/// Stands in for [`process_hit`]: material penetration and ricochet are not
+/// consulted. While pierces remain, the round keeps its velocity, is moved just
+/// past the hit point and the target is recorded so it is never hit again.
+/// Once they are used up the round stops like any other spent round. Every hit
+/// deals the payload's full damage. Hits on an entity already pierced are
+/// ignored.
 /// 
 /// # Arguments
-/// * `base_damage` - The original damage value before falloff
-/// * `distance` - The distance from the origin to the target
-/// * `falloff_start` - Distance at which damage falloff begins
-/// * `falloff_end` - Distance at which damage reaches minimum (50% of base)
-/// 
-/// # Returns
-/// The damage value after applying distance falloff
-#[allow(dead_code)]
-fn calculate_damage_falloff(base_damage: f32, distance: f32, falloff_start: f32, falloff_end: f32) -> f32 {
-    if distance <= falloff_start {
-        base_damage
-    } else if distance >= falloff_end {
-        base_damage * 0.5 // Minimum 50% damage
-    } else {
-        let t = (distance - falloff_start) / (falloff_end - falloff_start);
-        base_damage * (1.0 - t * 0.5)
+/// * `commands` - Bevy Commands for entity manipulation
+/// * `hit_events` - Event writer for sending hit events
+/// * `config` - Ballistics configuration resource
+/// * `projectile_entity` - Entity of the projectile that hit
+/// * `transform` - Projectile transform, moved past the hit point when piercing
+/// * `projectile` - Projectile component
+/// * `pierce` - The round's pierce budget
+/// * `payload` - Optional reference to the payload component
+/// * `hit_entity` - Entity that was hit by the projectile
+/// * `body` - Body the hit collider belongs to, see [`HitModifierQuery::body`]; each body is pierced once
+/// * `hit_point` - World-space position where the impact occurred
+/// * `hit_normal` - Surface normal vector at the impact point
+/// * `resistances` - Optional damage resistances of the hit entity, applied to the damage
+/// * `on_hit` - Optional callback of the projectile, run with the hit before the round is retired
+#[allow(clippy::too_many_arguments)]
+pub fn process_pierce_hit(
+    commands: &mut Commands,
+    hit_events: &mut MessageWriter<HitEvent>,
+    config: &BallisticsConfig,
+    projectile_entity: Entity,
+    transform: &mut Transform,
+    projectile: &Projectile,
+    pierce: &mut Pierce,
+    payload: Option<&Payload>,
+    hit_entity: Entity,
+    body: Entity,
+    hit_point: Vec3,
+    hit_normal: Vec3,
+    resistances: Option<&Resistances>,
+    on_hit: Option<&OnHitCallback>,
+) {
+    if pierce.has_pierced(body) {
+        return;
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_damage_falloff() {
-        // No falloff at close range
-        assert_eq!(calculate_damage_falloff(100.0, 10.0, 50.0, 100.0), 100.0);
+    let penetrated = pierce.remaining > 0;
+    if penetrated {
+        pierce.remaining -= 1;
+        pierce.pierced.push(body);
+        // Just past the entry point, so the next target along the path is found next step
+        transform.translation = hit_point + projectile.velocity.normalize_or_zero() * 0.05;
+    }
+
+    let hit = HitEvent {
+        projectile: projectile_entity,
+        target: hit_entity,
+        impact_point: hit_point,
+        normal: hit_normal,
+        velocity: projectile.velocity,
+        damage: hit_damage(payload, resistances),
+        penetrated,
+        ricocheted: false,
+        was_supersonic: projectile.is_supersonic,
+        impact_angle: surface::impact_angle(projectile.velocity, hit_normal),
+        age: projectile.age,
+        sensor: false,
+    };
+    if let Some(on_hit) = on_hit {
+        on_hit.call(&hit, commands);
+    }
+    hit_events.write(hit);
+
+    if !penetrated {
+        debris::retire_projectile(
+            commands,
+            config,
+            projectile_entity,
+            transform,
+            projectile,
+            hit_point,
+            hit_normal,
+        );
+    }
+}
+
+/// Restitution of a dud glancing off a surface without a `SurfaceMaterial`.
+const DUD_RESTITUTION: f32 = 0.3;
+
+/// Process a hit by a round that is not yet armed
+/// (see [`ArmingDistance`](crate::components::ArmingDistance)).
+/// 
+/// Stands in for [`process_hit`]: the round is a dud for this contact. It is
+/// reflected off the surface, keeping the struck material's restitution share
+/// of its speed, and moved just clear of it. No `HitEvent` is sent, so nothing
+/// detonates or sticks; a `RicochetEvent` reports the glance. Hits by a round
+/// already moving away from the surface are ignored.
+/// 
+/// # Arguments
+/// * `ricochet_events` - Event writer for ricochet events
+/// * `projectile_entity` - Entity of the projectile that hit
+/// * `transform` - Projectile transform, moved off the surface
+/// * `projectile` - Projectile component, redirected
+/// * `hit_entity` - Entity that was hit by the projectile
+/// * `hit_point` - World-space position where the impact occurred
+/// * `hit_normal` - Surface normal vector at the impact point
+/// * `surface` - Optional reference to the surface material component
+#[allow(clippy::too_many_arguments)]
+pub fn process_unarmed_hit(
+    ricochet_events: &mut MessageWriter<RicochetEvent>,
+    projectile_entity: Entity,
+    transform: &mut Transform,
+    projectile: &mut Projectile,
+    hit_entity: Entity,
+    hit_point: Vec3,
+    hit_normal: Vec3,
+    surface: Option<&SurfaceMaterial>,
+) {
+    if projectile.velocity.dot(hit_normal) > 0.001 {
+        return;
+    }
+
+    let restitution = surface.map_or(DUD_RESTITUTION, SurfaceMaterial::restitution);
+    let reflected = projectile.velocity.reflect(hit_normal) * restitution;
+    projectile.velocity = reflected;
+    projectile.last_hit = Some(hit_entity);
+    transform.translation = hit_point + hit_normal * 0.05;
+
+    ricochet_events.write(RicochetEvent {
+        projectile: projectile_entity,
+        impact_point: hit_point,
+        new_direction: reflected.normalize_or_zero(),
+        new_speed: reflected.length(),
+        surface: hit_entity,
+        respawned: None,
+    });
+}
+
+/// Maximum sensors a single sweep reports.
+pub const MAX_SENSOR_HITS: u32 = 8;
+
+/// Report a round crossing a sensor collider without stopping it.
+/// 
+/// Sends a zero-damage `HitEvent` with `sensor` and `penetrated` set, carrying
+/// the point and normal where the round entered the sensor. The projectile is untouched.
+/// 
+/// # Arguments
+/// * `hit_events` - Event writer for sending hit events
+/// * `projectile_entity` - Entity of the projectile
+/// * `projectile` - Projectile component
+/// * `sensor` - Sensor entity that was crossed
+/// * `hit_point` - World-space point where the round entered the sensor
+/// * `hit_normal` - Sensor surface normal at that point
+pub fn report_sensor_hit(
+    hit_events: &mut MessageWriter<HitEvent>,
+    projectile_entity: Entity,
+    projectile: &Projectile,
+    sensor: Entity,
+    hit_point: Vec3,
+    hit_normal: Vec3,
+) {
+    hit_events.write(HitEvent {
+        projectile: projectile_entity,
+        target: sensor,
+        impact_point: hit_point,
+        normal: hit_normal,
+        velocity: projectile.velocity,
+        damage: 0.0,
+        penetrated: true,
+        ricocheted: false,
+        was_supersonic: projectile.is_supersonic,
+        impact_angle: surface::impact_angle(projectile.velocity, hit_normal),
+        age: projectile.age,
+        sensor: true,
+    });
+}
+
+/// Calculate damage with distance falloff.
+/// 
+/// Applies a linear falloff to damage based on distance from the origin.
+/// Damage remains constant up to falloff_start, then decreases linearly
+/// until it reaches 50% of the original damage at falloff_end.
+/// 
+/// # Arguments
+/// * `base_damage` - The original damage value before falloff
+/// * `distance` - The distance from the origin to the target
+/// * `falloff_start` - Distance at which damage falloff begins
+/// * `falloff_end` - Distance at which damage reaches minimum (50% of base)
+/// 
+/// # Returns
+/// The damage value after applying distance falloff
+#[allow(dead_code)]
+fn calculate_damage_falloff(base_damage: f32, distance: f32, falloff_start: f32, falloff_end: f32) -> f32 {
+    if distance <= falloff_start {
+        base_damage
+    } else if distance >= falloff_end {
+        base_damage * 0.5 // Minimum 50% damage
+    } else {
+        let t = (distance - falloff_start) / (falloff_end - falloff_start);
+        base_damage * (1.0 - t * 0.5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::{RunSystemOnce, SystemState};
+    use crate::components::HitEffectType;
+    use crate::events::{PenetrationEvent, RicochetEvent};
+
+    /// World with the message queues [`process_hit`] writes to.
+    fn hit_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Messages<HitEvent>>();
+        world.init_resource::<Messages<RicochetEvent>>();
+        world.init_resource::<Messages<PenetrationEvent>>();
+        world.init_resource::<Messages<ProjectileDespawnedEvent>>();
+        world
+    }
+
+    /// Hit events sent so far in a [`hit_world`].
+    fn sent_hits(world: &World) -> Vec<HitEvent> {
+        world
+            .resource::<Messages<HitEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect()
+    }
+
+    /// One [`process_hit`] call against `target`; every optional input is off until set.
+    struct Strike<'a> {
+        target: Entity,
+        point: Vec3,
+        normal: Vec3,
+        payload: Option<&'a Payload>,
+        modifiers: HitModifiers<'a>,
+        on_hit: Option<&'a OnHitCallback>,
+    }
+
+    impl<'a> Strike<'a> {
+        fn new(target: Entity, point: Vec3, normal: Vec3) -> Self {
+            Self {
+                target,
+                point,
+                normal,
+                payload: None,
+                modifiers: HitModifiers::default(),
+                on_hit: None,
+            }
+        }
+
+        fn payload(mut self, payload: &'a Payload) -> Self {
+            self.payload = Some(payload);
+            self
+        }
+
+        fn surface(mut self, surface: &'a SurfaceMaterial) -> Self {
+            self.modifiers = self.modifiers.with_surface(surface);
+            self
+        }
+
+        fn armor(mut self, armor: &'a Armor) -> Self {
+            self.modifiers = self.modifiers.with_armor(armor);
+            self
+        }
+
+        fn on_hit(mut self, on_hit: &'a OnHitCallback) -> Self {
+            self.on_hit = Some(on_hit);
+            self
+        }
+
+        /// Hit a round held outside the world, then apply the queued commands.
+        fn apply(
+            self,
+            world: &mut World,
+            config: &BallisticsConfig,
+            bullet: Entity,
+            transform: &mut Transform,
+            projectile: &mut Projectile,
+        ) {
+            let mut state = SystemState::<(Commands, HitMessages)>::new(world);
+            let (mut commands, mut messages) = state.get_mut(world);
+            process_hit(
+                &mut commands,
+                &mut messages,
+                config,
+                bullet,
+                transform,
+                projectile,
+                self.payload,
+                self.target,
+                self.point,
+                self.normal,
+                self.modifiers,
+                self.on_hit,
+            );
+            state.apply(world);
+        }
+
+        /// Hit the `Transform` and `Projectile` of `bullet` in place, as
+        /// `handle_collisions` does, then apply the queued commands.
+        /// Does nothing once the round is gone.
+        fn apply_in_place(self, world: &mut World, config: &BallisticsConfig, bullet: Entity) {
+            let mut state = SystemState::<(
+                Commands,
+                HitMessages,
+                Query<(&mut Transform, &mut Projectile)>,
+            )>::new(world);
+            let (mut commands, mut messages, mut rounds) = state.get_mut(world);
+            if let Ok((mut transform, mut projectile)) = rounds.get_mut(bullet) {
+                process_hit(
+                    &mut commands,
+                    &mut messages,
+                    config,
+                    bullet,
+                    &mut transform,
+                    &mut projectile,
+                    self.payload,
+                    self.target,
+                    self.point,
+                    self.normal,
+                    self.modifiers,
+                    self.on_hit,
+                );
+            }
+            state.apply(world);
+        }
+    }
+
+    /// App with avian physics stepping a fixed 1/60 s per update.
+    #[cfg(feature = "dim3")]
+    fn physics_app() -> App {
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            bevy::asset::AssetPlugin::default(),
+            bevy::mesh::MeshPlugin,
+            bevy::scene::ScenePlugin,
+            avian3d::prelude::PhysicsPlugins::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        app
+    }
+
+    /// 2D counterpart of [`physics_app`], with avian2d.
+    #[cfg(feature = "dim2")]
+    fn physics_app_2d() -> App {
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            bevy::asset::AssetPlugin::default(),
+            bevy::mesh::MeshPlugin,
+            bevy::scene::ScenePlugin,
+            avian2d::prelude::PhysicsPlugins::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+        app
+    }
+
+    /// [`physics_app`] running the ballistics core plugin.
+    #[cfg(feature = "dim3")]
+    fn ballistics_app() -> App {
+        let mut app = physics_app();
+        app.add_plugins(crate::BallisticsCorePlugin);
+        app
+    }
+
+    #[test]
+    fn test_resolve_hit_surface_material() {
+        let mut world = World::new();
+        let wall = world
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::Dust,
+                ..Default::default()
+            })
+            .id();
+        let body = world
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::Blood,
+                ..Default::default()
+            })
+            .id();
+        let hitbox = world.spawn_empty().id();
+        let untagged = world.spawn_empty().id();
+
+        let (wall_hit, hitbox_hit, untagged_hit) = world
+            .run_system_once(move |surfaces: Query<&SurfaceMaterial>| {
+                (
+                    resolve_hit(wall, None, Vec3::ZERO, Vec3::Z, 5.0, &surfaces),
+                    resolve_hit(hitbox, Some(body), Vec3::ZERO, Vec3::Z, 5.0, &surfaces),
+                    resolve_hit(untagged, None, Vec3::ZERO, Vec3::Z, 5.0, &surfaces),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(wall_hit.entity, wall);
+        assert_eq!(wall_hit.collider_entity, wall);
+        assert!(wall_hit.surface.unwrap().hit_effect == HitEffectType::Dust);
+
+        // Child hitbox inherits the body's material but reports itself as the leaf
+        assert_eq!(hitbox_hit.entity, body);
+        assert_eq!(hitbox_hit.collider_entity, hitbox);
+        assert!(hitbox_hit.surface.unwrap().hit_effect == HitEffectType::Blood);
+
+        assert!(untagged_hit.surface.is_none());
+    }
+
+    #[test]
+    fn test_surface_material_found_on_ancestor() {
+        let mut world = World::new();
+        let root = world
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::WoodChips,
+                ..Default::default()
+            })
+            .id();
+        let mount = world.spawn(ChildOf(root)).id();
+        let collider = world.spawn(ChildOf(mount)).id();
+        let glass = world
+            .spawn((
+                ChildOf(mount),
+                SurfaceMaterial {
+                    hit_effect: HitEffectType::Glass,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let orphan = world.spawn_empty().id();
+        let untagged_child = world.spawn(ChildOf(orphan)).id();
+
+        let effects = world
+            .run_system_once(move |surfaces: Query<&SurfaceMaterial>, parents: Query<&ChildOf>| {
+                [collider, glass, untagged_child].map(|entity| {
+                    find_surface_material(entity, &surfaces, &parents).map(|s| s.hit_effect)
+                })
+            })
+            .unwrap();
+
+        // Collider child resolves the root's material two levels up
+        assert!(effects[0] == Some(HitEffectType::WoodChips));
+        // The nearest material wins
+        assert!(effects[1] == Some(HitEffectType::Glass));
+        assert!(effects[2].is_none());
+    }
+
+    #[test]
+    fn test_hit_modifiers_found_on_ancestors() {
+        let mut world = World::new();
+        // Resistances and armor tagged once on the root of a compound body
+        let root = world
+            .spawn((
+                Resistances {
+                    kinetic: 0.5,
+                    ..Default::default()
+                },
+                Armor::new(300.0),
+            ))
+            .id();
+        let limb = world.spawn(ChildOf(root)).id();
+        let hitbox = world.spawn(ChildOf(limb)).id();
+        // A plate child carries its own armor, which wins over the root's
+        let plate = world.spawn((ChildOf(root), Armor::new(900.0))).id();
+        let loose = world.spawn_empty().id();
+
+        let found = world
+            .run_system_once(move |targets: HitModifierQuery| {
+                [hitbox, plate, loose].map(|entity| {
+                    let modifiers = targets.get(entity);
+                    (
+                        modifiers.resistances.map(|r| r.kinetic),
+                        modifiers.armor.map(|armor| armor.rating),
+                    )
+                })
+            })
+            .unwrap();
+
+        assert_eq!(found[0], (Some(0.5), Some(300.0)));
+        assert_eq!(found[1], (Some(0.5), Some(900.0)));
+        assert_eq!(found[2], (None, None));
+    }
+
+    #[test]
+    fn test_resistances_scale_damage_by_payload_type() {
+        use crate::components::FalloffCurve;
+        use crate::events::ExplosionType;
+        use crate::systems::logic::calculate_explosion_damage;
+
+        let mut world = hit_world();
+        let target = world.spawn_empty().id();
+
+        // Plated: shrugs off bullets, not blasts
+        let plated = Resistances {
+            kinetic: 0.2,
+            ..Default::default()
+        };
+        let kinetic = Payload::Kinetic { damage: 40.0 };
+        let explosive = Payload::Explosive {
+            damage: 100.0,
+            radius: 5.0,
+            falloff: FalloffCurve::Linear,
+        };
+
+        let config = BallisticsConfig::default();
+        for (payload, resistances) in [
+            (&kinetic, Some(&plated)),
+            (&explosive, Some(&plated)),
+            (&kinetic, None),
+        ] {
+            let bullet = world.spawn_empty().id();
+            Strike {
+                modifiers: HitModifiers {
+                    resistances,
+                    ..Default::default()
+                },
+                ..Strike::new(target, Vec3::ZERO, Vec3::Z).payload(payload)
+            }
+            .apply(
+                &mut world,
+                &config,
+                bullet,
+                &mut Transform::default(),
+                &mut Projectile::new(Vec3::new(0.0, 0.0, -400.0)),
+            );
+        }
+
+        let damage: Vec<f32> = sent_hits(&world).iter().map(|hit| hit.damage).collect();
+        assert_eq!(damage, vec![40.0 * 0.2, 100.0, 40.0]);
+
+        // Blast damage goes through the explosive multiplier, untouched here
+        let blast = calculate_explosion_damage(100.0, 2.0, 5.0, FalloffCurve::Linear);
+        assert_eq!(blast * plated.factor_for_explosion(ExplosionType::HighExplosive), blast);
+        assert_eq!(plated.factor_for_payload(None), 0.2);
+        assert_eq!(Resistances::default().factor_for_explosion(ExplosionType::Incendiary), 1.0);
+    }
+
+    #[test]
+    fn test_penetration_chain_damage_retention() {
+        let mut world = hit_world();
+
+        let config = BallisticsConfig {
+            enable_ricochet: false,
+            damage_retained_on_penetration: 0.5,
+            ..Default::default()
+        };
+        let plate = SurfaceMaterial {
+            penetration_loss: 20.0,
+            ..Default::default()
+        };
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let bullet = world.spawn_empty().id();
+
+        let mut projectile = Projectile::new(Vec3::new(0.0, 0.0, -800.0));
+        let mut transform = Transform::default();
+        let payload = Payload::Kinetic { damage: 40.0 };
+        for (target, z) in [(first, -10.0), (second, -12.0)] {
+            Strike::new(target, Vec3::new(0.0, 0.0, z), Vec3::Z)
+                .payload(&payload)
+                .surface(&plate)
+                .apply(&mut world, &config, bullet, &mut transform, &mut projectile);
+        }
+
+        let hits = sent_hits(&world);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].target, first);
+        assert_eq!(hits[0].damage, 40.0);
+        assert_eq!(hits[1].target, second);
+        assert_eq!(hits[1].damage, 40.0 * 0.5);
+
+        // Energy and damage loss advance together, one step per surface passed
+        assert!(hits[0].penetrated && hits[1].penetrated);
+        assert_eq!(projectile.penetration_count, 2);
+        let resistance = plate.penetration_resistance();
+        let through = |speed: f32| {
+            let power = 0.5 * projectile.mass * speed.powi(2) * 0.25;
+            speed * ((power - resistance) / power).sqrt()
+        };
+        assert!((projectile.velocity.length() - through(through(800.0))).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_thick_wall_stops_round_thin_wall_lets_through() {
+        let config = BallisticsConfig {
+            enable_ricochet: false,
+            ..Default::default()
+        };
+        // 10 g at 400 m/s: the same concrete, 2 cm and 2 m deep
+        let wall = |thickness: f32| SurfaceMaterial {
+            penetration_loss: 50.0,
+            thickness,
+            ..Default::default()
+        };
+        let shoot = |surface: SurfaceMaterial| {
+            let mut world = hit_world();
+            let target = world.spawn_empty().id();
+            let bullet = world.spawn_empty().id();
+            let mut projectile = Projectile::new(Vec3::new(0.0, 0.0, -400.0));
+            let mut transform = Transform::default();
+            Strike::new(target, Vec3::new(0.0, 0.0, -5.0), Vec3::Z)
+                .surface(&surface)
+                .apply(&mut world, &config, bullet, &mut transform, &mut projectile);
+            let hit = sent_hits(&world).remove(0);
+            (hit, projectile, transform, world.get_entity(bullet).is_ok())
+        };
+
+        let (thin_hit, thin, _, thin_alive) = shoot(wall(0.02));
+        assert!(thin_hit.penetrated);
+        assert_eq!(thin.penetration_count, 1);
+        assert!(thin_alive);
+
+        let (thick_hit, thick, thick_transform, thick_alive) = shoot(wall(2.0));
+        assert!(!thick_hit.penetrated);
+        assert_eq!(thick.penetration_count, 0);
+        assert!(!thick_alive);
+        // Embedded partway in, short of the far face
+        let depth = -5.0 - thick_transform.translation.z;
+        assert!(depth > 0.0 && depth < 2.0, "depth {depth}");
+
+        assert_eq!(wall(2.0).penetration_resistance(), 100.0 * wall(0.02).penetration_resistance());
+    }
+
+    #[test]
+    fn test_corner_bounce_spends_after_max_ricochets() {
+        let mut world = hit_world();
+
+        let config = BallisticsConfig {
+            max_ricochets: 3,
+            ..Default::default()
+        };
+        // Every hit glances and keeps full speed, so only the limit can stop it
+        let steel = SurfaceMaterial {
+            ricochet_angle: std::f32::consts::FRAC_PI_2,
+            penetration_loss: 0.0,
+            ..Default::default()
+        };
+        let left_wall = world.spawn_empty().id();
+        let right_wall = world.spawn_empty().id();
+        let bullet = world
+            .spawn((Transform::default(), Projectile::new(Vec3::new(300.0, 0.0, -300.0))))
+            .id();
+
+        // One hit per frame, alternating between the two walls of the corner
+        for frame in 0..5 {
+            let (wall, point, normal) = if frame % 2 == 0 {
+                (right_wall, Vec3::new(1.0, 0.0, 0.0), Vec3::NEG_X)
+            } else {
+                (left_wall, Vec3::new(-1.0, 0.0, 0.0), Vec3::X)
+            };
+            Strike::new(wall, point, normal)
+                .surface(&steel)
+                .apply_in_place(&mut world, &config, bullet);
+
+            if frame < 3 {
+                assert_eq!(world.get::<Projectile>(bullet).unwrap().ricochet_count, frame + 1);
+            }
+        }
+
+        let ricochets = world.resource::<Messages<RicochetEvent>>();
+        assert_eq!(ricochets.iter_current_update_messages().count(), 3);
+
+        // The fourth hit is terminal: reported as a plain stop and the round is gone
+        let hits = sent_hits(&world);
+        assert_eq!(hits.len(), 4);
+        assert!(hits[..3].iter().all(|hit| hit.ricocheted));
+        assert!(!hits[3].ricocheted && !hits[3].penetrated);
+        assert!(world.get_entity(bullet).is_err());
+
+        // ...and its removal is reported with the reason
+        let despawns: Vec<_> = world
+            .resource::<Messages<ProjectileDespawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(despawns.len(), 1);
+        assert_eq!(despawns[0].projectile, bullet);
+        assert_eq!(despawns[0].reason, DespawnReason::RicochetLimit);
+        assert_eq!(despawns[0].position, hits[3].impact_point);
+    }
+
+    #[test]
+    fn test_pierce_passes_three_targets_and_stops_at_fourth() {
+        let mut world = hit_world();
+        world.insert_resource(BallisticsConfig::default());
+        let targets: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+        let velocity = Vec3::NEG_Z * 900.0;
+        let round = world
+            .spawn((
+                Transform::default(),
+                Projectile::new(velocity),
+                Payload::Kinetic { damage: 40.0 },
+                Pierce::new(3),
+            ))
+            .id();
+
+        // The first target is reported twice along the path; only the first counts
+        let hits = [targets[0], targets[0], targets[1], targets[2], targets[3]];
+        for (i, &target) in hits.iter().enumerate() {
+            world
+                .run_system_once(
+                    move |mut commands: Commands,
+                          mut hit_events: MessageWriter<HitEvent>,
+                          config: Res<BallisticsConfig>,
+                          mut rounds: Query<(&mut Transform, &Projectile, &mut Pierce, &Payload)>| {
+                        let (mut transform, projectile, mut pierce, payload) =
+                            rounds.get_mut(round).unwrap();
+                        let hit_point = Vec3::new(0.0, 0.0, -(i as f32) * 2.0);
+                        process_pierce_hit(
+                            &mut commands,
+                            &mut hit_events,
+                            &config,
+                            round,
+                            &mut transform,
+                            projectile,
+                            &mut pierce,
+                            Some(payload),
+                            target,
+                            target,
+                            hit_point,
+                            Vec3::Z,
+                            None,
+                            None,
+                        );
+                    },
+                )
+                .unwrap();
+        }
+
+        let events = sent_hits(&world);
+        let hit_targets: Vec<Entity> = events.iter().map(|event| event.target).collect();
+        assert_eq!(hit_targets, targets);
+        assert!(events[..3].iter().all(|event| event.penetrated && event.velocity == velocity));
+        assert!(!events[3].penetrated);
+        assert!(events.iter().all(|event| event.damage == 40.0));
+        // Stopped in the fourth target
+        assert!(world.get_entity(round).is_err());
+    }
+
+    #[test]
+    fn test_pierce_counts_compound_body_once() {
+        let mut world = hit_world();
+        world.insert_resource(BallisticsConfig::default());
+        let body = world.spawn_empty().id();
+        let torso = world.spawn(ChildOf(body)).id();
+        let arm = world.spawn(ChildOf(body)).id();
+        let round = world
+            .spawn((
+                Transform::default(),
+                Projectile::new(Vec3::NEG_Z * 900.0),
+                Payload::Kinetic { damage: 40.0 },
+                Pierce::new(2),
+            ))
+            .id();
+
+        for target in [torso, arm] {
+            world
+                .run_system_once(
+                    move |mut commands: Commands,
+                          mut hit_events: MessageWriter<HitEvent>,
+                          config: Res<BallisticsConfig>,
+                          targets: HitModifierQuery,
+                          mut rounds: Query<(&mut Transform, &Projectile, &mut Pierce, &Payload)>| {
+                        let (mut transform, projectile, mut pierce, payload) =
+                            rounds.get_mut(round).unwrap();
+                        process_pierce_hit(
+                            &mut commands,
+                            &mut hit_events,
+                            &config,
+                            round,
+                            &mut transform,
+                            projectile,
+                            &mut pierce,
+                            Some(payload),
+                            target,
+                            targets.body(target),
+                            Vec3::ZERO,
+                            Vec3::Z,
+                            None,
+                            None,
+                        );
+                    },
+                )
+                .unwrap();
+        }
+
+        // The arm belongs to the body already pierced through the torso
+        let events = sent_hits(&world);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].target, torso);
+        let pierce = world.get::<Pierce>(round).unwrap();
+        assert_eq!(pierce.remaining, 1);
+        assert_eq!(pierce.pierced, vec![body]);
+    }
+
+    #[test]
+    fn test_unarmed_rocket_glances_off_point_blank_wall() {
+        use crate::components::{ArmingDistance, FalloffCurve};
+
+        let mut world = hit_world();
+        let config = BallisticsConfig::default();
+        let wall = world.spawn_empty().id();
+        let payload = Payload::Explosive {
+            damage: 200.0,
+            radius: 6.0,
+            falloff: FalloffCurve::Power(1.0),
+        };
+        let arming = ArmingDistance(3.0);
+        let rocket = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 120.0)))
+            .id();
+
+        // Dispatches like handle_collisions: duds glance off, armed rounds hit
+        let strike = |world: &mut World, flown: f32| {
+            let transform = world.get::<Transform>(rocket).unwrap();
+            let normal = -world.get::<Projectile>(rocket).unwrap().velocity.normalize();
+            let point = transform.translation - normal * 0.3;
+            if arming.is_armed(flown) {
+                Strike::new(wall, point, normal)
+                    .payload(&payload)
+                    .apply_in_place(world, &config, rocket);
+                return;
+            }
+            world
+                .run_system_once(
+                    move |mut ricochets: MessageWriter<RicochetEvent>,
+                          mut rounds: Query<(&mut Transform, &mut Projectile)>| {
+                        let (mut transform, mut projectile) = rounds.get_mut(rocket).unwrap();
+                        process_unarmed_hit(
+                            &mut ricochets,
+                            rocket,
+                            &mut transform,
+                            &mut projectile,
+                            wall,
+                            point,
+                            normal,
+                            None,
+                        );
+                    },
+                )
+                .unwrap();
+        };
+
+        // 30 cm out of the tube: no hit, the rocket bounces back slower
+        strike(&mut world, 0.3);
+        assert_eq!(world.resource::<Messages<HitEvent>>().len(), 0);
+        assert_eq!(world.resource::<Messages<RicochetEvent>>().len(), 1);
+        let projectile = world.get::<Projectile>(rocket).unwrap();
+        assert!(projectile.velocity.z > 0.0);
+        assert!((projectile.velocity.length() - 120.0 * DUD_RESTITUTION).abs() < 1e-3);
+
+        // Once armed, the next contact is a real hit
+        strike(&mut world, 5.0);
+        let hits = sent_hits(&world);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].damage, 200.0);
+        assert!(world.get_entity(rocket).is_err());
+
+        // No component, or zero distance, means armed at the muzzle
+        assert!(ArmingDistance::default().is_armed(0.0));
+    }
+
+    #[test]
+    fn test_ricochet_modes_redirect_or_respawn() {
+        let steel = SurfaceMaterial {
+            ricochet_angle: std::f32::consts::FRAC_PI_2,
+            penetration_loss: 0.0,
+            ..Default::default()
+        };
+        let ricochet = |mode: RicochetMode| {
+            let mut world = hit_world();
+            let config = BallisticsConfig {
+                ricochet_mode: mode,
+                ..Default::default()
+            };
+            let wall = world.spawn_empty().id();
+            let mut projectile = Projectile::new(Vec3::new(300.0, 0.0, -300.0));
+            projectile.distance_travelled = 42.0;
+            projectile.penetration_power = 300.0;
+            let bullet = world
+                .spawn((
+                    Transform::default(),
+                    projectile,
+                    Payload::Kinetic { damage: 30.0 },
+                    NetworkId(7),
+                    NetProjectile {
+                        owner_id: 1,
+                        timestamp: 2.5,
+                        spread_seed: 99,
+                    },
+                ))
+                .id();
+
+            Strike::new(wall, Vec3::X, Vec3::NEG_X)
+                .surface(&steel)
+                .apply_in_place(&mut world, &config, bullet);
+
+            let events: Vec<RicochetEvent> = world
+                .resource::<Messages<RicochetEvent>>()
+                .iter_current_update_messages()
+                .cloned()
+                .collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].projectile, bullet);
+            (world, bullet, events[0].clone())
+        };
+
+        // Redirect (default): the same entity carries on
+        assert_eq!(BallisticsConfig::default().ricochet_mode, RicochetMode::Redirect);
+        let (world, bullet, event) = ricochet(RicochetMode::Redirect);
+        assert!(event.respawned.is_none());
+        let redirected = world.get::<Projectile>(bullet).unwrap().clone();
+        assert!(redirected.velocity.x < 0.0);
+        assert_eq!(world.get::<NetworkId>(bullet), Some(&NetworkId(7)));
+
+        // Respawn: the original is gone and a fresh entity continues the same flight
+        let (mut world, bullet, event) = ricochet(RicochetMode::Respawn);
+        assert!(world.get_entity(bullet).is_err());
+        let respawned = event.respawned.unwrap();
+        let projectile = world.get::<Projectile>(respawned).unwrap();
+        assert_eq!(projectile.velocity, redirected.velocity);
+        assert_eq!(projectile.ricochet_count, 1);
+        assert_eq!(projectile.distance_travelled, 42.0);
+        // Bled by the ricochet, like the redirected round
+        assert_eq!(projectile.penetration_power, redirected.penetration_power);
+        assert!(projectile.penetration_power < 300.0);
+        assert_eq!(
+            world.get::<Transform>(respawned).unwrap().translation,
+            Vec3::X + Vec3::NEG_X * 0.05
+        );
+        assert!(matches!(
+            world.get::<Payload>(respawned),
+            Some(Payload::Kinetic { damage }) if *damage == 30.0
+        ));
+
+        // Under a new identity every peer derives alike
+        let id = *world.get::<NetworkId>(respawned).unwrap();
+        assert_eq!(id, NetworkId(ProjectileRng::new(7).respawn_seed(1)));
+        assert_ne!(id, NetworkId(7));
+        let net = world.get::<NetProjectile>(respawned).unwrap();
+        assert_eq!((net.owner_id, net.timestamp), (1, 2.5));
+        assert_ne!(net.spread_seed, 99);
+
+        // Picked up by the server's snapshot query like any other replicated round
+        let replicated = world
+            .run_system_once(|rounds: Query<(&Transform, &Projectile, &NetworkId)>| {
+                rounds.iter().map(|(_, _, id)| *id).collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(replicated, vec![id]);
+    }
+
+    #[test]
+    fn test_ricochets_drain_penetration() {
+        // 5 cm of concrete a fresh 400 m/s round goes straight through
+        let wall = SurfaceMaterial {
+            penetration_loss: 50.0,
+            thickness: 0.05,
+            ..Default::default()
+        };
+        let steel = SurfaceMaterial {
+            penetration_loss: 100.0,
+            ricochet_angle: 0.6,
+            ..Default::default()
+        };
+        let shoot = |velocity: Vec3, bounces: &[Vec3]| {
+            let mut world = hit_world();
+            let config = BallisticsConfig::default();
+            let target = world.spawn_empty().id();
+            let bullet = world.spawn_empty().id();
+            let mut projectile = Projectile::new(velocity);
+            let mut transform = Transform::default();
+            let mut powers = vec![projectile.penetration_power];
+            let hits_in_order = bounces
+                .iter()
+                .map(|normal| (*normal, &steel))
+                .chain(std::iter::once((Vec3::Z, &wall)));
+            for (normal, surface) in hits_in_order {
+                let point = transform.translation;
+                Strike::new(target, point, normal)
+                    .surface(surface)
+                    .apply(&mut world, &config, bullet, &mut transform, &mut projectile);
+                // Power always matches the energy actually left
+                let energy = projectile.velocity.length_squared() / velocity.length_squared();
+                assert!((projectile.penetration_power - 100.0 * energy).abs() < 1e-2);
+                powers.push(projectile.penetration_power);
+            }
+            (projectile, powers)
+        };
+
+        let (direct, _) = shoot(Vec3::new(0.0, 0.0, -400.0), &[]);
+        assert_eq!(direct.penetration_count, 1);
+
+        // Off the floor and the ceiling, then into the same wall
+        let (bounced, powers) = shoot(Vec3::new(0.0, -200.0, -346.4), &[Vec3::Y, Vec3::NEG_Y]);
+        assert_eq!(bounced.ricochet_count, 2);
+        assert_eq!(bounced.penetration_count, 0);
+        // Each ricochet bleeds power; the stopped round keeps what it had left
+        assert!(powers[..3].windows(2).all(|pair| pair[1] < pair[0]), "{powers:?}");
+        assert_eq!(powers[3], powers[2]);
+        let angle = surface::impact_angle(bounced.velocity, Vec3::Z);
+        assert!(!surface::can_penetrate(&bounced, &wall, angle));
+        assert!(surface::can_penetrate(
+            &Projectile::new(Vec3::new(0.0, 0.0, -400.0)),
+            &wall,
+            0.0
+        ));
+    }
+
+    #[test]
+    fn test_slow_ricochet_stops_below_limit() {
+        let mut world = hit_world();
+
+        let config = BallisticsConfig {
+            max_ricochets: 10,
+            min_projectile_speed: 50.0,
+            ..Default::default()
+        };
+        let steel = SurfaceMaterial {
+            ricochet_angle: std::f32::consts::FRAC_PI_2,
+            penetration_loss: 0.0,
+            ..Default::default()
+        };
+        let wall = world.spawn_empty().id();
+        let bullet = world.spawn_empty().id();
+
+        let mut projectile = Projectile::new(Vec3::new(30.0, 0.0, -30.0));
+        Strike::new(wall, Vec3::X, Vec3::NEG_X).surface(&steel).apply(
+            &mut world,
+            &config,
+            bullet,
+            &mut Transform::default(),
+            &mut projectile,
+        );
+
+        assert_eq!(projectile.ricochet_count, 0);
+        assert!(world.get_entity(bullet).is_err());
+    }
+
+    #[test]
+    fn test_ricochet_limit_reported_only_when_it_despawns_the_round() {
+        use crate::components::SpentProjectile;
+        use crate::resources::SpentProjectileBehavior;
+
+        let steel = SurfaceMaterial {
+            ricochet_angle: std::f32::consts::FRAC_PI_2,
+            penetration_loss: 0.0,
+            ..Default::default()
+        };
+        let strike = |config: BallisticsConfig, speed: f32| {
+            let mut world = hit_world();
+            let wall = world.spawn_empty().id();
+            let bullet = world.spawn_empty().id();
+            let mut projectile = Projectile::new(Vec3::new(speed, 0.0, -speed));
+            Strike::new(wall, Vec3::X, Vec3::NEG_X).surface(&steel).apply(
+                &mut world,
+                &config,
+                bullet,
+                &mut Transform::default(),
+                &mut projectile,
+            );
+            let reasons: Vec<DespawnReason> = world
+                .resource::<Messages<ProjectileDespawnedEvent>>()
+                .iter_current_update_messages()
+                .map(|despawn| despawn.reason)
+                .collect();
+            (world, bullet, reasons)
+        };
+        let at_limit = BallisticsConfig {
+            max_ricochets: 0,
+            min_projectile_speed: 50.0,
+            ..Default::default()
+        };
+
+        // Out of bounces and fast enough to have bounced: the limit stopped it
+        let (_, _, reasons) = strike(at_limit.clone(), 300.0);
+        assert_eq!(reasons, vec![DespawnReason::RicochetLimit]);
+
+        // Too slow to bounce anyway: not the limit's doing
+        let (_, _, reasons) = strike(at_limit.clone(), 30.0);
+        assert!(reasons.is_empty());
+
+        // Pooled instead of despawned: nothing to report
+        let pooled = BallisticsConfig {
+            spent_projectile_behavior: SpentProjectileBehavior::Pool,
+            ..at_limit
+        };
+        let (world, bullet, reasons) = strike(pooled, 300.0);
+        assert!(reasons.is_empty());
+        assert!(world.get::<SpentProjectile>(bullet).is_some());
+    }
+
+    #[test]
+    fn test_on_hit_callback_runs_before_round_is_retired() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        #[derive(Component)]
+        struct Portal(Vec3);
+        #[derive(Component)]
+        struct Struck;
+
+        let mut world = hit_world();
+
+        let fired = Arc::new(AtomicBool::new(false));
+        let flag = fired.clone();
+        let wall = world.spawn_empty().id();
+        let callback = OnHitCallback::new(move |hit, commands| {
+            flag.store(true, Ordering::SeqCst);
+            commands.spawn(Portal(hit.impact_point));
+            // Would fail if the round had already been despawned
+            commands.entity(hit.projectile).insert(Struck);
+        });
+        let bullet = world.spawn(callback.clone()).id();
+
+        Strike::new(wall, Vec3::new(0.0, 1.0, -10.0), Vec3::Z)
+            .on_hit(&callback)
+            .apply(
+                &mut world,
+                &BallisticsConfig::default(),
+                bullet,
+                &mut Transform::default(),
+                &mut Projectile::new(Vec3::NEG_Z * 400.0),
+            );
+
+        assert!(fired.load(Ordering::SeqCst));
+        let portals: Vec<Vec3> = world.query::<&Portal>().iter(&world).map(|p| p.0).collect();
+        assert_eq!(portals, vec![Vec3::new(0.0, 1.0, -10.0)]);
+        // The round stopped and was retired after the callback ran
+        assert!(world.get_entity(bullet).is_err());
+        assert_eq!(world.resource::<Messages<HitEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn test_friendly_fire_filter() {
+        let mut world = World::new();
+        let shooter = world.spawn(Team(1)).id();
+        let teammate = world.spawn(Team(1)).id();
+        let enemy = world.spawn(Team(2)).id();
+        let crate_box = world.spawn_empty().id();
+        let round = world.spawn_empty().id();
+        let tagged_round = world.spawn(Team(1)).id();
+        let gone = world.spawn(Team(1)).id();
+        world.despawn(gone);
+
+        let off = BallisticsConfig {
+            friendly_fire: false,
+            ..Default::default()
+        };
+        let (blocked, live_owner, dead_owner, dead_owner_tagged) = world
+            .run_system_once(move |teams: Query<&Team>| {
+                let team = shooter_team(&teams, round, Some(shooter));
+                let blocked = [teammate, enemy, crate_box]
+                    .map(|target| is_friendly_fire(&off, &teams, team, target));
+                (
+                    blocked,
+                    team,
+                    shooter_team(&teams, round, Some(gone)),
+                    shooter_team(&teams, tagged_round, Some(gone)),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(blocked, [true, false, false]);
+        assert_eq!(live_owner, Some(Team(1)));
+        // A despawned owner has no team, but a round tagged at fire time keeps it
+        assert_eq!(dead_owner, None);
+        assert_eq!(dead_owner_tagged, Some(Team(1)));
+    }
+
+    #[test]
+    fn test_sloped_armor_defeats_round() {
+        let mut world = hit_world();
+        let config = BallisticsConfig::default();
+
+        let round = Projectile::new(Vec3::NEG_Z * 800.0).with_mass(0.01);
+        let power = 0.5 * round.mass * 800.0f32.powi(2) * 0.25;
+        // Defeated head-on, but 60° doubles the line-of-sight thickness
+        let rating = power * 0.6;
+        let angled = Vec3::new(3f32.sqrt(), 0.0, -1.0).normalize() * 800.0;
+        let cases = [
+            (Vec3::NEG_Z * 800.0, Armor::new(rating), true),
+            (angled, Armor::new(rating), false),
+            (Vec3::NEG_Z * 800.0, Armor::new(rating).with_slope(std::f32::consts::FRAC_PI_3), false),
+        ];
+
+        for (velocity, plate, expect_penetration) in cases {
+            let plate_entity = world.spawn(plate).id();
+            let entity = world
+                .spawn((Transform::default(), Projectile { velocity, ..round.clone() }))
+                .id();
+            Strike::new(plate_entity, Vec3::ZERO, Vec3::Z)
+                .armor(&plate)
+                .apply_in_place(&mut world, &config, entity);
+            let penetrated = world
+                .get::<Projectile>(entity)
+                .is_some_and(|projectile| projectile.penetration_count > 0);
+            assert_eq!(penetrated, expect_penetration, "{velocity} vs {plate:?}");
+        }
+        assert!((Armor::new(1.0).effective_rating(std::f32::consts::FRAC_PI_3) - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_hit_event_reports_impact_angle() {
+        let mut world = hit_world();
+        let config = BallisticsConfig::default();
+
+        // Head-on, 60° from the normal, and grazing
+        let velocities = [
+            Vec3::NEG_Z * 400.0,
+            Vec3::new(3f32.sqrt(), 0.0, -1.0) * 200.0,
+            Vec3::new(1.0, 0.0, -0.001) * 400.0,
+        ];
+        let wall = world.spawn_empty().id();
+        for velocity in velocities {
+            let round = world
+                .spawn((Transform::default(), Projectile::new(velocity)))
+                .id();
+            Strike::new(wall, Vec3::ZERO, Vec3::Z).apply_in_place(&mut world, &config, round);
+        }
+
+        let angles: Vec<f32> = sent_hits(&world).iter().map(|hit| hit.impact_angle).collect();
+        assert_eq!(angles.len(), 3);
+        assert!(angles[0].abs() < 1e-4);
+        assert!((angles[1] - std::f32::consts::FRAC_PI_3).abs() < 1e-4);
+        assert!(std::f32::consts::FRAC_PI_2 - angles[2] < 0.01);
+        for (angle, velocity) in angles.iter().zip(velocities) {
+            assert_eq!(*angle, surface::impact_angle(velocity, Vec3::Z));
+        }
+    }
+
+    #[test]
+    fn test_impact_supersonic_after_deceleration() {
+        use crate::events::FireEvent;
+        use crate::resources::BallisticsEnvironment;
+        use crate::systems::kinematics::update_projectiles_kinematics;
+        use std::time::Duration;
+
+        let env = BallisticsEnvironment::default();
+        // Both leave the muzzle faster than sound (~340 m/s at 15°C)
+        assert!(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0).with_environment(&env).is_supersonic);
+        assert!(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 900.0).with_environment(&env).is_supersonic);
+
+        // Kinematics iterates in parallel
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut world = hit_world();
+        world.insert_resource(env);
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(Time::<Fixed>::default());
+
+        let slow = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 400.0)))
+            .id();
+        let fast = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 900.0)))
+            .id();
+
+        // Half a second of flight: the 400 m/s round drops below the barrier
+        for _ in 0..30 {
+            world
+                .resource_mut::<Time<Fixed>>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            world.run_system_once(update_projectiles_kinematics).unwrap();
+        }
+
+        let wall = world.spawn_empty().id();
+        let config = BallisticsConfig::default();
+        for round in [slow, fast] {
+            let point = world.get::<Transform>(round).unwrap().translation;
+            Strike::new(wall, point, Vec3::Z).apply_in_place(&mut world, &config, round);
+        }
+
+        let hits: Vec<_> = sent_hits(&world)
+            .iter()
+            .map(|hit| (hit.projectile, hit.velocity.length(), hit.was_supersonic))
+            .collect();
+        assert_eq!(hits.len(), 2);
+        let (_, slow_speed, slow_crack) = hits[0];
+        let (_, fast_speed, fast_crack) = hits[1];
+        assert!(slow_speed < 340.0 && !slow_crack);
+        assert!(fast_speed > 340.0 && fast_crack);
+    }
+
+    #[test]
+    fn test_spent_round_dropped_as_debris() {
+        use crate::components::SpentProjectile;
+        use crate::resources::SpentProjectileBehavior;
+
+        let mut world = hit_world();
+
+        let config = BallisticsConfig {
+            spent_projectile_behavior: SpentProjectileBehavior::Drop,
+            enable_ricochet: false,
+            enable_penetration: false,
+            ..Default::default()
+        };
+        let wall = world.spawn_empty().id();
+        // Swept a little past the wall face, as the end of a step leaves it
+        let velocity = Vec3::new(30.0, 0.0, -400.0);
+        let bullet = world
+            .spawn((Transform::from_xyz(0.0, 0.0, -5.5), Projectile::new(velocity)))
+            .id();
+
+        Strike::new(wall, Vec3::new(0.0, 0.0, -5.0), Vec3::Z).apply_in_place(&mut world, &config, bullet);
+
+        // The entity survives as debris, out of reach of projectile queries
+        assert!(world.get_entity(bullet).is_ok());
+        assert!(world.get::<Projectile>(bullet).is_none());
+        assert!(world.get::<SpentProjectile>(bullet).is_some());
+
+        // Resting on the wall face, not inside it
+        let radius = Projectile::new(velocity).diameter * 0.5;
+        let position = world.get::<Transform>(bullet).unwrap().translation;
+        assert!(position.abs_diff_eq(Vec3::new(0.0, 0.0, -5.0 + radius), 1e-6));
+
+        // Bounces weakly off the wall and keeps a little of its slide
+        let dropped = debris::spent_round_velocity(velocity, Vec3::Z);
+        assert!(dropped.z > 0.0);
+        assert!(dropped.length() < velocity.length() * 0.25);
+        assert!(dropped.x > 0.0 && dropped.x < velocity.x);
+        #[cfg(feature = "dim3")]
+        assert_eq!(world.get::<avian3d::prelude::LinearVelocity>(bullet).unwrap().0, dropped);
+    }
+
+    #[test]
+    fn test_smooth_normal_leaves_flat_surfaces_alone() {
+        // Every sample on a flat wall agrees with the raw normal
+        let flat = smooth_normal(Vec3::Z, [Vec3::Z; 4], 1.0);
+        assert!(flat.abs_diff_eq(Vec3::Z, 1e-6));
+
+        // Two facets of a curve: the blend factor moves the normal toward their average
+        let raw = Vec3::new(0.3, 0.0, 1.0).normalize();
+        let other = Vec3::new(-0.3, 0.0, 1.0).normalize();
+        let off = smooth_normal(raw, [other], 0.0);
+        let half = smooth_normal(raw, [other], 0.5);
+        let full = smooth_normal(raw, [other], 1.0);
+        assert!(off.abs_diff_eq(raw, 1e-6));
+        assert!(full.abs_diff_eq(Vec3::Z, 1e-6));
+        assert!(half.x > 0.0 && half.x < raw.x);
+        assert!((half.length() - 1.0).abs() < 1e-6);
+
+        // Samples from the far side of a thin object are ignored
+        assert!(smooth_normal(raw, [-Vec3::Z], 1.0).abs_diff_eq(raw, 1e-6));
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_normal_smoothing_on_faceted_sphere() {
+        use avian3d::prelude::*;
+        use std::f32::consts::{PI, TAU};
+
+        let mut app = physics_app();
+
+        // A low-poly "rounded pillar": the hull of a coarse UV sphere
+        let mut points = vec![Vec3::Y, Vec3::NEG_Y];
+        for ring in 1..8 {
+            let lat = PI * ring as f32 / 8.0 - PI / 2.0;
+            for segment in 0..12 {
+                let lon = TAU * segment as f32 / 12.0;
+                points.push(Vec3::new(lat.cos() * lon.cos(), lat.sin(), lat.cos() * lon.sin()));
+            }
+        }
+        let sphere = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::convex_hull(points).expect("sphere hull"),
+                Transform::default(),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let (raw_error, smoothed_error) = app
+            .world_mut()
+            .run_system_once(move |spatial_query: SpatialQuery| {
+                let filter = SpatialQueryFilter::default();
+                let off = BallisticsConfig::default();
+                let on = BallisticsConfig {
+                    normal_smoothing: 1.0,
+                    normal_smoothing_radius: 0.15,
+                    ..Default::default()
+                };
+                let velocity = Vec3::NEG_Z * 300.0;
+                let (mut raw_error, mut smoothed_error, mut shots) = (0.0, 0.0, 0.0);
+
+                // Parallel shots sweeping across the sphere's face
+                for i in -6..=6 {
+                    for j in -6..=6 {
+                        let origin = Vec3::new(i as f32 * 0.1, j as f32 * 0.1, 5.0);
+                        let Some(hit) =
+                            spatial_query.cast_ray(origin, Dir3::NEG_Z, 10.0, false, &filter)
+                        else {
+                            continue;
+                        };
+                        assert_eq!(hit.entity, sphere);
+                        let ideal = velocity.reflect((origin + Vec3::NEG_Z * hit.distance).normalize());
+                        let smoothed = smoothed_hit_normal(
+                            &spatial_query,
+                            &filter,
+                            &on,
+                            origin,
+                            Dir3::NEG_Z,
+                            hit.distance,
+                            hit.entity,
+                            hit.normal,
+                        );
+                        let unsmoothed = smoothed_hit_normal(
+                            &spatial_query,
+                            &filter,
+                            &off,
+                            origin,
+                            Dir3::NEG_Z,
+                            hit.distance,
+                            hit.entity,
+                            hit.normal,
+                        );
+                        assert_eq!(unsmoothed, hit.normal);
+                        raw_error += velocity.reflect(unsmoothed).angle_between(ideal).powi(2);
+                        smoothed_error += velocity.reflect(smoothed).angle_between(ideal).powi(2);
+                        shots += 1.0;
+                    }
+                }
+                assert!(shots > 50.0, "only {shots} shots hit the sphere");
+                (raw_error / shots, smoothed_error / shots)
+            })
+            .unwrap();
+
+        // Ricochets scatter less around the ideal round-sphere bounce with smoothing on
+        assert!(
+            smoothed_error < raw_error * 0.5,
+            "smoothed variance {smoothed_error} vs raw {raw_error}"
+        );
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_trace_shot_hits_wall() {
+        use avian3d::prelude::*;
+
+        let mut app = physics_app();
+
+        let shooter = app
+            .world_mut()
+            .spawn((RigidBody::Static, Collider::sphere(0.5), Transform::default()))
+            .id();
+        let wall = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+                SurfaceMaterial {
+                    hit_effect: crate::components::HitEffectType::Dust,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let (hit, blocked) = app
+            .world_mut()
+            .run_system_once(
+                move |spatial_query: SpatialQuery,
+                      surfaces: Query<&SurfaceMaterial>,
+                      colliders: Query<&ColliderOf>| {
+                    let trace = |exclude| {
+                        trace_shot(
+                            &spatial_query,
+                            Vec3::ZERO,
+                            Vec3::NEG_Z,
+                            100.0,
+                            exclude,
+                            &surfaces,
+                            &colliders,
+                        )
+                    };
+                    (trace(Some(shooter)), trace(None))
+                },
+            )
+            .unwrap();
+
+        // Shooter is skipped, the wall's near face is 19.5m away
+        let hit = hit.expect("shot should hit the wall");
+        assert_eq!(hit.collider_entity, wall);
+        assert!((hit.distance - 19.5).abs() < 0.01);
+        assert!(hit.surface.is_some());
+
+        // Without the exclusion the shot starts inside the shooter
+        assert_eq!(blocked.unwrap().entity, shooter);
+    }
+
+    #[cfg(feature = "dim2")]
+    #[test]
+    fn test_trace_shot_2d_hits_wall() {
+        use avian2d::prelude::*;
+
+        let mut app = physics_app_2d();
+
+        let shooter = app
+            .world_mut()
+            .spawn((RigidBody::Static, Collider::circle(0.5), Transform::default()))
+            .id();
+        let wall = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::rectangle(1.0, 10.0),
+                Transform::from_xyz(20.0, 0.0, 0.0),
+                SurfaceMaterial {
+                    hit_effect: crate::components::HitEffectType::Dust,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let (hit, blocked) = app
+            .world_mut()
+            .run_system_once(
+                move |spatial_query: SpatialQuery,
+                      surfaces: Query<&SurfaceMaterial>,
+                      colliders: Query<&ColliderOf>| {
+                    let trace = |exclude| {
+                        trace_shot_2d(
+                            &spatial_query,
+                            Vec2::ZERO,
+                            Vec2::X,
+                            100.0,
+                            exclude,
+                            &surfaces,
+                            &colliders,
+                        )
+                    };
+                    (trace(Some(shooter)), trace(None))
+                },
+            )
+            .unwrap();
+
+        // Shooter is skipped, the wall's near face is 19.5m away
+        let hit = hit.expect("shot should hit the wall");
+        assert_eq!(hit.collider_entity, wall);
+        assert!((hit.distance - 19.5).abs() < 0.01);
+        assert!((hit.point - Vec3::new(19.5, 0.0, 0.0)).length() < 0.01);
+        assert!((hit.normal - Vec3::NEG_X).length() < 1e-3);
+        assert!(hit.surface.is_some());
+
+        // Without the exclusion the shot starts inside the shooter
+        assert_eq!(blocked.unwrap().entity, shooter);
+    }
+
+    #[test]
+    fn test_damage_falloff() {
+        // No falloff at close range
+        assert_eq!(calculate_damage_falloff(100.0, 10.0, 50.0, 100.0), 100.0);
 
         // Full falloff at max range
         assert_eq!(calculate_damage_falloff(100.0, 100.0, 50.0, 100.0), 50.0);
@@ -336,4 +2713,574 @@ mod tests {
         let mid_damage = calculate_damage_falloff(100.0, 75.0, 50.0, 100.0);
         assert!(mid_damage > 50.0 && mid_damage < 100.0);
     }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_first_step_hits_wall_at_muzzle() {
+        use avian3d::prelude::*;
+
+        let run = |schedule: CollisionSchedule| {
+            let mut app = ballistics_app();
+            let wall = app
+                .world_mut()
+                .spawn((
+                    RigidBody::Static,
+                    Collider::cuboid(2.0, 2.0, 0.1),
+                    Transform::from_xyz(10.0, 0.0, -0.3),
+                ))
+                .id();
+            // Sits on the line from the world origin to the muzzle
+            let decoy = app
+                .world_mut()
+                .spawn((
+                    RigidBody::Static,
+                    Collider::sphere(0.5),
+                    Transform::from_xyz(5.0, 0.0, 0.0),
+                ))
+                .id();
+            for _ in 0..5 {
+                app.update();
+            }
+            app.world_mut().resource_mut::<BallisticsConfig>().collision_schedule = schedule;
+
+            // Left at the default previous_position (the world origin)
+            app.world_mut().spawn((
+                Transform::from_xyz(10.0, 0.0, 0.0),
+                Projectile::new(Vec3::NEG_Z * 1200.0),
+            ));
+            let mut hits = Vec::new();
+            for _ in 0..5 {
+                app.update();
+                hits.extend(
+                    app.world()
+                        .resource::<Messages<HitEvent>>()
+                        .iter_current_update_messages()
+                        .map(|hit| hit.target),
+                );
+            }
+            (hits, wall, decoy)
+        };
+
+        for schedule in [CollisionSchedule::FixedUpdate, CollisionSchedule::Update] {
+            let (hits, wall, decoy) = run(schedule);
+            assert_eq!(hits.first(), Some(&wall), "{schedule:?}");
+            assert!(!hits.contains(&decoy), "{schedule:?}");
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_collision_schedule_selects_update() {
+        use avian3d::prelude::*;
+        use std::time::Duration;
+
+        let run = |schedule: CollisionSchedule| {
+            let mut app = ballistics_app();
+            app.world_mut().spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+            ));
+            for _ in 0..5 {
+                app.update();
+            }
+
+            // Stop fixed steps so only a per-frame check can see the crossing
+            app.world_mut()
+                .resource_mut::<Time<Fixed>>()
+                .set_timestep(Duration::from_secs(3600));
+            app.world_mut().resource_mut::<BallisticsConfig>().collision_schedule = schedule;
+            // Already flown through the wall since the last check
+            let mut projectile = Projectile::new(Vec3::new(0.0, 0.0, -500.0))
+                .with_previous_position(Vec3::new(0.0, 0.0, -15.0));
+            projectile.age = 0.1;
+            app.world_mut().spawn((Transform::from_xyz(0.0, 0.0, -25.0), projectile));
+            app.update();
+
+            app.world().resource::<Messages<HitEvent>>().len()
+        };
+
+        // Default: nothing is checked outside fixed steps
+        assert_eq!(BallisticsConfig::default().collision_schedule, CollisionSchedule::FixedUpdate);
+        assert_eq!(run(CollisionSchedule::FixedUpdate), 0);
+        assert_eq!(run(CollisionSchedule::Update), 1);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_update_ricochet_survives_interpolation() {
+        use crate::components::InterpolatedTransform;
+        use avian3d::prelude::*;
+
+        let mut app = ballistics_app();
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(10.0, 10.0, 1.0),
+            Transform::from_xyz(0.0, 0.0, -20.0),
+            SurfaceMaterial {
+                ricochet_angle: std::f32::consts::FRAC_PI_2,
+                penetration_loss: 1.0e6,
+                ..Default::default()
+            },
+        ));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<BallisticsConfig>().collision_schedule =
+            CollisionSchedule::Update;
+        let round = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Projectile::new(Vec3::NEG_Z * 300.0),
+                InterpolatedTransform::default(),
+            ))
+            .id();
+
+        let mut bounced = false;
+        for _ in 0..30 {
+            app.update();
+            bounced |= app.world().resource::<Messages<RicochetEvent>>().len() > 0;
+            if bounced {
+                // Never put back behind the wall by the render blend
+                let current = app.world().get::<InterpolatedTransform>(round).unwrap().current;
+                let rendered = app.world().get::<Transform>(round).unwrap().translation;
+                assert!(current.z > -19.5 && rendered.z > -19.5, "{current} {rendered}");
+            }
+        }
+        assert!(bounced);
+        assert!(app.world().get::<Projectile>(round).unwrap().velocity.z > 0.0);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_bouncing_grenade_rests_on_floor_before_detonating() {
+        use crate::components::{BounceOnContact, HitEffectType, ProjectileLogic};
+        use crate::events::ExplosionEvent;
+        use avian3d::prelude::*;
+
+        let mut app = ballistics_app();
+        app.insert_resource(BallisticsConfig {
+            // Thrown grenades are far slower than bullets
+            min_projectile_speed: 0.0,
+            ..Default::default()
+        });
+
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(40.0, 1.0, 40.0),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            SurfaceMaterial {
+                hit_effect: HitEffectType::Dust,
+                ..Default::default()
+            },
+        ));
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let mut projectile = Projectile::new(Vec3::new(3.0, -2.0, 0.0))
+            .with_previous_position(Vec3::new(0.0, 1.5, 0.0));
+        projectile.diameter = 0.1;
+        let fuse = 4.0;
+        let grenade = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 1.5, 0.0),
+                projectile,
+                ProjectileLogic::Timed { fuse, elapsed: 0.0 },
+                Payload::Explosive {
+                    damage: 100.0,
+                    radius: 5.0,
+                    falloff: crate::components::FalloffCurve::Power(1.0),
+                },
+                BounceOnContact,
+            ))
+            .id();
+
+        // Lands within a second, then has time to settle
+        for _ in 0..(3.5 * 60.0) as usize {
+            app.update();
+        }
+        assert!(app.world().get::<Projectile>(grenade).is_none());
+        let resting = app.world().get::<Transform>(grenade).unwrap().translation;
+        assert!(resting.y.abs() < 0.2, "resting at {resting}");
+        assert!(resting.x > 0.5, "kept rolling forward, at {resting}");
+        assert!(app.world().get::<LinearVelocity>(grenade).unwrap().0.length() < 0.5);
+
+        // The contact didn't reset the fuse: it goes off about 4 s after the throw
+        let mut explosion = None;
+        for _ in 0..60 {
+            app.update();
+            explosion = explosion.or_else(|| {
+                app.world()
+                    .resource::<Messages<ExplosionEvent>>()
+                    .iter_current_update_messages()
+                    .next()
+                    .cloned()
+            });
+        }
+        let explosion = explosion.expect("grenade detonated");
+        assert!(app.world().get_entity(grenade).is_err());
+        assert!(explosion.center.distance(resting) < 0.1);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_collision_mask_passes_through_friendly_layer() {
+        use avian3d::prelude::*;
+        use std::time::Duration;
+
+        const WORLD: u32 = 1 << 0;
+        const FRIENDLY: u32 = 1 << 1;
+
+        let mut app = ballistics_app();
+
+        // Shooter, a teammate in the line of fire and a wall behind them
+        let shooter = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::sphere(0.5),
+                CollisionLayers::new(FRIENDLY, LayerMask::ALL),
+                Transform::default(),
+            ))
+            .id();
+        let teammate = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                CollisionLayers::new(FRIENDLY, LayerMask::ALL),
+                Transform::from_xyz(0.0, 0.0, -10.0),
+            ))
+            .id();
+        let wall = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                CollisionLayers::new(WORLD, LayerMask::ALL),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .set_timestep(Duration::from_secs(3600));
+        app.world_mut().resource_mut::<BallisticsConfig>().collision_schedule =
+            CollisionSchedule::Update;
+
+        // Both rounds start inside the shooter and have already crossed both targets
+        let mut spawn_round = |mask: Option<ProjectileCollisionMask>| {
+            let mut projectile = Projectile::new(Vec3::new(0.0, 0.0, -500.0))
+                .with_owner(shooter)
+                .with_previous_position(Vec3::ZERO);
+            projectile.age = 0.1;
+            let mut round = app
+                .world_mut()
+                .spawn((Transform::from_xyz(0.0, 0.0, -25.0), projectile));
+            if let Some(mask) = mask {
+                round.insert(mask);
+            }
+            round.id()
+        };
+        let masked = spawn_round(Some(ProjectileCollisionMask::ALL.without(FRIENDLY)));
+        let unmasked = spawn_round(None);
+        app.update();
+
+        let hits: Vec<_> = app
+            .world()
+            .resource::<Messages<HitEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        let target_of = |round| hits.iter().find(|hit| hit.projectile == round).map(|hit| hit.target);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(target_of(masked), Some(wall));
+        assert_eq!(target_of(unmasked), Some(teammate));
+        assert!(hits.iter().all(|hit| hit.target != shooter));
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_round_passes_through_sensor_and_hits_wall() {
+        use avian3d::prelude::*;
+        use std::time::Duration;
+
+        let mut app = ballistics_app();
+
+        // A trigger volume in the line of fire and a wall behind it
+        let sensor = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Sensor,
+                Transform::from_xyz(0.0, 0.0, -10.0),
+            ))
+            .id();
+        let wall = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        // Drive the round by hand: only the collision sweep runs
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .set_timestep(Duration::from_secs(3600));
+        app.world_mut().resource_mut::<BallisticsConfig>().collision_schedule =
+            CollisionSchedule::Update;
+
+        let mut projectile =
+            Projectile::new(Vec3::new(0.0, 0.0, -500.0)).with_previous_position(Vec3::ZERO);
+        projectile.age = 0.1;
+        let round = app
+            .world_mut()
+            .spawn((Transform::from_xyz(0.0, 0.0, -15.0), projectile))
+            .id();
+        let hits_this_frame = |app: &App| -> Vec<HitEvent> {
+            app.world()
+                .resource::<Messages<HitEvent>>()
+                .iter_current_update_messages()
+                .cloned()
+                .collect()
+        };
+
+        // Crossing the sensor: reported at its front face, round keeps flying
+        app.update();
+        let hits = hits_this_frame(&app);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].target, sensor);
+        assert!(hits[0].impact_point.distance(Vec3::new(0.0, 0.0, -9.5)) < 1e-3);
+        assert!(hits[0].normal.distance(Vec3::Z) < 1e-3);
+        assert!(hits[0].sensor && hits[0].penetrated);
+        assert_eq!(hits[0].damage, 0.0);
+        assert!(app.world().get::<Projectile>(round).is_some());
+        assert_eq!(app.world().get::<Transform>(round).unwrap().translation.z, -15.0);
+
+        // Next sweep starts past the sensor and stops in the wall
+        app.world_mut().get_mut::<Transform>(round).unwrap().translation.z = -25.0;
+        app.update();
+        let hits = hits_this_frame(&app);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].target, wall);
+        assert!(!hits[0].sensor);
+        assert!(hits[0].impact_point.distance(Vec3::new(0.0, 0.0, -19.5)) < 1e-3);
+        assert!(app.world().get::<Projectile>(round).is_none());
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_no_hit_on_teammate_without_friendly_fire() {
+        use avian3d::prelude::*;
+        use std::time::Duration;
+
+        let mut app = ballistics_app();
+
+        let shooter = app.world_mut().spawn((Team(1), Transform::default())).id();
+        let teammate = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Team(1),
+                Transform::from_xyz(0.0, 0.0, -10.0),
+            ))
+            .id();
+        let enemy = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Team(2),
+                // On a layer the round's mask still hits
+                CollisionLayers::new(1 << 0, LayerMask::ALL),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .set_timestep(Duration::from_secs(3600));
+        {
+            let mut config = app.world_mut().resource_mut::<BallisticsConfig>();
+            config.collision_schedule = CollisionSchedule::Update;
+            config.friendly_fire = false;
+        }
+
+        let mut projectile = Projectile::new(Vec3::new(0.0, 0.0, -500.0))
+            .with_owner(shooter)
+            .with_previous_position(Vec3::ZERO);
+        projectile.age = 0.1;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, -25.0),
+            projectile,
+            ProjectileCollisionMask::ALL.without(1 << 3),
+        ));
+        app.update();
+
+        // The round passes through the teammate and stops in the enemy
+        let hits: Vec<_> = app
+            .world()
+            .resource::<Messages<HitEvent>>()
+            .iter_current_update_messages()
+            .map(|hit| hit.target)
+            .collect();
+        assert_eq!(hits, vec![enemy]);
+        assert!(!hits.contains(&teammate));
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_fresh_shards_sweep_past_siblings_and_bounce_surface() {
+        use crate::components::FragmentGrace;
+        use avian3d::prelude::*;
+        use std::time::Duration;
+
+        let mut app = ballistics_app();
+
+        let burst = app.world_mut().spawn_empty().id();
+        let grace = FragmentGrace {
+            burst,
+            ignore: None,
+            window: 0.05,
+        };
+        // Surface the grenade bounced on, then a sibling shard's collider, then a target
+        let wall = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Transform::from_xyz(0.0, 0.0, -5.0),
+            ))
+            .id();
+        let sibling = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::sphere(1.0),
+                FragmentGrace {
+                    ignore: Some(wall),
+                    ..grace
+                },
+                Transform::from_xyz(0.0, 0.0, -10.0),
+            ))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(10.0, 10.0, 1.0),
+                Transform::from_xyz(0.0, 0.0, -20.0),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .set_timestep(Duration::from_secs(3600));
+        app.world_mut()
+            .resource_mut::<BallisticsConfig>()
+            .collision_schedule = CollisionSchedule::Update;
+
+        let shard = |age: f32, x: f32| {
+            let origin = Vec3::new(x, 0.0, 0.0);
+            let mut projectile = Projectile::new(Vec3::NEG_Z * 500.0).with_previous_position(origin);
+            projectile.age = age;
+            (
+                Transform::from_translation(origin + Vec3::NEG_Z * 25.0),
+                projectile,
+                FragmentGrace {
+                    ignore: Some(wall),
+                    ..grace
+                },
+            )
+        };
+        // Within the grace window, then past it
+        let fresh = app.world_mut().spawn(shard(0.02, 0.0)).id();
+        let stale = app.world_mut().spawn(shard(0.1, 0.1)).id();
+        app.update();
+
+        let hits: Vec<(Entity, Entity)> = app
+            .world()
+            .resource::<Messages<HitEvent>>()
+            .iter_current_update_messages()
+            .map(|hit| (hit.projectile, hit.target))
+            .collect();
+        assert!(hits.contains(&(fresh, target)));
+        assert!(hits.contains(&(stale, wall)));
+        assert!(!hits.iter().any(|(_, struck)| *struck == sibling));
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_fast_substepped_rounds_hit_thin_plate() {
+        use avian3d::prelude::*;
+
+        let mut app = ballistics_app();
+        app.insert_resource(Time::<Fixed>::from_hz(60.0))
+            .insert_resource(BallisticsConfig {
+                max_substep_distance: 1.0,
+                ..Default::default()
+            });
+
+        // 1 cm steel plate 50 m downrange
+        let plate = app
+            .world_mut()
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(40.0, 40.0, 0.01),
+                Transform::from_xyz(0.0, 0.0, -50.0),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+
+        // 2000 m/s covers ~33 m per step; launch points put the plate at every phase of it
+        let rounds: Vec<Entity> = (0..16)
+            .map(|i| {
+                let origin = Vec3::new(i as f32 * 0.5 - 4.0, 0.0, i as f32 * -2.1);
+                app.world_mut()
+                    .spawn((
+                        Transform::from_translation(origin),
+                        Projectile::new(Vec3::NEG_Z * 2000.0)
+                            .with_diameter(0.00762)
+                            .with_previous_position(origin),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let mut struck = Vec::new();
+        for _ in 0..6 {
+            app.update();
+            struck.extend(
+                app.world()
+                    .resource::<Messages<HitEvent>>()
+                    .iter_current_update_messages()
+                    .filter(|hit| hit.target == plate)
+                    .map(|hit| hit.projectile),
+            );
+        }
+        for round in rounds {
+            assert!(struck.contains(&round), "round {round} tunneled through the plate");
+        }
+    }
 }
@@ -0,0 +1,428 @@
+//! Spent projectile system - despawns, drops or pools rounds that stop on impact.
+
+use bevy::prelude::*;
+
+use crate::components::{
+    AmmoType, ArmingDistance, Authoritative, BounceOnContact, BounceSurface, FragmentGrace,
+    Fragmentation, Guidance, NetProjectile, NetworkId, OnHitCallback, Payload, Pierce, Predicted,
+    Projectile, ProjectileCollisionMask, ProjectileLogic, SpentProjectile, SurfaceMaterial, Team,
+};
+use crate::resources::{BallisticsConfig, SpentProjectileBehavior, SpentProjectiles};
+
+/// Everything a round carries for its own flight, stripped when it is retired.
+///
+/// A pooled round comes back without any of these, so a reused entity can't
+/// inherit the old round's used-up pierce list, arming distance or network id.
+/// Visual components (interpolation, scale, trails) are left in place.
+pub type RoundComponents = (
+    (
+        Projectile,
+        ProjectileLogic,
+        Payload,
+        BounceOnContact,
+        Pierce,
+        ArmingDistance,
+        OnHitCallback,
+        AmmoType,
+    ),
+    (
+        Fragmentation,
+        FragmentGrace,
+        Guidance,
+        Team,
+        ProjectileCollisionMask,
+        NetworkId,
+        NetProjectile,
+        Predicted,
+        Authoritative,
+    ),
+);
+
+/// Retire a round that was spent on impact, according to
+/// `BallisticsConfig::spent_projectile_behavior`.
+///
+/// `Drop` keeps the entity as a dynamic rigid body resting against the surface
+/// it struck, moving off with the damped velocity of [`spent_round_velocity`];
+/// `Pool` hides it. Either way the [`RoundComponents`] are removed, so active
+/// projectile systems stop seeing it and a pooled round is reused clean.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for entity manipulation
+/// * `config` - Ballistics configuration resource
+/// * `entity` - Spent projectile entity
+/// * `transform` - Projectile transform, moved out of the surface when dropped
+/// * `projectile` - Projectile component at the moment it was spent
+/// * `hit_point` - World-space point where the round stopped
+/// * `hit_normal` - Surface normal at that point
+#[allow(clippy::too_many_arguments)]
+pub fn retire_projectile(
+    commands: &mut Commands,
+    config: &BallisticsConfig,
+    entity: Entity,
+    transform: &mut Transform,
+    projectile: &Projectile,
+    hit_point: Vec3,
+    hit_normal: Vec3,
+) {
+    let pooled = match config.spent_projectile_behavior {
+        SpentProjectileBehavior::Despawn => {
+            commands.entity(entity).despawn();
+            return;
+        }
+        SpentProjectileBehavior::Drop => false,
+        SpentProjectileBehavior::Pool => true,
+    };
+
+    let mut entity_commands = commands.entity(entity);
+    entity_commands
+        .remove::<RoundComponents>()
+        .insert(SpentProjectile {
+            elapsed: 0.0,
+            pooled,
+        });
+
+    if pooled {
+        entity_commands.insert(Visibility::Hidden);
+    } else {
+        let radius = (projectile.diameter * 0.5).max(0.001);
+        // Rest against the surface instead of inside or past it
+        transform.translation = hit_point + hit_normal * radius;
+        insert_debris_body(
+            &mut entity_commands,
+            radius,
+            spent_round_velocity(projectile.velocity, hit_normal),
+        );
+    }
+}
+
+/// Share of the into-surface speed a dropped round bounces back with.
+const SPENT_ROUND_RESTITUTION: f32 = 0.2;
+/// Share of the along-surface speed a dropped round keeps.
+const SPENT_ROUND_SLIDE: f32 = 0.3;
+
+/// Velocity a spent round drops away with after stopping against a surface.
+///
+/// The into-surface part is reflected and mostly absorbed, the along-surface
+/// part damped, so debris falls off the wall instead of tunnelling through it.
+///
+/// # Arguments
+/// * `velocity` - Velocity of the round at impact
+/// * `normal` - Surface normal at the impact point
+pub fn spent_round_velocity(velocity: Vec3, normal: Vec3) -> Vec3 {
+    let normal = normal.normalize_or_zero();
+    let into = velocity.dot(normal).min(0.0);
+    let along = velocity - normal * velocity.dot(normal);
+    along * SPENT_ROUND_SLIDE - normal * into * SPENT_ROUND_RESTITUTION
+}
+
+/// Make a dropped round a small dynamic body moving off at `velocity`.
+#[cfg_attr(not(any(feature = "dim3", feature = "dim2")), allow(unused_variables))]
+fn insert_debris_body(entity_commands: &mut EntityCommands, radius: f32, velocity: Vec3) {
+    #[cfg(feature = "dim3")]
+    {
+        use avian3d::prelude::*;
+        entity_commands.insert((
+            RigidBody::Dynamic,
+            Collider::sphere(radius),
+            LinearVelocity(velocity),
+        ));
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        use avian2d::prelude::*;
+        entity_commands.insert((
+            RigidBody::Dynamic,
+            Collider::circle(radius),
+            LinearVelocity(velocity.truncate()),
+        ));
+    }
+}
+
+/// Restitution of a bouncing grenade that struck a surface without a `SurfaceMaterial`.
+const DEFAULT_BOUNCE_RESTITUTION: f32 = 0.3;
+/// Friction of a bouncing grenade that struck a surface without a `SurfaceMaterial`.
+const DEFAULT_BOUNCE_FRICTION: f32 = 0.6;
+
+/// Hand a [`BounceOnContact`] round over to avian on its first surface contact.
+///
+/// The round becomes a dynamic sphere resting against the hit point, keeping its
+/// velocity, with the struck surface's restitution and friction. `Projectile` is
+/// removed so the integrator and collision sweep let go of it; `ProjectileLogic`
+/// and `Payload` stay, so the fuse keeps counting from where it was. The struck
+/// entity is kept as a [`BounceSurface`] for the grenade's shards to ignore.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for entity manipulation
+/// * `entity` - Grenade entity
+/// * `transform` - Grenade transform, moved out of the surface
+/// * `projectile` - Projectile component at the moment of contact
+/// * `hit_entity` - Entity that was struck
+/// * `hit_point` - World-space contact point
+/// * `hit_normal` - Surface normal at the contact point
+/// * `surface` - Surface material that was struck, if any
+#[cfg_attr(not(any(feature = "dim3", feature = "dim2")), allow(unused_variables))]
+#[allow(clippy::too_many_arguments)]
+pub fn hand_off_to_physics(
+    commands: &mut Commands,
+    entity: Entity,
+    transform: &mut Transform,
+    projectile: &Projectile,
+    hit_entity: Entity,
+    hit_point: Vec3,
+    hit_normal: Vec3,
+    surface: Option<&SurfaceMaterial>,
+) {
+    let radius = (projectile.diameter * 0.5).max(0.001);
+    let (restitution, friction) = surface.map_or(
+        (DEFAULT_BOUNCE_RESTITUTION, DEFAULT_BOUNCE_FRICTION),
+        |surface| (surface.restitution(), surface.friction()),
+    );
+    transform.translation = hit_point + hit_normal * radius;
+
+    let mut entity_commands = commands.entity(entity);
+    entity_commands
+        .remove::<(Projectile, BounceOnContact)>()
+        .insert(BounceSurface(hit_entity));
+
+    // The grenade's coefficients win over avian's defaults on the other collider
+    #[cfg(feature = "dim3")]
+    {
+        use avian3d::prelude::*;
+        entity_commands.insert((
+            RigidBody::Dynamic,
+            Collider::sphere(radius),
+            LinearVelocity(projectile.velocity),
+            Restitution::new(restitution).with_combine_rule(CoefficientCombine::Max),
+            Friction::new(friction).with_combine_rule(CoefficientCombine::Max),
+        ));
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        use avian2d::prelude::*;
+        entity_commands.insert((
+            RigidBody::Dynamic,
+            Collider::circle(radius),
+            LinearVelocity(projectile.velocity.truncate()),
+            Restitution::new(restitution).with_combine_rule(CoefficientCombine::Max),
+            Friction::new(friction).with_combine_rule(CoefficientCombine::Max),
+        ));
+    }
+}
+
+/// Track spent rounds, expire debris and enforce `max_spent_projectiles`.
+///
+/// Newly spent rounds are queued oldest-first. Debris is despawned once it has
+/// lived `spent_projectile_lifetime`; when either queue exceeds the cap the
+/// oldest entries are despawned.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for despawning
+/// * `time` - Fixed time resource to get delta time
+/// * `config` - Ballistics configuration resource
+/// * `tracker` - Debris and pool bookkeeping
+/// * `spent` - Spent rounds; those added since the last run are queued
+pub fn update_spent_projectiles(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    config: Res<BallisticsConfig>,
+    mut tracker: ResMut<SpentProjectiles>,
+    mut spent: Query<(Entity, &mut SpentProjectile)>,
+) {
+    for (entity, marker) in spent.iter_mut().filter(|(_, marker)| marker.is_added()) {
+        if marker.pooled {
+            tracker.available.push_back(entity);
+        } else {
+            tracker.debris.push_back(entity);
+        }
+    }
+
+    // Drop entries that were despawned or reused elsewhere
+    tracker.available.retain(|entity| spent.contains(*entity));
+
+    let dt = config.scaled_delta(time.delta_secs());
+    let lifetime = config.spent_projectile_lifetime;
+    tracker.debris.retain(|entity| {
+        let Ok((_, mut marker)) = spent.get_mut(*entity) else {
+            return false;
+        };
+        marker.elapsed += dt;
+        if marker.elapsed >= lifetime {
+            commands.entity(*entity).despawn();
+            return false;
+        }
+        true
+    });
+
+    let max = config.max_spent_projectiles;
+    while tracker.debris.len() > max {
+        if let Some(entity) = tracker.debris.pop_front() {
+            commands.entity(entity).despawn();
+        }
+    }
+    while tracker.available.len() > max {
+        if let Some(entity) = tracker.available.pop_front() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_debris_capped_oldest_first() {
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsConfig {
+            spent_projectile_behavior: SpentProjectileBehavior::Drop,
+            max_spent_projectiles: 2,
+            ..Default::default()
+        });
+        world.init_resource::<SpentProjectiles>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_spent_projectiles);
+
+        let rounds: Vec<Entity> = (0..3)
+            .map(|_| world.spawn(Projectile::new(Vec3::NEG_Z * 300.0)).id())
+            .collect();
+        for &round in &rounds {
+            world
+                .run_system_once(move |mut commands: Commands, config: Res<BallisticsConfig>| {
+                    let projectile = Projectile::new(Vec3::NEG_Z * 300.0);
+                    retire_projectile(
+                        &mut commands,
+                        &config,
+                        round,
+                        &mut Transform::default(),
+                        &projectile,
+                        Vec3::ZERO,
+                        Vec3::Z,
+                    );
+                })
+                .unwrap();
+            schedule.run(&mut world);
+        }
+
+        assert!(world.get_entity(rounds[0]).is_err());
+        for &round in &rounds[1..] {
+            assert!(world.get::<Projectile>(round).is_none());
+            assert!(world.get::<SpentProjectile>(round).is_some());
+        }
+        assert_eq!(world.resource::<SpentProjectiles>().debris.len(), 2);
+    }
+
+    #[test]
+    fn test_pooled_round_reused_clean() {
+        let mut world = World::new();
+        world.insert_resource(BallisticsConfig {
+            spent_projectile_behavior: SpentProjectileBehavior::Pool,
+            ..Default::default()
+        });
+        let mut pierce = Pierce::new(2);
+        pierce.remaining = 0;
+        pierce.pierced.push(Entity::PLACEHOLDER);
+        let round = world
+            .spawn((
+                Transform::default(),
+                Projectile::new(Vec3::NEG_Z * 300.0),
+                ProjectileLogic::Impact,
+                Payload::Kinetic { damage: 30.0 },
+                pierce,
+                ArmingDistance(3.0),
+                AmmoType::ArmorPiercing,
+                Team(1),
+                NetworkId(7),
+            ))
+            .id();
+
+        world
+            .run_system_once(move |mut commands: Commands, config: Res<BallisticsConfig>| {
+                let projectile = Projectile::new(Vec3::NEG_Z * 300.0);
+                retire_projectile(
+                    &mut commands,
+                    &config,
+                    round,
+                    &mut Transform::default(),
+                    &projectile,
+                    Vec3::ZERO,
+                    Vec3::Z,
+                );
+            })
+            .unwrap();
+
+        let entity = world.entity(round);
+        assert!(entity.contains::<SpentProjectile>());
+        assert!(!entity.contains::<Projectile>());
+        assert!(!entity.contains::<Pierce>());
+        assert!(!entity.contains::<ArmingDistance>());
+        assert!(!entity.contains::<AmmoType>());
+        assert!(!entity.contains::<Team>());
+        assert!(!entity.contains::<NetworkId>());
+        assert_eq!(entity.get::<Visibility>(), Some(&Visibility::Hidden));
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_bouncing_grenade_handoff_keeps_velocity_and_fuse() {
+        use crate::components::HitEffectType;
+        use avian3d::prelude::*;
+
+        let mut world = World::new();
+        let velocity = Vec3::new(4.0, -6.0, 1.0);
+        let grenade = world
+            .spawn((
+                Transform::from_xyz(0.0, 0.01, 0.0),
+                Projectile::new(velocity),
+                ProjectileLogic::Timed {
+                    fuse: 3.0,
+                    elapsed: 1.25,
+                },
+                Payload::Explosive {
+                    damage: 100.0,
+                    radius: 5.0,
+                    falloff: crate::components::FalloffCurve::Power(1.0),
+                },
+                BounceOnContact,
+            ))
+            .id();
+        let floor = SurfaceMaterial {
+            hit_effect: HitEffectType::Dust,
+            ..Default::default()
+        };
+        let ground = world.spawn_empty().id();
+
+        world
+            .run_system_once(
+                move |mut commands: Commands, mut grenades: Query<(&mut Transform, &Projectile)>| {
+                    let (mut transform, projectile) = grenades.get_mut(grenade).unwrap();
+                    hand_off_to_physics(
+                        &mut commands,
+                        grenade,
+                        &mut transform,
+                        projectile,
+                        ground,
+                        Vec3::ZERO,
+                        Vec3::Y,
+                        Some(&floor),
+                    );
+                },
+            )
+            .unwrap();
+
+        assert!(world.get::<Projectile>(grenade).is_none());
+        assert!(world.get::<BounceOnContact>(grenade).is_none());
+        assert_eq!(world.get::<BounceSurface>(grenade), Some(&BounceSurface(ground)));
+        assert_eq!(world.get::<LinearVelocity>(grenade).unwrap().0, velocity);
+        assert_eq!(world.get::<Restitution>(grenade).unwrap().coefficient, 0.25);
+        assert!(matches!(
+            world.get::<ProjectileLogic>(grenade),
+            Some(ProjectileLogic::Timed { elapsed, .. }) if *elapsed == 1.25
+        ));
+        assert!(world.get::<Payload>(grenade).is_some());
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
-use crate::components::Projectile;
+use crate::components::{BallisticsDebugOverlay, Projectile};
+use crate::resources::{BallisticsConfig, BallisticsStats, DecalPool, SpentProjectiles, TracerPool};
+
+/// Seconds over which the overlay's events/sec figure is averaged.
+const EVENT_RATE_WINDOW: f32 = 1.0;
 
 /// Draw debug gizmos for projectiles.
 ///
@@ -23,3 +27,189 @@ pub fn draw_projectile_debug(
         gizmos.line(transform.translation, end, Color::srgb(0.0, 1.0, 0.0));
     }
 }
+
+/// Run condition: `BallisticsConfig::debug_draw` is on.
+pub fn debug_draw_enabled(config: Res<BallisticsConfig>) -> bool {
+    config.debug_draw
+}
+
+/// Rolling events/sec counter behind the debug overlay.
+#[derive(Default)]
+pub struct EventRate {
+    last_total: u32,
+    window: f32,
+    per_second: f32,
+}
+
+/// Spawn and refresh the [`BallisticsDebugOverlay`] text node.
+///
+/// Shows the active projectile count and average speed, tracer and decal pool
+/// occupancy (available/capacity, when `BallisticsVfxPlugin` provides the
+/// pools), spent-round pool occupancy and debris count, and shots, hits,
+/// penetrations and ricochets per second (from `BallisticsStats`). The node is created on the first run, so no UI setup
+/// is needed. Meant to run only while `debug_draw` is on, see
+/// [`debug_draw_enabled`].
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning the overlay
+/// * `time` - Bevy Time resource
+/// * `config` - Ballistics configuration resource
+/// * `stats` - Running ballistics counters
+/// * `spent` - Spent-round debris and pool bookkeeping
+/// * `tracers` - Optional tracer pool
+/// * `decals` - Optional decal pool
+/// * `projectiles` - Query for active projectiles
+/// * `overlay` - Query for the overlay text
+/// * `rate` - Events/sec state kept between runs
+#[allow(clippy::too_many_arguments)]
+pub fn update_debug_overlay(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<BallisticsConfig>,
+    stats: Res<BallisticsStats>,
+    spent: Res<SpentProjectiles>,
+    tracers: Option<Res<TracerPool>>,
+    decals: Option<Res<DecalPool>>,
+    projectiles: Query<&Projectile>,
+    mut overlay: Query<&mut Text, With<BallisticsDebugOverlay>>,
+    mut rate: Local<EventRate>,
+) {
+    let total = stats.shots_fired + stats.hits + stats.penetrations + stats.ricochets;
+    // Stats may have been reset since the last run
+    if total < rate.last_total {
+        rate.last_total = total;
+    }
+    rate.window += time.delta_secs();
+    if rate.window >= EVENT_RATE_WINDOW {
+        rate.per_second = (total - rate.last_total) as f32 / rate.window;
+        rate.last_total = total;
+        rate.window = 0.0;
+    }
+
+    let (count, speed_sum) = projectiles
+        .iter()
+        .fold((0usize, 0.0f32), |(count, sum), p| (count + 1, sum + p.velocity.length()));
+    let average_speed = if count > 0 { speed_sum / count as f32 } else { 0.0 };
+
+    let mut readout = format!("Projectiles: {count}\nAvg speed: {average_speed:.0} m/s\n");
+    if let Some(tracers) = tracers {
+        readout += &format!("Tracer pool: {}/{}\n", tracers.available.len(), tracers.max_size);
+    }
+    if let Some(decals) = decals {
+        readout += &format!("Decal pool: {}/{}\n", decals.available.len(), decals.max_size);
+    }
+    readout += &format!(
+        "Spent rounds: {}/{} pooled (debris {})\nEvents/s: {:.1}",
+        spent.available.len(),
+        config.max_spent_projectiles,
+        spent.debris.len(),
+        rate.per_second,
+    );
+
+    match overlay.single_mut() {
+        Ok(mut text) => text.0 = readout,
+        Err(_) => {
+            commands.spawn((
+                Text::new(readout),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    right: Val::Px(10.0),
+                    ..default()
+                },
+                BallisticsDebugOverlay,
+            ));
+        }
+    }
+}
+
+/// Despawn the debug overlay once `debug_draw` is turned off.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for despawning
+/// * `config` - Ballistics configuration resource
+/// * `overlay` - Query for the overlay entity
+pub fn remove_debug_overlay(
+    mut commands: Commands,
+    config: Res<BallisticsConfig>,
+    overlay: Query<Entity, With<BallisticsDebugOverlay>>,
+) {
+    if config.debug_draw {
+        return;
+    }
+    for entity in overlay.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlay_app(debug_draw: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(BallisticsConfig {
+                debug_draw,
+                ..Default::default()
+            })
+            .init_resource::<BallisticsStats>()
+            .init_resource::<SpentProjectiles>()
+            .add_systems(
+                Update,
+                (
+                    update_debug_overlay.run_if(debug_draw_enabled),
+                    remove_debug_overlay.run_if(resource_changed::<BallisticsConfig>),
+                ),
+            );
+        app
+    }
+
+    #[test]
+    fn test_overlay_only_spawned_with_debug_draw() {
+        let mut app = overlay_app(false);
+        app.update();
+        app.update();
+        let mut overlays = app
+            .world_mut()
+            .query_filtered::<(), With<BallisticsDebugOverlay>>();
+        assert_eq!(overlays.iter(app.world()).count(), 0);
+
+        let mut app = overlay_app(true);
+        app.world_mut().spawn(Projectile::new(Vec3::NEG_Z * 300.0));
+        app.update();
+        app.update();
+        let mut overlays = app
+            .world_mut()
+            .query_filtered::<&Text, With<BallisticsDebugOverlay>>();
+        let texts: Vec<_> = overlays.iter(app.world()).collect();
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].0.contains("Projectiles: 1"));
+        assert!(texts[0].0.contains("Spent rounds: 0/"));
+        assert!(!texts[0].0.contains("Tracer pool"));
+
+        app.world_mut().insert_resource(TracerPool {
+            available: vec![Entity::PLACEHOLDER; 3],
+            max_size: 8,
+        });
+        app.world_mut().insert_resource(DecalPool::default());
+        app.update();
+        let mut overlays = app
+            .world_mut()
+            .query_filtered::<&Text, With<BallisticsDebugOverlay>>();
+        let text = &overlays.single(app.world()).unwrap().0;
+        assert!(text.contains("Tracer pool: 3/8"));
+        assert!(text.contains("Decal pool: 0/256"));
+
+        app.world_mut().resource_mut::<BallisticsConfig>().debug_draw = false;
+        app.update();
+        let mut overlays = app
+            .world_mut()
+            .query_filtered::<(), With<BallisticsDebugOverlay>>();
+        assert_eq!(overlays.iter(app.world()).count(), 0);
+    }
+}
@@ -0,0 +1,157 @@
+//! Event log system - records ballistics events into the `BallisticsEventLog`.
+
+use bevy::prelude::*;
+use bevy::ecs::message::MessageReader;
+
+use crate::events::{ExplosionEvent, FireEvent, HitEvent};
+use crate::resources::{BallisticsEventLog, BallisticsStats, LoggedBallisticsEvent};
+
+/// Append this frame's fire, hit and explosion events to the log.
+///
+/// Runs once per frame after the simulation, so events written in `FixedUpdate`
+/// and `Update` are both captured, stamped with the current game time.
+///
+/// # Arguments
+/// * `time` - Game time
+/// * `log` - Event log resource
+/// * `fire_events` - Message reader for fire events
+/// * `hit_events` - Message reader for hit events
+/// * `explosion_events` - Message reader for explosion events
+pub fn record_ballistics_events(
+    time: Res<Time>,
+    mut log: ResMut<BallisticsEventLog>,
+    mut fire_events: MessageReader<FireEvent>,
+    mut hit_events: MessageReader<HitEvent>,
+    mut explosion_events: MessageReader<ExplosionEvent>,
+) {
+    let now = time.elapsed_secs_f64();
+
+    for event in fire_events.read() {
+        log.record(now, LoggedBallisticsEvent::Fire(event.clone()));
+    }
+    for event in hit_events.read() {
+        log.record(now, LoggedBallisticsEvent::Hit(event.clone()));
+    }
+    for event in explosion_events.read() {
+        log.record(now, LoggedBallisticsEvent::Explosion(event.clone()));
+    }
+}
+
+/// Add this frame's shots and hits to `BallisticsStats`.
+///
+/// Rounds passing through sensors are not counted as hits.
+///
+/// # Arguments
+/// * `stats` - Shot and hit totals
+/// * `fire_events` - Message reader for fire events
+/// * `hit_events` - Message reader for hit events
+pub fn accumulate_ballistics_stats(
+    mut stats: ResMut<BallisticsStats>,
+    mut fire_events: MessageReader<FireEvent>,
+    mut hit_events: MessageReader<HitEvent>,
+) {
+    for event in fire_events.read() {
+        stats.shots_fired += 1;
+        stats.projectiles_fired += event.projectile_count.max(1);
+    }
+    for event in hit_events.read().filter(|event| !event.sensor) {
+        if event.ricocheted {
+            stats.ricochets += 1;
+            continue;
+        }
+        stats.hits += 1;
+        if event.penetrated {
+            stats.penetrations += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_shot_and_hit_logged_in_order() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, crate::BallisticsEventLogPlugin::default()))
+            .add_message::<FireEvent>()
+            .add_message::<HitEvent>()
+            .add_message::<ExplosionEvent>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        app.update();
+
+        app.world_mut().write_message(FireEvent::default());
+        app.update();
+        let fired_at = app.world().resource::<Time>().elapsed_secs_f64();
+
+        app.update();
+        let target = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(HitEvent::new(
+            Entity::PLACEHOLDER,
+            target,
+            Vec3::NEG_Z * 20.0,
+            Vec3::Z,
+            Vec3::NEG_Z * 380.0,
+            25.0,
+        ));
+        app.update();
+        let hit_at = app.world().resource::<Time>().elapsed_secs_f64();
+
+        let entries = app.world().resource::<BallisticsEventLog>().export();
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(entries[0].event, LoggedBallisticsEvent::Fire(_)));
+        assert!(matches!(
+            entries[1].event,
+            LoggedBallisticsEvent::Hit(HitEvent { target: t, .. }) if t == target
+        ));
+        assert_eq!(entries[0].time, fired_at);
+        assert_eq!(entries[1].time, hit_at);
+        assert!(entries[0].time < entries[1].time);
+    }
+
+    #[test]
+    fn test_stats_count_shots_and_hits() {
+        let mut app = App::new();
+        app.init_resource::<BallisticsStats>()
+            .add_message::<FireEvent>()
+            .add_message::<HitEvent>()
+            .add_systems(Update, accumulate_ballistics_stats);
+
+        let hit = |penetrated, ricocheted| HitEvent {
+            penetrated,
+            ricocheted,
+            ..HitEvent::new(
+                Entity::PLACEHOLDER,
+                Entity::PLACEHOLDER,
+                Vec3::ZERO,
+                Vec3::Z,
+                Vec3::NEG_Z * 380.0,
+                25.0,
+            )
+        };
+        // A rifle shot and an 8-pellet shotgun blast
+        app.world_mut().write_message(FireEvent::default());
+        app.world_mut()
+            .write_message(FireEvent::default().with_projectile_count(8));
+        app.world_mut().write_message(hit(false, false));
+        app.world_mut().write_message(hit(true, false));
+        app.world_mut().write_message(hit(false, true));
+        app.update();
+
+        let stats = app.world().resource::<BallisticsStats>().clone();
+        assert_eq!(stats.shots_fired, 2);
+        assert_eq!(stats.projectiles_fired, 9);
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.penetrations, 1);
+        assert_eq!(stats.ricochets, 1);
+        assert!((stats.accuracy() - 2.0 / 9.0).abs() < 1e-6);
+
+        app.world_mut().resource_mut::<BallisticsStats>().reset();
+        app.update();
+        assert_eq!(*app.world().resource::<BallisticsStats>(), BallisticsStats::default());
+    }
+}
@@ -0,0 +1,398 @@
+//! Fire system - spawns projectiles from FireEvents.
+//!
+//! This is opt-in through `BallisticsSpawnPlugin`: games that spawn their own
+//! projectiles and only write `FireEvent` for bookkeeping should not add it, or
+//! every shot is doubled.
+
+use bevy::prelude::*;
+use bevy::ecs::message::{MessageReader, MessageWriter};
+
+use crate::components::{Payload, Projectile, ProjectileLogic, Team};
+use crate::events::{FireEvent, ProjectileSpawnedEvent};
+use crate::resources::{BallisticsEnvironment, WeaponPreset, WeaponPresets};
+use crate::systems::accuracy::{pellet_directions, SpreadPattern};
+use crate::types::{ProjectileRng, ProjectileSpawnParams};
+use smallvec::SmallVec;
+
+/// Spawn projectiles for every FireEvent.
+///
+/// Physical properties come from the `WeaponPresets` entry at `weapon_type`
+/// (or `WeaponPreset::default()` if there is none). Each of the event's
+/// `projectile_count` pellets gets its own spread direction, seeded from
+/// `spread_seed` so clients and server agree, and a `ProjectileSpawnedEvent`.
+/// The shot's speed is adjusted for the `BallisticsEnvironment` temperature and
+/// varies by the preset's `muzzle_velocity_sd`, drawn from the same seed (see
+/// [`FireEvent::with_preset_variation`]).
+/// Rounds inherit the shooter's `Team`, if any, the event's `inherited_velocity`
+/// and its `AmmoType`, if set.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning projectiles
+/// * `fire_events` - Message reader for fire events
+/// * `spawned_events` - Message writer for projectile spawned events
+/// * `presets` - Optional weapon presets used for mass, drag, damage and velocity
+/// * `environment` - Optional environment for the temperature adjustment (default if absent)
+/// * `teams` - Query for shooter teams
+pub fn spawn_projectiles_from_fire_events(
+    mut commands: Commands,
+    mut fire_events: MessageReader<FireEvent>,
+    mut spawned_events: MessageWriter<ProjectileSpawnedEvent>,
+    presets: Option<Res<WeaponPresets>>,
+    environment: Option<Res<BallisticsEnvironment>>,
+    teams: Query<&Team>,
+) {
+    let default_environment;
+    let environment = match &environment {
+        Some(environment) => environment.as_ref(),
+        None => {
+            default_environment = BallisticsEnvironment::default();
+            &default_environment
+        }
+    };
+    for event in fire_events.read() {
+        let preset = presets
+            .as_ref()
+            .and_then(|presets| presets.presets.get(event.weapon_type))
+            .cloned()
+            .unwrap_or_else(WeaponPreset::default);
+        let event = event.clone().with_preset_variation(&preset, environment);
+        let params = event.to_spawn_params(&preset);
+        let team = params.owner.and_then(|owner| teams.get(owner).ok()).copied();
+        let rng = ProjectileRng::new(event.spread_seed);
+
+        for pellet in 0..event.projectile_count.max(1) {
+            let direction = if event.spread_angle > 0.0 {
+                rng.spread_direction(params.direction, event.spread_angle, pellet)
+            } else {
+                params.direction
+            };
+
+            let mut projectile = Projectile::new(direction * params.velocity + event.inherited_velocity)
+                .with_mass(params.mass)
+                .with_drag(params.drag)
+                .with_previous_position(params.origin);
+            projectile.spin = preset.spin;
+            projectile.is_supersonic = event.is_supersonic;
+            if let Some(owner) = params.owner {
+                projectile = projectile.with_owner(owner);
+            }
+
+            let mut round = commands.spawn((
+                Transform::from_translation(params.origin),
+                projectile,
+                ProjectileLogic::Impact,
+                Payload::Kinetic {
+                    damage: params.damage,
+                },
+            ));
+            if let Some(team) = team {
+                round.insert(team);
+            }
+            if let Some(ammo) = event.ammo {
+                round.insert(ammo);
+            }
+            let entity = round.id();
+
+            spawned_events.write(ProjectileSpawnedEvent {
+                entity,
+                origin: params.origin,
+                direction,
+                muzzle_velocity: params.velocity,
+                owner: params.owner,
+            });
+        }
+    }
+}
+
+/// Spawn a whole volley (shotgun blast, burst) in one call.
+///
+/// Directions are computed once by [`pellet_directions`] from `seed`. Every
+/// pellet gets a `Projectile` built from `params`, `Impact` logic, a clone of one
+/// kinetic `Payload`, and a clone of `bundle`, typically the shared mesh and
+/// material handles. Unlike the fire system, no `ProjectileSpawnedEvent` is sent.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning projectiles
+/// * `params` - Shared spawn parameters (origin, aim, speed, mass, drag, damage, owner)
+/// * `pattern` - Pellet distribution around `params.direction`
+/// * `count` - Number of pellets
+/// * `seed` - Random seed for deterministic spread
+/// * `bundle` - Extra components cloned onto every pellet
+///
+/// # Returns
+/// The spawned pellets, in pellet order, for the caller to tag
+pub fn spawn_volley<B: Bundle + Clone>(
+    commands: &mut Commands,
+    params: &ProjectileSpawnParams,
+    pattern: SpreadPattern,
+    count: u32,
+    seed: u64,
+    bundle: B,
+) -> SmallVec<[Entity; 16]> {
+    let payload = Payload::Kinetic {
+        damage: params.damage,
+    };
+    let mut base = Projectile::new(Vec3::ZERO)
+        .with_mass(params.mass)
+        .with_drag(params.drag)
+        .with_previous_position(params.origin);
+    base.owner = params.owner;
+
+    pellet_directions(params.direction, pattern, count, seed)
+        .into_iter()
+        .map(|direction| {
+            commands
+                .spawn((
+                    Transform::from_translation(params.origin),
+                    Projectile {
+                        velocity: direction * params.velocity,
+                        ..base.clone()
+                    },
+                    ProjectileLogic::Impact,
+                    payload.clone(),
+                    bundle.clone(),
+                ))
+                .id()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_spawn_event_per_projectile() {
+        let mut app = App::new();
+        app.add_message::<FireEvent>()
+            .add_message::<ProjectileSpawnedEvent>()
+            .add_systems(Update, spawn_projectiles_from_fire_events);
+
+        app.world_mut().write_message(
+            FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0)
+                .with_projectile_count(8)
+                .with_spread_angle(0.05)
+                .with_seed(7),
+        );
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::Y, Vec3::X, 900.0));
+        app.update();
+
+        let spawned: Vec<_> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(spawned.len(), 9);
+
+        let projectiles = app
+            .world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .count();
+        assert_eq!(projectiles, 9);
+
+        for event in &spawned {
+            let projectile = app.world().get::<Projectile>(event.entity).unwrap();
+            let speed = projectile.velocity.length();
+            assert!((speed - event.muzzle_velocity).abs() < 1e-3);
+            assert!(projectile.velocity.normalize().dot(event.direction) > 0.9999);
+        }
+        assert_eq!(spawned[8].origin, Vec3::Y);
+    }
+
+    #[test]
+    fn test_gameplay_group_spawns_without_default_group() {
+        use crate::{BallisticsCorePlugin, BallisticsGameplayPluginGroup};
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, BallisticsGameplayPluginGroup));
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0).with_projectile_count(3));
+        app.update();
+        let projectiles = app.world_mut().query::<&Projectile>().iter(app.world()).count();
+        assert_eq!(projectiles, 3);
+
+        // Shares its messages with the core plugin instead of registering them twice
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, BallisticsCorePlugin, BallisticsGameplayPluginGroup));
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0));
+        app.update();
+        let projectiles = app.world_mut().query::<&Projectile>().iter(app.world()).count();
+        assert_eq!(projectiles, 1);
+        assert_eq!(app.world().resource::<crate::resources::BallisticsStats>().shots_fired, 1);
+    }
+
+    #[test]
+    fn test_spawned_speed_varies_with_preset_sd() {
+        use crate::BallisticsGameplayPluginGroup;
+
+        let preset = WeaponPreset {
+            muzzle_velocity: 800.0,
+            muzzle_velocity_sd: 8.0,
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, BallisticsGameplayPluginGroup))
+            .insert_resource(WeaponPresets { presets: vec![preset.clone()] });
+
+        let env = crate::resources::BallisticsEnvironment::default();
+        for seed in 1..=4 {
+            app.world_mut()
+                .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 800.0).with_seed(seed));
+        }
+        // Already sampled by the caller: fired as is
+        app.world_mut().write_message(
+            FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 0.0)
+                .with_seed(1)
+                .with_preset_velocity(&preset, &env),
+        );
+        app.update();
+
+        let spawned: Vec<_> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(spawned.len(), 5);
+        for (event, seed) in spawned.iter().zip(1..=4) {
+            let expected = preset.sample_muzzle_velocity(&env, seed);
+            assert!((event.muzzle_velocity - expected).abs() < 1e-3);
+            let speed = app.world().get::<Projectile>(event.entity).unwrap().velocity.length();
+            assert!((speed - expected).abs() < 1e-2);
+        }
+        assert!(spawned[..4].iter().any(|event| (event.muzzle_velocity - 800.0).abs() > 1e-3));
+        assert!((spawned[4].muzzle_velocity - spawned[0].muzzle_velocity).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_spawned_speed_follows_temperature() {
+        use crate::resources::BallisticsEnvironment;
+        use crate::BallisticsGameplayPluginGroup;
+
+        let preset = WeaponPreset {
+            muzzle_velocity: 800.0,
+            temperature_sensitivity: 1.5,
+            ..Default::default()
+        };
+        let hot = BallisticsEnvironment {
+            temperature: 45.0,
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, BallisticsGameplayPluginGroup))
+            .insert_resource(WeaponPresets { presets: vec![preset.clone()] })
+            .insert_resource(hot.clone());
+
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 800.0));
+        app.update();
+
+        let spawned: Vec<_> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        let expected = preset.muzzle_velocity_at(&hot);
+        assert!((expected - 845.0).abs() < 1e-3);
+        assert!((spawned[0].muzzle_velocity - expected).abs() < 1e-3);
+        let speed = app.world().get::<Projectile>(spawned[0].entity).unwrap().velocity.length();
+        assert!((speed - expected).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_round_inherits_platform_velocity() {
+        let mut app = App::new();
+        app.add_message::<FireEvent>()
+            .add_message::<ProjectileSpawnedEvent>()
+            .add_systems(Update, spawn_projectiles_from_fire_events);
+
+        // Vehicle strafing along +X, firing straight ahead
+        let platform_velocity = Vec3::new(20.0, 0.0, 0.0);
+        app.world_mut().write_message(
+            FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0)
+                .with_inherited_velocity(platform_velocity)
+                .with_spawn_offset(0.5),
+        );
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 400.0));
+        app.update();
+
+        let spawned: Vec<_> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+
+        let carried = app.world().get::<Projectile>(spawned[0].entity).unwrap();
+        assert_eq!(carried.velocity, Vec3::new(20.0, 0.0, -400.0));
+        assert_eq!(carried.previous_position, Vec3::new(0.0, 0.0, -0.5));
+        assert_eq!(spawned[0].origin, Vec3::new(0.0, 0.0, -0.5));
+
+        // Inheritance is opt-in
+        let plain = app.world().get::<Projectile>(spawned[1].entity).unwrap();
+        assert_eq!(plain.velocity, Vec3::new(0.0, 0.0, -400.0));
+        assert_eq!(spawned[1].origin, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_spawn_volley() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        #[derive(Component, Clone)]
+        struct Pellet;
+
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let params = ProjectileSpawnParams::new(Vec3::Y, Vec3::NEG_Z, 400.0)
+            .with_damage(12.0)
+            .with_owner(owner);
+        let volley = |world: &mut World, seed| {
+            let params = params.clone();
+            world
+                .run_system_once(move |mut commands: Commands| {
+                    spawn_volley(
+                        &mut commands,
+                        &params,
+                        SpreadPattern::Random { spread_angle: 0.08 },
+                        9,
+                        seed,
+                        Pellet,
+                    )
+                })
+                .unwrap()
+        };
+
+        let pellets = volley(&mut world, 42);
+        assert_eq!(pellets.len(), 9);
+        let directions: Vec<Vec3> = pellets
+            .iter()
+            .map(|&pellet| {
+                assert!(world.get::<Pellet>(pellet).is_some());
+                let projectile = world.get::<Projectile>(pellet).unwrap();
+                assert_eq!(projectile.owner, Some(owner));
+                assert!((projectile.velocity.length() - 400.0).abs() < 1e-3);
+                projectile.velocity.normalize()
+            })
+            .collect();
+        for (i, a) in directions.iter().enumerate() {
+            for b in &directions[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        // Same seed, same volley
+        let again = volley(&mut world, 42);
+        for (&a, &b) in pellets.iter().zip(&again) {
+            assert_eq!(
+                world.get::<Projectile>(a).unwrap().velocity,
+                world.get::<Projectile>(b).unwrap().velocity
+            );
+        }
+    }
+}
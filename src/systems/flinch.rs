@@ -0,0 +1,109 @@
+//! Flinch system - view punch for entities that get hit.
+
+use bevy::prelude::*;
+use bevy::ecs::message::{MessageReader, MessageWriter};
+
+use crate::components::Flinchable;
+use crate::events::{FlinchEvent, HitEvent};
+
+/// Damage at which a hit produces full flinch; anything heavier saturates.
+pub const FLINCH_SATURATION_DAMAGE: f32 = 100.0;
+
+/// Share of the flinch kept by a round that passed through the target.
+pub const PENETRATION_FLINCH_FACTOR: f32 = 0.5;
+
+/// Flinch magnitude (0..1) for a hit.
+///
+/// Proportional to damage up to [`FLINCH_SATURATION_DAMAGE`], then capped.
+/// Rounds that went through the target deliver less of their momentum, and
+/// grazing hits fall off with the cosine of the impact angle.
+///
+/// # Arguments
+/// * `damage` - Damage dealt by the hit
+/// * `penetrated` - Whether the round passed through the target
+/// * `impact_angle` - Angle from the surface normal (radians, 0 = head-on)
+///
+/// # Returns
+/// Flinch magnitude between 0 and 1
+pub fn flinch_magnitude(damage: f32, penetrated: bool, impact_angle: f32) -> f32 {
+    let mut magnitude = (damage / FLINCH_SATURATION_DAMAGE).clamp(0.0, 1.0);
+    if penetrated {
+        magnitude *= PENETRATION_FLINCH_FACTOR;
+    }
+    magnitude * impact_angle.cos().max(0.0)
+}
+
+/// Send a `FlinchEvent` for every hit on a [`Flinchable`] entity.
+///
+/// Hits on a child collider flinch its nearest `Flinchable` ancestor, which is
+/// the event's `target`.
+///
+/// # Arguments
+/// * `hit_events` - Message reader for hit events
+/// * `flinchable` - Query for entities that opted in to flinching
+/// * `parents` - Query for hierarchy parents
+/// * `flinch_events` - Message writer for flinch events
+pub fn emit_flinch_events(
+    mut hit_events: MessageReader<HitEvent>,
+    flinchable: Query<(), With<Flinchable>>,
+    parents: Query<&ChildOf>,
+    mut flinch_events: MessageWriter<FlinchEvent>,
+) {
+    for hit in hit_events.read() {
+        let Some(target) = std::iter::once(hit.target)
+            .chain(parents.iter_ancestors(hit.target))
+            .find(|entity| flinchable.contains(*entity))
+        else {
+            continue;
+        };
+        let magnitude = flinch_magnitude(hit.damage, hit.penetrated, hit.impact_angle);
+        if magnitude <= 0.0 {
+            continue;
+        }
+        flinch_events.write(FlinchEvent {
+            target,
+            magnitude,
+            direction: hit.velocity.normalize_or_zero(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flinch_scales_with_damage() {
+        let mut app = App::new();
+        app.add_message::<HitEvent>()
+            .add_message::<FlinchEvent>()
+            .add_systems(Update, emit_flinch_events);
+
+        let player = app.world_mut().spawn(Flinchable).with_child(()).id();
+        let head = app.world().get::<Children>(player).unwrap()[0];
+        let crate_box = app.world_mut().spawn_empty().id();
+        let hit = |target, damage| {
+            HitEvent::new(Entity::PLACEHOLDER, target, Vec3::ZERO, Vec3::Z, Vec3::NEG_Z * 400.0, damage)
+        };
+        app.world_mut().write_message(hit(player, 20.0));
+        // A hitbox below the body flinches the body
+        app.world_mut().write_message(hit(head, 40.0));
+        app.world_mut().write_message(hit(crate_box, 40.0));
+        app.update();
+
+        let flinches: Vec<FlinchEvent> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<FlinchEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(flinches.len(), 2);
+        assert!(flinches.iter().all(|f| f.target == player && f.direction == Vec3::NEG_Z));
+        assert!((flinches[1].magnitude - 2.0 * flinches[0].magnitude).abs() < 1e-6);
+
+        // Saturates, and through-and-through or grazing hits kick less
+        assert_eq!(flinch_magnitude(1000.0, false, 0.0), 1.0);
+        assert!(flinch_magnitude(40.0, true, 0.0) < flinch_magnitude(40.0, false, 0.0));
+        assert!(flinch_magnitude(40.0, false, 1.2) < flinch_magnitude(40.0, false, 0.0));
+    }
+}
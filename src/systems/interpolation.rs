@@ -0,0 +1,190 @@
+//! Interpolation system - smooths rendered projectiles between fixed steps.
+//!
+//! Only entities carrying `InterpolatedTransform` are touched. Each frame the
+//! authoritative position is put back before the fixed loop runs, so the
+//! simulation never reads a blended `Transform`. A position written from
+//! outside the fixed loop is taken as the new authoritative one.
+
+use bevy::prelude::*;
+
+use crate::components::InterpolatedTransform;
+
+/// Seed both samples from the entity's `Transform` when interpolation is added.
+///
+/// # Arguments
+/// * `add` - The `InterpolatedTransform` added to an entity
+/// * `query` - Query for interpolated transforms
+pub fn init_interpolated_transform(
+    add: On<Add, InterpolatedTransform>,
+    mut query: Query<(&Transform, &mut InterpolatedTransform)>,
+) {
+    if let Ok((transform, mut interpolated)) = query.get_mut(add.entity) {
+        interpolated.reset(transform.translation);
+    }
+}
+
+/// Put the authoritative position back before the fixed loop runs.
+///
+/// Only a `Transform` still showing the blended position is restored. One moved
+/// since (a teleport, a pooled round reused) keeps the new position, and the
+/// samples are resynced to it.
+///
+/// # Arguments
+/// * `query` - Query for interpolated projectiles
+pub fn restore_interpolated_transforms(
+    mut query: Query<(&mut Transform, &mut InterpolatedTransform)>,
+) {
+    for (mut transform, mut interpolated) in query.iter_mut() {
+        if transform.translation == interpolated.rendered {
+            transform.translation = interpolated.current;
+        } else {
+            interpolated.reset(transform.translation);
+        }
+    }
+}
+
+/// Sample the position at the start of a fixed step.
+///
+/// # Arguments
+/// * `query` - Query for interpolated projectiles
+pub fn record_previous_positions(mut query: Query<(&Transform, &mut InterpolatedTransform)>) {
+    for (transform, mut interpolated) in query.iter_mut() {
+        interpolated.previous = transform.translation;
+    }
+}
+
+/// Sample the position at the end of a fixed step.
+///
+/// # Arguments
+/// * `query` - Query for interpolated projectiles
+pub fn record_current_positions(mut query: Query<(&Transform, &mut InterpolatedTransform)>) {
+    for (transform, mut interpolated) in query.iter_mut() {
+        interpolated.current = transform.translation;
+    }
+}
+
+/// Blend the rendered `Transform` between the last two fixed-step positions.
+///
+/// Runs in PostUpdate, before transform propagation. A `Transform` moved since
+/// the fixed loop (e.g. repositioned by collisions running in Update) is not
+/// blended over: the samples snap to it.
+///
+/// # Arguments
+/// * `fixed_time` - Fixed timestep clock, for the overstep fraction
+/// * `query` - Query for interpolated projectiles
+pub fn interpolate_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut InterpolatedTransform)>,
+) {
+    let fraction = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolated) in query.iter_mut() {
+        if transform.translation != interpolated.current {
+            interpolated.reset(transform.translation);
+        }
+        interpolated.rendered = interpolated.position_at(fraction);
+        transform.translation = interpolated.rendered;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn test_render_frame_mid_step_is_interpolated() {
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::from_hz(20.0));
+        world.add_observer(init_interpolated_transform);
+
+        let round = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 0.0), InterpolatedTransform::default()))
+            .id();
+        let plain = world.spawn(Transform::from_xyz(0.0, 0.0, 0.0)).id();
+
+        // One fixed step moves both rounds 10 m
+        world.run_system_once(restore_interpolated_transforms).unwrap();
+        world.run_system_once(record_previous_positions).unwrap();
+        for entity in [round, plain] {
+            world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(0.0, 0.0, -10.0);
+        }
+        world.run_system_once(record_current_positions).unwrap();
+
+        // Render frame a quarter of the way into the next step
+        world
+            .resource_mut::<Time<Fixed>>()
+            .accumulate_overstep(Duration::from_micros(12_500));
+        world.run_system_once(interpolate_transforms).unwrap();
+
+        let rendered = world.get::<Transform>(round).unwrap().translation;
+        assert!((rendered.z + 2.5).abs() < 1e-4, "rendered at {rendered}");
+        assert_eq!(world.get::<Transform>(plain).unwrap().translation.z, -10.0);
+
+        // The simulation sees the authoritative position again
+        world.run_system_once(restore_interpolated_transforms).unwrap();
+        assert_eq!(world.get::<Transform>(round).unwrap().translation.z, -10.0);
+    }
+
+    #[test]
+    fn test_writes_outside_fixed_loop_are_kept() {
+        use bevy::time::TimeUpdateStrategy;
+
+        #[derive(Resource, Default)]
+        struct Reposition(Option<Vec3>);
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, crate::BallisticsCorePlugin))
+            .insert_resource(Time::<Fixed>::from_hz(20.0))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .init_resource::<Reposition>()
+            // Flies 1 m per fixed step
+            .add_systems(FixedUpdate, |mut rounds: Query<&mut Transform, With<InterpolatedTransform>>| {
+                for mut transform in &mut rounds {
+                    transform.translation.z -= 1.0;
+                }
+            })
+            // Stands in for collisions running in Update, e.g. a ricochet moving the round
+            .add_systems(
+                Update,
+                |mut reposition: ResMut<Reposition>, mut rounds: Query<&mut Transform, With<InterpolatedTransform>>| {
+                    if let Some(position) = reposition.0.take() {
+                        for mut transform in &mut rounds {
+                            transform.translation = position;
+                        }
+                    }
+                },
+            );
+        let round = app
+            .world_mut()
+            .spawn((Transform::default(), InterpolatedTransform::default()))
+            .id();
+        for _ in 0..7 {
+            app.update();
+        }
+
+        // Repositioned in Update: rendered there, not blended back over it
+        let bounced = Vec3::new(5.0, 0.0, -1.0);
+        app.world_mut().resource_mut::<Reposition>().0 = Some(bounced);
+        app.update();
+        assert_eq!(app.world().get::<Transform>(round).unwrap().translation, bounced);
+
+        // The next fixed steps carry on from there
+        for _ in 0..6 {
+            app.update();
+        }
+        let current = app.world().get::<InterpolatedTransform>(round).unwrap().current;
+        assert_eq!(current.x, 5.0);
+        assert!(current.z < bounced.z);
+
+        // A teleport between frames is kept too
+        let teleport = Vec3::new(-3.0, 2.0, 0.0);
+        app.world_mut().get_mut::<Transform>(round).unwrap().translation = teleport;
+        app.update();
+        let rendered = app.world().get::<Transform>(round).unwrap().translation;
+        assert_eq!(rendered.truncate(), teleport.truncate());
+        assert!(rendered.z <= teleport.z && rendered.z >= teleport.z - 1.0);
+    }
+}
@@ -1,50 +1,426 @@
 //! Kinematics system - RK4 and Euler integration for projectile physics.
 
+use bevy::math::Affine3A;
 use bevy::prelude::*;
 
-use crate::components::Projectile;
-use crate::resources::{BallisticsConfig, BallisticsEnvironment};
+use crate::components::{DragRegime, Projectile, WindZone};
+use crate::resources::{BallisticsConfig, BallisticsEnvironment, CollisionSchedule, WeaponPresets};
+
+/// Maximum number of wind zones sampled per step.
+///
+/// Bounds the per-projectile cost of local wind; zones beyond this are ignored.
+pub const MAX_WIND_ZONES: usize = 32;
+
+/// Distance a round may cover in one fixed step before the timestep is flagged
+/// as too coarse (meters).
+///
+/// Longer steps make near-miss, proximity and thin-geometry checks unreliable.
+pub const MAX_STEP_DISTANCE: f32 = 10.0;
+
+/// Dynamic viscosity of air (Pa·s), used for Reynolds numbers.
+pub const AIR_DYNAMIC_VISCOSITY: f32 = 1.81e-5;
+
+/// Reynolds number at which linear and quadratic drag are equal.
+///
+/// Roughly where a sphere's drag coefficient levels off into the Newton regime.
+pub const REYNOLDS_CROSSOVER: f32 = 1000.0;
+
+/// Most substeps a single fixed step is split into.
+///
+/// Bounds the per-projectile cost of `BallisticsConfig::max_substep_distance`;
+/// past this, substeps get longer than the configured distance.
+pub const MAX_SUBSTEPS: u32 = 16;
+
+/// Number of integration substeps for a step covering `step_distance`.
+///
+/// # Arguments
+/// * `step_distance` - Approximate distance the round covers this step (meters)
+/// * `max_distance` - Longest allowed substep (meters, 0 = no substepping)
+///
+/// # Returns
+/// The substep count, from 1 to [`MAX_SUBSTEPS`]
+pub fn substep_count(step_distance: f32, max_distance: f32) -> u32 {
+    if max_distance <= 0.0 || step_distance.is_nan() || step_distance <= max_distance {
+        return 1;
+    }
+    ((step_distance / max_distance).ceil().min(MAX_SUBSTEPS as f32) as u32).max(1)
+}
+
+/// Apply `BallisticsConfig::fixed_timestep_hz` to `Time<Fixed>`.
+///
+/// Called by `BallisticsCorePlugin` once the app is built. Does nothing unless the
+/// app opted in with a rate; then `Time<Fixed>` is set to it (inserted if missing).
+/// Warns if the fastest `WeaponPresets` round would cover more than
+/// [`MAX_STEP_DISTANCE`] per step at the resulting rate.
+///
+/// # Arguments
+/// * `world` - The app's world
+pub fn apply_fixed_timestep(world: &mut World) {
+    let Some(hz) = world
+        .get_resource::<BallisticsConfig>()
+        .and_then(|config| config.fixed_timestep_hz)
+    else {
+        return;
+    };
+    if !(hz.is_finite() && hz > 0.0) {
+        warn!("Ignoring invalid BallisticsConfig::fixed_timestep_hz of {hz}");
+        return;
+    }
+
+    match world.get_resource_mut::<Time<Fixed>>() {
+        Some(mut time) => time.set_timestep_hz(hz),
+        None => world.insert_resource(Time::<Fixed>::from_hz(hz)),
+    }
+
+    let step = world.resource::<Time<Fixed>>().timestep().as_secs_f32();
+    let fastest = world.get_resource::<WeaponPresets>().and_then(|presets| {
+        presets
+            .presets
+            .iter()
+            .map(|preset| preset.muzzle_velocity)
+            .reduce(f32::max)
+    });
+    if let Some(fastest) = fastest.filter(|speed| speed * step > MAX_STEP_DISTANCE) {
+        warn!(
+            "FixedUpdate step of {:.1} ms lets a {fastest} m/s round travel {:.1} m per step; \
+             raise the rate to at least {:.0} Hz to avoid tunneling",
+            step * 1000.0,
+            fastest * step,
+            (fastest / MAX_STEP_DISTANCE).ceil()
+        );
+    }
+}
+
+/// A [`WindZone`] resolved to world space once per step.
+///
+/// Sampling rejects far-away points with a bounding-sphere check before doing
+/// the local-space shape test.
+#[derive(Clone, Copy)]
+pub struct ActiveWindZone {
+    zone: WindZone,
+    world_to_local: Affine3A,
+    rotation: Quat,
+    center: Vec3,
+    bounding_radius_sq: f32,
+}
+
+impl ActiveWindZone {
+    /// Snapshot a wind zone at its entity's current global transform.
+    pub fn new(transform: &GlobalTransform, zone: &WindZone) -> Self {
+        let (scale, rotation, center) = transform.to_scale_rotation_translation();
+        let bounding_radius = zone.shape.bounding_radius() * scale.abs().max_element();
+        Self {
+            zone: *zone,
+            world_to_local: transform.affine().inverse(),
+            rotation,
+            center,
+            bounding_radius_sq: bounding_radius * bounding_radius,
+        }
+    }
+
+    /// World-space wind velocity this zone adds at `point` (zero outside).
+    pub fn velocity_at(&self, point: Vec3) -> Vec3 {
+        if point.distance_squared(self.center) > self.bounding_radius_sq {
+            return Vec3::ZERO;
+        }
+        let local = self.world_to_local.transform_point3(point);
+        self.rotation * self.zone.velocity_at_local(local)
+    }
+}
+
+/// Sum of the local wind from every zone covering `point`.
+///
+/// # Arguments
+/// * `zones` - Wind zones resolved for this step
+/// * `point` - World-space sample position
+///
+/// # Returns
+/// The extra wind velocity to add to the environment wind (m/s)
+pub fn wind_zones_at(zones: &[ActiveWindZone], point: Vec3) -> Vec3 {
+    zones.iter().map(|zone| zone.velocity_at(point)).sum()
+}
 
 /// Update projectile positions using physics integration.
 ///
 /// Runs in FixedUpdate for deterministic simulation.
-/// Supports both RK4 (accurate) and Euler (fast) integration methods.
+/// Fast rounds are split into substeps per `BallisticsConfig::max_substep_distance`;
+/// `previous_position` still marks the start of the whole fixed step, so collision
+/// and near-miss sweeps cover the full displacement whatever the substep count.
+/// Supports both RK4 (accurate) and Euler (fast) integration methods. With the
+/// `deterministic` feature both run in fixed point (see [`crate::fixed_point`]).
+/// The step is scaled by `BallisticsConfig::time_scale`. A projectile's first step
+/// starts from its spawn position (see `BallisticsConfig::euler_first_step`).
+/// Projectiles whose position or velocity is no longer finite are left alone
+/// (with a warning) rather than spreading NaN through the rest of the simulation;
+/// `cleanup_expired_projectiles` then despawns them with `DespawnReason::NonFinite`.
 /// 
 /// # Arguments
 /// * `time` - Bevy FixedTime resource to get delta time
 /// * `env` - Ballistics environment resource with physics parameters
 /// * `config` - Ballistics configuration resource
 /// * `query` - Query for transform and projectile components to update
+/// * `wind_zones` - Query for localized wind zones (at most `MAX_WIND_ZONES` are used)
 pub fn update_projectiles_kinematics(
     time: Res<Time<Fixed>>,
     env: Res<BallisticsEnvironment>,
     config: Res<BallisticsConfig>,
-    mut query: Query<(&mut Transform, &mut Projectile)>,
+    mut query: Query<(Entity, &mut Transform, &mut Projectile)>,
+    wind_zones: Query<(&GlobalTransform, &WindZone)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
     let effective_density = env.effective_air_density();
+    let zones: Vec<ActiveWindZone> = wind_zones
+        .iter()
+        .take(MAX_WIND_ZONES)
+        .map(|(transform, zone)| ActiveWindZone::new(transform, zone))
+        .collect();
+
+    query.par_iter_mut().for_each(|(entity, mut transform, mut bullet)| {
+        if !(bullet.velocity.is_finite() && transform.translation.is_finite()) {
+            warn_once!(
+                "Skipping projectile {entity} with non-finite state (position {}, velocity {}); it will be despawned",
+                transform.translation,
+                bullet.velocity
+            );
+            return;
+        }
+        advance_projectile(
+            &mut transform,
+            &mut bullet,
+            dt,
+            &env,
+            &config,
+            effective_density,
+            &zones,
+        );
+    });
+}
 
-    query.par_iter_mut().for_each(|(mut transform, mut bullet)| {
-        // Store previous position for collision detection
+/// Advance a single projectile by one time step.
+///
+/// This is the per-projectile body of `update_projectiles_kinematics`, shared with
+/// [`step_projectile`] (and through it `predict_trajectory`) so previews and tools
+/// follow exactly the same path as live rounds. The system passes the density and
+/// wind zones it resolved once for the whole step.
+///
+/// # Arguments
+/// * `transform` - Mutable reference to the projectile transform
+/// * `bullet` - Mutable reference to the projectile component
+/// * `dt` - Time step in seconds
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+/// * `effective_density` - Scene-wide effective air density
+/// * `wind_zones` - Localized wind zones added on top of the environment wind
+fn advance_projectile(
+    transform: &mut Transform,
+    bullet: &mut Projectile,
+    dt: f32,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    effective_density: f32,
+    wind_zones: &[ActiveWindZone],
+) {
+    // Store previous position for collision detection. When collisions run in Update,
+    // the collision sweep owns it instead so that several fixed steps between frames
+    // are all covered by one sweep. A projectile's first step always starts from where
+    // it was spawned, whatever `previous_position` it was created with.
+    let first_step = bullet.age == 0.0;
+    if first_step || config.collision_schedule == CollisionSchedule::FixedUpdate {
         bullet.previous_position = transform.translation;
+    }
 
-        if config.use_rk4 {
+    // Substeps only refine the integration: `previous_position` keeps the start of
+    // the whole step, so the collision sweep still spans every substep.
+    let substeps = substep_count(bullet.velocity.length() * dt, config.max_substep_distance);
+    let sub_dt = dt / substeps as f32;
+    let mut travelled = 0.0;
+    for substep in 0..substeps {
+        let (air_density, wind) =
+            sample_air(env, config, transform.translation, effective_density, wind_zones);
+
+        let rk4 = config.use_rk4 && !(first_step && substep == 0 && config.euler_first_step);
+        if bullet.no_drag {
+            // Arcade round: exact parabola, no aerodynamics at all
+            integrate_parabola(transform, bullet, sub_dt, env);
+        } else if cfg!(feature = "deterministic") {
+            // Integer integration, bit-identical on every peer given the same inputs
+            #[cfg(feature = "deterministic")]
+            crate::fixed_point::integrate_transform(
+                transform,
+                bullet,
+                sub_dt,
+                &crate::fixed_point::FixedFlight::new(
+                    bullet,
+                    env,
+                    air_density,
+                    wind,
+                    config.gravity_velocity_cutoff,
+                ),
+                rk4,
+            );
+        } else if rk4 {
             // RK4 Integration - More accurate
-            integrate_rk4(&mut transform, &mut bullet, dt, &env, effective_density);
+            integrate_rk4(transform, bullet, sub_dt, env, air_density, wind, config.gravity_velocity_cutoff);
         } else {
             // Euler Integration - Simpler, faster
-            integrate_euler(&mut transform, &mut bullet, dt, &env, effective_density);
+            integrate_euler(transform, bullet, sub_dt, env, air_density, wind, config.gravity_velocity_cutoff);
         }
+        travelled += bullet.velocity.length() * sub_dt;
+    }
 
-        // Update age and distance
-        bullet.age += dt;
-        bullet.distance_travelled += bullet.velocity.length() * dt;
+    // Update age and distance
+    let speed = bullet.velocity.length();
+    bullet.age += dt;
+    bullet.distance_travelled += travelled;
+    bullet.is_supersonic = env.is_supersonic(speed);
 
-        // Update transform rotation to face velocity direction
-        if bullet.velocity.length_squared() > 0.001 {
-            transform.look_to(bullet.velocity.normalize(), Vec3::Y);
-        }
-    });
+    // Update transform rotation to face velocity direction
+    if bullet.velocity.length_squared() > 0.001 {
+        transform.look_to(bullet.velocity.normalize(), env.up_axis);
+    }
+}
+
+/// Advance one projectile by one step, outside the ECS.
+///
+/// Does exactly what `update_projectiles_kinematics` does to each projectile:
+/// integrates position and velocity, sets `previous_position`, `age`,
+/// `distance_travelled` and `is_supersonic`, and turns the transform to face
+/// the velocity. Entity-attached [`WindZone`]s are not included. Useful for
+/// precise tests and offline trajectory tools.
+///
+/// # Arguments
+/// * `projectile` - Projectile to advance
+/// * `transform` - Its transform, moved to the new position
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+/// * `dt` - Time step in seconds (already scaled by `time_scale`, if any)
+pub fn step_projectile(
+    projectile: &mut Projectile,
+    transform: &mut Transform,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    dt: f32,
+) {
+    advance_projectile(transform, projectile, dt, env, config, env.effective_air_density(), &[]);
+}
+
+/// Predict the flight path of a projectile without spawning it.
+///
+/// Steps a copy of the projectile with the same integrator, wind and density model
+/// used by `update_projectiles_kinematics`, so the preview matches live flight.
+/// Entity-attached `WindZone`s are not included; only the environment wind is.
+///
+/// # Arguments
+/// * `origin` - Launch position in world space
+/// * `projectile` - Projectile to simulate; its `velocity` is the launch velocity
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+/// * `dt` - Time step in seconds (use the FixedUpdate period to match live flight)
+/// * `max_time` - Maximum simulated flight time in seconds
+///
+/// # Returns
+/// Positions after each step, starting with `origin`
+pub fn predict_trajectory(
+    origin: Vec3,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    dt: f32,
+    max_time: f32,
+) -> Vec<Vec3> {
+    if dt <= 0.0 {
+        return vec![origin];
+    }
+
+    let steps = (max_time / dt).ceil().max(0.0) as usize;
+    let mut transform = Transform::from_translation(origin);
+    let mut bullet = projectile.clone();
+    bullet.previous_position = origin;
+
+    let mut points = Vec::with_capacity(steps + 1);
+    points.push(origin);
+    for _ in 0..steps {
+        step_projectile(&mut bullet, &mut transform, env, config, dt);
+        points.push(transform.translation);
+    }
+    points
+}
+
+/// Net acceleration on a projectile from the environment, for custom integrators.
+///
+/// Gravity (scaled by `gravity_scale`), aerodynamic drag and the environment wind
+/// at the projectile's height, with the air density the kinematics system would use
+/// (see `BallisticsConfig::altitude_density`). This is the same derivative the
+/// built-in RK4 and Euler steps integrate, so driving a projectile with it (e.g.
+/// as an avian force) follows the crate's flight model. Entity-attached
+/// [`WindZone`]s are not included; sample them with [`wind_zones_at`] if needed.
+///
+/// # Arguments
+/// * `projectile` - Projectile at its current velocity
+/// * `position` - Projectile's current world-space position
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// The acceleration acting on the projectile (m/s²)
+pub fn apply_environment_forces(
+    projectile: &Projectile,
+    position: Vec3,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Vec3 {
+    let (air_density, wind) =
+        sample_air(env, config, position, env.effective_air_density(), &[]);
+    calculate_acceleration(
+        projectile,
+        projectile.velocity,
+        env,
+        air_density,
+        wind,
+        config.gravity_velocity_cutoff,
+    )
+}
+
+/// Air density and wind velocity at a projectile's position.
+///
+/// Wind is sampled at the projectile's height (constant wind if no profile),
+/// plus any local zones the projectile is inside.
+fn sample_air(
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    position: Vec3,
+    effective_density: f32,
+    wind_zones: &[ActiveWindZone],
+) -> (f32, Vec3) {
+    let height = env.height_of(position);
+    let wind = env.wind_at(height) + wind_zones_at(wind_zones, position);
+    (air_density_for(env, config, height, effective_density), wind)
+}
+
+/// Air density experienced by a projectile at the given height.
+///
+/// Returns the precomputed scene-wide density unless `altitude_density` is enabled,
+/// in which case the density is evaluated at the environment altitude plus the height.
+///
+/// # Arguments
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+/// * `height` - Projectile height (along the up axis) in meters
+/// * `effective_density` - Scene-wide effective air density
+///
+/// # Returns
+/// The air density to use for drag calculations
+fn air_density_for(
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    height: f32,
+    effective_density: f32,
+) -> f32 {
+    if config.altitude_density {
+        env.air_density_at(env.altitude + height)
+    } else {
+        effective_density
+    }
 }
 
 /// RK4 (Runge-Kutta 4th order) integration step.
@@ -58,21 +434,27 @@ pub fn update_projectiles_kinematics(
 /// * `dt` - Time step for the integration
 /// * `env` - Reference to the ballistics environment
 /// * `air_density` - Effective air density for drag calculations
+/// * `wind` - Wind velocity sampled at the projectile's position
+/// * `gravity_cutoff` - Speed above which gravity fades out
 fn integrate_rk4(
     transform: &mut Transform,
     bullet: &mut Projectile,
     dt: f32,
     env: &BallisticsEnvironment,
     air_density: f32,
+    wind: Vec3,
+    gravity_cutoff: f32,
 ) {
     let pos = transform.translation;
     let vel = bullet.velocity;
 
     // RK4 coefficients for acceleration
-    let k1 = calculate_acceleration(bullet, vel, env, air_density);
-    let k2 = calculate_acceleration(bullet, vel + k1 * (dt / 2.0), env, air_density);
-    let k3 = calculate_acceleration(bullet, vel + k2 * (dt / 2.0), env, air_density);
-    let k4 = calculate_acceleration(bullet, vel + k3 * dt, env, air_density);
+    let k1 = calculate_acceleration(bullet, vel, env, air_density, wind, gravity_cutoff);
+    let k2 =
+        calculate_acceleration(bullet, vel + k1 * (dt / 2.0), env, air_density, wind, gravity_cutoff);
+    let k3 =
+        calculate_acceleration(bullet, vel + k2 * (dt / 2.0), env, air_density, wind, gravity_cutoff);
+    let k4 = calculate_acceleration(bullet, vel + k3 * dt, env, air_density, wind, gravity_cutoff);
 
     // Weighted average of acceleration
     let final_accel = (k1 + k2 * 2.0 + k3 * 2.0 + k4) / 6.0;
@@ -94,27 +476,64 @@ fn integrate_rk4(
 /// * `dt` - Time step for the integration
 /// * `env` - Reference to the ballistics environment
 /// * `air_density` - Effective air density for drag calculations
+/// * `wind` - Wind velocity sampled at the projectile's position
+/// * `gravity_cutoff` - Speed above which gravity fades out
 fn integrate_euler(
     transform: &mut Transform,
     bullet: &mut Projectile,
     dt: f32,
     env: &BallisticsEnvironment,
     air_density: f32,
+    wind: Vec3,
+    gravity_cutoff: f32,
 ) {
-    let accel = calculate_acceleration(bullet, bullet.velocity, env, air_density);
+    let accel =
+        calculate_acceleration(bullet, bullet.velocity, env, air_density, wind, gravity_cutoff);
     bullet.velocity += accel * dt;
     transform.translation += bullet.velocity * dt;
 }
 
+/// Closed-form step under gravity alone, for [`Projectile::no_drag`] rounds.
+/// 
+/// Constant acceleration integrates exactly, so repeated steps stay on the
+/// parabola `p0 + v0*t + 0.5*g*t²` whatever the step size.
+/// 
+/// # Arguments
+/// * `transform` - Mutable reference to the transform component to update
+/// * `bullet` - Mutable reference to the projectile component
+/// * `dt` - Time step for the integration
+/// * `env` - Reference to the ballistics environment
+fn integrate_parabola(
+    transform: &mut Transform,
+    bullet: &mut Projectile,
+    dt: f32,
+    env: &BallisticsEnvironment,
+) {
+    let gravity = env.gravity * bullet.gravity_scale;
+    transform.translation += bullet.velocity * dt + 0.5 * gravity * dt * dt;
+    bullet.velocity += gravity * dt;
+}
+
 /// Calculate acceleration on projectile from gravity and aerodynamic drag.
 ///
-/// Uses the drag equation: F_drag = 0.5 * ρ * v² * Cd * A
+/// Uses the drag equation: F_drag = 0.5 * ρ * v² * Cd * A, or its linear or
+/// mixed form for other [`DragRegime`]s (see [`drag_force`]).
+///
+/// Gravity is scaled by the projectile's `gravity_scale`; drag is not. Above
+/// `gravity_cutoff` gravity is further scaled by `(gravity_cutoff / speed)²`,
+/// which is 1.0 at the cutoff so a decelerating round drops back in smoothly.
+///
+/// Drag acts on the air-relative velocity `v - wind`, so a crosswind pushes the
+/// projectile sideways and head/tailwinds change how quickly it slows down.
+/// A [`Projectile::no_drag`] round gets full gravity only.
 /// 
 /// # Arguments
 /// * `bullet` - Reference to the projectile component
 /// * `vel` - Current velocity vector of the projectile
 /// * `env` - Reference to the ballistics environment
 /// * `air_density` - Effective air density for drag calculations
+/// * `wind` - Wind velocity at the projectile's position
+/// * `gravity_cutoff` - Speed above which gravity fades out (`f32::INFINITY` for never)
 /// 
 /// # Returns
 /// The acceleration vector acting on the projectile
@@ -123,27 +542,99 @@ fn calculate_acceleration(
     vel: Vec3,
     env: &BallisticsEnvironment,
     air_density: f32,
+    wind: Vec3,
+    gravity_cutoff: f32,
 ) -> Vec3 {
+    let gravity = env.gravity * bullet.gravity_scale;
+    if bullet.no_drag {
+        return gravity;
+    }
+    let gravity = gravity * gravity_cutoff_factor(vel.length(), gravity_cutoff);
+
     // Velocity relative to air (accounting for wind)
-    let relative_vel = vel - env.wind;
+    let relative_vel = vel - wind;
     let speed = relative_vel.length();
 
     // Avoid division by zero for stationary projectiles
     if speed < 0.001 {
-        return env.gravity;
+        return gravity;
     }
 
     let direction = relative_vel.normalize();
 
-    // Drag force magnitude: 0.5 * ρ * v² * Cd * A
-    let drag_magnitude =
-        0.5 * air_density * speed.powi(2) * bullet.drag_coefficient * bullet.reference_area;
+    // Drag force magnitude for the round's regime (0.5 * ρ * v² * Cd * A when quadratic)
+    let drag_magnitude = drag_force(bullet, speed, air_density);
 
     // Drag acceleration = F_drag / mass (opposite to velocity direction)
     let drag_accel = direction * (drag_magnitude / bullet.mass);
 
     // Total acceleration = gravity - drag
-    env.gravity - drag_accel
+    gravity - drag_accel
+}
+
+/// Approximate Reynolds number of a round moving through air.
+///
+/// # Arguments
+/// * `speed` - Airspeed in m/s
+/// * `diameter` - Projectile diameter in meters
+/// * `air_density` - Air density in kg/m³
+///
+/// # Returns
+/// `ρ * v * d / μ`, with μ = [`AIR_DYNAMIC_VISCOSITY`]
+pub fn reynolds_number(speed: f32, diameter: f32, air_density: f32) -> f32 {
+    air_density * speed * diameter / AIR_DYNAMIC_VISCOSITY
+}
+
+/// Airspeed at which a round's Reynolds number reaches [`REYNOLDS_CROSSOVER`].
+///
+/// Linear and quadratic drag are equal at this speed. None when the diameter
+/// or air density is not positive, in which case every regime drags quadratically.
+pub fn drag_crossover_speed(diameter: f32, air_density: f32) -> Option<f32> {
+    (diameter > 0.0 && air_density > 0.0)
+        .then(|| REYNOLDS_CROSSOVER * AIR_DYNAMIC_VISCOSITY / (air_density * diameter))
+}
+
+/// Drag force on a projectile at the given airspeed (N).
+///
+/// `Quadratic` is the drag equation `0.5 * ρ * v² * Cd * A`. `Linear` replaces
+/// one factor of `v` with the crossover speed (see [`drag_crossover_speed`]).
+/// `Mixed` blends the two, weighting linear drag by `1 / (1 + Re / REYNOLDS_CROSSOVER)`,
+/// so fast rounds are all but purely quadratic. Wind is accounted for by
+/// passing the air-relative speed.
+///
+/// # Arguments
+/// * `bullet` - Projectile; its drag coefficient, area, diameter and regime are used
+/// * `speed` - Airspeed in m/s
+/// * `air_density` - Air density in kg/m³
+///
+/// # Returns
+/// The magnitude of the drag force, acting against the air-relative velocity
+pub fn drag_force(bullet: &Projectile, speed: f32, air_density: f32) -> f32 {
+    let factor = 0.5 * air_density * bullet.drag_coefficient * bullet.reference_area;
+    let quadratic = factor * speed.powi(2);
+    let Some(crossover) = drag_crossover_speed(bullet.diameter, air_density) else {
+        return quadratic;
+    };
+    let linear = factor * crossover * speed;
+
+    match bullet.drag_regime {
+        DragRegime::Quadratic => quadratic,
+        DragRegime::Linear => linear,
+        DragRegime::Mixed => {
+            let reynolds = reynolds_number(speed, bullet.diameter, air_density);
+            let linear_share = 1.0 / (1.0 + reynolds / REYNOLDS_CROSSOVER);
+            linear * linear_share + quadratic * (1.0 - linear_share)
+        }
+    }
+}
+
+/// Share of gravity applied at `speed` under a gravity velocity cutoff.
+fn gravity_cutoff_factor(speed: f32, cutoff: f32) -> f32 {
+    if speed <= cutoff {
+        1.0
+    } else {
+        (cutoff / speed).powi(2)
+    }
 }
 
 /// System to update projectile guidance towards target.
@@ -153,14 +644,16 @@ fn calculate_acceleration(
 /// 
 /// # Arguments
 /// * `time` - Bevy Time resource
+/// * `config` - Ballistics configuration resource (time scale)
 /// * `projectiles` - Query for guided projectiles
 /// * `transforms` - Query for global transforms (targets)
 pub fn update_guidance(
     time: Res<Time>,
+    config: Res<BallisticsConfig>,
     mut projectiles: Query<(&mut Projectile, &Transform, &mut crate::components::Guidance)>,
     transforms: Query<&GlobalTransform>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
 
     for (mut projectile, transform, mut guidance) in projectiles.iter_mut() {
         guidance.elapsed += dt;
@@ -176,56 +669,105 @@ pub fn update_guidance(
         };
 
         if let Ok(target_transform) = transforms.get(target_entity) {
-            let target_pos = target_transform.translation();
-            let current_pos = transform.translation;
-            let current_vel = projectile.velocity;
-
-            let direction_to_target = (target_pos - current_pos).normalize_or_zero();
-            
-            // Avoid steering if already there or zero velocity
-            if direction_to_target.length_squared() < 0.001 || current_vel.length_squared() < 0.001 {
-                continue;
+            let steered = steer_towards(
+                projectile.velocity,
+                transform.translation,
+                target_transform.translation(),
+                guidance.turn_rate * dt,
+            );
+            if steered != projectile.velocity {
+                projectile.velocity = steered;
             }
+        }
+    }
+}
 
-            let current_dir = current_vel.normalize();
-            let speed = current_vel.length();
-
-            // Calculate rotation needed
-            // Actually simpler: rotate `current_dir` towards `direction_to_target`
-            
-            // Calculate angle between current velocity and target direction
-            let angle = current_dir.angle_between(direction_to_target);
-            
-            // Limit by turn rate
-            let max_turn = guidance.turn_rate * dt;
-            
-            if angle < 0.001 {
-                continue;
-            }
+/// Turn a velocity toward a point, keeping its speed.
+/// 
+/// The steering step of [`update_guidance`]: the direction rotates toward
+/// `target` by at most `max_turn` radians. Velocities that are zero, already
+/// on target, or pointing straight away from it are returned unchanged.
+/// 
+/// # Arguments
+/// * `velocity` - Current velocity
+/// * `position` - Current position
+/// * `target` - Point to steer toward
+/// * `max_turn` - Largest rotation allowed this step (radians)
+/// 
+/// # Returns
+/// The steered velocity
+pub fn steer_towards(velocity: Vec3, position: Vec3, target: Vec3, max_turn: f32) -> Vec3 {
+    let direction_to_target = (target - position).normalize_or_zero();
 
-            let new_dir = if angle <= max_turn {
-                direction_to_target
-            } else {
-                // Slerp rotation
-                // Find rotation axis
-                let rotation_axis = current_dir.cross(direction_to_target).normalize_or_zero();
-                if rotation_axis.length_squared() < 0.001 {
-                    // Vectors are parallel or anti-parallel
-                     continue; 
-                }
-                let rotation = Quat::from_axis_angle(rotation_axis, max_turn);
-                rotation * current_dir
-            };
-
-            projectile.velocity = new_dir * speed;
-        }
+    // Avoid steering if already there or zero velocity
+    if direction_to_target.length_squared() < 0.001 || velocity.length_squared() < 0.001 {
+        return velocity;
+    }
+
+    let current_dir = velocity.normalize();
+    let speed = velocity.length();
+
+    // Calculate angle between current velocity and target direction
+    let angle = current_dir.angle_between(direction_to_target);
+    if angle < 0.001 {
+        return velocity;
     }
+
+    let new_dir = if angle <= max_turn {
+        direction_to_target
+    } else {
+        // Rotate about the axis between the two directions
+        let rotation_axis = current_dir.cross(direction_to_target).normalize_or_zero();
+        if rotation_axis.length_squared() < 0.001 {
+            // Vectors are parallel or anti-parallel
+            return velocity;
+        }
+        Quat::from_axis_angle(rotation_axis, max_turn) * current_dir
+    };
+
+    new_dir * speed
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fixed_timestep_applied_only_on_opt_in() {
+        use std::time::Duration;
+
+        // Default: Bevy's rate, or whatever the app chose, stays
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, crate::BallisticsCorePlugin));
+        app.finish();
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().timestep(),
+            Time::<Fixed>::default().timestep()
+        );
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, crate::BallisticsCorePlugin))
+            .insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.finish();
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().timestep(),
+            Duration::from_secs_f64(1.0 / 30.0)
+        );
+
+        // Opted in: the configured rate wins
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, crate::BallisticsCorePlugin))
+            .insert_resource(BallisticsConfig {
+                fixed_timestep_hz: Some(120.0),
+                ..Default::default()
+            });
+        app.finish();
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().timestep(),
+            Time::<Fixed>::from_hz(120.0).timestep()
+        );
+    }
+
     #[test]
     fn test_drag_calculation() {
         let bullet = Projectile {
@@ -237,7 +779,7 @@ mod tests {
         };
 
         let env = BallisticsEnvironment::default();
-        let accel = calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density);
+        let accel = calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density, env.wind, f32::INFINITY);
 
         // Should have downward gravity component
         assert!(accel.y < 0.0);
@@ -254,9 +796,554 @@ mod tests {
         };
 
         let env = BallisticsEnvironment::default();
-        let accel = calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density);
+        let accel = calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density, env.wind, f32::INFINITY);
 
         // Only gravity should apply
         assert_eq!(accel, env.gravity);
     }
+
+    #[test]
+    fn test_substeps_keep_sweep_over_whole_step() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig {
+            max_substep_distance: 1.0,
+            ..Default::default()
+        };
+        let dt = 1.0 / 60.0;
+        assert_eq!(substep_count(2000.0 * dt, 1.0), MAX_SUBSTEPS);
+        assert_eq!(substep_count(2000.0 * dt, 0.0), 1);
+        assert_eq!(substep_count(0.5, 1.0), 1);
+
+        // A 1 cm plate 50 m out, at a different phase of the step for every launch point
+        let plate = -50.0..=-49.99;
+        for offset in 0..16 {
+            let origin = Vec3::new(0.0, 0.0, offset as f32 * -2.1);
+            let mut transform = Transform::from_translation(origin);
+            let mut bullet = Projectile::new(Vec3::NEG_Z * 2000.0).with_diameter(0.00762);
+            let mut crossed = false;
+            for _ in 0..4 {
+                let start = transform.translation;
+                advance_projectile(&mut transform, &mut bullet, dt, &env, &config, env.air_density, &[]);
+                // The sweep runs from the start of the step, not the last substep
+                assert_eq!(bullet.previous_position, start);
+                assert!(start.z - transform.translation.z > 30.0);
+                crossed |= transform.translation.z <= *plate.end() && start.z >= *plate.start();
+            }
+            assert!(crossed, "launch from {origin} skipped the plate");
+        }
+
+        // Substepping refines the same flight rather than changing it
+        let flight = |config: &BallisticsConfig| {
+            let round = Projectile::new(Vec3::new(0.0, 50.0, -2000.0)).with_diameter(0.00762);
+            predict_trajectory(Vec3::ZERO, &round, &env, config, dt, 1.0)
+        };
+        let substepped = *flight(&config).last().unwrap();
+        let single = *flight(&BallisticsConfig::default()).last().unwrap();
+        assert!(substepped.distance(single) < 0.01 * single.length(), "{substepped} vs {single}");
+    }
+
+    #[test]
+    fn test_first_step_starts_at_spawn_position() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig {
+            collision_schedule: CollisionSchedule::Update,
+            ..Default::default()
+        };
+        let muzzle = Vec3::new(10.0, 1.5, 0.0);
+        let mut transform = Transform::from_translation(muzzle);
+        // Spawned without a previous position
+        let mut bullet = Projectile::new(Vec3::NEG_Z * 1200.0);
+        let dt = 1.0 / 60.0;
+
+        advance_projectile(&mut transform, &mut bullet, dt, &env, &config, env.air_density, &[]);
+        assert_eq!(bullet.previous_position, muzzle);
+        // Plain Euler step: moved by exactly the updated velocity
+        let euler = muzzle + bullet.velocity * dt;
+        assert!(transform.translation.distance(euler) < 1e-4);
+
+        // Later steps leave previous_position to the Update-schedule sweep
+        advance_projectile(&mut transform, &mut bullet, dt, &env, &config, env.air_density, &[]);
+        assert_eq!(bullet.previous_position, muzzle);
+    }
+
+    #[test]
+    fn test_environment_forces_match_euler_step() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(6.0, 0.0, 2.0),
+            ..Default::default()
+        };
+        let config = BallisticsConfig {
+            use_rk4: false,
+            altitude_density: true,
+            ..Default::default()
+        };
+        let position = Vec3::new(0.0, 250.0, 0.0);
+        let mut projectile = Projectile::new(Vec3::new(0.0, 30.0, -800.0));
+        projectile.age = 0.5;
+        let dt = 1.0 / 60.0;
+
+        let accel = apply_environment_forces(&projectile, position, &env, &config);
+        let expected_velocity = projectile.velocity + accel * dt;
+        let expected_position = position + expected_velocity * dt;
+
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut world = World::new();
+        world.insert_resource(env);
+        world.insert_resource(config);
+        world.insert_resource(Time::<Fixed>::default());
+        let round = world
+            .spawn((Transform::from_translation(position), projectile))
+            .id();
+        world
+            .resource_mut::<Time<Fixed>>()
+            .advance_by(Duration::from_secs_f32(dt));
+        world.run_system_once(update_projectiles_kinematics).unwrap();
+
+        let stepped = world.get::<Projectile>(round).unwrap().velocity;
+        let moved = world.get::<Transform>(round).unwrap().translation;
+        assert!(stepped.distance(expected_velocity) < 1e-4, "{stepped} vs {expected_velocity}");
+        assert!(moved.distance(expected_position) < 1e-4, "{moved} vs {expected_position}");
+    }
+
+    #[test]
+    fn test_manual_steps_match_prediction_and_system() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(4.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        let config = BallisticsConfig::default();
+        let origin = Vec3::new(0.0, 1.5, 0.0);
+        let launch = Projectile::new(Vec3::new(0.0, 20.0, -600.0)).with_diameter(0.00762);
+        let dt = 1.0 / 60.0;
+        let steps = 30;
+
+        let predicted = predict_trajectory(origin, &launch, &env, &config, dt, steps as f32 * dt);
+
+        let mut manual = launch.clone();
+        let mut transform = Transform::from_translation(origin);
+        let mut travelled = 0.0;
+        for point in predicted.iter().skip(1) {
+            let before = transform.translation;
+            step_projectile(&mut manual, &mut transform, &env, &config, dt);
+            travelled += transform.translation.distance(before);
+            assert_eq!(transform.translation, *point);
+        }
+        assert_eq!(predicted.len(), steps + 1);
+        assert!((manual.age - steps as f32 * dt).abs() < 1e-5);
+        // Chord lengths of a gently curving path are close to the arc length
+        assert!((manual.distance_travelled - travelled).abs() / travelled < 0.01);
+
+        // The live system moves the round the same way
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut world = World::new();
+        world.insert_resource(env);
+        world.insert_resource(config);
+        world.insert_resource(Time::<Fixed>::default());
+        let round = world
+            .spawn((Transform::from_translation(origin), launch))
+            .id();
+        for _ in 0..steps {
+            world
+                .resource_mut::<Time<Fixed>>()
+                .advance_by(Duration::from_secs_f32(dt));
+            world.run_system_once(update_projectiles_kinematics).unwrap();
+        }
+        let live = world.get::<Transform>(round).unwrap().translation;
+        assert!(live.distance(transform.translation) < 1e-3, "{live} vs {}", transform.translation);
+    }
+
+    #[test]
+    fn test_system_skips_non_finite_projectiles() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut world = World::new();
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(Time::<Fixed>::default());
+        world
+            .resource_mut::<Time<Fixed>>()
+            .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+
+        let healthy = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 400.0)))
+            .id();
+        let corrupted = world
+            .spawn((
+                Transform::default(),
+                Projectile::new(Vec3::new(f32::NAN, 0.0, -400.0)),
+            ))
+            .id();
+        world.run_system_once(update_projectiles_kinematics).unwrap();
+
+        assert!(world.get::<Transform>(healthy).unwrap().translation.z < 0.0);
+        let skipped = world.get::<Projectile>(corrupted).unwrap();
+        assert_eq!(skipped.age, 0.0);
+        assert_eq!(world.get::<Transform>(corrupted).unwrap().translation, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_no_drag_flies_exact_parabola() {
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(15.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        let config = BallisticsConfig::default();
+        let p0 = Vec3::new(2.0, 1.5, 0.0);
+        let v0 = Vec3::new(0.0, 40.0, -250.0);
+        let dt = 1.0 / 60.0;
+
+        let mut transform = Transform::from_translation(p0);
+        let mut arcade = Projectile::new(v0).no_drag();
+        let mut dragged_transform = Transform::from_translation(p0);
+        let mut dragged = Projectile::new(v0);
+        for _ in 0..120 {
+            advance_projectile(&mut transform, &mut arcade, dt, &env, &config, env.air_density, &[]);
+            advance_projectile(&mut dragged_transform, &mut dragged, dt, &env, &config, env.air_density, &[]);
+        }
+
+        let t = 120.0 * dt;
+        let expected = p0 + v0 * t + 0.5 * env.gravity * t * t;
+        assert!(transform.translation.distance(expected) < 1e-3, "{} vs {expected}", transform.translation);
+        assert!(arcade.velocity.distance(v0 + env.gravity * t) < 1e-3);
+
+        // Same round with drag slows down and is carried by the wind
+        assert!(dragged.velocity.length() < arcade.velocity.length());
+        assert!(dragged_transform.translation.x > p0.x);
+    }
+
+    #[test]
+    fn test_gravity_cutoff_flattens_fast_rounds() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig {
+            gravity_velocity_cutoff: 600.0,
+            ..Default::default()
+        };
+        let drop_after = |speed: f32, config: &BallisticsConfig| {
+            let bullet = Projectile::new(Vec3::new(0.0, 0.0, -speed)).with_drag(0.0);
+            -predict_trajectory(Vec3::ZERO, &bullet, &env, config, 1.0 / 60.0, 0.5)
+                .last()
+                .unwrap()
+                .y
+        };
+
+        // Above the cutoff a round drops less than one below it
+        let fast = drop_after(900.0, &config);
+        let slow = drop_after(400.0, &config);
+        assert!(fast > 0.0 && fast < slow, "fast {fast}, slow {slow}");
+        // Below the cutoff nothing changes, and the default never cuts gravity
+        assert!((slow - drop_after(400.0, &BallisticsConfig::default())).abs() < 1e-5);
+        assert!(drop_after(900.0, &BallisticsConfig::default()) > fast);
+
+        // No pop when a round decelerates through the cutoff
+        assert_eq!(gravity_cutoff_factor(600.0, 600.0), 1.0);
+        assert!(1.0 - gravity_cutoff_factor(600.1, 600.0) < 1e-3);
+        assert_eq!(gravity_cutoff_factor(900.0, f32::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_gravity_scale() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let normal = Projectile::new(Vec3::new(0.0, 0.0, -300.0));
+        let floaty = normal.clone().with_gravity_scale(0.0);
+        let heavy = normal.clone().with_gravity_scale(2.0);
+
+        let end = |p: &Projectile| {
+            *predict_trajectory(Vec3::ZERO, p, &env, &config, 1.0 / 60.0, 1.0)
+                .last()
+                .unwrap()
+        };
+        let (normal_end, floaty_end, heavy_end) = (end(&normal), end(&floaty), end(&heavy));
+
+        // No gravity: flies flat, drag still slows it the same amount
+        assert_eq!(floaty_end.y, 0.0);
+        assert!((floaty_end.z - normal_end.z).abs() < 0.5);
+        assert!(normal_end.y < -1.0);
+        assert!(heavy_end.y < normal_end.y * 1.9);
+
+        // Stationary projectile falls at the scaled rate
+        let accel = calculate_acceleration(&heavy, Vec3::ZERO, &env, env.air_density, env.wind, f32::INFINITY);
+        assert_eq!(accel, env.gravity * 2.0);
+    }
+
+    #[test]
+    fn test_wind_profile_two_layers() {
+        let env = BallisticsEnvironment::default().with_wind_profile(vec![
+            (100.0, Vec3::new(10.0, 0.0, 0.0)),
+            (0.0, Vec3::new(2.0, 0.0, 0.0)),
+        ]);
+
+        // Interpolates between layers
+        assert!((env.wind_at(50.0).x - 6.0).abs() < 1e-5);
+        assert!((env.wind_at(25.0).x - 4.0).abs() < 1e-5);
+        // Clamps below and above the profile
+        assert_eq!(env.wind_at(-20.0), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(env.wind_at(500.0), Vec3::new(10.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_empty_wind_profile_matches_constant_wind() {
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(3.0, 0.0, -1.0),
+            ..Default::default()
+        };
+        let bullet = Projectile::new(Vec3::new(0.0, 0.0, -400.0));
+
+        assert_eq!(env.wind_at(1234.0), env.wind);
+        assert_eq!(
+            calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density, env.wind_at(10.0), f32::INFINITY),
+            calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density, env.wind, f32::INFINITY),
+        );
+    }
+
+    /// Fly a rifle round along -Z and record its position each time it passes a range mark.
+    fn positions_at_ranges(env: &BallisticsEnvironment, ranges: &[f32]) -> Vec<Vec3> {
+        let mut transform = Transform::default();
+        let mut bullet = Projectile::new(Vec3::new(0.0, 0.0, -800.0)).with_reference_area(0.00005);
+        let density = env.effective_air_density();
+        let dt = 1.0 / 120.0;
+
+        let mut positions = Vec::with_capacity(ranges.len());
+        let mut previous = transform.translation;
+        for &range in ranges {
+            while -transform.translation.z < range {
+                previous = transform.translation;
+                let wind = env.wind_at(transform.translation.y);
+                integrate_rk4(&mut transform, &mut bullet, dt, env, density, wind, f32::INFINITY);
+            }
+            // Interpolate back to the exact range mark
+            let current = transform.translation;
+            let t = (range + previous.z) / (previous.z - current.z);
+            positions.push(previous.lerp(current, t));
+        }
+        positions
+    }
+
+    #[test]
+    fn test_crosswind_deflection_grows_with_range() {
+        let env = BallisticsEnvironment {
+            wind: Vec3::new(5.0, 0.0, 0.0),
+            ..Default::default()
+        };
+        let positions = positions_at_ranges(&env, &[100.0, 200.0, 300.0]);
+
+        assert!(positions[0].x > 0.0);
+        assert!(positions[1].x > positions[0].x);
+        assert!(positions[2].x > positions[1].x);
+    }
+
+    #[test]
+    fn test_headwind_and_tailwind_change_drop() {
+        let calm = BallisticsEnvironment::default();
+        let headwind = BallisticsEnvironment {
+            wind: Vec3::new(0.0, 0.0, 10.0),
+            ..Default::default()
+        };
+        let tailwind = BallisticsEnvironment {
+            wind: Vec3::new(0.0, 0.0, -10.0),
+            ..Default::default()
+        };
+
+        let calm_drop = -positions_at_ranges(&calm, &[300.0])[0].y;
+        let head_drop = -positions_at_ranges(&headwind, &[300.0])[0].y;
+        let tail_drop = -positions_at_ranges(&tailwind, &[300.0])[0].y;
+
+        assert!(head_drop > calm_drop);
+        assert!(tail_drop < calm_drop);
+    }
+
+    #[test]
+    fn test_zero_wind_drag_uses_plain_velocity() {
+        let env = BallisticsEnvironment::default();
+        let bullet = Projectile::new(Vec3::new(300.0, 20.0, -500.0));
+
+        // Drag computed directly from the projectile velocity
+        let speed = bullet.velocity.length();
+        let drag = bullet.velocity.normalize()
+            * (0.5 * env.air_density * speed.powi(2) * bullet.drag_coefficient * bullet.reference_area
+                / bullet.mass);
+
+        assert_eq!(
+            calculate_acceleration(&bullet, bullet.velocity, &env, env.air_density, Vec3::ZERO, f32::INFINITY),
+            env.gravity - drag,
+        );
+    }
+
+    #[test]
+    fn test_linear_drag_stops_slow_glob_sooner() {
+        let env = BallisticsEnvironment {
+            gravity: Vec3::ZERO,
+            ..Default::default()
+        };
+        let config = BallisticsConfig::default();
+        let dt = 1.0 / 60.0;
+
+        // Slow, light, draggy glob: well below the crossover Reynolds number
+        let glob = Projectile::new(Vec3::X * 10.0)
+            .with_diameter(0.005)
+            .with_mass(5.2e-5)
+            .with_drag(1.0);
+
+        let time_to_stop = |regime: DragRegime| {
+            let mut projectile = glob.clone().with_drag_regime(regime);
+            let mut transform = Transform::default();
+            let mut time = 0.0;
+            while projectile.velocity.length() > 0.1 && time < 60.0 {
+                step_projectile(&mut projectile, &mut transform, &env, &config, dt);
+                time += dt;
+            }
+            time
+        };
+
+        let quadratic = time_to_stop(DragRegime::Quadratic);
+        let linear = time_to_stop(DragRegime::Linear);
+        let mixed = time_to_stop(DragRegime::Mixed);
+        assert!(linear < quadratic * 0.3, "linear {linear}s vs quadratic {quadratic}s");
+        // Mixed is close to linear this far below the crossover Reynolds number
+        assert!(mixed >= linear && mixed < quadratic * 0.3);
+    }
+
+    #[test]
+    fn test_mixed_drag_stays_quadratic_for_fast_rounds() {
+        let air_density = BallisticsEnvironment::default().air_density;
+        let round = Projectile::new(Vec3::X * 800.0).with_diameter(0.00762);
+        let mixed = round.clone().with_drag_regime(DragRegime::Mixed);
+
+        assert!(reynolds_number(800.0, 0.00762, air_density) > 100.0 * REYNOLDS_CROSSOVER);
+        let quadratic_force = drag_force(&round, 800.0, air_density);
+        let mixed_force = drag_force(&mixed, 800.0, air_density);
+        assert!((mixed_force - quadratic_force).abs() < quadratic_force * 0.005);
+
+        // Linear and quadratic drag meet at the crossover speed
+        let crossover = drag_crossover_speed(0.00762, air_density).unwrap();
+        let linear = round.clone().with_drag_regime(DragRegime::Linear);
+        let at_crossover = drag_force(&round, crossover, air_density);
+        assert!((drag_force(&linear, crossover, air_density) - at_crossover).abs() < at_crossover * 1e-4);
+    }
+
+    #[test]
+    fn test_altitude_density_lowers_drag_at_apex() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig {
+            altitude_density: true,
+            ..Default::default()
+        };
+        let effective = env.effective_air_density();
+
+        // Lob a slow, draggy round steeply upwards and track its apex
+        let mut transform = Transform::default();
+        let mut bullet = Projectile::new(Vec3::new(0.0, 260.0, -150.0)).with_reference_area(0.00002);
+        let mut apex = 0.0_f32;
+        for _ in 0..6000 {
+            let density = air_density_for(&env, &config, transform.translation.y, effective);
+            integrate_rk4(&mut transform, &mut bullet, 1.0 / 120.0, &env, density, env.wind, f32::INFINITY);
+            apex = apex.max(transform.translation.y);
+            if bullet.velocity.y < 0.0 {
+                break;
+            }
+        }
+        assert!(apex > 1000.0);
+
+        let launch_density = air_density_for(&env, &config, 0.0, effective);
+        let apex_density = air_density_for(&env, &config, apex, effective);
+        assert!(apex_density < launch_density);
+
+        // Same velocity, less drag at apex than at launch
+        let probe = Vec3::new(0.0, 0.0, -300.0);
+        let drag_at = |density: f32| {
+            (calculate_acceleration(&bullet, probe, &env, density, env.wind, f32::INFINITY) - env.gravity).length()
+        };
+        let launch_drag = drag_at(launch_density);
+        let apex_drag = drag_at(apex_density);
+        assert!(apex_drag < launch_drag);
+
+        // Flag off: density stays scene-wide regardless of height
+        let flat = BallisticsConfig::default();
+        assert_eq!(air_density_for(&env, &flat, apex, effective), effective);
+    }
+
+    #[test]
+    fn test_2d_projectile_falls_in_xy_plane() {
+        let config = BallisticsConfig::default();
+        let grenade = Projectile::new(Vec3::new(10.0, 10.0, 0.0)).with_mass(0.5);
+
+        let env = BallisticsEnvironment::new_2d();
+        let path = predict_trajectory(Vec3::ZERO, &grenade, &env, &config, 1.0 / 60.0, 2.5);
+        let apex = path.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+        let end = *path.last().unwrap();
+
+        // Rises, then comes back down past the launch height while moving forward
+        assert!(apex > 3.0);
+        assert!(end.y < 0.0);
+        assert!(end.x > 15.0);
+        assert!(path.iter().all(|p| p.z == 0.0));
+
+        // Flipped up axis (screen-space Y down): same arc, mirrored
+        let flipped = BallisticsEnvironment::new_2d().with_up_axis(Vec3::NEG_Y);
+        assert_eq!(flipped.gravity, Vec3::new(0.0, 9.81, 0.0));
+        let thrown_up = Projectile { velocity: Vec3::new(10.0, -10.0, 0.0), ..grenade };
+        let mirrored = predict_trajectory(Vec3::ZERO, &thrown_up, &flipped, &config, 1.0 / 60.0, 2.5);
+        let mirrored_end = *mirrored.last().unwrap();
+        assert!((mirrored_end.y + end.y).abs() < 1e-3);
+        assert!((mirrored_end.x - end.x).abs() < 1e-3);
+        assert_eq!(flipped.height_of(mirrored_end), end.y);
+    }
+
+    #[test]
+    fn test_wind_zone_deflects_only_inside() {
+        use crate::components::WindZoneShape;
+
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let density = env.effective_air_density();
+
+        // Crosswind fan covering z in -120..-80, blowing along +X
+        let fan = WindZone::new(
+            WindZoneShape::Box { half_extents: Vec3::new(5.0, 5.0, 20.0) },
+            Vec3::new(30.0, 0.0, 0.0),
+        );
+        let zones = [ActiveWindZone::new(
+            &GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -100.0)),
+            &fan,
+        )];
+
+        let mut transform = Transform::default();
+        let mut bullet = Projectile::new(Vec3::new(0.0, 0.0, -400.0));
+        let mut lateral_before_exit = 0.0;
+        while transform.translation.z > -200.0 {
+            let z = transform.translation.z;
+            let before = bullet.velocity.x;
+            advance_projectile(&mut transform, &mut bullet, 1.0 / 120.0, &env, &config, density, &zones);
+
+            if z > -80.0 {
+                assert_eq!(bullet.velocity.x, 0.0);
+            } else if z > -120.0 {
+                assert!(bullet.velocity.x > before);
+                lateral_before_exit = bullet.velocity.x;
+            } else {
+                // Past the fan: drag only bleeds off the sideways drift
+                assert!(bullet.velocity.x <= before);
+            }
+        }
+        assert!(lateral_before_exit > 0.0);
+        assert!(transform.translation.x > 0.0);
+
+        // Zone wind adds to the environment wind
+        let point = Vec3::new(0.0, 0.0, -100.0);
+        let windy = BallisticsEnvironment {
+            wind: Vec3::new(0.0, 0.0, 5.0),
+            ..Default::default()
+        };
+        let total = windy.wind_at(0.0) + wind_zones_at(&zones, point);
+        assert_eq!(total, Vec3::new(30.0, 0.0, 5.0));
+        assert_eq!(wind_zones_at(&zones, Vec3::new(0.0, 0.0, -50.0)), Vec3::ZERO);
+    }
 }
@@ -3,63 +3,416 @@
 use bevy::prelude::*;
 use bevy::ecs::message::{MessageWriter, MessageReader};
 
-use crate::components::{Payload, ProjectileLogic};
-use crate::events::{ExplosionEvent, ExplosionType};
+use crate::components::{
+    BounceSurface, FalloffCurve, FragmentGrace, Fragmentation, NetProjectile, Payload, Projectile,
+    ProjectileLogic, ProximityTarget, SurfaceMaterial,
+};
+use crate::events::{
+    DespawnReason, ExplosionEvent, ExplosionType, ProjectileDespawnedEvent, ProjectileSpawnedEvent,
+};
+use crate::resources::{
+    BallisticsConfig, BallisticsEnvironment, OrphanPolicy, ProjectilesByOwner, RecentExplosions,
+    SpatialGrid,
+};
+use crate::systems::spatial::closest_point_on_segment;
+use crate::types::ProjectileRng;
+
+/// Half-angle (radians) of the cone submunitions are scattered in.
+const SUBMUNITION_SPREAD: f32 = 0.6;
+
+/// Thickness (meters) of penetrable cover that stops an explosion completely.
+pub const EXPLOSION_COVER_STOP_THICKNESS: f32 = 0.3;
+
+/// Maximum pieces of cover considered between an explosion and one entity.
+#[cfg(any(feature = "dim3", feature = "dim2"))]
+const MAX_EXPLOSION_COVER_HITS: u32 = 8;
 
 /// Process projectile-specific logic (timers, proximity triggers).
 /// 
 /// This system handles special projectile behaviors like timed fuses,
 /// proximity triggers, and other logic that's not handled by the collision system.
 /// 
+/// Cluster payloads deploy their submunitions instead of exploding, either when
+/// the fuse fires or when the projectile descends to its `deploy_altitude`.
+/// Projectiles with `Fragmentation` throw out their shards when they detonate.
+/// At most `BallisticsConfig::max_submunitions` submunitions and shards are
+/// spawned per step in total.
+/// 
 /// # Arguments
 /// * `commands` - Bevy Commands for entity manipulation
 /// * `time` - Bevy FixedTime resource to get delta time
+/// * `env` - Ballistics environment (up axis for deploy altitude)
+/// * `config` - Ballistics configuration (submunition cap)
 /// * `explosion_events` - Message writer for explosion events
+/// * `spawned_events` - Message writer for spawned submunitions
+/// * `proximity_targets` - Spatial grid of proximity targets, rebuilt this step
 /// * `projectiles` - Query for projectile entities and their components
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn process_projectile_logic(
     mut commands: Commands,
     time: Res<Time<Fixed>>,
+    env: Res<BallisticsEnvironment>,
+    config: Res<BallisticsConfig>,
     mut explosion_events: MessageWriter<ExplosionEvent>,
-    mut projectiles: Query<(Entity, &Transform, &mut ProjectileLogic, Option<&Payload>)>,
+    mut spawned_events: MessageWriter<ProjectileSpawnedEvent>,
+    proximity_targets: Res<SpatialGrid<ProximityTarget>>,
+    mut projectiles: Query<(
+        Entity,
+        &Transform,
+        &mut ProjectileLogic,
+        Option<&Payload>,
+        Option<&Projectile>,
+        Option<&Fragmentation>,
+        Option<&NetProjectile>,
+        Option<&BounceSurface>,
+    )>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
+    let mut submunition_budget = config.max_submunitions;
 
-    for (entity, transform, mut logic, payload) in projectiles.iter_mut() {
-        match logic.as_mut() {
+    for (entity, transform, mut logic, payload, projectile, fragmentation, net, bounced) in
+        projectiles.iter_mut()
+    {
+        let triggered = match logic.as_mut() {
             ProjectileLogic::Timed { fuse, elapsed } => {
+                // A grenade cooked past its fuse is already due and goes off this step
                 *elapsed += dt;
-                if *elapsed >= *fuse {
-                    // Trigger explosion based on payload
-                    trigger_explosion(
-                        &mut commands,
-                        &mut explosion_events,
-                        entity,
-                        transform.translation,
-                        payload,
-                    );
-                }
+                *elapsed >= *fuse
             }
-            ProjectileLogic::Proximity { range: _ } => {
-                // TODO: Query nearby entities and check distance
-                // For now, this is a placeholder
+            ProjectileLogic::Proximity { range } => {
+                proximity_triggered(&proximity_targets, transform.translation, projectile, *range)
             }
             ProjectileLogic::Impact | ProjectileLogic::Sticky => {
                 // Handled by collision system
+                false
             }
             ProjectileLogic::Hitscan { .. } => {
                 // Handled by process_hitscan system (or ignored if not dim3)
+                false
+            }
+        };
+
+        if let (Some(Payload::Cluster { deploy_altitude, .. }), Some(projectile)) = (payload, projectile) {
+            let descending = projectile.velocity.dot(env.up_axis) < 0.0;
+            let below = env.height_of(transform.translation) <= *deploy_altitude;
+            if triggered || (descending && below) {
+                // Networked rounds share their seed; local ones only need to differ
+                let rng = net.map_or(ProjectileRng::new(entity.to_bits()), ProjectileRng::from);
+                let spawned = deploy_cluster(
+                    &mut commands,
+                    &mut spawned_events,
+                    entity,
+                    transform.translation,
+                    projectile,
+                    payload.unwrap(),
+                    rng,
+                    submunition_budget,
+                );
+                submunition_budget -= spawned;
             }
+            continue;
+        }
+
+        if triggered {
+            if let Some(fragmentation) = fragmentation {
+                // Networked rounds share their seed; local ones only need to differ
+                let rng = net.map_or(ProjectileRng::new(entity.to_bits()), ProjectileRng::from);
+                submunition_budget -= spawn_fragments(
+                    &mut commands,
+                    &mut spawned_events,
+                    entity,
+                    transform.translation,
+                    fragmentation,
+                    rng,
+                    projectile.and_then(|p| p.owner),
+                    // A grenade handed to avian no longer has a `Projectile`
+                    projectile
+                        .and_then(|p| p.last_hit)
+                        .or(bounced.map(|surface| surface.0)),
+                    submunition_budget,
+                );
+            }
+
+            // Trigger explosion based on payload
+            trigger_explosion(
+                &mut commands,
+                &mut explosion_events,
+                entity,
+                transform.translation,
+                payload,
+                projectile.and_then(|p| p.owner),
+            );
+        }
+    }
+}
+
+/// Replace a cluster projectile with its submunitions.
+///
+/// Submunitions are scattered in a cone around the parent's velocity, drawn from
+/// `rng` so peers sharing the parent's seed agree, keep its speed and owner, and
+/// carry the cluster's `submunition` payload with a `Timed` fuse
+/// (`submunition_fuse`) or `Impact` logic. The parent is despawned, unless the
+/// budget is already spent: then nothing happens and the parent deploys on a
+/// later step.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning and despawning
+/// * `spawned_events` - Message writer, one event per submunition
+/// * `entity` - Cluster projectile entity
+/// * `position` - Deploy position
+/// * `parent` - Cluster projectile component
+/// * `payload` - The cluster payload
+/// * `rng` - Randomness of the cluster projectile
+/// * `budget` - Maximum number of submunitions that may still be spawned
+///
+/// # Returns
+/// The number of submunitions spawned
+#[allow(clippy::too_many_arguments)]
+pub fn deploy_cluster(
+    commands: &mut Commands,
+    spawned_events: &mut MessageWriter<ProjectileSpawnedEvent>,
+    entity: Entity,
+    position: Vec3,
+    parent: &Projectile,
+    payload: &Payload,
+    rng: ProjectileRng,
+    budget: u32,
+) -> u32 {
+    let Payload::Cluster {
+        submunition_count,
+        submunition,
+        submunition_fuse,
+        ..
+    } = payload
+    else {
+        return 0;
+    };
+
+    if budget == 0 && *submunition_count > 0 {
+        return 0;
+    }
+    let count = (*submunition_count).min(budget);
+    let speed = parent.velocity.length();
+    let direction = parent.velocity.normalize_or(Vec3::NEG_Y);
+
+    for i in 0..count {
+        let pellet_direction = rng.submunition_direction(direction, SUBMUNITION_SPREAD, i);
+        let logic = match submunition_fuse {
+            Some(fuse) => ProjectileLogic::Timed {
+                fuse: *fuse,
+                elapsed: 0.0,
+            },
+            None => ProjectileLogic::Impact,
+        };
+        let projectile = Projectile {
+            velocity: pellet_direction * speed,
+            previous_position: position,
+            penetration_count: 0,
+            ricochet_count: 0,
+            age: 0.0,
+            distance_travelled: 0.0,
+            ..parent.clone()
+        };
+
+        let submunition_entity = commands
+            .spawn((
+                Transform::from_translation(position),
+                projectile,
+                logic,
+                (**submunition).clone(),
+            ))
+            .id();
+        spawned_events.write(ProjectileSpawnedEvent {
+            entity: submunition_entity,
+            origin: position,
+            direction: pellet_direction,
+            muzzle_velocity: speed,
+            owner: parent.owner,
+        });
+    }
+
+    commands.entity(entity).despawn();
+    count
+}
+
+/// Direction of shard `index` out of `count`, evenly spread over the sphere.
+///
+/// Uses the Fibonacci lattice: equal-area bands in height, stepped by the
+/// golden angle in azimuth.
+pub fn even_sphere_direction(index: u32, count: u32) -> Vec3 {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+    let z = 1.0 - (2.0 * index as f32 + 1.0) / count.max(1) as f32;
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+    let azimuth = golden_angle * index as f32;
+    Vec3::new(radius * azimuth.cos(), radius * azimuth.sin(), z)
+}
+
+/// Direction of shard `index` out of `count`, evenly spread around the XY circle.
+pub fn even_circle_direction(index: u32, count: u32) -> Vec3 {
+    let angle = std::f32::consts::TAU * index as f32 / count.max(1) as f32;
+    Vec2::from_angle(angle).extend(0.0)
+}
+
+/// Nominal direction of shard `index` out of `count`: [`even_sphere_direction`],
+/// or [`even_circle_direction`] with the `dim2` feature, whose sweep ignores z.
+pub fn even_fragment_direction(index: u32, count: u32) -> Vec3 {
+    if cfg!(feature = "dim2") {
+        even_circle_direction(index, count)
+    } else {
+        even_sphere_direction(index, count)
+    }
+}
+
+/// Launch velocities of every shard, in shard order.
+///
+/// Shard `i` starts from [`even_fragment_direction`] and takes its jitter from
+/// [`ProjectileRng::fragment_jitter`] ([`ProjectileRng::fragment_jitter_2d`] with
+/// `dim2`), so the same seed always gives the same pattern.
+///
+/// # Arguments
+/// * `fragmentation` - Shard count, speed and jitter bounds
+/// * `rng` - Randomness of the detonating projectile
+///
+/// # Returns
+/// Exactly `fragmentation.count` velocities
+pub fn fragment_velocities(fragmentation: &Fragmentation, rng: ProjectileRng) -> Vec<Vec3> {
+    (0..fragmentation.count)
+        .map(|i| {
+            let jitter = if cfg!(feature = "dim2") {
+                ProjectileRng::fragment_jitter_2d
+            } else {
+                ProjectileRng::fragment_jitter
+            };
+            let (direction, speed_factor) = jitter(
+                &rng,
+                i,
+                even_fragment_direction(i, fragmentation.count),
+                fragmentation.direction_jitter,
+                fragmentation.speed_jitter,
+            );
+            direction * fragmentation.speed * speed_factor
+        })
+        .collect()
+}
+
+/// Spawn the shards of a detonating projectile.
+///
+/// Each shard is an `Impact` round with a kinetic payload, owned by the parent's owner.
+/// Unless `fragmentation.grace_period` is zero, each also gets a [`FragmentGrace`]
+/// so it skips its siblings and `last_hit` for that long. At most `budget` shards
+/// are spawned, spread evenly as if the burst had only that many; the rest are
+/// dropped, since the parent goes off either way.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning
+/// * `spawned_events` - Message writer, one event per shard
+/// * `burst` - The detonating projectile
+/// * `position` - Detonation point
+/// * `fragmentation` - Shard settings
+/// * `rng` - Randomness of the detonating projectile
+/// * `owner` - Owner of the detonating projectile
+/// * `last_hit` - Entity the detonating projectile last hit (or its [`BounceSurface`])
+/// * `budget` - Maximum number of shards that may still be spawned
+///
+/// # Returns
+/// The number of shards spawned
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_fragments(
+    commands: &mut Commands,
+    spawned_events: &mut MessageWriter<ProjectileSpawnedEvent>,
+    burst: Entity,
+    position: Vec3,
+    fragmentation: &Fragmentation,
+    rng: ProjectileRng,
+    owner: Option<Entity>,
+    last_hit: Option<Entity>,
+    budget: u32,
+) -> u32 {
+    let count = fragmentation.count.min(budget);
+    let velocities = fragment_velocities(
+        &Fragmentation {
+            count,
+            ..fragmentation.clone()
+        },
+        rng,
+    );
+    for &velocity in &velocities {
+        let mut shard = Projectile::new(velocity)
+            .with_mass(fragmentation.mass)
+            .with_previous_position(position);
+        if let Some(owner) = owner {
+            shard = shard.with_owner(owner);
         }
+        let mut entity = commands.spawn((
+            Transform::from_translation(position),
+            shard,
+            ProjectileLogic::Impact,
+            Payload::Kinetic {
+                damage: fragmentation.damage,
+            },
+        ));
+        if fragmentation.grace_period > 0.0 {
+            entity.insert(FragmentGrace {
+                burst,
+                ignore: last_hit,
+                window: fragmentation.grace_period,
+            });
+        }
+        let entity = entity.id();
+        spawned_events.write(ProjectileSpawnedEvent {
+            entity,
+            origin: position,
+            direction: velocity.normalize_or_zero(),
+            muzzle_velocity: velocity.length(),
+            owner,
+        });
     }
+    velocities.len() as u32
+}
+
+/// Whether a proximity fuse should fire this step.
+///
+/// Sweeps the step from the projectile's previous position (or just its current
+/// position for static mines) and checks only grid cells near it. The owner is
+/// ignored so a rocket doesn't detonate on its launcher.
+///
+/// # Arguments
+/// * `targets` - Spatial grid of proximity targets
+/// * `position` - Current position of the fuse
+/// * `projectile` - Projectile data, if the fuse is on a moving projectile
+/// * `range` - Trigger range in meters
+///
+/// # Returns
+/// True if any target is within `range` of the swept step
+pub fn proximity_triggered(
+    targets: &SpatialGrid<ProximityTarget>,
+    position: Vec3,
+    projectile: Option<&Projectile>,
+    range: f32,
+) -> bool {
+    let start = projectile.map_or(position, |p| p.previous_position);
+    let owner = projectile.and_then(|p| p.owner);
+    let mut triggered = false;
+    targets.for_each_near_segment(start, position, range, |target| {
+        if !triggered && owner != Some(target.entity) {
+            triggered = closest_point_on_segment(start, position, target.position)
+                .distance(target.position)
+                <= range;
+        }
+    });
+    triggered
 }
 
 /// Trigger explosion based on payload type.
+/// 
+/// `instigator` is the projectile's owner, carried on the event.
 fn trigger_explosion(
     commands: &mut Commands,
     explosion_events: &mut MessageWriter<ExplosionEvent>,
     entity: Entity,
     position: Vec3,
     payload: Option<&Payload>,
+    instigator: Option<Entity>,
 ) {
     // Send explosion event based on payload type
     if let Some(payload) = payload {
@@ -72,6 +425,7 @@ fn trigger_explosion(
                     falloff: *falloff,
                     explosion_type: ExplosionType::HighExplosive,
                     source: Some(entity),
+                    instigator,
                 });
             }
             Payload::Incendiary { duration: _, damage_per_second, radius } => {
@@ -79,9 +433,10 @@ fn trigger_explosion(
                     center: position,
                     radius: *radius,
                     damage: *damage_per_second,
-                    falloff: 1.0,
+                    falloff: FalloffCurve::Power(1.0),
                     explosion_type: ExplosionType::Incendiary,
                     source: Some(entity),
+                    instigator,
                 });
             }
             Payload::Flash { intensity: _, duration: _, radius } => {
@@ -89,9 +444,10 @@ fn trigger_explosion(
                     center: position,
                     radius: *radius,
                     damage: 0.0,
-                    falloff: 1.0,
+                    falloff: FalloffCurve::Power(1.0),
                     explosion_type: ExplosionType::Flash,
                     source: Some(entity),
+                    instigator,
                 });
             }
             Payload::Smoke { duration: _, radius } => {
@@ -99,14 +455,18 @@ fn trigger_explosion(
                     center: position,
                     radius: *radius,
                     damage: 0.0,
-                    falloff: 1.0,
+                    falloff: FalloffCurve::Power(1.0),
                     explosion_type: ExplosionType::Smoke,
                     source: Some(entity),
+                    instigator,
                 });
             }
             Payload::Kinetic { .. } => {
                 // Kinetic payloads don't explode
             }
+            Payload::Cluster { .. } => {
+                // Deployed by process_projectile_logic, which has the parent projectile
+            }
         }
     }
 
@@ -115,74 +475,148 @@ fn trigger_explosion(
 }
 
 /// Cleanup projectiles that have exceeded their lifetime or distance limits.
+///
+/// Rounds whose position or velocity is no longer finite, which kinematics
+/// skips and would otherwise never age out, go with `DespawnReason::NonFinite`.
+/// Sends a `ProjectileDespawnedEvent` with the reason and the round's age.
 pub fn cleanup_expired_projectiles(
     mut commands: Commands,
     config: Res<BallisticsConfig>,
-    projectiles: Query<(Entity, &crate::components::Projectile)>,
+    projectiles: Query<(Entity, &Transform, &crate::components::Projectile)>,
+    mut despawn_events: MessageWriter<ProjectileDespawnedEvent>,
+) {
+    for (entity, transform, projectile) in projectiles.iter() {
+        let reason = if !(projectile.velocity.is_finite() && transform.translation.is_finite()) {
+            DespawnReason::NonFinite
+        } else if projectile.age >= config.max_projectile_lifetime {
+            DespawnReason::Lifetime
+        } else if projectile.distance_travelled >= config.max_projectile_distance {
+            DespawnReason::Distance
+        } else if projectile.velocity.length() < config.min_projectile_speed && projectile.age > 0.1 {
+            DespawnReason::Speed
+        } else {
+            continue;
+        };
+        commands.entity(entity).despawn();
+        despawn_events.write(ProjectileDespawnedEvent {
+            projectile: entity,
+            position: transform.translation,
+            reason,
+            age: projectile.age,
+        });
+    }
+}
+
+/// Despawn rounds whose owner no longer exists, under `OrphanPolicy::DespawnWithOwner`.
+///
+/// Owners are taken from `ProjectilesByOwner`, so only owners with rounds in
+/// flight are checked. Sends a `ProjectileDespawnedEvent` with
+/// `DespawnReason::Orphaned` for each round. Does nothing under `OrphanPolicy::Continue`.
+///
+/// # Arguments
+/// * `commands` - Bevy Commands for despawning
+/// * `config` - Ballistics configuration resource (orphan policy)
+/// * `index` - Projectiles-by-owner index
+/// * `live` - Query matching every entity, to tell whether an owner still exists
+/// * `projectiles` - Query for projectiles
+/// * `despawn_events` - Message writer for despawn events
+pub fn despawn_orphaned_projectiles(
+    mut commands: Commands,
+    config: Res<BallisticsConfig>,
+    index: Res<ProjectilesByOwner>,
+    live: Query<()>,
+    projectiles: Query<(&Transform, &crate::components::Projectile)>,
+    mut despawn_events: MessageWriter<ProjectileDespawnedEvent>,
 ) {
-    for (entity, projectile) in projectiles.iter() {
-        if projectile.age >= config.max_projectile_lifetime
-            || projectile.distance_travelled >= config.max_projectile_distance
-            || (projectile.velocity.length() < config.min_projectile_speed && projectile.age > 0.1)
-        {
+    if config.orphan_policy != OrphanPolicy::DespawnWithOwner {
+        return;
+    }
+
+    for owner in index.owners().filter(|owner| !live.contains(*owner)) {
+        for &entity in index.get(owner) {
+            let Ok((transform, projectile)) = projectiles.get(entity) else {
+                continue;
+            };
             commands.entity(entity).despawn();
+            despawn_events.write(ProjectileDespawnedEvent {
+                projectile: entity,
+                position: transform.translation,
+                reason: DespawnReason::Orphaned,
+                age: projectile.age,
+            });
         }
     }
 }
 
-#[cfg(any(feature = "dim3", feature = "dim2"))]
-use crate::events::HitEvent;
-#[cfg(any(feature = "dim3", feature = "dim2"))]
-use crate::resources::BallisticsConfig;
 #[cfg(any(feature = "dim3", feature = "dim2"))]
 use crate::systems::collision;
 
 
 /// Process hitscan projectiles (lasers, railguns).
 /// 
-/// Performs an immediate raycast and despawns the projectile entity.
+/// Performs an immediate raycast and despawns the projectile entity. With a
+/// nonzero `beam_radius` the trace is a sphere cast instead, and the hit point
+/// and normal are taken from the surface the sphere touched.
 #[cfg(feature = "dim3")]
 pub fn process_hitscan(
     mut commands: Commands,
-    mut hit_events: MessageWriter<HitEvent>,
-    mut ricochet_events: MessageWriter<crate::events::RicochetEvent>,
-    mut penetration_events: MessageWriter<crate::events::PenetrationEvent>,
+    mut messages: collision::HitMessages,
     config: Res<BallisticsConfig>,
     spatial_query: avian3d::prelude::SpatialQuery,
-    projectiles: Query<(Entity, &Transform, &ProjectileLogic, Option<&Payload>)>,
+    projectiles: Query<(
+        Entity,
+        &Transform,
+        &ProjectileLogic,
+        Option<&Payload>,
+        Option<&crate::components::OnHitCallback>,
+    )>,
+    targets: collision::HitModifierQuery,
 ) {
     use avian3d::prelude::*;
-    for (entity, transform, logic, payload) in projectiles.iter() {
-        if let ProjectileLogic::Hitscan { range } = logic {
+    for (entity, transform, logic, payload, on_hit) in projectiles.iter() {
+        if let ProjectileLogic::Hitscan { range, beam_radius } = logic {
             let start = transform.translation;
             let direction = transform.forward(); 
             let filter = SpatialQueryFilter::default().with_excluded_entities([entity]);
 
-            if let Some(hit) = spatial_query.cast_ray(
-                start,
-                direction,
-                *range,
-                true,
-                &filter,
-            ) {
-                let hit_point = start + *direction * hit.distance;
+            let hit = if *beam_radius > 0.0 {
+                spatial_query
+                    .cast_shape(
+                        &Collider::sphere(*beam_radius),
+                        start,
+                        Quat::IDENTITY,
+                        direction,
+                        &ShapeCastConfig::from_max_distance(*range),
+                        &filter,
+                    )
+                    .map(|hit| (hit.entity, hit.point1, hit.normal1))
+            } else {
+                spatial_query
+                    .cast_ray(start, direction, *range, true, &filter)
+                    .map(|hit| (hit.entity, start + *direction * hit.distance, hit.normal))
+            };
+
+            if let Some((target, hit_point, hit_normal)) = hit {
                 let mut dummy_projectile = crate::components::Projectile::default();
 
                 let mut temp_transform = *transform; // Copy current transform
                 collision::process_hit(
                     &mut commands,
-                    &mut hit_events,
-                    &mut ricochet_events,
-                    &mut penetration_events,
+                    &mut messages,
                     &config,
                     entity,
                     &mut temp_transform,
                     &mut dummy_projectile,
                     payload,
-                    hit.entity,
+                    target,
                     hit_point,
-                    hit.normal,
-                    None,
+                    hit_normal,
+                    // Beams neither ricochet nor penetrate: only resistances apply
+                    collision::HitModifiers {
+                        resistances: targets.get(target).resistances,
+                        ..Default::default()
+                    },
+                    on_hit,
                 );
             }
 
@@ -192,19 +626,26 @@ pub fn process_hitscan(
 }
 
 /// Process hitscan projectiles for 2D.
+/// 
+/// Same as `process_hitscan`, with a circle cast for a nonzero `beam_radius`.
 #[cfg(feature = "dim2")]
 pub fn process_hitscan_2d(
     mut commands: Commands,
-    mut hit_events: MessageWriter<HitEvent>,
-    mut ricochet_events: MessageWriter<crate::events::RicochetEvent>,
-    mut penetration_events: MessageWriter<crate::events::PenetrationEvent>,
+    mut messages: collision::HitMessages,
     config: Res<BallisticsConfig>,
     spatial_query: avian2d::prelude::SpatialQuery,
-    projectiles: Query<(Entity, &Transform, &ProjectileLogic, Option<&Payload>)>,
+    projectiles: Query<(
+        Entity,
+        &Transform,
+        &ProjectileLogic,
+        Option<&Payload>,
+        Option<&crate::components::OnHitCallback>,
+    )>,
+    targets: collision::HitModifierQuery,
 ) {
     use avian2d::prelude::*;
-    for (entity, transform, logic, payload) in projectiles.iter() {
-        if let ProjectileLogic::Hitscan { range } = logic {
+    for (entity, transform, logic, payload, on_hit) in projectiles.iter() {
+        if let ProjectileLogic::Hitscan { range, beam_radius } = logic {
             let start = transform.translation.xy();
             let direction = match Dir2::new(transform.up().xy()) { // In 2D, 'up' is often forward
                 Ok(dir) => dir,
@@ -213,34 +654,47 @@ pub fn process_hitscan_2d(
             
             let filter = SpatialQueryFilter::default().with_excluded_entities([entity]);
 
-            if let Some(hit) = spatial_query.cast_ray(
-                start,
-                direction,
-                *range,
-                true,
-                &filter,
-            ) {
-                let hit_point = start + *direction * hit.distance;
+            let hit = if *beam_radius > 0.0 {
+                spatial_query
+                    .cast_shape(
+                        &Collider::circle(*beam_radius),
+                        start,
+                        0.0,
+                        direction,
+                        &ShapeCastConfig::from_max_distance(*range),
+                        &filter,
+                    )
+                    .map(|hit| (hit.entity, hit.point1, hit.normal1))
+            } else {
+                spatial_query
+                    .cast_ray(start, direction, *range, true, &filter)
+                    .map(|hit| (hit.entity, start + *direction * hit.distance, hit.normal))
+            };
+
+            if let Some((target, hit_point, hit_normal)) = hit {
                 let hit_point_3d = Vec3::new(hit_point.x, hit_point.y, transform.translation.z);
-                let hit_normal_3d = Vec3::new(hit.normal.x, hit.normal.y, 0.0);
+                let hit_normal_3d = Vec3::new(hit_normal.x, hit_normal.y, 0.0);
                 
                 let mut dummy_projectile = crate::components::Projectile::default();
 
                 let mut temp_transform = *transform; // Copy current transform
                 collision::process_hit(
                     &mut commands,
-                    &mut hit_events,
-                    &mut ricochet_events,
-                    &mut penetration_events,
+                    &mut messages,
                     &config,
                     entity,
                     &mut temp_transform,
                     &mut dummy_projectile,
                     payload,
-                    hit.entity,
+                    target,
                     hit_point_3d,
                     hit_normal_3d,
-                    None,
+                    // Beams neither ricochet nor penetrate: only resistances apply
+                    collision::HitModifiers {
+                        resistances: targets.get(target).resistances,
+                        ..Default::default()
+                    },
+                    on_hit,
                 );
             }
 
@@ -252,13 +706,13 @@ pub fn process_hitscan_2d(
 /// Calculate explosion damage with distance falloff.
 /// 
 /// Computes the damage at a given distance from an explosion center,
-/// applying a power-based falloff function.
+/// following the explosion's [`FalloffCurve`].
 /// 
 /// # Arguments
 /// * `base_damage` - The maximum damage at the explosion center
 /// * `distance` - The distance from the explosion center to the target
 /// * `radius` - The maximum radius of the explosion effect
-/// * `falloff` - The exponent controlling the rate of damage falloff
+/// * `falloff` - Curve from full damage at the center to none at the edge
 /// 
 /// # Returns
 /// The damage value at the specified distance
@@ -266,16 +720,168 @@ pub fn calculate_explosion_damage(
     base_damage: f32,
     distance: f32,
     radius: f32,
-    falloff: f32,
+    falloff: FalloffCurve,
 ) -> f32 {
-    if distance >= radius {
-        return 0.0;
+    falloff.damage(base_damage, distance, radius)
+}
+
+/// Refill `RecentExplosions` with this frame's explosions.
+/// 
+/// # Arguments
+/// * `recent` - Buffer of this frame's explosions, cleared first
+/// * `explosion_events` - Message reader for explosion events
+pub fn record_recent_explosions(
+    mut recent: ResMut<RecentExplosions>,
+    mut explosion_events: MessageReader<ExplosionEvent>,
+) {
+    recent.explosions.clear();
+    recent.explosions.extend(explosion_events.read().cloned());
+}
+
+/// Fraction of an explosion that passes through one piece of static cover.
+/// 
+/// Cover with a `SurfaceMaterial` is penetrable: the thicker it is, the less it
+/// lets through, down to nothing at `EXPLOSION_COVER_STOP_THICKNESS`.
+/// Cover without one is solid.
+/// 
+/// # Arguments
+/// * `surface` - Surface material of the cover, if any
+/// 
+/// # Returns
+/// Transmitted fraction in `[0, 1]`
+pub fn cover_transmission(surface: Option<&SurfaceMaterial>) -> f32 {
+    surface.map_or(0.0, |surface| {
+        (1.0 - surface.thickness / EXPLOSION_COVER_STOP_THICKNESS).clamp(0.0, 1.0)
+    })
+}
+
+/// Line-of-sight check between an explosion and the entities it reaches.
+/// 
+/// The blast impulse is attenuated by it automatically. Damage is applied by the
+/// game, so for cover to shield against it too, scale the result of
+/// [`calculate_explosion_damage`] by [`ExplosionCover::exposure`].
+/// Each collider on the line is resolved to its body through `ColliderOf`. Static
+/// cover (colliders of no body, or of a `RigidBody::Static`) attenuates the blast by
+/// the collider's [`cover_transmission`]; dynamic bodies, including their child
+/// colliders, never shield, and neither does any collider of the target itself.
+/// Disabled by `BallisticsConfig::explosion_occlusion`.
+#[cfg(feature = "dim3")]
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct ExplosionCover<'w, 's> {
+    config: Res<'w, BallisticsConfig>,
+    spatial_query: avian3d::prelude::SpatialQuery<'w, 's>,
+    colliders: Query<'w, 's, &'static avian3d::prelude::ColliderOf>,
+    bodies: Query<'w, 's, &'static avian3d::prelude::RigidBody>,
+    surfaces: Query<'w, 's, &'static SurfaceMaterial>,
+}
+
+#[cfg(feature = "dim3")]
+impl ExplosionCover<'_, '_> {
+    /// Fraction of an explosion that reaches `target`.
+    /// 
+    /// # Arguments
+    /// * `event` - The explosion
+    /// * `target` - Entity being reached; its own colliders never count as cover
+    /// * `target_position` - World position of the target
+    /// 
+    /// # Returns
+    /// 1.0 in the open (or with occlusion disabled), 0.0 fully behind solid cover
+    pub fn exposure(&self, event: &ExplosionEvent, target: Entity, target_position: Vec3) -> f32 {
+        use avian3d::prelude::*;
+
+        if !self.config.explosion_occlusion {
+            return 1.0;
+        }
+        let to_target = target_position - event.center;
+        let Ok(direction) = Dir3::new(to_target) else {
+            return 1.0;
+        };
+        let body = |entity: Entity| self.colliders.get(entity).map_or(entity, |of| of.body);
+        let target_body = body(target);
+        let filter = SpatialQueryFilter::default()
+            .with_excluded_entities([target, target_body].into_iter().chain(event.source));
+
+        self.spatial_query
+            .ray_hits(
+                event.center,
+                direction,
+                to_target.length(),
+                MAX_EXPLOSION_COVER_HITS,
+                true,
+                &filter,
+            )
+            .iter()
+            .map(|hit| (hit.entity, body(hit.entity)))
+            .filter(|&(_, hit_body)| {
+                hit_body != target_body
+                    && self.bodies.get(hit_body).ok().is_none_or(|body| body.is_static())
+            })
+            .map(|(collider, hit_body)| {
+                let surface = self.surfaces.get(collider).or_else(|_| self.surfaces.get(hit_body));
+                cover_transmission(surface.ok())
+            })
+            .product()
     }
+}
+
+/// 2D counterpart of [`ExplosionCover`], testing cover in the XY plane.
+#[cfg(feature = "dim2")]
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct ExplosionCover2d<'w, 's> {
+    config: Res<'w, BallisticsConfig>,
+    spatial_query: avian2d::prelude::SpatialQuery<'w, 's>,
+    colliders: Query<'w, 's, &'static avian2d::prelude::ColliderOf>,
+    bodies: Query<'w, 's, &'static avian2d::prelude::RigidBody>,
+    surfaces: Query<'w, 's, &'static SurfaceMaterial>,
+}
+
+#[cfg(feature = "dim2")]
+impl ExplosionCover2d<'_, '_> {
+    /// Fraction of an explosion that reaches `target`.
+    /// 
+    /// # Arguments
+    /// * `event` - The explosion
+    /// * `target` - Entity being reached; its own colliders never count as cover
+    /// * `target_position` - World position of the target
+    /// 
+    /// # Returns
+    /// 1.0 in the open (or with occlusion disabled), 0.0 fully behind solid cover
+    pub fn exposure(&self, event: &ExplosionEvent, target: Entity, target_position: Vec3) -> f32 {
+        use avian2d::prelude::*;
 
-    let normalized_distance = distance / radius;
-    let falloff_factor = (1.0 - normalized_distance).powf(falloff);
+        if !self.config.explosion_occlusion {
+            return 1.0;
+        }
+        let to_target = (target_position - event.center).xy();
+        let Ok(direction) = Dir2::new(to_target) else {
+            return 1.0;
+        };
+        let body = |entity: Entity| self.colliders.get(entity).map_or(entity, |of| of.body);
+        let target_body = body(target);
+        let filter = SpatialQueryFilter::default()
+            .with_excluded_entities([target, target_body].into_iter().chain(event.source));
 
-    base_damage * falloff_factor
+        self.spatial_query
+            .ray_hits(
+                event.center.xy(),
+                direction,
+                to_target.length(),
+                MAX_EXPLOSION_COVER_HITS,
+                true,
+                &filter,
+            )
+            .iter()
+            .map(|hit| (hit.entity, body(hit.entity)))
+            .filter(|&(_, hit_body)| {
+                hit_body != target_body
+                    && self.bodies.get(hit_body).ok().is_none_or(|body| body.is_static())
+            })
+            .map(|(collider, hit_body)| {
+                let surface = self.surfaces.get(collider).or_else(|_| self.surfaces.get(hit_body));
+                cover_transmission(surface.ok())
+            })
+            .product()
+    }
 }
 
 /// Grenade presets for common throwable types.
@@ -298,7 +904,7 @@ pub mod presets {
             Payload::Explosive {
                 damage: 150.0,
                 radius: 10.0,
-                falloff: 1.5,
+                falloff: FalloffCurve::Power(1.5),
             },
         )
     }
@@ -375,7 +981,7 @@ pub mod presets {
             Payload::Explosive {
                 damage: 200.0,
                 radius: 5.0,
-                falloff: 2.0,
+                falloff: FalloffCurve::Power(2.0),
             },
         )
     }
@@ -399,26 +1005,29 @@ pub struct ExplosionAffected {
 /// 
 /// This system reads explosion events and applies outward impulse forces
 /// to all entities with ExplosionAffected component within the blast radius.
+/// The base impulse per explosion type comes from [`ExplosionImpulseTable`](crate::resources::ExplosionImpulseTable).
 /// Uses avian3d's LinearVelocity component for physics integration.
+/// Cover between the center and an entity reduces or blocks its impulse, see [`ExplosionCover`].
+/// The shooter and its parent rig are spared inside their [`SelfDamageGrace`](crate::resources::SelfDamageGrace) radius.
 #[cfg(feature = "dim3")]
 pub fn apply_explosion_impulse(
     mut explosion_events: MessageReader<ExplosionEvent>,
+    impulses: Res<crate::resources::ExplosionImpulseTable>,
+    grace: Res<crate::resources::SelfDamageGrace>,
+    cover: ExplosionCover,
+    parents: Query<&ChildOf>,
     mut affected_entities: Query<(Entity, &Transform, &ExplosionAffected, &mut avian3d::prelude::LinearVelocity)>,
 ) {
     for event in explosion_events.read() {
-        let base_impulse = match event.explosion_type {
-            crate::events::ExplosionType::HighExplosive => 30.0,
-            crate::events::ExplosionType::Incendiary => 5.0,
-            crate::events::ExplosionType::Flash => 2.0,
-            crate::events::ExplosionType::Smoke => 0.5,
-            crate::events::ExplosionType::Fragmentation => 25.0,
-            crate::events::ExplosionType::Concussion => 50.0,
-            crate::events::ExplosionType::EMP => 0.0,
-        };
+        let base_impulse = impulses.get(event.explosion_type);
 
         if base_impulse <= 0.0 {
             continue;
         }
+        let instigator_parent = event
+            .instigator
+            .and_then(|instigator| parents.get(instigator).ok())
+            .map(|child_of| child_of.parent());
 
         for (entity, transform, affected, mut velocity) in affected_entities.iter_mut() {
             if Some(entity) == event.source {
@@ -432,12 +1041,17 @@ pub fn apply_explosion_impulse(
                 continue;
             }
 
+            let exposure = cover.exposure(event, entity, transform.translation);
+            if exposure <= 0.0 {
+                continue;
+            }
+
             let direction = to_entity.normalize();
-            let normalized_distance = distance / event.radius;
-            let falloff_factor = (1.0 - normalized_distance).powf(event.falloff);
+            let falloff_factor = event.falloff.factor(distance, event.radius);
             
             let mass_factor = if affected.mass > 0.0 { 1.0 / affected.mass } else { 1.0 };
-            let impulse_magnitude = base_impulse * falloff_factor * mass_factor;
+            let self_factor = grace.impulse_factor(event, entity, instigator_parent, distance);
+            let impulse_magnitude = base_impulse * falloff_factor * mass_factor * exposure * self_factor;
             
             let impulse_direction = (direction + Vec3::Y * 0.3).normalize();
             let impulse = impulse_direction * impulse_magnitude;
@@ -448,20 +1062,27 @@ pub fn apply_explosion_impulse(
 }
 
 /// Apply physics impulse to nearby entities from explosions for 2D.
+/// 
+/// Uses the same [`ExplosionImpulseTable`](crate::resources::ExplosionImpulseTable) as the 3D system.
 #[cfg(feature = "dim2")]
 pub fn apply_explosion_impulse_2d(
     mut explosion_events: MessageReader<ExplosionEvent>,
+    impulses: Res<crate::resources::ExplosionImpulseTable>,
+    grace: Res<crate::resources::SelfDamageGrace>,
+    cover: ExplosionCover2d,
+    parents: Query<&ChildOf>,
     mut affected_entities: Query<(Entity, &Transform, &ExplosionAffected, &mut avian2d::prelude::LinearVelocity)>,
 ) {
     for event in explosion_events.read() {
-        let base_impulse = match event.explosion_type {
-            crate::events::ExplosionType::HighExplosive => 30.0,
-            _ => 5.0,
-        };
+        let base_impulse = impulses.get(event.explosion_type);
 
         if base_impulse <= 0.0 {
             continue;
         }
+        let instigator_parent = event
+            .instigator
+            .and_then(|instigator| parents.get(instigator).ok())
+            .map(|child_of| child_of.parent());
 
         for (entity, transform, affected, mut velocity) in affected_entities.iter_mut() {
             if Some(entity) == event.source {
@@ -475,12 +1096,17 @@ pub fn apply_explosion_impulse_2d(
                 continue;
             }
 
+            let exposure = cover.exposure(event, entity, transform.translation);
+            if exposure <= 0.0 {
+                continue;
+            }
+
             let direction = to_entity.normalize();
-            let normalized_distance = distance / event.radius;
-            let falloff_factor = (1.0 - normalized_distance).powf(event.falloff);
+            let falloff_factor = event.falloff.factor(distance, event.radius);
             
             let mass_factor = if affected.mass > 0.0 { 1.0 / affected.mass } else { 1.0 };
-            let impulse_magnitude = base_impulse * falloff_factor * mass_factor;
+            let self_factor = grace.impulse_factor(event, entity, instigator_parent, distance);
+            let impulse_magnitude = base_impulse * falloff_factor * mass_factor * exposure * self_factor;
             
             let impulse = direction * impulse_magnitude;
 
@@ -504,24 +1130,1142 @@ mod tests {
 
     #[test]
     fn test_explosion_damage_at_center() {
-        let damage = calculate_explosion_damage(100.0, 0.0, 10.0, 1.0);
+        let damage = calculate_explosion_damage(100.0, 0.0, 10.0, FalloffCurve::Power(1.0));
         assert_eq!(damage, 100.0);
     }
 
     #[test]
     fn test_explosion_damage_at_edge() {
-        let damage = calculate_explosion_damage(100.0, 10.0, 10.0, 1.0);
+        let damage = calculate_explosion_damage(100.0, 10.0, 10.0, FalloffCurve::Power(1.0));
         assert_eq!(damage, 0.0);
     }
 
     #[test]
     fn test_explosion_damage_falloff() {
         // Linear falloff (1.0)
-        let damage_half = calculate_explosion_damage(100.0, 5.0, 10.0, 1.0);
+        let damage_half = calculate_explosion_damage(100.0, 5.0, 10.0, FalloffCurve::Power(1.0));
         assert!((damage_half - 50.0).abs() < 0.01);
 
         // Quadratic falloff (2.0) - less damage at same distance
-        let damage_quad = calculate_explosion_damage(100.0, 5.0, 10.0, 2.0);
+        let damage_quad = calculate_explosion_damage(100.0, 5.0, 10.0, FalloffCurve::Power(2.0));
         assert!(damage_quad < damage_half);
     }
+
+    #[test]
+    fn test_self_damage_grace_spares_shooter_next_to_blast() {
+        use crate::resources::SelfDamageGrace;
+
+        let mut world = World::new();
+        let shooter = world.spawn_empty().id();
+        let vehicle = world.spawn_empty().id();
+        let bystander = world.spawn_empty().id();
+        let event = ExplosionEvent {
+            center: Vec3::ZERO,
+            radius: 8.0,
+            damage: 100.0,
+            falloff: FalloffCurve::Power(1.0),
+            explosion_type: ExplosionType::HighExplosive,
+            source: None,
+            instigator: Some(shooter),
+        };
+        let damage = |grace: &SelfDamageGrace, entity: Entity, distance: f32| {
+            calculate_explosion_damage(event.damage, distance, event.radius, event.falloff)
+                * grace.damage_factor(&event, entity, Some(vehicle), distance)
+        };
+
+        // Off by default: the shooter takes the full blast
+        assert!(damage(&SelfDamageGrace::default(), shooter, 1.0) > 80.0);
+
+        let grace = SelfDamageGrace::uniform(2.0);
+        assert_eq!(damage(&grace, shooter, 1.0), 0.0);
+        assert_eq!(damage(&grace, vehicle, 1.0), 0.0);
+        assert!(damage(&grace, bystander, 1.0) > 80.0);
+        assert!(damage(&grace, shooter, 4.0) > 0.0);
+
+        // Per-type radius
+        let grace = SelfDamageGrace {
+            high_explosive: 0.0,
+            ..SelfDamageGrace::uniform(2.0)
+        };
+        assert!(damage(&grace, shooter, 1.0) > 80.0);
+
+        // Rocket jumping: no damage but the full launch
+        let jump = SelfDamageGrace {
+            impulse_scale: 1.0,
+            ..SelfDamageGrace::uniform(2.0)
+        };
+        assert_eq!(jump.damage_factor(&event, shooter, None, 1.0), 0.0);
+        assert_eq!(jump.impulse_factor(&event, shooter, None, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_falloff_curves_bounded_and_non_increasing() {
+        let plateau = FalloffCurve::Plateau {
+            full_radius: 4.0,
+            edge_radius: 8.0,
+        };
+        let curves = [
+            FalloffCurve::Power(1.5),
+            FalloffCurve::Linear,
+            FalloffCurve::InverseSquare,
+            plateau,
+        ];
+        for curve in curves {
+            let center = calculate_explosion_damage(100.0, 0.0, 10.0, curve);
+            let edge = calculate_explosion_damage(100.0, 10.0, 10.0, curve);
+            assert_eq!(center, 100.0, "{curve:?}");
+            assert_eq!(edge, 0.0, "{curve:?}");
+
+            let mut previous = center;
+            for step in 1..=100 {
+                let damage = calculate_explosion_damage(100.0, step as f32 * 0.1, 10.0, curve);
+                assert!((0.0..=100.0).contains(&damage), "{curve:?} {damage}");
+                assert!(damage <= previous, "{curve:?} rises at step {step}");
+                previous = damage;
+            }
+        }
+
+        // Mid-radius values
+        let mid = |curve| calculate_explosion_damage(100.0, 5.0, 10.0, curve);
+        assert!((mid(FalloffCurve::Linear) - 50.0).abs() < 1e-4);
+        assert_eq!(mid(FalloffCurve::Linear), mid(FalloffCurve::Power(1.0)));
+        assert!((mid(FalloffCurve::Power(1.5)) - 100.0 * 0.5f32.powf(1.5)).abs() < 1e-3);
+        // Inverse square drops hard away from the center
+        assert!(mid(FalloffCurve::InverseSquare) < mid(FalloffCurve::Linear) / 2.0);
+        // Full strength across the plateau, then linear to the edge radius
+        assert_eq!(calculate_explosion_damage(100.0, 4.0, 10.0, plateau), 100.0);
+        assert!((mid(plateau) - 75.0).abs() < 1e-4);
+        assert_eq!(calculate_explosion_damage(100.0, 8.0, 10.0, plateau), 0.0);
+        // Equal radii make a step
+        let step = FalloffCurve::Plateau {
+            full_radius: 6.0,
+            edge_radius: 6.0,
+        };
+        assert_eq!(mid(step), 100.0);
+        assert_eq!(calculate_explosion_damage(100.0, 6.5, 10.0, step), 0.0);
+    }
+
+    #[test]
+    fn test_recent_explosions_report_intensity_for_one_frame() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(record_recent_explosions);
+        world.init_resource::<RecentExplosions>();
+        world.init_resource::<bevy::ecs::message::Messages<ExplosionEvent>>();
+        world.write_message(ExplosionEvent {
+            center: Vec3::new(10.0, 0.0, 0.0),
+            radius: 5.0,
+            damage: 100.0,
+            falloff: FalloffCurve::Power(2.0),
+            explosion_type: ExplosionType::HighExplosive,
+            source: None,
+            instigator: None,
+        });
+        schedule.run(&mut world);
+
+        let recent = world.resource::<RecentExplosions>();
+        let inside = recent.affects(Vec3::new(12.0, 0.0, 0.0)).unwrap();
+        // Same curve as the damage: (1 - 2/5)^2
+        assert!((inside - 0.36).abs() < 1e-5);
+        assert_eq!(
+            inside * 100.0,
+            calculate_explosion_damage(100.0, 2.0, 5.0, FalloffCurve::Power(2.0))
+        );
+        assert_eq!(recent.affects(Vec3::new(16.0, 0.0, 0.0)), None);
+
+        // Next frame without explosions: nothing is affected any more
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<RecentExplosions>().affects(Vec3::new(10.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_cover_transmission() {
+        assert_eq!(cover_transmission(None), 0.0);
+        let thin = SurfaceMaterial {
+            thickness: 0.03,
+            ..Default::default()
+        };
+        assert!((cover_transmission(Some(&thin)) - 0.9).abs() < 1e-5);
+        let thick = SurfaceMaterial {
+            thickness: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(cover_transmission(Some(&thick)), 0.0);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_wall_blocks_explosion_impulse() {
+        use crate::resources::{ExplosionImpulseTable, SelfDamageGrace};
+        use avian3d::prelude::*;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        fn launch_speeds(explosion_occlusion: bool) -> (f32, f32) {
+            let mut app = App::new();
+            app.add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                bevy::asset::AssetPlugin::default(),
+                bevy::mesh::MeshPlugin,
+                bevy::scene::ScenePlugin,
+                PhysicsPlugins::default(),
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .insert_resource(BallisticsConfig {
+                explosion_occlusion,
+                ..Default::default()
+            })
+            .init_resource::<ExplosionImpulseTable>()
+            .init_resource::<SelfDamageGrace>()
+            .add_message::<ExplosionEvent>()
+            .add_systems(Update, apply_explosion_impulse);
+
+            // Wall between the blast and the +X target
+            app.world_mut().spawn((
+                RigidBody::Static,
+                Collider::cuboid(0.2, 4.0, 4.0),
+                Transform::from_xyz(2.0, 0.0, 0.0),
+            ));
+            let mut target = |x: f32| {
+                app.world_mut()
+                    .spawn((
+                        Transform::from_xyz(x, 0.0, 0.0),
+                        ExplosionAffected { mass: 1.0 },
+                        LinearVelocity::ZERO,
+                    ))
+                    .id()
+            };
+            let covered = target(4.0);
+            let exposed = target(-4.0);
+
+            for _ in 0..5 {
+                app.update();
+            }
+            app.world_mut().write_message(ExplosionEvent {
+                center: Vec3::ZERO,
+                radius: 10.0,
+                damage: 100.0,
+                falloff: FalloffCurve::Power(1.0),
+                explosion_type: ExplosionType::HighExplosive,
+                source: None,
+                instigator: None,
+            });
+            app.update();
+
+            let speed = |entity| app.world().get::<LinearVelocity>(entity).unwrap().0.length();
+            (speed(covered), speed(exposed))
+        }
+
+        let (covered, exposed) = launch_speeds(true);
+        assert_eq!(covered, 0.0);
+        assert!(exposed > 1.0);
+
+        let (covered, exposed) = launch_speeds(false);
+        assert!((covered - exposed).abs() < 1e-4);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_child_colliders_of_dynamic_bodies_never_cover() {
+        use avian3d::prelude::*;
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            bevy::asset::AssetPlugin::default(),
+            bevy::mesh::MeshPlugin,
+            bevy::scene::ScenePlugin,
+            PhysicsPlugins::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(BallisticsConfig::default());
+
+        // A compound target whose own shell faces the blast
+        let target = app
+            .world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Collider::sphere(0.5),
+                Transform::from_xyz(4.0, 0.0, 0.0),
+            ))
+            .with_child((Collider::cuboid(0.2, 2.0, 2.0), Transform::from_xyz(-1.0, 0.0, 0.0)))
+            .id();
+        // A loose crate built from a child collider, between the blast and a bystander
+        app.world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Transform::from_xyz(-2.0, 0.0, 0.0),
+            ))
+            .with_child(Collider::cuboid(0.2, 2.0, 2.0));
+        let bystander = app.world_mut().spawn(Transform::from_xyz(-4.0, 0.0, 0.0)).id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let event = ExplosionEvent {
+            center: Vec3::ZERO,
+            radius: 10.0,
+            damage: 100.0,
+            falloff: FalloffCurve::Power(1.0),
+            explosion_type: ExplosionType::HighExplosive,
+            source: None,
+            instigator: None,
+        };
+        let (target_exposure, bystander_exposure) = app
+            .world_mut()
+            .run_system_once(move |cover: ExplosionCover| {
+                (
+                    cover.exposure(&event, target, Vec3::new(4.0, 0.0, 0.0)),
+                    cover.exposure(&event, bystander, Vec3::new(-4.0, 0.0, 0.0)),
+                )
+            })
+            .unwrap();
+        assert_eq!(target_exposure, 1.0);
+        assert_eq!(bystander_exposure, 1.0);
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_retuned_impulse_table_scales_knockback() {
+        use crate::resources::{ExplosionImpulseTable, SelfDamageGrace};
+        use avian3d::prelude::*;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        fn launch_speed(impulses: ExplosionImpulseTable) -> f32 {
+            let mut app = App::new();
+            app.add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                bevy::asset::AssetPlugin::default(),
+                bevy::mesh::MeshPlugin,
+                bevy::scene::ScenePlugin,
+                PhysicsPlugins::default(),
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .init_resource::<BallisticsConfig>()
+            .insert_resource(impulses)
+            .init_resource::<SelfDamageGrace>()
+            .add_message::<ExplosionEvent>()
+            .add_systems(Update, apply_explosion_impulse);
+
+            let target = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(3.0, 0.0, 0.0),
+                    ExplosionAffected { mass: 2.0 },
+                    LinearVelocity::ZERO,
+                ))
+                .id();
+
+            for _ in 0..5 {
+                app.update();
+            }
+            app.world_mut().write_message(ExplosionEvent {
+                center: Vec3::ZERO,
+                radius: 10.0,
+                damage: 100.0,
+                falloff: FalloffCurve::Power(1.0),
+                explosion_type: ExplosionType::HighExplosive,
+                source: None,
+                instigator: None,
+            });
+            app.update();
+
+            app.world().get::<LinearVelocity>(target).unwrap().0.length()
+        }
+
+        let stock = launch_speed(ExplosionImpulseTable::default());
+        let retuned = launch_speed(ExplosionImpulseTable {
+            high_explosive: 90.0,
+            ..Default::default()
+        });
+        assert!(stock > 0.0);
+        assert!((retuned / stock - 3.0).abs() < 1e-4);
+    }
+
+    #[cfg(feature = "dim2")]
+    #[test]
+    fn test_2d_knockback_follows_impulse_table() {
+        use crate::resources::{ExplosionImpulseTable, SelfDamageGrace};
+        use avian2d::prelude::*;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        fn launch_speed(explosion_type: ExplosionType) -> f32 {
+            let mut app = App::new();
+            app.add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                bevy::asset::AssetPlugin::default(),
+                bevy::mesh::MeshPlugin,
+                bevy::scene::ScenePlugin,
+                PhysicsPlugins::default(),
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .init_resource::<BallisticsConfig>()
+            .init_resource::<ExplosionImpulseTable>()
+            .init_resource::<SelfDamageGrace>()
+            .add_message::<ExplosionEvent>()
+            .add_systems(Update, apply_explosion_impulse_2d);
+
+            let target = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(3.0, 0.0, 0.0),
+                    ExplosionAffected { mass: 1.0 },
+                    LinearVelocity::ZERO,
+                ))
+                .id();
+
+            for _ in 0..5 {
+                app.update();
+            }
+            app.world_mut().write_message(ExplosionEvent {
+                center: Vec3::ZERO,
+                radius: 10.0,
+                damage: 100.0,
+                falloff: FalloffCurve::Power(1.0),
+                explosion_type,
+                source: None,
+                instigator: None,
+            });
+            app.update();
+
+            app.world().get::<LinearVelocity>(target).unwrap().0.length()
+        }
+
+        // 2D shares the 3D table: it used to push every type but high explosive with 5
+        let falloff = FalloffCurve::Power(1.0).factor(3.0, 10.0);
+        let pinned = [
+            (ExplosionType::HighExplosive, 30.0),
+            (ExplosionType::Incendiary, 5.0),
+            (ExplosionType::Flash, 2.0),
+            (ExplosionType::Smoke, 0.5),
+            (ExplosionType::Fragmentation, 25.0),
+            (ExplosionType::Concussion, 50.0),
+            (ExplosionType::EMP, 0.0),
+        ];
+        for (explosion_type, impulse) in pinned {
+            let speed = launch_speed(explosion_type);
+            assert!((speed - impulse * falloff).abs() < 1e-4, "{explosion_type:?}: {speed}");
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    #[test]
+    fn test_hitscan_beam_radius_catches_near_miss() {
+        use crate::events::HitEvent;
+        use avian3d::prelude::*;
+        use bevy::ecs::message::Messages;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        fn fire(beam_radius: f32) -> Option<HitEvent> {
+            let mut app = App::new();
+            app.add_plugins((
+                MinimalPlugins,
+                TransformPlugin,
+                bevy::asset::AssetPlugin::default(),
+                bevy::mesh::MeshPlugin,
+                bevy::scene::ScenePlugin,
+                PhysicsPlugins::default(),
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .init_resource::<BallisticsConfig>()
+            .add_message::<HitEvent>()
+            .add_message::<crate::events::RicochetEvent>()
+            .add_message::<crate::events::PenetrationEvent>()
+            .add_message::<ProjectileDespawnedEvent>()
+            .add_systems(Update, process_hitscan);
+
+            // 0.2m sphere whose edge is 0.1m off the beam's center line
+            app.world_mut().spawn((
+                RigidBody::Static,
+                Collider::sphere(0.2),
+                Transform::from_xyz(0.3, 0.0, -10.0),
+            ));
+            for _ in 0..5 {
+                app.update();
+            }
+
+            app.world_mut().spawn((
+                Transform::default(),
+                ProjectileLogic::Hitscan { range: 50.0, beam_radius },
+            ));
+            app.update();
+            app.world()
+                .resource::<Messages<HitEvent>>()
+                .iter_current_update_messages()
+                .next()
+                .cloned()
+        }
+
+        assert!(fire(0.0).is_none());
+
+        let hit = fire(0.25).expect("wide beam should graze the target");
+        let center = Vec3::new(0.3, 0.0, -10.0);
+        let offset = hit.impact_point - center;
+        assert!((offset.length() - 0.2).abs() < 0.01, "hit at {}", hit.impact_point);
+        assert!(hit.normal.dot(offset.normalize()) > 0.99);
+    }
+
+    #[test]
+    fn test_cooked_grenade_detonates_early() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        let (logic, payload) = presets::frag_grenade();
+        let cooked = world
+            .spawn((Transform::default(), logic.clone().with_cook_time(2.0), payload.clone()))
+            .id();
+        let overcooked = world
+            .spawn((Transform::default(), logic.with_cook_time(4.0), payload))
+            .id();
+
+        let dt = 1.0 / 64.0;
+        let mut step = 0;
+        let mut overcooked_step = None;
+        while world.get_entity(cooked).is_ok() {
+            step += 1;
+            world
+                .resource_mut::<Time<Fixed>>()
+                .advance_by(Duration::from_secs_f32(dt));
+            world.run_system_once(process_projectile_logic).unwrap();
+            if overcooked_step.is_none() && world.get_entity(overcooked).is_err() {
+                overcooked_step = Some(step);
+            }
+            assert!(step < 1000);
+        }
+
+        // Cooked past its fuse: goes off on the very first step
+        assert_eq!(overcooked_step, Some(1));
+        // 2 s of a 3 s fuse cooked: about one second of flight left
+        let flight_time = step as f32 * dt;
+        assert!((flight_time - 1.0).abs() <= dt, "detonated after {flight_time}s");
+        assert_eq!(world.resource::<Messages<ExplosionEvent>>().len(), 2);
+    }
+
+    #[test]
+    fn test_half_time_scale_doubles_fuse_time() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        fn steps_to_detonate(config: BallisticsConfig) -> u32 {
+            let mut world = World::new();
+            world.insert_resource(Time::<Fixed>::default());
+            world.insert_resource(BallisticsEnvironment::default());
+            world.insert_resource(config);
+            world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+            world.init_resource::<Messages<ExplosionEvent>>();
+            world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+            let (logic, payload) = presets::frag_grenade();
+            let grenade = world.spawn((Transform::default(), logic, payload)).id();
+
+            let mut step = 0;
+            while world.get_entity(grenade).is_ok() {
+                step += 1;
+                world
+                    .resource_mut::<Time<Fixed>>()
+                    .advance_by(Duration::from_secs_f32(1.0 / 64.0));
+                world.run_system_once(process_projectile_logic).unwrap();
+                assert!(step < 2000);
+            }
+            step
+        }
+
+        let real_time = steps_to_detonate(BallisticsConfig::default());
+        let half_speed = steps_to_detonate(BallisticsConfig {
+            time_scale: 0.5,
+            ..Default::default()
+        });
+        let deterministic = steps_to_detonate(BallisticsConfig {
+            time_scale: 0.5,
+            ignore_time_scale: true,
+            ..Default::default()
+        });
+
+        assert!(half_speed.abs_diff(real_time * 2) <= 1, "{real_time} vs {half_speed}");
+        assert_eq!(deterministic, real_time);
+    }
+
+    #[test]
+    fn test_age_counts_fixed_steps_and_rides_on_despawn() {
+        use bevy::ecs::message::Messages;
+        use std::time::Duration;
+
+        let dt = 1.0 / 64.0;
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig {
+            max_projectile_lifetime: 0.5,
+            ..Default::default()
+        });
+        world.init_resource::<Messages<ProjectileDespawnedEvent>>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                crate::systems::kinematics::update_projectiles_kinematics,
+                cleanup_expired_projectiles,
+            )
+                .chain(),
+        );
+
+        let round = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 400.0)))
+            .id();
+        assert_eq!(world.get::<Projectile>(round).unwrap().age, 0.0);
+
+        let step = |world: &mut World, schedule: &mut Schedule| {
+            world
+                .resource_mut::<Time<Fixed>>()
+                .advance_by(Duration::from_secs_f32(dt));
+            schedule.run(world);
+        };
+        for _ in 0..10 {
+            step(&mut world, &mut schedule);
+        }
+        let age = world.get::<Projectile>(round).unwrap().age;
+        assert!((age - 10.0 * dt).abs() < 1e-5, "{age}");
+
+        while world.get_entity(round).is_ok() {
+            step(&mut world, &mut schedule);
+        }
+        let events: Vec<_> = world
+            .resource::<Messages<ProjectileDespawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].projectile, round);
+        assert_eq!(events[0].reason, DespawnReason::Lifetime);
+        assert!((events[0].age - 32.0 * dt).abs() < 1e-4, "{}", events[0].age);
+    }
+
+    #[test]
+    fn test_non_finite_rounds_are_cleaned_up() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(BallisticsConfig::default());
+        world.init_resource::<Messages<ProjectileDespawnedEvent>>();
+
+        let lost = world
+            .spawn((Transform::default(), Projectile::new(Vec3::new(f32::NAN, 0.0, -400.0))))
+            .id();
+        let flung = world
+            .spawn((
+                Transform::from_xyz(0.0, f32::INFINITY, 0.0),
+                Projectile::new(Vec3::NEG_Z * 400.0),
+            ))
+            .id();
+        let healthy = world
+            .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 400.0)))
+            .id();
+        world.run_system_once(cleanup_expired_projectiles).unwrap();
+
+        let despawned: Vec<(Entity, DespawnReason)> = world
+            .resource::<Messages<ProjectileDespawnedEvent>>()
+            .iter_current_update_messages()
+            .map(|event| (event.projectile, event.reason))
+            .collect();
+        assert_eq!(
+            despawned,
+            vec![(lost, DespawnReason::NonFinite), (flung, DespawnReason::NonFinite)]
+        );
+        assert!(world.get_entity(lost).is_err());
+        assert!(world.get_entity(flung).is_err());
+        assert!(world.get_entity(healthy).is_ok());
+    }
+
+    #[test]
+    fn test_shards_ignore_their_burst_at_first() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        // The grenade skipped off a wall before going off next to it
+        let wall = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let (_, payload) = presets::frag_grenade();
+        let mut grenade = Projectile::new(Vec3::ZERO);
+        grenade.last_hit = Some(wall);
+        let burst = world
+            .spawn((
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                grenade,
+                ProjectileLogic::Timed {
+                    fuse: 0.0,
+                    elapsed: 0.0,
+                },
+                payload,
+                Fragmentation::new(12, 600.0).with_grace_period(0.04),
+            ))
+            .id();
+        world.run_system_once(process_projectile_logic).unwrap();
+
+        let shards: Vec<Entity> = world
+            .resource::<Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .map(|event| event.entity)
+            .collect();
+        assert_eq!(shards.len(), 12);
+        let graces: Vec<FragmentGrace> = shards
+            .iter()
+            .map(|shard| *world.get::<FragmentGrace>(*shard).unwrap())
+            .collect();
+        assert!(graces.iter().all(|grace| grace.burst == burst && grace.ignore == Some(wall)));
+
+        // First step after the burst: siblings and the wall are skipped, real targets are not
+        let first_step = 1.0 / 60.0;
+        for (i, grace) in graces.iter().enumerate() {
+            for (j, sibling) in shards.iter().enumerate().filter(|(j, _)| *j != i) {
+                assert!(grace.ignores(first_step, *sibling, Some(&graces[j])));
+            }
+            assert!(grace.ignores(first_step, wall, None));
+            assert!(!grace.ignores(first_step, target, None));
+        }
+
+        // Shards of another burst are fair game, and the grace runs out
+        let other = FragmentGrace {
+            burst: target,
+            ..graces[0]
+        };
+        assert!(!graces[0].ignores(first_step, shards[1], Some(&other)));
+        assert!(!graces[0].ignores(0.05, shards[1], Some(&graces[1])));
+        assert!(!graces[0].ignores(0.05, wall, None));
+
+        // No grace period, no grace
+        let mut world = World::new();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+        let burst = world.spawn_empty().id();
+        world
+            .run_system_once(
+                move |mut commands: Commands, mut spawned: MessageWriter<ProjectileSpawnedEvent>| {
+                    spawn_fragments(
+                        &mut commands,
+                        &mut spawned,
+                        burst,
+                        Vec3::ZERO,
+                        &Fragmentation::new(4, 600.0).with_grace_period(0.0),
+                        ProjectileRng::new(1),
+                        None,
+                        None,
+                        u32::MAX,
+                    );
+                },
+            )
+            .unwrap();
+        let mut graces = world.query::<&FragmentGrace>();
+        assert_eq!(graces.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn test_shards_share_the_submunition_budget() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig {
+            max_submunitions: 20,
+            ..Default::default()
+        });
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        let (_, payload) = presets::frag_grenade();
+        for x in [0.0, 10.0] {
+            world.spawn((
+                Transform::from_xyz(x, 1.0, 0.0),
+                Projectile::new(Vec3::ZERO),
+                ProjectileLogic::Timed {
+                    fuse: 0.0,
+                    elapsed: 0.0,
+                },
+                payload.clone(),
+                Fragmentation::new(12, 600.0).with_jitter(0.0, 0.0),
+            ));
+        }
+        world.run_system_once(process_projectile_logic).unwrap();
+
+        // 12 shards from the first grenade, the 8 left in the budget from the second
+        let spawned = world
+            .resource::<Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .count();
+        assert_eq!(spawned, 20);
+        assert_eq!(world.query::<&ProjectileLogic>().iter(&world).count(), 20);
+        // Both grenades still went off
+        let explosions = world
+            .resource::<Messages<ExplosionEvent>>()
+            .iter_current_update_messages()
+            .count();
+        assert_eq!(explosions, 2);
+    }
+
+    #[test]
+    fn test_even_circle_directions_stay_in_plane() {
+        for i in 0..8 {
+            let direction = even_circle_direction(i, 8);
+            assert_eq!(direction.z, 0.0);
+            assert!((direction.length() - 1.0).abs() < 1e-6);
+        }
+        assert!(even_circle_direction(2, 8).distance(Vec3::Y) < 1e-6);
+        assert!(even_circle_direction(4, 8).distance(Vec3::NEG_X) < 1e-6);
+    }
+
+    #[test]
+    fn test_handed_off_grenade_shards_ignore_bounce_surface() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        // Avian owns the grenade now: no `Projectile`, only the surface it landed on
+        let floor = world.spawn_empty().id();
+        let (_, payload) = presets::frag_grenade();
+        world.spawn((
+            Transform::from_xyz(0.0, 0.1, 0.0),
+            ProjectileLogic::Timed {
+                fuse: 0.0,
+                elapsed: 0.0,
+            },
+            payload,
+            Fragmentation::new(6, 600.0),
+            BounceSurface(floor),
+        ));
+        world.run_system_once(process_projectile_logic).unwrap();
+
+        let mut graces = world.query::<&FragmentGrace>();
+        assert_eq!(graces.iter(&world).count(), 6);
+        assert!(graces.iter(&world).all(|grace| grace.ignore == Some(floor)));
+    }
+
+    #[test]
+    fn test_fragment_pattern_follows_parent_seed() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let shards = Fragmentation::new(24, 800.0).with_jitter(0.2, 0.25);
+        let net = NetProjectile {
+            owner_id: 3,
+            timestamp: 1.0,
+            spread_seed: 0xF00D,
+        };
+
+        let first = fragment_velocities(&shards, ProjectileRng::from(&net));
+        let second = fragment_velocities(&shards, ProjectileRng::from(&net));
+        let other = fragment_velocities(&shards, ProjectileRng::new(0xBEEF));
+        assert_eq!(first.len(), 24);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+
+        // Jitter stays within bounds and actually moves shards off the even sphere
+        let even = fragment_velocities(&shards.clone().with_jitter(0.0, 0.0), ProjectileRng::from(&net));
+        for (i, (jittered, even)) in first.iter().zip(&even).enumerate() {
+            assert!((jittered.length() - 800.0).abs() <= 800.0 * 0.25 + 1e-2);
+            assert!(jittered.angle_between(*even) <= 0.2 + 1e-4);
+            assert!((even.length() - 800.0).abs() < 1e-2);
+            assert!(even.normalize().distance(even_fragment_direction(i as u32, 24)) < 1e-5);
+        }
+        assert!(first.iter().zip(&even).any(|(a, b)| a.angle_between(*b) > 0.01));
+
+        // The detonation spawns exactly `count` shards along those velocities
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        let owner = world.spawn_empty().id();
+        let (_, payload) = presets::frag_grenade();
+        let grenade = world
+            .spawn((
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                Projectile::new(Vec3::ZERO).with_owner(owner),
+                ProjectileLogic::Timed {
+                    fuse: 0.0,
+                    elapsed: 0.0,
+                },
+                payload,
+                shards.clone(),
+                net.clone(),
+            ))
+            .id();
+        world.run_system_once(process_projectile_logic).unwrap();
+
+        assert!(world.get_entity(grenade).is_err());
+        let spawned: Vec<_> = world
+            .resource::<Messages<ProjectileSpawnedEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(spawned.len(), 24);
+        for (event, velocity) in spawned.iter().zip(&first) {
+            let shard = world.get::<Projectile>(event.entity).unwrap();
+            assert_eq!(shard.velocity, *velocity);
+            assert_eq!(shard.owner, Some(owner));
+        }
+    }
+
+    #[test]
+    fn test_owner_despawned_mid_flight() {
+        use crate::components::Team;
+        use crate::systems::{kinematics, ownership, targeting};
+        use bevy::ecs::message::Messages;
+        use std::time::Duration;
+
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+
+        let run = |orphan_policy: OrphanPolicy| {
+            let mut world = World::new();
+            world.insert_resource(Time::<Fixed>::default());
+            world.insert_resource(BallisticsEnvironment::default());
+            world.insert_resource(BallisticsConfig {
+                orphan_policy,
+                ..Default::default()
+            });
+            world.init_resource::<ProjectilesByOwner>();
+            world.init_resource::<Messages<ProjectileDespawnedEvent>>();
+            world.add_observer(ownership::index_projectile_owner);
+            world.add_observer(ownership::unindex_projectile_owner);
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(
+                (
+                    targeting::assign_locked_targets,
+                    kinematics::update_projectiles_kinematics,
+                    cleanup_expired_projectiles,
+                    despawn_orphaned_projectiles,
+                )
+                    .chain(),
+            );
+
+            let owner = world.spawn(Team(1)).id();
+            let owned: Vec<Entity> = (0..2)
+                .map(|_| {
+                    world
+                        .spawn((
+                            Transform::default(),
+                            Projectile::new(Vec3::NEG_Z * 400.0).with_owner(owner),
+                            Team(1),
+                        ))
+                        .id()
+                })
+                .collect();
+            let stray = world
+                .spawn((Transform::default(), Projectile::new(Vec3::NEG_Z * 400.0)))
+                .id();
+
+            let step = |world: &mut World, schedule: &mut Schedule| {
+                world
+                    .resource_mut::<Time<Fixed>>()
+                    .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+                schedule.run(world);
+            };
+            step(&mut world, &mut schedule);
+            world.despawn(owner);
+            step(&mut world, &mut schedule);
+            step(&mut world, &mut schedule);
+
+            let orphaned: Vec<_> = world
+                .resource::<Messages<ProjectileDespawnedEvent>>()
+                .iter_current_update_messages()
+                .filter(|event| event.reason == DespawnReason::Orphaned)
+                .map(|event| event.projectile)
+                .collect();
+            let alive = owned.iter().filter(|round| world.get_entity(**round).is_ok()).count();
+            assert!(world.get::<Projectile>(stray).unwrap().age > 0.0);
+            (alive, orphaned, world.resource::<ProjectilesByOwner>().count(owner), owned)
+        };
+
+        // Default: the dead shooter's rounds fly on
+        assert_eq!(BallisticsConfig::default().orphan_policy, OrphanPolicy::Continue);
+        let (alive, orphaned, indexed, _) = run(OrphanPolicy::Continue);
+        assert_eq!(alive, 2);
+        assert!(orphaned.is_empty());
+        assert_eq!(indexed, 2);
+
+        let (alive, mut orphaned, indexed, owned) = run(OrphanPolicy::DespawnWithOwner);
+        assert_eq!(alive, 0);
+        orphaned.sort();
+        let mut expected = owned.clone();
+        expected.sort();
+        assert_eq!(orphaned, expected);
+        assert_eq!(indexed, 0);
+    }
+
+    #[test]
+    fn test_cluster_deploys_below_altitude() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig {
+            max_submunitions: 5,
+            ..Default::default()
+        });
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        let owner = world.spawn_empty().id();
+        let cluster = Payload::Cluster {
+            submunition_count: 8,
+            submunition: Box::new(Payload::Explosive {
+                damage: 50.0,
+                radius: 3.0,
+                falloff: FalloffCurve::Power(1.0),
+            }),
+            deploy_altitude: 50.0,
+            submunition_fuse: None,
+        };
+        let spawn = |world: &mut World, y: f32, vy: f32| {
+            world
+                .spawn((
+                    Transform::from_xyz(0.0, y, 0.0),
+                    Projectile::new(Vec3::new(100.0, vy, 0.0)).with_owner(owner),
+                    ProjectileLogic::Impact,
+                    cluster.clone(),
+                ))
+                .id()
+        };
+        // Climbing through the altitude and still high up: neither deploys
+        let climbing = spawn(&mut world, 40.0, 20.0);
+        let high = spawn(&mut world, 80.0, -20.0);
+        let falling = spawn(&mut world, 40.0, -20.0);
+
+        world.run_system_once(process_projectile_logic).unwrap();
+
+        assert!(world.get_entity(climbing).is_ok());
+        assert!(world.get_entity(high).is_ok());
+        assert!(world.get_entity(falling).is_err());
+
+        // Capped at max_submunitions, every child inherits the owner and spawn point
+        let events = world.resource::<Messages<ProjectileSpawnedEvent>>();
+        let spawned: Vec<_> = events.iter_current_update_messages().cloned().collect();
+        assert_eq!(spawned.len(), 5);
+        for event in &spawned {
+            assert_eq!(event.owner, Some(owner));
+            assert!((event.origin.y - 40.0).abs() < 1e-4);
+            let child = world.get::<Projectile>(event.entity).unwrap();
+            assert_eq!(child.owner, Some(owner));
+            assert_eq!(child.previous_position, event.origin);
+            assert!(matches!(
+                world.get::<Payload>(event.entity),
+                Some(Payload::Explosive { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_cluster_waits_for_budget_and_follows_net_seed() {
+        use bevy::ecs::message::Messages;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<Fixed>::default());
+        world.insert_resource(BallisticsEnvironment::default());
+        world.insert_resource(BallisticsConfig {
+            max_submunitions: 0,
+            ..Default::default()
+        });
+        world.insert_resource(SpatialGrid::<ProximityTarget>::new(8.0));
+        world.init_resource::<Messages<ExplosionEvent>>();
+        world.init_resource::<Messages<ProjectileSpawnedEvent>>();
+
+        let cluster = Payload::Cluster {
+            submunition_count: 4,
+            submunition: Box::new(Payload::Kinetic { damage: 10.0 }),
+            deploy_altitude: 50.0,
+            submunition_fuse: None,
+        };
+        let net = NetProjectile {
+            owner_id: 1,
+            timestamp: 0.0,
+            spread_seed: 0xC1A5,
+        };
+        let parents: Vec<Entity> = (0..2)
+            .map(|_| {
+                world
+                    .spawn((
+                        Transform::from_xyz(0.0, 40.0, 0.0),
+                        Projectile::new(Vec3::new(100.0, -20.0, 0.0)),
+                        ProjectileLogic::Impact,
+                        cluster.clone(),
+                        net.clone(),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        // No budget left this step: the parents wait instead of vanishing
+        world.run_system_once(process_projectile_logic).unwrap();
+        assert!(parents.iter().all(|parent| world.get_entity(*parent).is_ok()));
+        let events = world.resource::<Messages<ProjectileSpawnedEvent>>();
+        assert_eq!(events.iter_current_update_messages().count(), 0);
+
+        world.resource_mut::<BallisticsConfig>().max_submunitions = 8;
+        world.run_system_once(process_projectile_logic).unwrap();
+        assert!(parents.iter().all(|parent| world.get_entity(*parent).is_err()));
+
+        // Same seed, same scatter, whatever the parent entity
+        let events = world.resource::<Messages<ProjectileSpawnedEvent>>();
+        let directions: Vec<Vec3> = events.iter_current_update_messages().map(|event| event.direction).collect();
+        assert_eq!(directions.len(), 8);
+        assert_eq!(directions[..4], directions[4..]);
+        assert_ne!(directions[0], directions[1]);
+    }
 }
@@ -2,8 +2,18 @@
 
 pub mod accuracy;
 pub mod collision;
+pub mod debris;
+pub mod fire;
+pub mod flinch;
+pub mod interpolation;
 pub mod kinematics;
 pub mod logic;
+pub mod near_miss;
+pub mod ownership;
+pub mod spatial;
 pub mod surface;
+pub mod targeting;
+pub mod trajectory;
 pub mod vfx;
 pub mod debug;
+pub mod event_log;
@@ -0,0 +1,250 @@
+//! Near-miss system - detects projectiles passing close to listeners.
+
+use bevy::prelude::*;
+use bevy::ecs::message::{MessageReader, MessageWriter};
+
+use crate::components::{BulletListener, Projectile};
+use crate::events::{FireEvent, NearMissEvent, ShotReportEvent};
+use crate::resources::{BallisticsConfig, BallisticsEnvironment, GridEntry, SpatialGrid};
+use crate::systems::spatial::closest_point_on_segment;
+
+/// Result of testing one flight segment against a listener.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearMiss {
+    /// Point on the segment closest to the listener
+    pub closest_point: Vec3,
+    /// Distance from the listener to `closest_point` (meters)
+    pub distance: f32,
+    /// Speed towards the listener at the start of the segment (m/s, negative when receding)
+    pub closing_speed: f32,
+}
+
+impl NearMiss {
+    /// Whether the round was approaching the listener.
+    pub fn closing(&self) -> bool {
+        self.closing_speed > 0.0
+    }
+}
+
+/// Test a projectile's flight segment against a listener.
+///
+/// # Arguments
+/// * `start` - Projectile position at the start of the step
+/// * `end` - Projectile position at the end of the step
+/// * `velocity` - Projectile velocity relative to the listener
+/// * `listener` - Listener position
+/// * `radius` - Detection radius in meters
+///
+/// # Returns
+/// The near miss, or None if the segment stays outside `radius`
+pub fn check_near_miss(
+    start: Vec3,
+    end: Vec3,
+    velocity: Vec3,
+    listener: Vec3,
+    radius: f32,
+) -> Option<NearMiss> {
+    let closest_point = closest_point_on_segment(start, end, listener);
+    let distance = closest_point.distance(listener);
+    if distance > radius {
+        return None;
+    }
+
+    // Listener-to-projectile direction at the start of the pass
+    let closing_speed = velocity.dot((listener - start).normalize_or_zero());
+    Some(NearMiss {
+        closest_point,
+        distance,
+        closing_speed,
+    })
+}
+
+/// Emit `NearMissEvent`s for projectiles passing listeners.
+///
+/// Runs after kinematics and sweeps each projectile's step from `previous_position`
+/// to its current position. A pass is reported once: on the step the round enters
+/// the listener's radius, or on its first step if it was spawned inside it.
+/// Listeners are treated as stationary and never hear their own shots. Only
+/// listeners in grid cells near the step are tested.
+///
+/// # Arguments
+/// * `time` - Time resource (fixed time in FixedUpdate)
+/// * `config` - Ballistics configuration resource (time scale)
+/// * `projectiles` - Query for projectiles and their transforms
+/// * `listeners` - Spatial grid of listeners, rebuilt this step
+/// * `near_miss_events` - Message writer for near-miss events
+pub fn detect_near_misses(
+    time: Res<Time>,
+    config: Res<BallisticsConfig>,
+    projectiles: Query<(Entity, &Transform, &Projectile)>,
+    listeners: Res<SpatialGrid<BulletListener>>,
+    mut near_miss_events: MessageWriter<NearMissEvent>,
+) {
+    let dt = config.scaled_delta(time.delta_secs());
+    for (entity, transform, projectile) in projectiles.iter() {
+        let start = projectile.previous_position;
+        let end = transform.translation;
+        let first_step = projectile.age <= dt;
+
+        listeners.for_each_near_segment(start, end, 0.0, |listener| {
+            if let Some(near_miss) = near_miss_for(projectile, end, first_step, listener) {
+                near_miss_events.write(NearMissEvent {
+                    projectile: entity,
+                    listener: listener.entity,
+                    closest_point: near_miss.closest_point,
+                    distance: near_miss.distance,
+                    relative_velocity: projectile.velocity,
+                    closing: near_miss.closing(),
+                });
+            }
+        });
+    }
+}
+
+/// Send a `ShotReportEvent` to every listener for each shot fired.
+///
+/// Each listener gets its own delay, from its distance to the muzzle and the
+/// environment's temperature-adjusted speed of sound.
+///
+/// # Arguments
+/// * `env` - Ballistics environment (speed of sound)
+/// * `fire_events` - Message reader for fire events
+/// * `listeners` - Query for listener entities and their transforms
+/// * `report_events` - Message writer for shot report events
+pub fn emit_shot_reports(
+    env: Res<BallisticsEnvironment>,
+    mut fire_events: MessageReader<FireEvent>,
+    listeners: Query<(Entity, &GlobalTransform), With<BulletListener>>,
+    mut report_events: MessageWriter<ShotReportEvent>,
+) {
+    let speed_of_sound = env.speed_of_sound();
+    for event in fire_events.read() {
+        for (listener, transform) in listeners.iter() {
+            report_events.write(ShotReportEvent {
+                position: event.origin,
+                delay: transform.translation().distance(event.origin) / speed_of_sound,
+                listener,
+            });
+        }
+    }
+}
+
+/// Near-miss test for one projectile step against one listener entry,
+/// including the owner and once-per-pass rules.
+fn near_miss_for(
+    projectile: &Projectile,
+    end: Vec3,
+    first_step: bool,
+    listener: &GridEntry,
+) -> Option<NearMiss> {
+    if projectile.owner == Some(listener.entity) {
+        return None;
+    }
+
+    let start = projectile.previous_position;
+    let started_inside = start.distance(listener.position) <= listener.radius;
+    if started_inside && !first_step {
+        // Already reported when it entered
+        return None;
+    }
+
+    check_near_miss(start, end, projectile.velocity, listener.position, listener.radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shot_report_delay_per_listener() {
+        let mut app = App::new();
+        app.insert_resource(BallisticsEnvironment {
+            temperature: 20.0,
+            ..Default::default()
+        })
+        .add_message::<FireEvent>()
+        .add_message::<ShotReportEvent>()
+        .add_systems(Update, emit_shot_reports);
+
+        let far = app
+            .world_mut()
+            .spawn((BulletListener::default(), GlobalTransform::from_xyz(340.0, 0.0, 0.0)))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn((BulletListener::default(), GlobalTransform::from_xyz(0.0, 0.0, 34.0)))
+            .id();
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 900.0));
+        app.update();
+
+        let reports: Vec<ShotReportEvent> = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ShotReportEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(reports.len(), 2);
+        let delay_for = |listener| reports.iter().find(|r| r.listener == listener).unwrap().delay;
+
+        // ~343 m/s at 20°C
+        assert!((delay_for(far) - 1.0).abs() < 0.02, "{}", delay_for(far));
+        assert!((delay_for(far) - 340.0 / 343.2).abs() < 1e-3);
+        assert!((delay_for(near) * 10.0 - delay_for(far)).abs() < 1e-4);
+
+        // Colder air carries sound slower
+        app.world_mut().resource_mut::<BallisticsEnvironment>().temperature = -20.0;
+        app.world_mut()
+            .write_message(FireEvent::new(Vec3::ZERO, Vec3::NEG_Z, 900.0));
+        app.update();
+        let cold = app
+            .world()
+            .resource::<bevy::ecs::message::Messages<ShotReportEvent>>()
+            .iter_current_update_messages()
+            .find(|r| r.listener == far)
+            .unwrap()
+            .delay;
+        assert!(cold > delay_for(far));
+    }
+
+    #[test]
+    fn test_near_miss_closing_vs_receding() {
+        let listener = Vec3::new(1.0, 0.0, -50.0);
+        let velocity = Vec3::new(0.0, 0.0, -850.0);
+
+        // Supersonic round flying past the listener within one step
+        let incoming = check_near_miss(
+            Vec3::new(0.0, 0.0, -45.0),
+            Vec3::new(0.0, 0.0, -58.0),
+            velocity,
+            listener,
+            3.0,
+        )
+        .unwrap();
+        assert!(incoming.closing());
+        assert!(incoming.closing_speed > 343.0);
+        assert!((incoming.distance - 1.0).abs() < 1e-4);
+        assert_eq!(incoming.closest_point, Vec3::new(0.0, 0.0, -50.0));
+
+        // Round already past the listener, flying away
+        let outgoing = check_near_miss(
+            Vec3::new(0.0, 0.0, -51.0),
+            Vec3::new(0.0, 0.0, -64.0),
+            velocity,
+            listener,
+            3.0,
+        )
+        .unwrap();
+        assert!(!outgoing.closing());
+
+        // Wide miss
+        assert!(check_near_miss(
+            Vec3::new(10.0, 0.0, -45.0),
+            Vec3::new(10.0, 0.0, -58.0),
+            velocity,
+            listener,
+            3.0,
+        )
+        .is_none());
+    }
+}
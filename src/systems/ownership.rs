@@ -0,0 +1,74 @@
+//! Ownership system - keeps the `ProjectilesByOwner` index in step with projectiles.
+
+use bevy::prelude::*;
+
+use crate::components::Projectile;
+use crate::resources::ProjectilesByOwner;
+
+/// Index a projectile under its owner when it is spawned.
+///
+/// # Arguments
+/// * `add` - The `Projectile` added to an entity
+/// * `projectiles` - Query for projectiles
+/// * `index` - Projectiles-by-owner index
+pub fn index_projectile_owner(
+    add: On<Add, Projectile>,
+    projectiles: Query<&Projectile>,
+    mut index: ResMut<ProjectilesByOwner>,
+) {
+    if let Some(owner) = projectiles.get(add.entity).ok().and_then(|projectile| projectile.owner) {
+        index.insert(owner, add.entity);
+    }
+}
+
+/// Drop a projectile from the index when it is despawned or retired.
+///
+/// # Arguments
+/// * `remove` - The `Projectile` about to be removed from an entity
+/// * `projectiles` - Query for projectiles (still present while this runs)
+/// * `index` - Projectiles-by-owner index
+pub fn unindex_projectile_owner(
+    remove: On<Remove, Projectile>,
+    projectiles: Query<&Projectile>,
+    mut index: ResMut<ProjectilesByOwner>,
+) {
+    let owner = projectiles.get(remove.entity).ok().and_then(|projectile| projectile.owner);
+    index.remove(owner, remove.entity);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_follows_spawn_and_despawn() {
+        let mut world = World::new();
+        world.init_resource::<ProjectilesByOwner>();
+        world.add_observer(index_projectile_owner);
+        world.add_observer(unindex_projectile_owner);
+
+        let owner = world.spawn_empty().id();
+        let rounds: Vec<Entity> = (0..3)
+            .map(|_| {
+                world
+                    .spawn(Projectile::new(Vec3::NEG_Z * 400.0).with_owner(owner))
+                    .id()
+            })
+            .collect();
+        world.spawn(Projectile::new(Vec3::NEG_Z * 400.0));
+
+        assert_eq!(world.resource::<ProjectilesByOwner>().count(owner), 3);
+
+        world.despawn(rounds[1]);
+        let index = world.resource::<ProjectilesByOwner>();
+        assert_eq!(index.count(owner), 2);
+        assert!(!index.get(owner).contains(&rounds[1]));
+
+        // Rounds retired as debris lose their Projectile and leave the index too
+        world.entity_mut(rounds[0]).remove::<Projectile>();
+        world.despawn(rounds[2]);
+        let index = world.resource::<ProjectilesByOwner>();
+        assert_eq!(index.count(owner), 0);
+        assert_eq!(index.owners().count(), 0);
+    }
+}
@@ -0,0 +1,123 @@
+//! Spatial grid system - keeps the proximity and near-miss target grids current.
+
+use bevy::prelude::*;
+
+use crate::components::{BulletListener, ProximityTarget};
+use crate::resources::{BallisticsConfig, SpatialGrid};
+
+/// Rebuild the target grids from current positions.
+///
+/// Runs once per fixed step before proximity fuses and near-miss detection.
+/// Cost is linear in the number of targets and listeners.
+///
+/// # Arguments
+/// * `config` - Ballistics configuration (grid cell size)
+/// * `targets` - Query for proximity targets
+/// * `listeners` - Query for bullet listeners
+/// * `proximity_grid` - Grid of proximity targets
+/// * `listener_grid` - Grid of bullet listeners
+pub fn rebuild_spatial_grids(
+    config: Res<BallisticsConfig>,
+    targets: Query<(Entity, &GlobalTransform), With<ProximityTarget>>,
+    listeners: Query<(Entity, &GlobalTransform, &BulletListener)>,
+    mut proximity_grid: ResMut<SpatialGrid<ProximityTarget>>,
+    mut listener_grid: ResMut<SpatialGrid<BulletListener>>,
+) {
+    proximity_grid.clear(config.spatial_grid_cell_size);
+    for (entity, transform) in targets.iter() {
+        proximity_grid.insert(entity, transform.translation(), 0.0);
+    }
+
+    listener_grid.clear(config.spatial_grid_cell_size);
+    for (entity, transform, listener) in listeners.iter() {
+        listener_grid.insert(entity, transform.translation(), listener.radius);
+    }
+}
+
+/// Point on the segment `start..end` closest to `point`.
+pub fn closest_point_on_segment(start: Vec3, end: Vec3, point: Vec3) -> Vec3 {
+    let segment = end - start;
+    let length_sq = segment.length_squared();
+    if length_sq <= 0.0 {
+        return start;
+    }
+    let t = ((point - start).dot(segment) / length_sq).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::near_miss::check_near_miss;
+    use std::collections::BTreeSet;
+
+    /// Tiny deterministic generator for test positions.
+    fn lcg(state: &mut u64) -> f32 {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (*state >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    #[test]
+    fn test_grid_matches_brute_force() {
+        let mut world = World::new();
+        let mut state = 0x5EED;
+        let random_point = |state: &mut u64| {
+            Vec3::new(lcg(state), lcg(state) * 0.2, lcg(state)) * 200.0 - Vec3::new(100.0, 20.0, 100.0)
+        };
+
+        // Targets with varying near-miss radii
+        let targets: Vec<(Entity, Vec3, f32)> = (0..300)
+            .map(|i| {
+                let position = random_point(&mut state);
+                (world.spawn_empty().id(), position, 1.0 + (i % 4) as f32)
+            })
+            .collect();
+        // Projectile steps of up to ~15m, like a fast round at 60 Hz
+        let steps: Vec<(Vec3, Vec3)> = (0..500)
+            .map(|_| {
+                let start = random_point(&mut state);
+                let step = Vec3::new(lcg(&mut state) - 0.5, lcg(&mut state) - 0.5, lcg(&mut state) - 0.5) * 30.0;
+                (start, start + step)
+            })
+            .collect();
+
+        let mut grid = SpatialGrid::<BulletListener>::new(4.0);
+        grid.clear(6.0);
+        for &(entity, position, radius) in &targets {
+            grid.insert(entity, position, radius);
+        }
+        assert_eq!(grid.len(), targets.len());
+
+        let range = 2.5;
+        let (mut brute, mut hashed) = (BTreeSet::new(), BTreeSet::new());
+        for (i, &(start, end)) in steps.iter().enumerate() {
+            let velocity = (end - start) * 60.0;
+            for &(entity, position, radius) in &targets {
+                if check_near_miss(start, end, velocity, position, radius).is_some() {
+                    brute.insert(("near_miss", i, entity));
+                }
+                if closest_point_on_segment(start, end, position).distance(position) <= range {
+                    brute.insert(("proximity", i, entity));
+                }
+            }
+
+            grid.for_each_near_segment(start, end, 0.0, |entry| {
+                if check_near_miss(start, end, velocity, entry.position, entry.radius).is_some() {
+                    hashed.insert(("near_miss", i, entry.entity));
+                }
+            });
+            grid.for_each_near_segment(start, end, range, |entry| {
+                if closest_point_on_segment(start, end, entry.position).distance(entry.position) <= range {
+                    hashed.insert(("proximity", i, entry.entity));
+                }
+            });
+        }
+
+        assert!(brute.len() > 20, "test should trigger some pairs");
+        assert_eq!(brute, hashed);
+
+        // Rebuilding drops stale entries
+        grid.clear(6.0);
+        assert!(grid.is_empty());
+    }
+}
@@ -5,6 +5,13 @@ use bevy::prelude::*;
 use crate::components::{Projectile, SurfaceMaterial};
 use crate::resources::BallisticsConfig;
 
+/// Fraction of the into-surface (normal) speed kept after a ricochet, relative to
+/// the fraction of along-surface speed the material keeps.
+///
+/// Impacts lose far more energy driving into the surface than sliding along it,
+/// so grazing hits keep most of their speed while steep ones die.
+const RICOCHET_NORMAL_RESTITUTION: f32 = 0.3;
+
 /// Process surface interactions (penetration, ricochet).
 /// 
 /// This system handles the advanced surface interaction logic including
@@ -24,7 +31,8 @@ pub fn process_surface_interactions(
 /// Calculate if a projectile can penetrate a surface.
 /// 
 /// Determines whether a projectile has sufficient penetration power to pass
-/// through a surface, taking into account the impact angle.
+/// through the whole layer ([`SurfaceMaterial::penetration_resistance`]),
+/// taking into account the impact angle.
 /// 
 /// # Arguments
 /// * `projectile` - Reference to the projectile component
@@ -42,13 +50,14 @@ pub fn can_penetrate(
     let angle_factor = impact_angle.cos().abs();
     let effective_power = projectile.penetration_power * angle_factor;
 
-    effective_power > surface.penetration_loss
+    effective_power > surface.penetration_resistance()
 }
 
 /// Calculate remaining penetration power after passing through material.
 /// 
 /// Computes how much penetration power remains after a projectile travels
-/// through a certain distance of a surface material.
+/// through a certain distance of a surface material, losing
+/// [`SurfaceMaterial::resistance_through`] that distance.
 /// 
 /// # Arguments
 /// * `initial_power` - The projectile's initial penetration power
@@ -63,36 +72,73 @@ pub fn calculate_remaining_penetration(
     travel_distance: f32,
 ) -> f32 {
     // Power loss is proportional to travel distance through material
-    let distance_factor = travel_distance / surface.thickness;
-    let power_loss = surface.penetration_loss * distance_factor;
+    (initial_power - surface.resistance_through(travel_distance)).max(0.0)
+}
 
-    (initial_power - power_loss).max(0.0)
+/// Penetration power left after a change of speed.
+/// 
+/// Penetration power stands for the round's kinetic energy, so it follows the
+/// square of the share of speed kept. It never grows.
+/// 
+/// # Arguments
+/// * `power` - Penetration power before the interaction
+/// * `old_speed` - Speed before the interaction (m/s)
+/// * `new_speed` - Speed after the interaction (m/s)
+/// 
+/// # Returns
+/// The penetration power matching `new_speed`
+pub fn retained_penetration_power(power: f32, old_speed: f32, new_speed: f32) -> f32 {
+    if old_speed <= 0.0 {
+        return power;
+    }
+    power * (new_speed / old_speed).clamp(0.0, 1.0).powi(2)
 }
 
 /// Calculate exit velocity after penetration.
 /// 
-/// Computes the velocity of a projectile after it has penetrated a surface,
-/// accounting for energy loss due to material resistance.
+/// Computes the velocity of a projectile after it has penetrated a surface.
+/// The round keeps the energy left over after the material's resistance over
+/// the distance travelled (up to the full `thickness`), the same way armor is
+/// defeated, so speed falls with the square root of the energy kept.
 /// 
 /// # Arguments
 /// * `entry_velocity` - The velocity vector of the projectile when entering the surface
+/// * `power` - Energy the round brings to the surface, in the units of `penetration_loss`
 /// * `surface` - Reference to the surface material component
 /// * `travel_distance` - The distance the projectile traveled through the material
 /// 
 /// # Returns
-/// The velocity vector of the projectile after penetration
+/// The velocity vector of the projectile after penetration, zero if the
+/// material absorbs all of `power`
 pub fn calculate_exit_velocity(
     entry_velocity: Vec3,
+    power: f32,
     surface: &SurfaceMaterial,
     travel_distance: f32,
 ) -> Vec3 {
-    // Speed reduction based on material resistance and distance
-    let speed = entry_velocity.length();
-    let thickness_ratio = (travel_distance / surface.thickness).min(1.0);
-    let speed_loss_ratio = surface.penetration_loss / 100.0 * thickness_ratio;
-    let exit_speed = speed * (1.0 - speed_loss_ratio).max(0.1);
+    if power <= 0.0 {
+        return Vec3::ZERO;
+    }
+    let depth = travel_distance.min(surface.thickness);
+    let kept = ((power - surface.resistance_through(depth)) / power).max(0.0);
 
-    entry_velocity.normalize() * exit_speed
+    entry_velocity * kept.sqrt()
+}
+
+/// Angle between an incoming projectile and the surface normal.
+/// 
+/// # Arguments
+/// * `velocity` - The velocity vector of the projectile
+/// * `surface_normal` - The normal vector of the surface
+/// 
+/// # Returns
+/// Radians from the normal: 0 for a head-on hit (or a stationary round),
+/// π/2 for a grazing one
+pub fn impact_angle(velocity: Vec3, surface_normal: Vec3) -> f32 {
+    let Some(direction) = velocity.try_normalize() else {
+        return 0.0;
+    };
+    direction.dot(-surface_normal).clamp(-1.0, 1.0).acos()
 }
 
 /// Check if projectile should ricochet based on impact angle.
@@ -112,9 +158,6 @@ pub fn should_ricochet(
     surface_normal: Vec3,
     surface: &SurfaceMaterial,
 ) -> bool {
-    // Calculate impact angle (angle from surface normal)
-    let dot = velocity.normalize().dot(-surface_normal);
-    
     // Early return if projectile is moving AWAY from the surface normal (exiting)
     // We use a small epsilon to avoid floating point issues.
     // If velocity.dot(surface_normal) is positive, the projectile is moving away from the surface.
@@ -122,11 +165,11 @@ pub fn should_ricochet(
         return false;
     }
 
-    let impact_angle = dot.min(1.0).max(-1.0).acos();
+    let angle = impact_angle(velocity, surface_normal);
 
     // Ricochet occurs when impact angle is very shallow (near PI/2 from normal)
     // The threshold is measured FROM the surface, so we compare with PI/2 - threshold
-    impact_angle > (std::f32::consts::FRAC_PI_2 - surface.ricochet_angle)
+    angle > (std::f32::consts::FRAC_PI_2 - surface.ricochet_angle)
 }
 
 /// Calculate ricochet direction and speed.
@@ -134,6 +177,12 @@ pub fn should_ricochet(
 /// Computes the new direction and speed of a projectile after it ricochets
 /// off a surface, accounting for energy loss during the impact.
 /// 
+/// The velocity is split into the component along the surface normal and the
+/// tangential component. Only the normal component is reflected, and each is
+/// damped separately: the tangential part keeps the material's retention factor
+/// (harder surfaces keep more), the normal part only a fraction of that. Both
+/// factors are at most 1.0, so a ricochet never gains energy.
+/// 
 /// # Arguments
 /// * `velocity` - The velocity vector of the projectile before ricochet
 /// * `surface_normal` - The normal vector of the surface
@@ -146,17 +195,17 @@ pub fn calculate_ricochet(
     surface_normal: Vec3,
     surface: &SurfaceMaterial,
 ) -> (Vec3, f32) {
-    let speed = velocity.length();
-    let direction = velocity.normalize();
+    let normal_velocity = velocity.dot(surface_normal) * surface_normal;
+    let tangential_velocity = velocity - normal_velocity;
 
-    // Reflect direction off surface
-    let reflected = direction - 2.0 * direction.dot(surface_normal) * surface_normal;
+    // Harder surfaces preserve more energy along the surface
+    let tangential_retention = 1.0 - (surface.penetration_loss / 200.0).clamp(0.0, 0.8);
+    let normal_retention = tangential_retention * RICOCHET_NORMAL_RESTITUTION;
 
-    // Speed loss on ricochet (harder surfaces preserve more energy)
-    let speed_retention = 1.0 - (surface.penetration_loss / 200.0).min(0.8);
-    let new_speed = speed * speed_retention;
+    // Reflect only the normal component, damping each part separately
+    let outgoing = tangential_velocity * tangential_retention - normal_velocity * normal_retention;
 
-    (reflected.normalize(), new_speed)
+    (outgoing.normalize_or_zero(), outgoing.length())
 }
 
 /// Material presets for common surfaces.
@@ -314,6 +363,36 @@ mod tests {
         assert!(speed < velocity.length());
     }
 
+    #[test]
+    fn test_ricochet_keeps_grazing_speed() {
+        let normal = Vec3::Y;
+        let surface = materials::metal();
+        let retention = |angle_from_surface: f32| {
+            let velocity = Vec3::new(angle_from_surface.cos(), -angle_from_surface.sin(), 0.0) * 400.0;
+            let (direction, speed) = calculate_ricochet(velocity, normal, &surface);
+            assert!(direction.y > 0.0 && direction.x > 0.0);
+            speed / 400.0
+        };
+
+        let shallow = retention(5f32.to_radians());
+        let steep = retention(60f32.to_radians());
+        assert!(shallow > steep);
+        // Grazing keeps nearly all of metal's along-surface retention, steep loses most
+        let flat = 1.0 - surface.penetration_loss / 200.0;
+        assert!(shallow > flat * 0.95);
+        assert!(steep < flat * 0.6);
+
+        // Never gains energy, even on a lossless surface hit head-on or grazing
+        let lossless = SurfaceMaterial {
+            penetration_loss: 0.0,
+            ..Default::default()
+        };
+        for velocity in [Vec3::new(0.0, -400.0, 0.0), Vec3::new(400.0, -1.0, 0.0)] {
+            let (_, speed) = calculate_ricochet(velocity, normal, &lossless);
+            assert!(speed <= velocity.length());
+        }
+    }
+
     #[test]
     fn test_penetration_check() {
         let mut projectile = Projectile::default();
@@ -325,8 +404,37 @@ mod tests {
         // Should penetrate glass
         assert!(can_penetrate(&projectile, &weak_surface, 0.0));
 
-        // Should not penetrate metal with low power
-        projectile.penetration_power = 50.0;
+        // Should not penetrate a 1 cm steel plate with low power
+        projectile.penetration_power = 15.0;
         assert!(!can_penetrate(&projectile, &strong_surface, 0.0));
     }
+
+    #[test]
+    fn test_exit_velocity_keeps_leftover_energy() {
+        let velocity = Vec3::NEG_Z * 400.0;
+        let wall = SurfaceMaterial {
+            penetration_loss: 30.0,
+            thickness: 0.2,
+            ..Default::default()
+        };
+        let resistance = wall.penetration_resistance();
+
+        // A quarter of the energy left is half the speed, however thick the layer
+        let exit = calculate_exit_velocity(velocity, resistance * 4.0 / 3.0, &wall, wall.thickness);
+        assert!((exit.length() - 200.0).abs() < 1e-2);
+        assert_eq!(exit.normalize(), Vec3::NEG_Z);
+
+        // Only the depth actually crossed counts
+        let halfway = calculate_exit_velocity(velocity, resistance * 4.0 / 3.0, &wall, 0.1);
+        assert!(halfway.length() > exit.length());
+
+        // More than the round can defeat
+        let stopped = calculate_exit_velocity(velocity, resistance * 0.5, &wall, wall.thickness);
+        assert_eq!(stopped, Vec3::ZERO);
+        let mut projectile = Projectile::new(velocity);
+        projectile.penetration_power = resistance * 0.5;
+        assert!(!can_penetrate(&projectile, &wall, 0.0));
+        projectile.penetration_power = resistance * 2.0;
+        assert!(can_penetrate(&projectile, &wall, 0.0));
+    }
 }
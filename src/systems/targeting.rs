@@ -0,0 +1,219 @@
+//! Target lock system - seekers that paint `Lockable` entities for guided rounds.
+
+use bevy::prelude::*;
+use bevy::ecs::message::MessageWriter;
+
+use crate::components::{Guidance, Lockable, Projectile, TargetLock};
+use crate::events::LockAcquiredEvent;
+use crate::resources::BallisticsConfig;
+
+/// Whether `point` lies inside a seeker's cone.
+///
+/// # Arguments
+/// * `origin` - Seeker position
+/// * `forward` - Seeker boresight (normalized internally)
+/// * `lock` - Seeker settings (range and cone half-angle)
+/// * `point` - World-space position to test
+///
+/// # Returns
+/// True if `point` is within `lock.range` and `lock.cone_angle` of the boresight
+pub fn in_lock_cone(origin: Vec3, forward: Vec3, lock: &TargetLock, point: Vec3) -> bool {
+    let to_point = point - origin;
+    let distance = to_point.length();
+    if distance > lock.range {
+        return false;
+    }
+    if distance <= f32::EPSILON {
+        return true;
+    }
+    forward.normalize_or_zero().angle_between(to_point / distance) <= lock.cone_angle
+}
+
+/// Pick the candidate a seeker would lock onto.
+///
+/// The cone stands in for a single ray down the boresight, which would
+/// almost never strike a small target exactly. Of the candidates inside the
+/// cone the one closest to the boresight wins. The seeker's owner is never
+/// picked. Occlusion is not tested.
+///
+/// # Arguments
+/// * `origin` - Seeker position
+/// * `forward` - Seeker boresight
+/// * `lock` - Seeker settings
+/// * `candidates` - Lockable entities and their world positions
+///
+/// # Returns
+/// The entity to lock, or None if nothing is in the cone
+pub fn lock_target(
+    origin: Vec3,
+    forward: Vec3,
+    lock: &TargetLock,
+    candidates: impl IntoIterator<Item = (Entity, Vec3)>,
+) -> Option<Entity> {
+    let forward = forward.normalize_or_zero();
+    candidates
+        .into_iter()
+        .filter(|(entity, _)| Some(*entity) != lock.owner)
+        .filter(|(_, position)| in_lock_cone(origin, forward, lock, *position))
+        .map(|(entity, position)| (entity, forward.angle_between(position - origin)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Acquire, hold and drop seeker locks.
+///
+/// A held target that leaves the cone is kept for `grace_period` seconds and
+/// dropped after that, or at once if it is despawned or loses `Lockable`.
+/// A seeker without a target locks the best candidate from [`lock_target`]
+/// and sends a `LockAcquiredEvent`.
+///
+/// # Arguments
+/// * `time` - Bevy Time resource
+/// * `config` - Ballistics configuration resource (time scale)
+/// * `seekers` - Weapons carrying a `TargetLock`
+/// * `lockables` - Entities that can be locked
+/// * `lock_events` - Message writer for lock events
+pub fn update_target_locks(
+    time: Res<Time>,
+    config: Res<BallisticsConfig>,
+    mut seekers: Query<(Entity, &GlobalTransform, &mut TargetLock)>,
+    lockables: Query<(Entity, &GlobalTransform), With<Lockable>>,
+    mut lock_events: MessageWriter<LockAcquiredEvent>,
+) {
+    let dt = config.scaled_delta(time.delta_secs());
+
+    for (weapon, seeker, mut lock) in seekers.iter_mut() {
+        let origin = seeker.translation();
+        let forward = *seeker.forward();
+
+        if let Some(target) = lock.target {
+            match lockables.get(target) {
+                Ok((_, transform)) if in_lock_cone(origin, forward, &lock, transform.translation()) => {
+                    lock.time_outside = 0.0;
+                }
+                Ok(_) => {
+                    lock.time_outside += dt;
+                    if lock.time_outside > lock.grace_period {
+                        lock.target = None;
+                    }
+                }
+                Err(_) => lock.target = None,
+            }
+            if lock.target.is_some() {
+                continue;
+            }
+        }
+
+        let candidates = lockables
+            .iter()
+            .filter(|(entity, _)| *entity != weapon)
+            .map(|(entity, transform)| (entity, transform.translation()));
+        if let Some(target) = lock_target(origin, forward, &lock, candidates) {
+            lock.target = Some(target);
+            lock.time_outside = 0.0;
+            lock_events.write(LockAcquiredEvent {
+                weapon,
+                owner: lock.owner,
+                target,
+            });
+        }
+    }
+}
+
+/// Hand a held lock to guided rounds as they are fired.
+///
+/// Newly spawned rounds with an empty `Guidance::target` take the target of
+/// the `TargetLock` whose `owner` fired them. Rounds spawned with a target
+/// keep it.
+///
+/// # Arguments
+/// * `seekers` - Weapons carrying a `TargetLock`
+/// * `missiles` - Guided rounds added since the last run
+pub fn assign_locked_targets(
+    seekers: Query<&TargetLock>,
+    mut missiles: Query<(&Projectile, &mut Guidance), Added<Guidance>>,
+) {
+    for (projectile, mut guidance) in missiles.iter_mut() {
+        if guidance.target.is_some() || projectile.owner.is_none() {
+            continue;
+        }
+        guidance.target = seekers
+            .iter()
+            .find(|lock| lock.owner == projectile.owner && lock.target.is_some())
+            .and_then(|lock| lock.target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use std::time::Duration;
+
+    #[test]
+    fn test_lock_feeds_next_missile_and_survives_grace_period() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(BallisticsConfig::default());
+        world.init_resource::<Messages<LockAcquiredEvent>>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((update_target_locks, assign_locked_targets).chain());
+
+        // The shooter is itself lockable and sits right on the boresight
+        let shooter = world
+            .spawn((Lockable, GlobalTransform::from_xyz(0.0, 0.0, -0.5)))
+            .id();
+        let weapon = world
+            .spawn((
+                TargetLock::new(shooter).with_grace_period(0.45),
+                GlobalTransform::IDENTITY,
+            ))
+            .id();
+        let target = world
+            .spawn((Lockable, GlobalTransform::from_xyz(1.0, 0.0, -100.0)))
+            .id();
+        world.spawn((Lockable, GlobalTransform::from_xyz(100.0, 0.0, 0.0)));
+
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<TargetLock>(weapon).unwrap().target, Some(target));
+        let events: Vec<_> = world
+            .resource::<Messages<LockAcquiredEvent>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].target, target);
+        assert_eq!(events[0].owner, Some(shooter));
+
+        let missile = world
+            .spawn((
+                Projectile::new(Vec3::NEG_Z * 200.0).with_owner(shooter),
+                Guidance::default(),
+            ))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Guidance>(missile).unwrap().target, Some(target));
+
+        // Target breaks out of the cone: held through the grace period, then dropped
+        world
+            .entity_mut(target)
+            .insert(GlobalTransform::from_xyz(80.0, 0.0, -20.0));
+        for _ in 0..4 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.get::<TargetLock>(weapon).unwrap().target, Some(target));
+
+        for _ in 0..2 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.get::<TargetLock>(weapon).unwrap().target, None);
+    }
+}
@@ -0,0 +1,828 @@
+//! Trajectory solvers - range and firing-solution helpers built on the live integrator.
+
+use bevy::prelude::*;
+
+use crate::components::{Guidance, Payload, Projectile, ProjectileLogic, Sight, WeaponZeroing};
+use crate::resources::{BallisticsConfig, BallisticsEnvironment};
+use crate::systems::kinematics::{predict_trajectory, step_projectile, steer_towards};
+
+/// Time step used by the solvers (seconds).
+const SOLVER_DT: f32 = 1.0 / 120.0;
+
+/// Time step used to predict danger zones (seconds).
+const DANGER_ZONE_DT: f32 = 1.0 / 60.0;
+
+/// How far ahead danger zones are predicted (seconds); later detonations are left out.
+pub const DANGER_ZONE_HORIZON: f32 = 10.0;
+
+/// Golden-section iterations for angle searches (~1e-7 rad over 0..90°).
+const GOLDEN_SECTION_ITERATIONS: usize = 40;
+
+/// Maximum refinement iterations for firing solutions.
+const INTERCEPT_MAX_ITERATIONS: usize = 24;
+
+/// Miss distance (meters) below which a firing solution is considered converged.
+const INTERCEPT_TOLERANCE: f32 = 0.01;
+
+/// Launch velocity for a shot fired along -Z, elevated by `angle` radians.
+fn launch_velocity(muzzle_velocity: f32, angle: f32) -> Vec3 {
+    Vec3::new(0.0, angle.sin(), -angle.cos()) * muzzle_velocity
+}
+
+/// Fly a shot over flat ground at `y = 0` and return `(range, peak_height)`.
+///
+/// The flight is limited by `config.max_projectile_lifetime`, like a live round;
+/// a shot still airborne at that point reports its horizontal distance so far.
+fn fly_over_flat_ground(
+    muzzle_velocity: f32,
+    angle: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> (f32, f32) {
+    let mut shot = projectile.clone();
+    shot.velocity = launch_velocity(muzzle_velocity, angle);
+
+    let points = predict_trajectory(
+        Vec3::ZERO,
+        &shot,
+        env,
+        config,
+        SOLVER_DT,
+        config.max_projectile_lifetime,
+    );
+
+    let peak = points.iter().fold(0.0_f32, |peak, p| peak.max(p.y));
+
+    // Interpolate the step that crosses back through the ground plane
+    let range = points
+        .windows(2)
+        .find(|w| w[0].y >= 0.0 && w[1].y < 0.0)
+        .map(|w| {
+            let t = w[0].y / (w[0].y - w[1].y);
+            w[0].lerp(w[1], t).xz().length()
+        })
+        .unwrap_or_else(|| points.last().map_or(0.0, |p| p.xz().length()));
+
+    (range, peak)
+}
+
+/// Find the launch angle that maximizes flat-ground range.
+///
+/// Runs a golden-section search over 0..90° elevation, evaluating each angle
+/// with `predict_trajectory`, so drag, wind and density are all accounted for
+/// (unlike the vacuum 45° rule). Shots are fired along -Z from ground level.
+///
+/// # Arguments
+/// * `muzzle_velocity` - Launch speed in meters per second
+/// * `projectile` - Projectile physical properties (velocity is ignored)
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// A tuple containing the optimal elevation angle (radians) and the range it reaches (meters)
+pub fn max_range(
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> (f32, f32) {
+    let range_at =
+        |angle: f32| fly_over_flat_ground(muzzle_velocity, angle, projectile, env, config).0;
+
+    let inv_phi = (5.0_f32.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (0.0_f32, std::f32::consts::FRAC_PI_2);
+    let mut a = high - inv_phi * (high - low);
+    let mut b = low + inv_phi * (high - low);
+    let mut range_a = range_at(a);
+    let mut range_b = range_at(b);
+
+    for _ in 0..GOLDEN_SECTION_ITERATIONS {
+        if range_a < range_b {
+            low = a;
+            a = b;
+            range_a = range_b;
+            b = low + inv_phi * (high - low);
+            range_b = range_at(b);
+        } else {
+            high = b;
+            b = a;
+            range_b = range_a;
+            a = high - inv_phi * (high - low);
+            range_a = range_at(a);
+        }
+    }
+
+    let angle = (low + high) * 0.5;
+    (angle, range_at(angle))
+}
+
+/// Calculate the peak height (maximum ordinate) of a shot.
+///
+/// # Arguments
+/// * `muzzle_velocity` - Launch speed in meters per second
+/// * `angle` - Elevation angle in radians
+/// * `projectile` - Projectile physical properties (velocity is ignored)
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// The highest point of the trajectory above the launch height (meters)
+pub fn max_ordinate(
+    muzzle_velocity: f32,
+    angle: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> f32 {
+    fly_over_flat_ground(muzzle_velocity, angle, projectile, env, config).1
+}
+
+/// Where and when a shot crosses the plane through `target` facing the shooter.
+///
+/// # Returns
+/// `Some((crossing_point, time_of_flight))`, or None if the shot never gets that far
+fn cross_target_plane(
+    shooter_pos: Vec3,
+    direction: Vec3,
+    target: Vec3,
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Option<(Vec3, f32)> {
+    let to_target = target - shooter_pos;
+    let distance = to_target.length();
+    let line_of_sight = to_target.normalize_or_zero();
+
+    let mut shot = projectile.clone();
+    shot.velocity = direction * muzzle_velocity;
+    let points = predict_trajectory(
+        shooter_pos,
+        &shot,
+        env,
+        config,
+        SOLVER_DT,
+        config.max_projectile_lifetime,
+    );
+
+    let along = |p: Vec3| (p - shooter_pos).dot(line_of_sight);
+    points.windows(2).enumerate().find_map(|(i, w)| {
+        let (before, after) = (along(w[0]), along(w[1]));
+        (before < distance && after >= distance).then(|| {
+            let t = (distance - before) / (after - before);
+            (w[0].lerp(w[1], t), (i as f32 + t) * SOLVER_DT)
+        })
+    })
+}
+
+/// Solve the aim direction needed to hit a moving target.
+///
+/// Iterates time-of-flight against the target's predicted position, flying each
+/// candidate shot through `predict_trajectory` so drag and gravity drop are both
+/// included. Each iteration shifts the aim point by the miss at the target plane.
+///
+/// # Arguments
+/// * `shooter_pos` - Muzzle position in world space
+/// * `target_pos` - Current target position
+/// * `target_vel` - Target velocity (assumed constant) in meters per second
+/// * `muzzle_velocity` - Launch speed in meters per second
+/// * `projectile` - Projectile physical properties (velocity is ignored)
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// The normalized aim direction, or None if the target can't be reached
+/// (too far/fast for the round's lifetime) or the solution doesn't converge
+pub fn solve_intercept(
+    shooter_pos: Vec3,
+    target_pos: Vec3,
+    target_vel: Vec3,
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Option<Vec3> {
+    if muzzle_velocity <= 0.0 {
+        return None;
+    }
+
+    let mut time_of_flight = (target_pos - shooter_pos).length() / muzzle_velocity;
+    let mut aim_point = target_pos + target_vel * time_of_flight;
+
+    for _ in 0..INTERCEPT_MAX_ITERATIONS {
+        let predicted = target_pos + target_vel * time_of_flight;
+        let direction = (aim_point - shooter_pos).try_normalize()?;
+
+        let (crossing, flight_time) = cross_target_plane(
+            shooter_pos,
+            direction,
+            predicted,
+            muzzle_velocity,
+            projectile,
+            env,
+            config,
+        )?;
+
+        let miss = predicted - crossing;
+        let converged = miss.length() < INTERCEPT_TOLERANCE
+            && (flight_time - time_of_flight).abs() * target_vel.length() < INTERCEPT_TOLERANCE;
+        if converged {
+            return Some(direction);
+        }
+
+        aim_point += miss;
+        time_of_flight = flight_time;
+    }
+
+    None
+}
+
+/// Solve the bore elevation that puts the shot on the line of sight at `zero_distance`.
+///
+/// The sight sits `sight_height` above the bore and looks level along -Z, so the
+/// round has to climb through the line of sight and fall back onto it at the zero
+/// range. Each iteration flies the shot through `predict_trajectory` and corrects
+/// the angle by the vertical miss, so drag and the current environment are included.
+///
+/// # Arguments
+/// * `zero_distance` - Distance at which the shot should meet the line of sight (meters)
+/// * `muzzle_velocity` - Launch speed in meters per second
+/// * `sight_height` - Height of the sight above the bore axis (meters)
+/// * `projectile` - Projectile physical properties (velocity is ignored)
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// The pitch adjustment in radians, or None if the round can't reach the zero distance
+pub fn compute_zero_pitch(
+    zero_distance: f32,
+    muzzle_velocity: f32,
+    sight_height: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Option<f32> {
+    if zero_distance <= 0.0 || muzzle_velocity <= 0.0 {
+        return None;
+    }
+
+    let target = Vec3::new(0.0, sight_height, -zero_distance);
+    let mut angle = (sight_height / zero_distance).atan();
+
+    for _ in 0..INTERCEPT_MAX_ITERATIONS {
+        let direction = Vec3::new(0.0, angle.sin(), -angle.cos());
+        let (crossing, _) = cross_target_plane(
+            Vec3::ZERO,
+            direction,
+            target,
+            muzzle_velocity,
+            projectile,
+            env,
+            config,
+        )?;
+
+        let miss = target.y - crossing.y;
+        if miss.abs() < INTERCEPT_TOLERANCE * 0.1 {
+            return Some(angle);
+        }
+        angle += (miss / zero_distance).atan();
+    }
+
+    None
+}
+
+/// Angle (radians) the shot passes below the line of sight at `target_distance`,
+/// when fired with the bore elevated for the sight's current zero.
+fn holdover_angle(
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    sight: &Sight,
+    target_distance: f32,
+) -> Option<f32> {
+    if target_distance <= 0.0 {
+        return None;
+    }
+
+    let zero_pitch = compute_zero_pitch(
+        sight.current_zero,
+        muzzle_velocity,
+        sight.height_over_bore,
+        projectile,
+        env,
+        config,
+    )?;
+
+    let direction = Vec3::new(0.0, zero_pitch.sin(), -zero_pitch.cos());
+    let target = Vec3::new(0.0, sight.height_over_bore, -target_distance);
+    let (crossing, _) = cross_target_plane(
+        Vec3::ZERO,
+        direction,
+        target,
+        muzzle_velocity,
+        projectile,
+        env,
+        config,
+    )?;
+
+    Some(((target.y - crossing.y) / target_distance).atan())
+}
+
+/// Elevation correction in milliradians for a target at a measured distance.
+///
+/// Flies the round with the bore set for the sight's current zero and measures
+/// how far it lands from the line of sight at `target_distance`, using the same
+/// integrator as live flight. The result is relative to the zero, not the bore:
+/// it is 0 at the zero distance, positive ("mil up") beyond it and negative
+/// when the round is still climbing above the line of sight.
+///
+/// # Arguments
+/// * `muzzle_velocity` - Launch speed in meters per second
+/// * `projectile` - Projectile physical properties (velocity is ignored)
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+/// * `sight` - Sight providing the current zero and height over bore
+/// * `target_distance` - Measured distance to the target (meters)
+///
+/// # Returns
+/// The holdover in mils, or None if the round can't reach the zero or the target
+pub fn holdover_mils(
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    sight: &Sight,
+    target_distance: f32,
+) -> Option<f32> {
+    holdover_angle(muzzle_velocity, projectile, env, config, sight, target_distance)
+        .map(|angle| angle * 1000.0)
+}
+
+/// Elevation correction in minutes of angle for a target at a measured distance.
+///
+/// Same as [`holdover_mils`], expressed in MOA (1/60 of a degree).
+///
+/// # Returns
+/// The holdover in MOA, or None if the round can't reach the zero or the target
+pub fn holdover_moa(
+    muzzle_velocity: f32,
+    projectile: &Projectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+    sight: &Sight,
+    target_distance: f32,
+) -> Option<f32> {
+    holdover_angle(muzzle_velocity, projectile, env, config, sight, target_distance)
+        .map(|angle| angle.to_degrees() * 60.0)
+}
+
+/// Re-solve every [`WeaponZeroing`] after the environment changes.
+///
+/// Runs the solver only on frames where `BallisticsEnvironment` was mutated (e.g.
+/// a map with a different altitude or temperature was loaded), for all zeroings
+/// at once in parallel. Zeroings without a recorded solution are left as they are.
+///
+/// # Arguments
+/// * `env` - Ballistics environment resource
+/// * `config` - Ballistics configuration resource
+/// * `zeroings` - Query for weapon zeroings
+pub fn rezero_on_environment_change(
+    env: Res<BallisticsEnvironment>,
+    config: Res<BallisticsConfig>,
+    mut zeroings: Query<&mut WeaponZeroing>,
+) {
+    if !env.is_changed() {
+        return;
+    }
+    zeroings.par_iter_mut().for_each(|mut zeroing| {
+        if zeroing.solution.is_some() {
+            zeroing.rezero(&env, &config);
+        }
+    });
+}
+
+/// A live projectile to check for a danger zone.
+///
+/// Built from the projectile's components, e.g. while iterating a query. Guided
+/// rounds also need the position of the entity they are homing on.
+///
+/// # Fields
+/// * `position` - Current world-space position
+/// * `projectile` - Projectile component
+/// * `logic` - Fuse logic
+/// * `payload` - Payload, which sets the zone radius
+/// * `guidance` - Guidance and the current position of its target
+#[derive(Clone, Copy)]
+pub struct IncomingProjectile<'a> {
+    /// Current world-space position
+    pub position: Vec3,
+    /// Projectile component
+    pub projectile: &'a Projectile,
+    /// Fuse logic
+    pub logic: &'a ProjectileLogic,
+    /// Payload, which sets the zone radius
+    pub payload: Option<&'a Payload>,
+    /// Guidance and the current position of its target
+    pub guidance: Option<(&'a Guidance, Vec3)>,
+}
+
+impl<'a> IncomingProjectile<'a> {
+    /// Creates an incoming projectile with no payload or guidance.
+    ///
+    /// # Arguments
+    /// * `position` - Current world-space position
+    /// * `projectile` - Projectile component
+    /// * `logic` - Fuse logic
+    pub fn new(position: Vec3, projectile: &'a Projectile, logic: &'a ProjectileLogic) -> Self {
+        Self {
+            position,
+            projectile,
+            logic,
+            payload: None,
+            guidance: None,
+        }
+    }
+
+    /// Builder pattern: set the payload.
+    pub fn with_payload(mut self, payload: Option<&'a Payload>) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    /// Builder pattern: home on `target_position` with `guidance`.
+    pub fn with_guidance(mut self, guidance: &'a Guidance, target_position: Vec3) -> Self {
+        self.guidance = Some((guidance, target_position));
+        self
+    }
+}
+
+/// Predict where live grenades and guided rounds will go off, for AI avoidance.
+///
+/// Each projectile is flown ahead with `step_projectile`, so the prediction
+/// follows the live flight model. `Timed` rounds detonate where they are when
+/// the fuse burns down; guided rounds steer like `update_guidance` toward their
+/// target's current position and impact when they reach it, unless the fuse
+/// goes first. Nothing is ray cast, so rounds are assumed to fly clear of
+/// scenery, and unguided rounds without a fuse produce no zone. Rounds that
+/// would expire first, or only go off beyond [`DANGER_ZONE_HORIZON`], are left
+/// out, which keeps the cost at a few hundred steps per projectile.
+///
+/// # Arguments
+/// * `projectiles` - Live projectiles to check
+/// * `env` - Reference to the ballistics environment
+/// * `config` - Reference to the ballistics configuration
+///
+/// # Returns
+/// `(center, radius, time_to_impact)` for each predicted detonation or impact,
+/// with the radius taken from the payload (zero without one) and the time in seconds
+pub fn predicted_impact_zones<'a>(
+    projectiles: impl IntoIterator<Item = IncomingProjectile<'a>>,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Vec<(Vec3, f32, f32)> {
+    projectiles
+        .into_iter()
+        .filter_map(|incoming| predict_impact_zone(&incoming, env, config))
+        .collect()
+}
+
+/// Fly one projectile ahead for [`predicted_impact_zones`].
+fn predict_impact_zone(
+    incoming: &IncomingProjectile,
+    env: &BallisticsEnvironment,
+    config: &BallisticsConfig,
+) -> Option<(Vec3, f32, f32)> {
+    let lifetime = (config.max_projectile_lifetime - incoming.projectile.age).max(0.0);
+    let horizon = lifetime.min(DANGER_ZONE_HORIZON);
+    let detonation = incoming.logic.fuse_remaining().filter(|fuse| *fuse <= horizon);
+    if detonation.is_none() && incoming.guidance.is_none() {
+        return None;
+    }
+
+    let radius = incoming.payload.map_or(0.0, Payload::radius);
+    let flight_time = detonation.unwrap_or(horizon);
+    let steps = (flight_time / DANGER_ZONE_DT).ceil() as usize;
+    let dt = if steps > 0 { flight_time / steps as f32 } else { 0.0 };
+
+    let mut bullet = incoming.projectile.clone();
+    bullet.previous_position = incoming.position;
+    let mut transform = Transform::from_translation(incoming.position);
+    let mut guidance = incoming.guidance.map(|(guidance, target)| (guidance.clone(), target));
+
+    for step in 0..steps {
+        if let Some((guidance, target)) = guidance.as_mut() {
+            // Reaching the target within this step is the impact
+            let distance = transform.translation.distance(*target);
+            let speed = bullet.velocity.length();
+            if distance <= speed * dt {
+                return Some((*target, radius, step as f32 * dt + distance / speed));
+            }
+
+            guidance.elapsed += dt;
+            if guidance.elapsed >= guidance.delay {
+                bullet.velocity = steer_towards(
+                    bullet.velocity,
+                    transform.translation,
+                    *target,
+                    guidance.turn_rate * dt,
+                );
+            }
+        }
+        step_projectile(&mut bullet, &mut transform, env, config, dt);
+    }
+
+    detonation.map(|fuse| (transform.translation, radius, fuse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_flight_config() -> BallisticsConfig {
+        BallisticsConfig {
+            max_projectile_lifetime: 60.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vacuum_optimal_angle_is_45_degrees() {
+        let env = BallisticsEnvironment {
+            air_density: 0.0,
+            ..Default::default()
+        };
+        let projectile = Projectile::new(Vec3::ZERO);
+        let (angle, range) = max_range(50.0, &projectile, &env, &long_flight_config());
+
+        assert!((angle.to_degrees() - 45.0).abs() < 0.5);
+        // Vacuum range: v² / g
+        assert!((range - 50.0 * 50.0 / 9.81).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_drag_lowers_optimal_angle() {
+        let env = BallisticsEnvironment::default();
+        let projectile = Projectile::new(Vec3::ZERO);
+        let (angle, range) = max_range(300.0, &projectile, &env, &long_flight_config());
+
+        assert!(angle.to_degrees() < 45.0);
+        assert!(range < 300.0 * 300.0 / 9.81);
+        assert!(range > 0.0);
+    }
+
+    #[test]
+    fn test_max_ordinate() {
+        let env = BallisticsEnvironment {
+            air_density: 0.0,
+            ..Default::default()
+        };
+        let config = long_flight_config();
+        let projectile = Projectile::new(Vec3::ZERO);
+
+        // Vacuum apex: (v sin θ)² / 2g
+        let apex = max_ordinate(40.0, std::f32::consts::FRAC_PI_2, &projectile, &env, &config);
+        assert!((apex - 40.0 * 40.0 / (2.0 * 9.81)).abs() < 0.5);
+
+        // Steeper shots climb higher
+        let low = max_ordinate(40.0, 0.3, &projectile, &env, &config);
+        let high = max_ordinate(40.0, 0.9, &projectile, &env, &config);
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_intercept_perpendicular_target() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let projectile = Projectile::new(Vec3::ZERO).with_reference_area(0.00005);
+        let target_pos = Vec3::new(0.0, 0.0, -300.0);
+        let target_vel = Vec3::new(15.0, 0.0, 0.0);
+
+        let direction =
+            solve_intercept(Vec3::ZERO, target_pos, target_vel, 800.0, &projectile, &env, &config)
+                .expect("target should be reachable");
+
+        // Leads the target and aims above it to compensate for drop
+        assert!(direction.x > 0.0);
+        assert!(direction.y > 0.0);
+
+        // Flying the solution meets the moving target at the time of flight
+        // (it moves across the line of fire, so the range to its plane barely changes)
+        let (_, time) =
+            cross_target_plane(Vec3::ZERO, direction, target_pos, 800.0, &projectile, &env, &config)
+                .unwrap();
+        let meet = target_pos + target_vel * time;
+        let (crossing, _) =
+            cross_target_plane(Vec3::ZERO, direction, meet, 800.0, &projectile, &env, &config)
+                .unwrap();
+        assert!(crossing.distance(meet) < 0.05);
+    }
+
+    #[test]
+    fn test_intercept_unreachable_target() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let projectile = Projectile::new(Vec3::ZERO);
+
+        // Target fleeing faster than the round can fly
+        let result = solve_intercept(
+            Vec3::ZERO,
+            Vec3::new(0.0, 0.0, -100.0),
+            Vec3::new(0.0, 0.0, -1000.0),
+            400.0,
+            &projectile,
+            &env,
+            &config,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_zero_pitch_meets_line_of_sight() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let projectile = Projectile::new(Vec3::ZERO).with_reference_area(0.00005);
+
+        let near = compute_zero_pitch(100.0, 800.0, 0.05, &projectile, &env, &config).unwrap();
+        let far = compute_zero_pitch(300.0, 800.0, 0.05, &projectile, &env, &config).unwrap();
+        assert!(near > 0.0);
+        assert!(far > near);
+
+        let direction = Vec3::new(0.0, far.sin(), -far.cos());
+        let target = Vec3::new(0.0, 0.05, -300.0);
+        let (crossing, _) =
+            cross_target_plane(Vec3::ZERO, direction, target, 800.0, &projectile, &env, &config)
+                .unwrap();
+        assert!((crossing.y - 0.05).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_holdover_grows_with_distance() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let projectile = Projectile::new(Vec3::ZERO).with_reference_area(0.00005);
+        let sight = Sight::default();
+
+        let at_zero = holdover_mils(800.0, &projectile, &env, &config, &sight, 100.0).unwrap();
+        let mid = holdover_mils(800.0, &projectile, &env, &config, &sight, 300.0).unwrap();
+        let far = holdover_mils(800.0, &projectile, &env, &config, &sight, 600.0).unwrap();
+
+        // Relative to the 100 m zero, not the bore
+        assert!(at_zero.abs() < 0.05);
+        assert!(mid > 0.0);
+        assert!(far > mid);
+
+        // 1 mil = 3.4377 MOA
+        let far_moa = holdover_moa(800.0, &projectile, &env, &config, &sight, 600.0).unwrap();
+        assert!((far_moa - far * 3.4377).abs() < 0.01);
+
+        // Dialing the holdover on top of the zero puts the live shot on the line of sight
+        let zero = compute_zero_pitch(100.0, 800.0, sight.height_over_bore, &projectile, &env, &config)
+            .unwrap();
+        let pitch = zero + far / 1000.0;
+        let target = Vec3::new(0.0, sight.height_over_bore, -600.0);
+        let (crossing, _) = cross_target_plane(
+            Vec3::ZERO,
+            Vec3::new(0.0, pitch.sin(), -pitch.cos()),
+            target,
+            800.0,
+            &projectile,
+            &env,
+            &config,
+        )
+        .unwrap();
+        assert!((crossing.y - target.y).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_rezero_follows_altitude_change() {
+        let projectile = Projectile::new(Vec3::ZERO).with_reference_area(0.00005);
+        let sight = Sight {
+            current_zero: 400.0,
+            ..Default::default()
+        };
+
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+        let mut app = App::new();
+        app.init_resource::<BallisticsEnvironment>()
+            .init_resource::<BallisticsConfig>()
+            .add_systems(Update, rezero_on_environment_change);
+
+        let mut zeroing = WeaponZeroing::default();
+        sight.apply_zero(
+            &mut zeroing,
+            800.0,
+            &projectile,
+            &BallisticsEnvironment::default(),
+            &BallisticsConfig::default(),
+        );
+        let sea_level = zeroing.pitch_adjustment;
+        let rifle = app.world_mut().spawn(zeroing).id();
+        let hand_set = app
+            .world_mut()
+            .spawn(WeaponZeroing {
+                pitch_adjustment: 0.004,
+                ..Default::default()
+            })
+            .id();
+        let pitch = |app: &App, entity| app.world().get::<WeaponZeroing>(entity).unwrap().pitch_adjustment;
+
+        app.update();
+        assert!((pitch(&app, rifle) - sea_level).abs() < 1e-6);
+
+        // Nothing is re-solved while the environment stays the same
+        app.world_mut().get_mut::<WeaponZeroing>(rifle).unwrap().pitch_adjustment = 0.0;
+        app.update();
+        assert_eq!(pitch(&app, rifle), 0.0);
+
+        // Thinner air up a mountain: less drop, so less elevation
+        app.world_mut().resource_mut::<BallisticsEnvironment>().altitude = 3000.0;
+        app.update();
+        let mountain = pitch(&app, rifle);
+        assert!(mountain > 0.0 && mountain < sea_level, "{mountain} vs {sea_level}");
+        assert_eq!(pitch(&app, hand_set), 0.004);
+    }
+
+    #[test]
+    fn test_thrown_grenade_danger_zone() {
+        use crate::systems::logic::presets;
+
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let (logic, payload) = presets::frag_grenade();
+        let logic = logic.with_cook_time(1.0);
+        let origin = Vec3::new(0.0, 1.5, 0.0);
+        let grenade = Projectile::new(Vec3::new(0.0, 6.0, -12.0))
+            .with_mass(0.4)
+            .with_diameter(0.06);
+
+        let zones = predicted_impact_zones(
+            [IncomingProjectile::new(origin, &grenade, &logic).with_payload(Some(&payload))],
+            &env,
+            &config,
+        );
+        assert_eq!(zones.len(), 1);
+        let (center, radius, time) = zones[0];
+        assert_eq!(radius, 10.0);
+        assert_eq!(time, 2.0);
+
+        // Where the live integrator puts the grenade when its fuse burns down
+        let path = predict_trajectory(origin, &grenade, &env, &config, DANGER_ZONE_DT, 2.0);
+        let detonation = *path.last().unwrap();
+        assert!(center.distance(detonation) < 1e-3, "{center} vs {detonation}");
+        assert!(detonation.y < origin.y, "grenade should be falling by then");
+
+        // A plain impact round gives no zone, nor does a fuse past the horizon
+        let impact = ProjectileLogic::Impact;
+        let long_fuse = ProjectileLogic::Timed {
+            fuse: DANGER_ZONE_HORIZON + 1.0,
+            elapsed: 0.0,
+        };
+        let zones = predicted_impact_zones(
+            [
+                IncomingProjectile::new(origin, &grenade, &impact),
+                IncomingProjectile::new(origin, &grenade, &long_fuse),
+            ],
+            &env,
+            &config,
+        );
+        assert!(zones.is_empty());
+    }
+
+    #[test]
+    fn test_guided_round_danger_zone_at_target() {
+        let env = BallisticsEnvironment::default();
+        let config = BallisticsConfig::default();
+        let rocket = Projectile::new(Vec3::new(0.0, 0.0, -100.0))
+            .with_mass(10.0)
+            .with_diameter(0.1);
+        let logic = ProjectileLogic::Impact;
+        let payload = Payload::Explosive {
+            damage: 200.0,
+            radius: 6.0,
+            falloff: crate::components::FalloffCurve::Power(1.5),
+        };
+        let guidance = Guidance {
+            turn_rate: 2.0,
+            delay: 0.0,
+            ..Default::default()
+        };
+        // Off to the side of the launch line
+        let target = Vec3::new(30.0, 0.0, -150.0);
+
+        let zones = predicted_impact_zones(
+            [IncomingProjectile::new(Vec3::ZERO, &rocket, &logic)
+                .with_payload(Some(&payload))
+                .with_guidance(&guidance, target)],
+            &env,
+            &config,
+        );
+        assert_eq!(zones.len(), 1);
+        let (center, radius, time) = zones[0];
+        assert_eq!(center, target);
+        assert_eq!(radius, 6.0);
+        // Longer than a straight flight, well within the horizon
+        assert!(time > target.length() / 100.0 && time < 3.0, "{time}");
+    }
+}
@@ -3,31 +3,64 @@
 use bevy::prelude::*;
 use bevy::ecs::message::MessageReader;
 
-use crate::components::{BulletTracer, HitEffectType, ImpactDecal};
-use crate::events::HitEvent;
-use crate::resources::{DecalPool, TracerPool};
+use crate::components::{BulletTracer, HitEffectType, ImpactDecal, SurfaceMaterial, TracerRamp};
+use crate::events::{HitEvent, PenetrationEvent, RicochetEvent};
+use crate::resources::{
+    BallisticsAssets, BallisticsConfig, BallisticsEnvironment, DecalPool, TracerPool,
+};
+use crate::systems::accuracy::apply_spread_to_direction;
+use crate::systems::collision::find_surface_material;
+#[cfg(feature = "dim2")]
+use crate::systems::accuracy::SpreadRng;
 
 /// Update tracer lifetimes and hide expired ones.
 /// 
 /// This system updates the lifetime of bullet tracers and returns them to the pool
 /// when they expire, rather than despawning them to improve performance.
+/// Tracers with a [`TracerRamp`] get their own material (or sprite color)
+/// recolored along the ramp every frame, so a reactivated pooled tracer starts
+/// bright again.
 /// 
 /// # Arguments
 /// * `_commands` - Bevy Commands for entity manipulation (currently unused in this function)
 /// * `time` - Bevy Time resource to get delta time
+/// * `config` - Ballistics configuration resource (time scale)
 /// * `pool` - Mutable reference to the tracer pool resource
+/// * `materials` - Material assets, for ramped tracers
 /// * `tracers` - Query for tracer entities and their components
+#[allow(clippy::type_complexity)]
 pub fn update_tracers(
     _commands: Commands,
     time: Res<Time>,
+    config: Res<BallisticsConfig>,
     mut pool: ResMut<TracerPool>,
-    mut tracers: Query<(Entity, &mut BulletTracer, &mut Visibility)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tracers: Query<(
+        Entity,
+        &mut BulletTracer,
+        &mut Visibility,
+        Option<&MeshMaterial3d<StandardMaterial>>,
+        Option<&mut Sprite>,
+    )>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
 
-    for (entity, mut tracer, mut visibility) in tracers.iter_mut() {
+    for (entity, mut tracer, mut visibility, material, sprite) in tracers.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            // Pooled, waiting for reuse
+            continue;
+        }
         tracer.lifetime -= dt;
 
+        if let Some(color) = tracer.ramp_color() {
+            if let Some(material) = material.and_then(|material| materials.get_mut(&material.0)) {
+                apply_tracer_color(material, color);
+            }
+            if let Some(mut sprite) = sprite {
+                sprite.color = color.into();
+            }
+        }
+
         if tracer.lifetime <= 0.0 {
             // Return to pool instead of despawning
             *visibility = Visibility::Hidden;
@@ -39,30 +72,37 @@ pub fn update_tracers(
 /// Spawn impact effects at hit locations.
 /// 
 /// This system listens for hit events and spawns appropriate visual effects
-/// at the impact location based on the surface material and hit type.
+/// at the impact location based on the surface material and hit type. Effects
+/// share the cached material for the surface's `HitEffectType` and the unit
+/// sphere mesh, sized through the transform scale, so sustained fire allocates
+/// no new assets. The material is looked up through the hierarchy like
+/// collisions do, so a child collider shows its root's effect. Targets without a
+/// SurfaceMaterial spark. Sensor pass-throughs leave no mark.
 /// 
 /// # Arguments
 /// * `commands` - Bevy Commands for spawning entities
 /// * `hit_events` - Event reader for hit events
+/// * `ballistics_assets` - Shared effect meshes and materials
+/// * `surfaces` - Query for surface material components
+/// * `parents` - Query for hierarchy parents, to find a child collider's material
+/// * `pool` - Mutable reference to the decal pool
 pub fn spawn_impact_effects(
     mut commands: Commands,
     mut hit_events: MessageReader<HitEvent>,
     ballistics_assets: Res<crate::resources::BallisticsAssets>,
+    surfaces: Query<&SurfaceMaterial>,
+    parents: Query<&ChildOf>,
     mut pool: ResMut<DecalPool>,
 ) {
-    for event in hit_events.read() {
-        let effect_type = HitEffectType::Sparks; // Would come from surface material
+    for event in hit_events.read().filter(|event| !event.sensor) {
+        let effect_type = find_surface_material(event.target, &surfaces, &parents)
+            .map(|surface| surface.hit_effect)
+            .unwrap_or_default();
         let rotation = Quat::from_rotation_arc(Vec3::Y, event.normal);
-
-        let material = match effect_type {
-            HitEffectType::Sparks => ballistics_assets.spark_material.clone(),
-            HitEffectType::Dust => ballistics_assets.dust_material.clone(),
-            HitEffectType::Blood => ballistics_assets.blood_material.clone(),
-            _ => ballistics_assets.spark_material.clone(),
-        };
+        let material = ballistics_assets.hit_effect_material(effect_type).clone();
 
         let position = event.impact_point + event.normal * 0.01;
-        let scale = Vec3::splat(0.05);
+        let scale = Vec3::splat(impact_effect_size(effect_type));
 
         if let Some(entity) = pool.get() {
             commands.entity(entity).insert((
@@ -72,7 +112,7 @@ pub fn spawn_impact_effects(
                     .with_rotation(rotation)
                     .with_scale(scale),
                 Visibility::Visible,
-                ImpactDecal { lifetime: 0.5 },
+                ImpactDecal::new(0.5),
             ));
         } else {
             commands.spawn((
@@ -82,12 +122,24 @@ pub fn spawn_impact_effects(
                     .with_rotation(rotation)
                     .with_scale(scale),
                 Visibility::Visible,
-                ImpactDecal { lifetime: 0.5 },
+                ImpactDecal::new(0.5),
             ));
         }
     }
 }
 
+/// Radius of an impact effect on the unit sphere mesh, per surface effect.
+fn impact_effect_size(effect: HitEffectType) -> f32 {
+    match effect {
+        HitEffectType::Sparks => 0.05,
+        HitEffectType::Dust => 0.12,
+        HitEffectType::Blood => 0.08,
+        HitEffectType::WoodChips => 0.07,
+        HitEffectType::Glass => 0.06,
+        HitEffectType::Water => 0.15,
+    }
+}
+
 /// Cleanup expired visual effects.
 /// 
 /// This system updates the lifetime of impact decals and returns them to the pool
@@ -96,18 +148,21 @@ pub fn spawn_impact_effects(
 /// # Arguments
 /// * `_commands` - Bevy Commands for entity manipulation (currently unused in this function)
 /// * `time` - Bevy Time resource to get delta time
+/// * `config` - Ballistics configuration resource (time scale)
 /// * `pool` - Mutable reference to the decal pool resource
 /// * `decals` - Query for decal entities and their components
 pub fn cleanup_expired_effects(
     _commands: Commands,
     time: Res<Time>,
+    config: Res<BallisticsConfig>,
     mut pool: ResMut<DecalPool>,
     mut decals: Query<(Entity, &mut ImpactDecal, &mut Visibility)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
 
     for (entity, mut decal, mut visibility) in decals.iter_mut() {
         decal.lifetime -= dt;
+        decal.age += dt;
 
         if decal.lifetime <= 0.0 {
             *visibility = Visibility::Hidden;
@@ -117,14 +172,84 @@ pub fn cleanup_expired_effects(
 }
 
 
+/// Remaining lifetime given to decals evicted by the decal budget (seconds).
+pub const DECAL_EVICTION_FADE: f32 = 0.5;
+
+/// Keep the number of live decals within `BallisticsConfig::max_decals`.
+/// 
+/// When there are more visible decals than the budget, the oldest excess ones
+/// have their remaining lifetime cut to [`DECAL_EVICTION_FADE`], so they expire
+/// and return to the pool shortly instead of all at once. Newer decals are left alone.
+/// 
+/// # Arguments
+/// * `config` - Ballistics configuration resource (decal budget)
+/// * `decals` - Query for decal entities and their components
+pub fn enforce_decal_budget(
+    config: Res<BallisticsConfig>,
+    mut decals: Query<(Entity, &mut ImpactDecal, &Visibility)>,
+) {
+    let mut live: Vec<(f32, Entity)> = decals
+        .iter()
+        .filter(|(_, decal, visibility)| decal.lifetime > 0.0 && **visibility != Visibility::Hidden)
+        .map(|(entity, decal, _)| (decal.age, entity))
+        .collect();
+    if live.len() <= config.max_decals {
+        return;
+    }
+
+    let excess = live.len() - config.max_decals;
+    live.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+    for &(_, entity) in &live[..excess] {
+        if let Ok((_, mut decal, _)) = decals.get_mut(entity) {
+            decal.lifetime = decal.lifetime.min(DECAL_EVICTION_FADE);
+        }
+    }
+}
+
+/// Set a tracer material's emissive and blended base color to a ramp color.
+fn apply_tracer_color(material: &mut StandardMaterial, color: LinearRgba) {
+    material.emissive = color;
+    material.base_color = color.into();
+}
+
+/// Give a tracer its own ramp material, or reset the one it kept from the pool.
+fn init_tracer_material(ramp: TracerRamp) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let existing = entity
+            .get::<MeshMaterial3d<StandardMaterial>>()
+            .map(|material| material.0.clone());
+        let handle = entity.world_scope(|world| {
+            let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+            match existing.and_then(|handle| materials.get_mut(&handle).map(|m| (handle, m))) {
+                Some((handle, material)) => {
+                    apply_tracer_color(material, ramp.start);
+                    handle
+                }
+                None => {
+                    let mut material = StandardMaterial {
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        ..default()
+                    };
+                    apply_tracer_color(&mut material, ramp.start);
+                    materials.add(material)
+                }
+            }
+        });
+        entity.insert(MeshMaterial3d(handle));
+    }
+}
+
 /// Spawn a bullet tracer with actual mesh from pool or create new.
 /// 
-/// This function creates a visible tracer effect using a stretched mesh.
+/// This function creates a visible tracer effect using a stretched mesh. Each
+/// tracer owns a material that burns out from the settings' color to transparent
+/// black over its lifetime; pooled tracers keep that material and are reset to
+/// the start of the ramp when reused.
 /// 
 /// # Arguments
 /// * `commands` - Bevy Commands for spawning entities
-/// * `meshes` - Asset storage for meshes
-/// * `materials` - Asset storage for materials
+/// * `ballistics_assets` - Shared effect meshes and materials
 /// * `pool` - Mutable reference to the tracer pool
 /// * `origin` - World-space position where the tracer should start
 /// * `direction` - Direction vector for the tracer's movement
@@ -143,37 +268,71 @@ pub fn spawn_tracer_with_assets(
     settings: &tracer_config::TracerSettings,
 ) -> Entity {
     let lifetime = settings.length / speed * 10.0;
-    
+    let glow = settings.color.to_linear() * (TRACER_EMISSIVE_SCALE * settings.glow_intensity);
+    let ramp = TracerRamp::burnout(glow.with_alpha(1.0));
+    let tracer = BulletTracer {
+        lifetime,
+        trail_length: settings.length,
+        ..default()
+    }
+    .with_ramp(ramp);
+
+    let bundle = (
+        Mesh3d(ballistics_assets.tracer_mesh.clone()),
+        tracer_transform(origin, direction, settings.up),
+        Visibility::Visible,
+        tracer,
+    );
+
     if let Some(entity) = pool.get() {
-        // Reuse pooled tracer
-        commands.entity(entity).insert((
-            Mesh3d(ballistics_assets.tracer_mesh.clone()),
-            MeshMaterial3d(ballistics_assets.spark_material.clone()), // Use generic for now
-            Transform::from_translation(origin).looking_to(direction, Vec3::Y),
-            Visibility::Visible,
-            BulletTracer {
-                lifetime,
-                trail_length: settings.length,
-            },
-        ));
+        // Reuse pooled tracer along with its material
+        commands
+            .entity(entity)
+            .insert(bundle)
+            .queue(init_tracer_material(ramp));
         entity
     } else {
         // Create new tracer
         commands
-            .spawn((
-                Mesh3d(ballistics_assets.tracer_mesh.clone()),
-                MeshMaterial3d(ballistics_assets.spark_material.clone()),
-                Transform::from_translation(origin).looking_to(direction, Vec3::Y),
-                Visibility::Visible,
-                BulletTracer {
-                    lifetime,
-                    trail_length: settings.length,
-                },
-            ))
+            .spawn(bundle)
+            .queue(init_tracer_material(ramp))
             .id()
     }
 }
 
+/// `|dot|` between direction and up above which tracers use a fallback up vector.
+const TRACER_PARALLEL_DOT: f32 = 0.999;
+
+/// Transform for a tracer at `origin` looking along `direction`.
+/// 
+/// `looking_to` has no defined roll when the direction is parallel to the up
+/// vector, which makes tracers fired straight up or down flicker. In that
+/// case the up vector falls back to `Vec3::Z` (or `Vec3::X` if `up` is
+/// itself Z), so the basis is always well-formed.
+/// 
+/// # Arguments
+/// * `origin` - World-space position of the tracer
+/// * `direction` - Direction of travel
+/// * `up` - Preferred up vector
+/// 
+/// # Returns
+/// A Transform with a finite, normalized rotation
+pub fn tracer_transform(origin: Vec3, direction: Vec3, up: Vec3) -> Transform {
+    let direction = direction.normalize_or(Vec3::NEG_Z);
+    let up = up.normalize_or(Vec3::Y);
+    let up = if direction.dot(up).abs() < TRACER_PARALLEL_DOT {
+        up
+    } else if up.dot(Vec3::Z).abs() < TRACER_PARALLEL_DOT {
+        Vec3::Z
+    } else {
+        Vec3::X
+    };
+    Transform::from_translation(origin).looking_to(direction, up)
+}
+
+/// Emissive multiplier for a tracer at `glow_intensity` 1.0.
+const TRACER_EMISSIVE_SCALE: f32 = 5.0;
+
 /// Spawn a bullet tracer from pool or create new (simple version).
 /// 
 /// This function attempts to reuse a tracer from the pool, or creates a new one
@@ -203,11 +362,12 @@ pub fn spawn_tracer(
     if let Some(entity) = pool.get() {
         // Reuse pooled tracer
         commands.entity(entity).insert((
-            Transform::from_translation(origin).looking_to(direction, Vec3::Y),
+            tracer_transform(origin, direction, Vec3::Y),
             Visibility::Visible,
             BulletTracer {
                 lifetime,
                 trail_length: tracer_length,
+                ..default()
             },
         ));
         entity
@@ -215,11 +375,12 @@ pub fn spawn_tracer(
         // Create new tracer
         commands
             .spawn((
-                Transform::from_translation(origin).looking_to(direction, Vec3::Y),
+                tracer_transform(origin, direction, Vec3::Y),
                 Visibility::Visible,
                 BulletTracer {
                     lifetime,
                     trail_length: tracer_length,
+                    ..default()
                 },
             ))
             .id()
@@ -258,7 +419,7 @@ pub fn spawn_decal(
                 .with_rotation(rotation)
                 .with_scale(Vec3::splat(size)),
             Visibility::Visible,
-            ImpactDecal { lifetime },
+            ImpactDecal::new(lifetime),
         ));
         entity
     } else {
@@ -269,15 +430,182 @@ pub fn spawn_decal(
                     .with_rotation(rotation)
                     .with_scale(Vec3::splat(size)),
                 Visibility::Visible,
-                ImpactDecal { lifetime },
+                ImpactDecal::new(lifetime),
             ))
             .id()
     }
 }
 
+/// Remaining penetration power at which the exit spray reaches full size.
+const PENETRATION_EFFECT_REFERENCE_POWER: f32 = 500.0;
+
+/// Spawn entry and exit effects for penetrations.
+/// 
+/// Each PenetrationEvent gets a spark flash at the entry point facing back
+/// toward the shooter and a dust spray at the exit point facing along the shot.
+/// The exit spray shrinks and fades faster as `remaining_power` drops, so a
+/// round that barely made it through leaves only a small puff. Both effects come
+/// from the decal pool. With the `dim2` feature, [`spawn_penetration_effects_2d`]
+/// draws them as sprites instead.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `penetration_events` - Event reader for penetration events
+/// * `ballistics_assets` - Shared effect meshes and materials
+/// * `pool` - Mutable reference to the decal pool
+pub fn spawn_penetration_effects(
+    mut commands: Commands,
+    mut penetration_events: MessageReader<PenetrationEvent>,
+    ballistics_assets: Res<crate::resources::BallisticsAssets>,
+    mut pool: ResMut<DecalPool>,
+) {
+    for event in penetration_events.read() {
+        let through = (event.exit_point - event.entry_point)
+            .try_normalize()
+            .unwrap_or(Vec3::Y);
+
+        let entry = spawn_decal(
+            &mut commands,
+            &mut pool,
+            event.entry_point - through * 0.01,
+            -through,
+            0.05,
+            0.5,
+        );
+        commands.entity(entry).insert((
+            Mesh3d(ballistics_assets.sphere_mesh.clone()),
+            MeshMaterial3d(ballistics_assets.spark_material.clone()),
+        ));
+
+        let strength =
+            (event.remaining_power / PENETRATION_EFFECT_REFERENCE_POWER).clamp(0.0, 1.0);
+        let exit = spawn_decal(
+            &mut commands,
+            &mut pool,
+            event.exit_point,
+            through,
+            0.03 + 0.09 * strength,
+            0.2 + 0.6 * strength,
+        );
+        commands.entity(exit).insert((
+            Mesh3d(ballistics_assets.sphere_mesh.clone()),
+            MeshMaterial3d(ballistics_assets.dust_material.clone()),
+        ));
+    }
+}
+
+/// Ricochet speed at which a spray reaches its full particle count.
+const RICOCHET_EFFECT_REFERENCE_SPEED: f32 = 600.0;
+
+/// How a surface sheds debris when a round glances off it.
+struct RicochetSpray {
+    /// Particle count at full ricochet speed
+    max_count: u32,
+    /// Half-angle of the spray cone around the outgoing direction (radians)
+    cone: f32,
+    /// Sparks are drawn as streaks, everything else as small chunks
+    streaks: bool,
+    material: Handle<StandardMaterial>,
+}
+
+impl RicochetSpray {
+    /// Particle count at full speed, cone half-angle and streak style of `effect`.
+    fn shape(effect: HitEffectType) -> (u32, f32, bool) {
+        match effect {
+            HitEffectType::Sparks => (12, 0.25, true),
+            HitEffectType::Dust => (5, 0.6, false),
+            HitEffectType::WoodChips => (4, 0.5, false),
+            HitEffectType::Glass => (6, 0.4, false),
+            HitEffectType::Water => (4, 0.8, false),
+            HitEffectType::Blood => (3, 0.5, false),
+        }
+    }
+
+    fn for_effect(effect: HitEffectType, assets: &BallisticsAssets) -> Self {
+        let (max_count, cone, streaks) = Self::shape(effect);
+        Self {
+            max_count,
+            cone,
+            streaks,
+            material: assets.hit_effect_material(effect).clone(),
+        }
+    }
+}
+
+/// Spawn a directional debris spray for each ricochet.
+/// 
+/// Particles fan out in a cone around the ricochet's `new_direction`; the count
+/// scales with the speed the round kept. Metal (`Sparks`) throws a tight spray of
+/// spark streaks, other surfaces a wider puff of their own debris (dust, wood,
+/// glass, blood). Surfaces without a SurfaceMaterial spark. Particles come from
+/// the decal pool.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `ricochet_events` - Event reader for ricochet events
+/// * `ballistics_assets` - Shared effect meshes and materials
+/// * `surfaces` - Query for surface material components
+/// * `parents` - Query for hierarchy parents, to find a child collider's material
+/// * `pool` - Mutable reference to the decal pool
+pub fn spawn_ricochet_effects(
+    mut commands: Commands,
+    mut ricochet_events: MessageReader<RicochetEvent>,
+    ballistics_assets: Res<BallisticsAssets>,
+    surfaces: Query<&SurfaceMaterial>,
+    parents: Query<&ChildOf>,
+    mut pool: ResMut<DecalPool>,
+) {
+    for event in ricochet_events.read() {
+        let Ok(direction) = Dir3::new(event.new_direction) else {
+            continue;
+        };
+        let effect = find_surface_material(event.surface, &surfaces, &parents)
+            .map(|surface| surface.hit_effect)
+            .unwrap_or_default();
+        let spray = RicochetSpray::for_effect(effect, &ballistics_assets);
+
+        let strength = (event.new_speed / RICOCHET_EFFECT_REFERENCE_SPEED).clamp(0.15, 1.0);
+        let count = ((spray.max_count as f32 * strength).ceil() as u32).max(1);
+        let seed = event.projectile.to_bits();
+
+        for i in 0..count {
+            let particle_direction =
+                apply_spread_to_direction(*direction, spray.cone, seed.wrapping_add(i as u64));
+            let position =
+                event.impact_point + particle_direction * (0.05 + 0.15 * i as f32 / count as f32);
+            let particle = spawn_decal(
+                &mut commands,
+                &mut pool,
+                position,
+                particle_direction,
+                0.02,
+                0.15 + 0.25 * strength,
+            );
+
+            // Decals point their Y axis along the given normal, so streaks stretch along the spray
+            let (mesh, scale) = if spray.streaks {
+                (
+                    ballistics_assets.tracer_mesh.clone(),
+                    Vec3::new(0.25, 0.05 + 0.1 * strength, 0.25),
+                )
+            } else {
+                (ballistics_assets.sphere_mesh.clone(), Vec3::splat(0.02))
+            };
+            commands.entity(particle).insert((
+                Mesh3d(mesh),
+                MeshMaterial3d(spray.material.clone()),
+                Transform::from_translation(position)
+                    .with_rotation(Quat::from_rotation_arc(Vec3::Y, particle_direction))
+                    .with_scale(scale),
+            ));
+        }
+    }
+}
+
 /// VFX configuration for different weapon types.
 pub mod tracer_config {
     use super::*;
+    use crate::components::AmmoType;
 
     /// Configuration settings for bullet tracer visual effects.
     /// 
@@ -289,11 +617,14 @@ pub mod tracer_config {
     /// * `width` - The visual width of the tracer
     /// * `length` - The length of the tracer effect
     /// * `glow_intensity` - The intensity of the tracer's glow effect
+    /// * `up` - Preferred up vector when orienting the tracer (see [`tracer_transform`])
+    #[derive(Clone, Debug)]
     pub struct TracerSettings {
         pub color: Color,
         pub width: f32,
         pub length: f32,
         pub glow_intensity: f32,
+        pub up: Vec3,
     }
 
     impl Default for TracerSettings {
@@ -307,6 +638,7 @@ pub mod tracer_config {
                 width: 0.02,
                 length: 2.0,
                 glow_intensity: 1.0,
+                up: Vec3::Y,
             }
         }
     }
@@ -323,6 +655,7 @@ pub mod tracer_config {
             width: 0.015,
             length: 3.0,
             glow_intensity: 0.8,
+            up: Vec3::Y,
         }
     }
 
@@ -338,6 +671,7 @@ pub mod tracer_config {
             width: 0.01,
             length: 5.0,
             glow_intensity: 1.2,
+            up: Vec3::Y,
         }
     }
 
@@ -353,6 +687,29 @@ pub mod tracer_config {
             width: 0.02,
             length: 1.5,
             glow_intensity: 0.6,
+            up: Vec3::Y,
+        }
+    }
+
+    /// Tints a weapon's tracer for the ammunition it fires.
+    /// 
+    /// The ammunition sets the color and width; length, glow and up vector stay
+    /// the weapon's. With no ammunition the weapon settings are returned unchanged.
+    /// 
+    /// # Arguments
+    /// * `weapon` - Tracer settings picked for the weapon (e.g. [`rifle`])
+    /// * `ammo` - Ammunition fired, if known
+    /// 
+    /// # Returns
+    /// The TracerSettings to spawn the tracer with
+    pub fn for_ammo(weapon: TracerSettings, ammo: Option<AmmoType>) -> TracerSettings {
+        match ammo {
+            Some(ammo) => TracerSettings {
+                color: ammo.tracer_color(),
+                width: ammo.tracer_width(),
+                ..weapon
+            },
+            None => weapon,
         }
     }
 
@@ -368,6 +725,7 @@ pub mod tracer_config {
             width: 0.005,
             length: 100.0,
             glow_intensity: 2.0,
+            up: Vec3::Y,
         }
     }
 }
@@ -386,9 +744,10 @@ use crate::events::ExplosionEvent;
 pub fn update_muzzle_flash(
     mut commands: Commands,
     time: Res<Time>,
+    config: Res<BallisticsConfig>,
     mut flashes: Query<(Entity, &mut MuzzleFlash, &mut Transform)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
 
     for (entity, mut flash, mut transform) in flashes.iter_mut() {
         flash.lifetime -= dt;
@@ -440,9 +799,10 @@ pub fn spawn_muzzle_flash(
 pub fn update_explosion_vfx(
     mut commands: Commands,
     time: Res<Time>,
+    config: Res<BallisticsConfig>,
     mut explosions: Query<(Entity, &mut ExplosionVFX, &mut Transform)>,
 ) {
-    let dt = time.delta_secs();
+    let dt = config.scaled_delta(time.delta_secs());
 
     for (entity, mut explosion, mut transform) in explosions.iter_mut() {
         explosion.lifetime -= dt;
@@ -488,4 +848,1290 @@ pub fn spawn_explosion_vfx_from_event(
     }
 }
 
+// ============================================================================
+// Projectile Visual Scale
+// ============================================================================
+
+/// Scale projectiles by distance travelled for visibility.
+/// 
+/// Only touches projectiles with a [`ProjectileVisualScale`](crate::components::ProjectileVisualScale);
+/// the scale is visual only and does not affect collision.
+/// 
+/// # Arguments
+/// * `projectiles` - Query for opted-in projectiles and their transforms
+pub fn update_projectile_visual_scale(
+    mut projectiles: Query<(
+        &mut Transform,
+        &crate::components::Projectile,
+        &crate::components::ProjectileVisualScale,
+    )>,
+) {
+    for (mut transform, projectile, visual_scale) in projectiles.iter_mut() {
+        transform.scale = Vec3::splat(visual_scale.scale_at(projectile.distance_travelled));
+    }
+}
+
+// ============================================================================
+// Persistent Trail System
+// ============================================================================
+
+/// Most points a persistent trail keeps; past this the older points are decimated.
+pub const MAX_TRAIL_POINTS: usize = 256;
+
+/// Shortest segment recorded (meters), so a frame without a physics step adds nothing.
+const MIN_TRAIL_SEGMENT: f32 = 0.01;
+
+/// Create a trail entity for every new [`PersistentTrail`](crate::components::PersistentTrail).
+/// 
+/// The trail lives on its own entity so it can outlast the projectile.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning trail entities
+/// * `projectiles` - Query for projectiles that just gained a trail
+pub fn spawn_persistent_trails(
+    mut commands: Commands,
+    projectiles: Query<
+        (Entity, &Transform, &crate::components::PersistentTrail),
+        Added<crate::components::PersistentTrail>,
+    >,
+) {
+    for (entity, transform, trail) in projectiles.iter() {
+        commands.spawn((
+            crate::components::TrailPolyline {
+                source: Some(entity),
+                trail: *trail,
+                points: [crate::components::TrailPoint {
+                    position: transform.translation,
+                    age: 0.0,
+                }]
+                .into(),
+            },
+            Transform::IDENTITY,
+            Visibility::Visible,
+        ));
+    }
+}
+
+/// Record projectile positions into their trails and age out old points.
+/// 
+/// A point is dropped once it is `lifetime` old. A trail stops growing when its
+/// projectile is despawned or spent, and despawns when its last point has faded.
+/// Trails longer than [`MAX_TRAIL_POINTS`] drop every other older point, so the
+/// whole path stays covered at a coarser resolution.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for despawning finished trails
+/// * `time` - Bevy Time resource to get delta time
+/// * `config` - Ballistics configuration resource (time scale)
+/// * `projectiles` - Query for live projectile transforms
+/// * `trails` - Query for trail entities
+pub fn update_persistent_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<BallisticsConfig>,
+    projectiles: Query<&Transform, With<crate::components::Projectile>>,
+    mut trails: Query<(Entity, &mut crate::components::TrailPolyline)>,
+) {
+    let dt = config.scaled_delta(time.delta_secs());
+
+    for (entity, mut polyline) in trails.iter_mut() {
+        let lifetime = polyline.trail.lifetime;
+        for point in polyline.points.iter_mut() {
+            point.age += dt;
+        }
+        while polyline.points.front().is_some_and(|point| point.age >= lifetime) {
+            polyline.points.pop_front();
+        }
+
+        let head = polyline
+            .source
+            .and_then(|source| projectiles.get(source).ok())
+            .map(|transform| transform.translation);
+        match head {
+            Some(position) => {
+                let moved = polyline
+                    .points
+                    .back()
+                    .is_none_or(|last| last.position.distance(position) >= MIN_TRAIL_SEGMENT);
+                if moved {
+                    polyline.points.push_back(crate::components::TrailPoint { position, age: 0.0 });
+                }
+            }
+            None => polyline.source = None,
+        }
+
+        if polyline.points.len() > MAX_TRAIL_POINTS {
+            let newest = polyline.points.len() - 1;
+            let mut index = 0;
+            polyline.points.retain(|_| {
+                let keep = index % 2 == 0 || index == newest;
+                index += 1;
+                keep
+            });
+        }
+
+        if polyline.source.is_none() && polyline.points.is_empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Rebuild the ribbon mesh of every changed trail.
+/// 
+/// Each trail gets its own mesh and an unlit, alpha blended material on its first
+/// update; vertex colors carry the per-point fade. Ribbons turn their face to the
+/// active 3D camera; without one they lie across the environment's up axis (in
+/// 2D, flat in the play plane).
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for attaching meshes and materials
+/// * `env` - Ballistics environment (up axis)
+/// * `meshes` - Mesh assets
+/// * `materials` - Material assets
+/// * `cameras` - 3D cameras; the first active one is faced
+/// * `trails` - Query for trails changed since the last run
+#[allow(clippy::type_complexity)]
+pub fn update_trail_meshes(
+    mut commands: Commands,
+    env: Res<BallisticsEnvironment>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    trails: Query<
+        (Entity, &crate::components::TrailPolyline, Option<&Mesh3d>),
+        Changed<crate::components::TrailPolyline>,
+    >,
+) {
+    let eye = cameras
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .map(|(_, transform)| transform.translation());
+    let normal = if cfg!(feature = "dim2") { Vec3::Z } else { env.up_axis };
+
+    for (entity, polyline, mesh) in trails.iter() {
+        let ribbon = build_trail_mesh(polyline, eye, normal);
+        if let Some(existing) = mesh.and_then(|mesh| meshes.get_mut(&mesh.0)) {
+            *existing = ribbon;
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            Mesh3d(meshes.add(ribbon)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            })),
+        ));
+    }
+}
+
+/// Build a flat ribbon through a trail's points, fading each point by its age.
+///
+/// The ribbon is widened across the line of sight from `eye` so it faces the
+/// viewer, or across `normal` when there is no eye (or the trail points straight
+/// at it).
+fn build_trail_mesh(
+    polyline: &crate::components::TrailPolyline,
+    eye: Option<Vec3>,
+    normal: Vec3,
+) -> Mesh {
+    use bevy::asset::RenderAssetUsages;
+    use bevy::mesh::{Indices, PrimitiveTopology};
+
+    let trail = &polyline.trail;
+    let points = &polyline.points;
+    let half_width = trail.width * 0.5;
+    let mut positions = Vec::with_capacity(points.len() * 2);
+    let mut colors = Vec::with_capacity(points.len() * 2);
+    let mut indices = Vec::with_capacity(points.len().saturating_sub(1) * 6);
+
+    for (i, point) in points.iter().enumerate() {
+        let previous = points[i.saturating_sub(1)].position;
+        let next = points[(i + 1).min(points.len() - 1)].position;
+        let tangent = next - previous;
+        let facing = eye.map_or(normal, |eye| eye - point.position);
+        let side = tangent
+            .cross(facing)
+            .try_normalize()
+            .or_else(|| tangent.cross(normal).try_normalize())
+            .or_else(|| tangent.try_normalize().map(|tangent| tangent.any_orthonormal_vector()))
+            .unwrap_or(Vec3::X)
+            * half_width;
+        positions.push((point.position - side).to_array());
+        positions.push((point.position + side).to_array());
+
+        let fade = if trail.lifetime > 0.0 {
+            (1.0 - point.age / trail.lifetime).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let color = trail.color.with_alpha(trail.color.alpha * fade).to_f32_array();
+        colors.push(color);
+        colors.push(color);
+
+        if i + 1 < points.len() {
+            let base = (i * 2) as u32;
+            indices.extend_from_slice(&[base, base + 1, base + 2, base + 1, base + 3, base + 2]);
+        }
+    }
+
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+// ============================================================================
+// 2D Sprite Effects
+// ============================================================================
+
+/// Color of an impact effect drawn as a sprite, matching its cached 3D material.
+#[cfg(feature = "dim2")]
+fn impact_effect_color(effect: HitEffectType) -> Color {
+    match effect {
+        HitEffectType::Sparks => Color::srgb(1.0, 0.7, 0.2),
+        HitEffectType::Dust => Color::srgba(0.6, 0.5, 0.4, 0.8),
+        HitEffectType::Blood => Color::srgb(0.5, 0.0, 0.0),
+        HitEffectType::WoodChips => Color::srgb(0.45, 0.3, 0.15),
+        HitEffectType::Glass => Color::srgba(0.8, 0.9, 1.0, 0.5),
+        HitEffectType::Water => Color::srgba(0.6, 0.75, 0.9, 0.6),
+    }
+}
+
+/// Sprite size per meter of 3D impact effect size; 2D scenes are usually laid out in pixels.
+#[cfg(feature = "dim2")]
+pub const IMPACT_SPRITE_SCALE: f32 = 100.0;
+
+/// Spawn a sprite bullet tracer from pool or create new.
+/// 
+/// 2D counterpart of [`spawn_tracer_with_assets`]: a `settings.length` by
+/// `settings.width` sprite rotated to `direction`, sharing the `BulletTracer`
+/// component and the tracer pool. The sprite color burns out along the tracer's
+/// ramp, see [`update_tracers`].
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `pool` - Mutable reference to the tracer pool
+/// * `origin` - World-space position where the tracer should start
+/// * `direction` - Direction of travel in the XY plane
+/// * `speed` - Speed of the tracer in world units per second
+/// * `settings` - Tracer visual settings
+/// 
+/// # Returns
+/// The Entity ID of the spawned tracer
+#[cfg(feature = "dim2")]
+pub fn spawn_tracer_2d(
+    commands: &mut Commands,
+    pool: &mut TracerPool,
+    origin: Vec2,
+    direction: Vec2,
+    speed: f32,
+    settings: &tracer_config::TracerSettings,
+) -> Entity {
+    let lifetime = settings.length / speed * 10.0;
+    let ramp = TracerRamp::burnout(settings.color.to_linear() * settings.glow_intensity);
+    let bundle = (
+        Sprite {
+            color: ramp.start.into(),
+            custom_size: Some(Vec2::new(settings.length, settings.width)),
+            ..default()
+        },
+        Transform::from_translation(origin.extend(0.0))
+            .with_rotation(Quat::from_rotation_z(direction.to_angle())),
+        Visibility::Visible,
+        BulletTracer {
+            lifetime,
+            trail_length: settings.length,
+            ..default()
+        }
+        .with_ramp(ramp),
+    );
+
+    match pool.get() {
+        Some(entity) => {
+            commands.entity(entity).insert(bundle);
+            entity
+        }
+        None => commands.spawn(bundle).id(),
+    }
+}
+
+/// Spawn a sprite impact decal from pool or create new.
+/// 
+/// The decal lies flat along the surface: its local Y axis points along the
+/// 2D `normal`. Shares `ImpactDecal` and the decal pool with the 3D effects,
+/// so the decal budget and cleanup apply unchanged.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `pool` - Mutable reference to the decal pool
+/// * `point` - Impact point
+/// * `normal` - Surface normal in the XY plane
+/// * `effect` - Surface effect, for size and color
+/// 
+/// # Returns
+/// The Entity ID of the spawned decal
+#[cfg(feature = "dim2")]
+pub fn spawn_impact_decal_2d(
+    commands: &mut Commands,
+    pool: &mut DecalPool,
+    point: Vec2,
+    normal: Vec2,
+    effect: HitEffectType,
+) -> Entity {
+    spawn_sprite_decal_2d(commands, pool, point, normal, effect, 1.0, 0.5)
+}
+
+/// [`spawn_impact_decal_2d`] with the sprite scaled by `scale` and lasting `lifetime` seconds.
+#[cfg(feature = "dim2")]
+fn spawn_sprite_decal_2d(
+    commands: &mut Commands,
+    pool: &mut DecalPool,
+    point: Vec2,
+    normal: Vec2,
+    effect: HitEffectType,
+    scale: f32,
+    lifetime: f32,
+) -> Entity {
+    let normal = normal.normalize_or(Vec2::Y);
+    let size = impact_effect_size(effect) * IMPACT_SPRITE_SCALE * scale;
+    let bundle = (
+        Sprite {
+            color: impact_effect_color(effect),
+            custom_size: Some(Vec2::new(size * 2.0, size)),
+            ..default()
+        },
+        // Rotate the sprite's +Y onto the normal
+        Transform::from_translation(point.extend(0.0))
+            .with_rotation(Quat::from_rotation_z(Vec2::Y.angle_to(normal))),
+        Visibility::Visible,
+        ImpactDecal::new(lifetime),
+    );
+
+    match pool.get() {
+        Some(entity) => {
+            commands.entity(entity).insert(bundle);
+            entity
+        }
+        None => commands.spawn(bundle).id(),
+    }
+}
+
+/// Spawn sprite impact decals at hit locations.
+/// 
+/// 2D counterpart of [`spawn_impact_effects`]; replaces it when the `dim2`
+/// feature is enabled.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `hit_events` - Event reader for hit events
+/// * `surfaces` - Query for surface material components
+/// * `parents` - Query for hierarchy parents, to find a child collider's material
+/// * `pool` - Mutable reference to the decal pool
+#[cfg(feature = "dim2")]
+pub fn spawn_impact_effects_2d(
+    mut commands: Commands,
+    mut hit_events: MessageReader<HitEvent>,
+    surfaces: Query<&SurfaceMaterial>,
+    parents: Query<&ChildOf>,
+    mut pool: ResMut<DecalPool>,
+) {
+    for event in hit_events.read().filter(|event| !event.sensor) {
+        let effect_type = find_surface_material(event.target, &surfaces, &parents)
+            .map(|surface| surface.hit_effect)
+            .unwrap_or_default();
+        spawn_impact_decal_2d(
+            &mut commands,
+            &mut pool,
+            event.impact_point.truncate(),
+            event.normal.truncate(),
+            effect_type,
+        );
+    }
+}
+
+/// Spawn sprite entry and exit effects for penetrations.
+/// 
+/// 2D counterpart of [`spawn_penetration_effects`]; replaces it when the `dim2`
+/// feature is enabled. The entry flash faces back toward the shooter, and the
+/// exit puff faces along the shot and shrinks and fades faster as
+/// `remaining_power` drops.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `penetration_events` - Event reader for penetration events
+/// * `pool` - Mutable reference to the decal pool
+#[cfg(feature = "dim2")]
+pub fn spawn_penetration_effects_2d(
+    mut commands: Commands,
+    mut penetration_events: MessageReader<PenetrationEvent>,
+    mut pool: ResMut<DecalPool>,
+) {
+    for event in penetration_events.read() {
+        let entry_point = event.entry_point.truncate();
+        let exit_point = event.exit_point.truncate();
+        let through = (exit_point - entry_point).try_normalize().unwrap_or(Vec2::Y);
+
+        spawn_sprite_decal_2d(
+            &mut commands,
+            &mut pool,
+            entry_point,
+            -through,
+            HitEffectType::Sparks,
+            1.0,
+            0.5,
+        );
+
+        let strength =
+            (event.remaining_power / PENETRATION_EFFECT_REFERENCE_POWER).clamp(0.0, 1.0);
+        spawn_sprite_decal_2d(
+            &mut commands,
+            &mut pool,
+            exit_point,
+            through,
+            HitEffectType::Dust,
+            0.3 + 0.7 * strength,
+            0.2 + 0.6 * strength,
+        );
+    }
+}
+
+/// Spawn a sprite debris spray for each ricochet.
+/// 
+/// 2D counterpart of [`spawn_ricochet_effects`]; replaces it when the `dim2`
+/// feature is enabled. Sprites fan out in the XY plane around the ricochet's
+/// `new_direction`, as many as the 3D spray throws for the surface.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning entities
+/// * `ricochet_events` - Event reader for ricochet events
+/// * `surfaces` - Query for surface material components
+/// * `parents` - Query for hierarchy parents, to find a child collider's material
+/// * `pool` - Mutable reference to the decal pool
+#[cfg(feature = "dim2")]
+pub fn spawn_ricochet_effects_2d(
+    mut commands: Commands,
+    mut ricochet_events: MessageReader<RicochetEvent>,
+    surfaces: Query<&SurfaceMaterial>,
+    parents: Query<&ChildOf>,
+    mut pool: ResMut<DecalPool>,
+) {
+    for event in ricochet_events.read() {
+        let Some(direction) = event.new_direction.truncate().try_normalize() else {
+            continue;
+        };
+        let effect = find_surface_material(event.surface, &surfaces, &parents)
+            .map(|surface| surface.hit_effect)
+            .unwrap_or_default();
+        let (max_count, cone, _) = RicochetSpray::shape(effect);
+
+        let strength = (event.new_speed / RICOCHET_EFFECT_REFERENCE_SPEED).clamp(0.15, 1.0);
+        let count = ((max_count as f32 * strength).ceil() as u32).max(1);
+        let mut rng = SpreadRng::new(event.projectile.to_bits());
+
+        for i in 0..count {
+            let angle = (rng.next_unit() * 2.0 - 1.0) * cone;
+            let particle_direction = Vec2::from_angle(angle).rotate(direction);
+            let offset = (0.05 + 0.15 * i as f32 / count as f32) * IMPACT_SPRITE_SCALE;
+            spawn_sprite_decal_2d(
+                &mut commands,
+                &mut pool,
+                event.impact_point.truncate() + particle_direction * offset,
+                particle_direction,
+                effect,
+                0.3,
+                0.15 + 0.25 * strength,
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Laser Sight System
+// ============================================================================
+
+/// Dot radius as a fraction of the shared unit sphere mesh (meters).
+#[cfg(feature = "dim3")]
+const LASER_DOT_SCALE: f32 = 0.01;
+
+/// Project each laser sight's dot onto the first surface along its beam.
+/// 
+/// Raycasts forward from every [`LaserSight`](crate::components::LaserSight) with
+/// `trace_shot` and moves the sight's dot to the hit point. The dot is spawned
+/// once per sight and then reused; when nothing is hit within range it is parked
+/// at max range and hidden. Changing the sight's `color` or `render_layers`
+/// updates the existing dot; [`despawn_laser_dot`] removes it with the sight.
+/// 
+/// # Arguments
+/// * `commands` - Bevy Commands for spawning dot entities
+/// * `spatial_query` - Avian3D spatial query
+/// * `ballistics_assets` - Shared meshes for the dot
+/// * `materials` - Material storage for per-sight dot colors
+/// * `surfaces` - Query for surface material components
+/// * `colliders` - Query mapping colliders to their rigid bodies
+/// * `sights` - Query for laser sights and their world transforms
+/// * `dots` - Query for existing dot entities
+#[cfg(feature = "dim3")]
+#[allow(clippy::too_many_arguments)]
+pub fn update_laser_sights(
+    mut commands: Commands,
+    spatial_query: avian3d::prelude::SpatialQuery,
+    ballistics_assets: Res<crate::resources::BallisticsAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    surfaces: Query<&crate::components::SurfaceMaterial>,
+    colliders: Query<&avian3d::prelude::ColliderOf>,
+    mut sights: Query<(&GlobalTransform, &mut crate::components::LaserSight)>,
+    mut dots: Query<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial3d<StandardMaterial>,
+            &mut bevy::camera::visibility::RenderLayers,
+        ),
+        With<crate::components::LaserDot>,
+    >,
+) {
+    for (muzzle, mut sight) in sights.iter_mut() {
+        let origin = muzzle.translation();
+        let direction = muzzle.forward();
+
+        let hit = crate::systems::collision::trace_shot(
+            &spatial_query,
+            origin,
+            *direction,
+            sight.max_range,
+            sight.owner,
+            &surfaces,
+            &colliders,
+        );
+
+        let (position, visibility) = match &hit {
+            Some(hit) => (hit.point, Visibility::Visible),
+            None => (origin + *direction * sight.max_range, Visibility::Hidden),
+        };
+
+        if let Some((mut transform, mut dot_visibility, material, mut layers)) =
+            sight.dot.and_then(|dot| dots.get_mut(dot).ok())
+        {
+            transform.translation = position;
+            *dot_visibility = visibility;
+            if sight.is_changed() {
+                if let Some(material) = materials.get_mut(&material.0) {
+                    material.base_color = sight.color;
+                    material.emissive = sight.color.into();
+                }
+                if *layers != sight.render_layers {
+                    *layers = sight.render_layers.clone();
+                }
+            }
+            continue;
+        }
+
+        let dot = commands
+            .spawn((
+                Mesh3d(ballistics_assets.sphere_mesh.clone()),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: sight.color,
+                    emissive: sight.color.into(),
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_translation(position).with_scale(Vec3::splat(LASER_DOT_SCALE)),
+                visibility,
+                sight.render_layers.clone(),
+                crate::components::LaserDot,
+            ))
+            .id();
+        sight.dot = Some(dot);
+    }
+}
+
+/// Despawn a laser sight's dot when the sight is removed or despawned.
+///
+/// # Arguments
+/// * `remove` - The `LaserSight` about to be removed from an entity
+/// * `commands` - Bevy Commands for despawning the dot
+/// * `sights` - Query for laser sights (still present while this runs)
+pub fn despawn_laser_dot(
+    remove: On<Remove, crate::components::LaserSight>,
+    mut commands: Commands,
+    sights: Query<&crate::components::LaserSight>,
+) {
+    if let Some(dot) = sights.get(remove.entity).ok().and_then(|sight| sight.dot) {
+        commands.entity(dot).try_despawn();
+    }
+}
+
+#[cfg(all(test, feature = "dim3"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ammo_type_tints_weapon_tracer() {
+        use crate::components::AmmoType;
+
+        let ball = tracer_config::for_ammo(tracer_config::sniper(), Some(AmmoType::Ball));
+        let tracer = tracer_config::for_ammo(tracer_config::sniper(), Some(AmmoType::Tracer));
+        assert_ne!(ball.color, tracer.color);
+        assert_eq!(ball.length, tracer_config::sniper().length);
+
+        // No ammo: the weapon's own settings are untouched
+        let weapon = tracer_config::for_ammo(tracer_config::sniper(), None);
+        assert_eq!(weapon.color, tracer_config::sniper().color);
+        assert_eq!(weapon.width, tracer_config::sniper().width);
+
+        assert!(AmmoType::Incendiary.incendiary_payload().is_some());
+        assert!(AmmoType::ArmorPiercing.incendiary_payload().is_none());
+    }
+
+    #[test]
+    fn test_projectile_visual_scale_interpolates() {
+        use crate::components::{Projectile, ProjectileVisualScale};
+        use bevy::ecs::system::RunSystemOnce;
+
+        let curve = ProjectileVisualScale {
+            near: 0.1,
+            far: 1.1,
+            far_distance: 400.0,
+        };
+        assert_eq!(curve.scale_at(0.0), 0.1);
+        assert!((curve.scale_at(100.0) - 0.35).abs() < 1e-6);
+        assert!((curve.scale_at(200.0) - 0.6).abs() < 1e-6);
+        assert_eq!(curve.scale_at(400.0), 1.1);
+        // Clamped past both ends
+        assert_eq!(curve.scale_at(5000.0), 1.1);
+        assert_eq!(curve.scale_at(-10.0), 0.1);
+
+        let mut world = World::new();
+        let mut flown = Projectile::new(Vec3::NEG_Z);
+        flown.distance_travelled = 200.0;
+        let scaled = world.spawn((Transform::default(), flown.clone(), curve)).id();
+        let untouched = world
+            .spawn((Transform::from_scale(Vec3::splat(0.01)), flown))
+            .id();
+        world.run_system_once(update_projectile_visual_scale).unwrap();
+
+        let scale = world.get::<Transform>(scaled).unwrap().scale;
+        assert!(scale.abs_diff_eq(Vec3::splat(0.6), 1e-6));
+        // Projectiles without the component are left alone
+        assert_eq!(world.get::<Transform>(untouched).unwrap().scale, Vec3::splat(0.01));
+    }
+
+    #[test]
+    fn test_persistent_trail_follows_projectile_then_fades() {
+        use crate::components::{PersistentTrail, Projectile, TrailPolyline};
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.init_resource::<BallisticsConfig>();
+        world.insert_resource(Time::<()>::default());
+
+        let projectile = world
+            .spawn((
+                Transform::default(),
+                Projectile::new(Vec3::NEG_Z * 100.0),
+                PersistentTrail {
+                    lifetime: 5.0,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        world.run_system_once(spawn_persistent_trails).unwrap();
+
+        let step = |world: &mut World, millis: u64| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            world.run_system_once(update_persistent_trails).unwrap();
+        };
+        let trail = |world: &mut World| {
+            world
+                .query::<(Entity, &TrailPolyline)>()
+                .iter(world)
+                .map(|(entity, polyline)| (entity, polyline.points.len()))
+                .next()
+        };
+
+        // One point per frame while the projectile moves, capped
+        let mut previous = trail(&mut world).unwrap().1;
+        for frame in 1..=400 {
+            world.get_mut::<Transform>(projectile).unwrap().translation.z = -(frame as f32);
+            step(&mut world, 10);
+            let (_, count) = trail(&mut world).unwrap();
+            if frame < MAX_TRAIL_POINTS {
+                assert_eq!(count, previous + 1);
+            }
+            assert!(count <= MAX_TRAIL_POINTS);
+            previous = count;
+        }
+
+        // Stops growing once the projectile is gone, then fades out and despawns
+        world.despawn(projectile);
+        let (entity, before) = trail(&mut world).unwrap();
+        let mesh = build_trail_mesh(world.get::<TrailPolyline>(entity).unwrap(), None, Vec3::Y);
+        assert_eq!(mesh.count_vertices(), before * 2);
+        step(&mut world, 10);
+        assert!(trail(&mut world).unwrap().1 <= before);
+        for _ in 0..6 {
+            step(&mut world, 1000);
+        }
+        assert!(world.get_entity(entity).is_err());
+    }
+
+    #[test]
+    fn test_trail_ribbon_faces_the_viewer() {
+        use crate::components::{PersistentTrail, TrailPoint, TrailPolyline};
+        use bevy::mesh::VertexAttributeValues;
+
+        let polyline = |points: &[Vec3]| TrailPolyline {
+            source: None,
+            trail: PersistentTrail::default(),
+            points: points
+                .iter()
+                .map(|&position| TrailPoint { position, age: 0.0 })
+                .collect(),
+        };
+        // Offset between the two edge vertices of the first point
+        let width = |mesh: Mesh| match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(positions)) => {
+                Vec3::from(positions[1]) - Vec3::from(positions[0])
+            }
+            _ => panic!("trail mesh without positions"),
+        };
+
+        // Level trail seen from the side: the ribbon stands up to face the eye
+        let level = polyline(&[Vec3::ZERO, Vec3::NEG_Z * 10.0]);
+        let side = width(build_trail_mesh(&level, Some(Vec3::new(10.0, 0.0, -5.0)), Vec3::Y));
+        assert!((side.length() - 0.03).abs() < 1e-6);
+        assert!(side.normalize().dot(Vec3::Y).abs() > 0.999);
+
+        // A vertical trail without an eye still has its full width
+        let vertical = polyline(&[Vec3::ZERO, Vec3::Y * 10.0]);
+        let side = width(build_trail_mesh(&vertical, None, Vec3::Y));
+        assert!((side.length() - 0.03).abs() < 1e-6);
+        assert!(side.dot(Vec3::Y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_decal_budget_fades_oldest_first() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(BallisticsConfig {
+            max_decals: 3,
+            ..Default::default()
+        });
+        world.init_resource::<DecalPool>();
+        world.insert_resource(Time::<()>::default());
+
+        let place = |world: &mut World, x: f32| {
+            world
+                .run_system_once(move |mut commands: Commands, mut pool: ResMut<DecalPool>| {
+                    spawn_decal(&mut commands, &mut pool, Vec3::X * x, Vec3::Y, 0.1, 30.0)
+                })
+                .unwrap()
+        };
+        let age = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            world.run_system_once(cleanup_expired_effects).unwrap();
+        };
+
+        // Five decals a second apart against a budget of three
+        let mut decals = Vec::new();
+        for i in 0..5 {
+            decals.push(place(&mut world, i as f32));
+            age(&mut world);
+        }
+        world.run_system_once(enforce_decal_budget).unwrap();
+
+        let lifetime = |world: &World, decal: Entity| world.get::<ImpactDecal>(decal).unwrap().lifetime;
+        for &oldest in &decals[..2] {
+            assert_eq!(lifetime(&world, oldest), DECAL_EVICTION_FADE);
+        }
+        for (i, &fresh) in decals[2..].iter().enumerate() {
+            assert_eq!(lifetime(&world, fresh), 30.0 - (3 - i) as f32);
+        }
+
+        // Evicted decals expire and go back to the pool within the fade
+        age(&mut world);
+        assert_eq!(world.resource::<DecalPool>().available.len(), 2);
+        assert!(decals[..2].iter().all(|&decal| {
+            world.get::<Visibility>(decal) == Some(&Visibility::Hidden)
+        }));
+    }
+
+    #[test]
+    fn test_tracer_ramp_burns_out() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.init_resource::<BallisticsConfig>();
+        world.init_resource::<TracerPool>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<crate::resources::BallisticsAssets>();
+        world.insert_resource(Time::<()>::default());
+
+        let spawn = |world: &mut World| {
+            world
+                .run_system_once(
+                    |mut commands: Commands,
+                     assets: Res<crate::resources::BallisticsAssets>,
+                     mut pool: ResMut<TracerPool>| {
+                        // 2m tracer at 20 m/s lives 1 second
+                        spawn_tracer_with_assets(
+                            &mut commands,
+                            &assets,
+                            &mut pool,
+                            Vec3::ZERO,
+                            Vec3::NEG_Z,
+                            20.0,
+                            &tracer_config::TracerSettings::default(),
+                        )
+                    },
+                )
+                .unwrap()
+        };
+        let emissive = |world: &World, tracer: Entity| {
+            let handle = &world.get::<MeshMaterial3d<StandardMaterial>>(tracer).unwrap().0;
+            let material = world.resource::<Assets<StandardMaterial>>().get(handle).unwrap();
+            (material.emissive, material.base_color.alpha())
+        };
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(250));
+            world.run_system_once(update_tracers).unwrap();
+        };
+
+        let tracer = spawn(&mut world);
+        let (start, _) = emissive(&world, tracer);
+        let mut previous = start.red + start.green + start.blue;
+        for _ in 0..3 {
+            step(&mut world);
+            let (color, _) = emissive(&world, tracer);
+            let brightness = color.red + color.green + color.blue;
+            assert!(brightness < previous);
+            previous = brightness;
+        }
+
+        // Burned out to transparent black, then hidden and pooled
+        step(&mut world);
+        let (end, alpha) = emissive(&world, tracer);
+        assert_eq!(end.red + end.green + end.blue, 0.0);
+        assert_eq!(alpha, 0.0);
+        assert_eq!(world.get::<Visibility>(tracer), Some(&Visibility::Hidden));
+
+        // Reusing the pooled tracer restarts its own material at the ramp start
+        let materials_before = world.resource::<Assets<StandardMaterial>>().len();
+        let reused = spawn(&mut world);
+        assert_eq!(reused, tracer);
+        assert_eq!(emissive(&world, reused).0, start);
+        assert_eq!(world.resource::<Assets<StandardMaterial>>().len(), materials_before);
+    }
+
+    #[test]
+    fn test_vertical_tracer_has_valid_rotation() {
+        for direction in [Vec3::Y, Vec3::NEG_Y, Vec3::new(0.0, 1.0, 1e-5)] {
+            let transform = tracer_transform(Vec3::ZERO, direction, Vec3::Y);
+            assert!(transform.rotation.is_finite());
+            assert!(transform.rotation.is_normalized());
+            assert!(transform.forward().dot(direction.normalize()) > 0.999);
+        }
+
+        // Horizontal tracers keep the plain `looking_to` orientation
+        let direction = Vec3::new(1.0, 0.2, -1.0).normalize();
+        let transform = tracer_transform(Vec3::ONE, direction, Vec3::Y);
+        let expected = Transform::from_translation(Vec3::ONE).looking_to(direction, Vec3::Y);
+        assert_eq!(transform, expected);
+    }
+
+    #[test]
+    fn test_penetration_spawns_entry_and_exit_effects() {
+        let mut app = App::new();
+        app.add_message::<PenetrationEvent>()
+            .init_resource::<crate::resources::BallisticsAssets>()
+            .init_resource::<DecalPool>()
+            .add_systems(Update, spawn_penetration_effects);
+
+        app.world_mut().write_message(PenetrationEvent {
+            projectile: Entity::PLACEHOLDER,
+            entry_point: Vec3::new(0.0, 1.0, -10.0),
+            exit_point: Vec3::new(0.0, 1.0, -10.05),
+            target: Entity::PLACEHOLDER,
+            remaining_power: 600.0,
+        });
+        app.world_mut().write_message(PenetrationEvent {
+            projectile: Entity::PLACEHOLDER,
+            entry_point: Vec3::new(5.0, 1.0, 0.0),
+            exit_point: Vec3::new(5.05, 1.0, 0.0),
+            target: Entity::PLACEHOLDER,
+            remaining_power: 0.0,
+        });
+        app.update();
+
+        let effects: Vec<(Vec3, f32)> = app
+            .world_mut()
+            .query::<(&Transform, &ImpactDecal)>()
+            .iter(app.world())
+            .map(|(transform, _)| (transform.translation, transform.scale.x))
+            .collect();
+        assert_eq!(effects.len(), 4);
+
+        // Two effects per event; a spent round's exit spray is smaller
+        let scale_at = |point: Vec3| effects.iter().find(|(p, _)| *p == point).unwrap().1;
+        assert!(scale_at(Vec3::new(5.05, 1.0, 0.0)) < scale_at(Vec3::new(0.0, 1.0, -10.05)));
+    }
+
+    #[test]
+    fn test_impact_effects_reuse_cached_assets() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()))
+            .init_asset::<StandardMaterial>()
+            .init_asset::<Mesh>()
+            .add_message::<HitEvent>()
+            .init_resource::<BallisticsAssets>()
+            .init_resource::<DecalPool>()
+            .add_systems(Update, spawn_impact_effects);
+
+        let wood_material = app
+            .world_mut()
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+        let sphere_mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::new(1.0));
+        {
+            let mut assets = app.world_mut().resource_mut::<BallisticsAssets>();
+            assets.wood_material = wood_material.clone();
+            assets.sphere_mesh = sphere_mesh.clone();
+        }
+        // A child collider with its material on the root
+        let wall = app
+            .world_mut()
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::WoodChips,
+                ..Default::default()
+            })
+            .with_child(())
+            .id();
+        let wall = app.world().get::<Children>(wall).unwrap()[0];
+        let plate = app
+            .world_mut()
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::Sparks,
+                ..Default::default()
+            })
+            .id();
+
+        let normal = Vec3::new(1.0, 1.0, 0.0).normalize();
+        let materials_before = app.world().resource::<Assets<StandardMaterial>>().len();
+        let meshes_before = app.world().resource::<Assets<Mesh>>().len();
+        for i in 0..50 {
+            app.world_mut().write_message(HitEvent::new(
+                Entity::PLACEHOLDER,
+                if i % 2 == 0 { wall } else { plate },
+                Vec3::ZERO,
+                normal,
+                Vec3::NEG_X * 400.0,
+                30.0,
+            ));
+            app.update();
+        }
+
+        // Sustained fire adds no materials or meshes
+        assert_eq!(
+            app.world().resource::<Assets<StandardMaterial>>().len(),
+            materials_before
+        );
+        assert_eq!(app.world().resource::<Assets<Mesh>>().len(), meshes_before);
+
+        let decals: Vec<(Transform, Handle<Mesh>, Handle<StandardMaterial>)> = app
+            .world_mut()
+            .query::<(&Transform, &Mesh3d, &MeshMaterial3d<StandardMaterial>, &ImpactDecal)>()
+            .iter(app.world())
+            .map(|(transform, mesh, material, _)| (*transform, mesh.0.clone(), material.0.clone()))
+            .collect();
+        assert_eq!(decals.len(), 50);
+        for (transform, mesh, _) in &decals {
+            assert_eq!(*mesh, sphere_mesh);
+            assert!((transform.rotation * Vec3::Y).dot(normal) > 0.999);
+        }
+
+        // Size still varies per effect, through the scale
+        let scale_of = |handle: &Handle<StandardMaterial>| {
+            decals.iter().find(|(_, _, material)| material == handle).unwrap().0.scale.x
+        };
+        let spark_material = app.world().resource::<BallisticsAssets>().spark_material.clone();
+        assert!(decals.iter().any(|(_, _, material)| *material == wood_material));
+        assert!(scale_of(&wood_material) > scale_of(&spark_material));
+    }
+
+    #[test]
+    fn test_ricochet_sparks_spray_along_new_direction() {
+        let mut app = App::new();
+        app.add_message::<RicochetEvent>()
+            .init_resource::<BallisticsAssets>()
+            .init_resource::<DecalPool>()
+            .add_systems(Update, spawn_ricochet_effects);
+
+        let steel = app
+            .world_mut()
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::Sparks,
+                ..Default::default()
+            })
+            .id();
+        // A child collider with its material on the root
+        let wood = app
+            .world_mut()
+            .spawn(SurfaceMaterial {
+                hit_effect: HitEffectType::WoodChips,
+                ..Default::default()
+            })
+            .with_child(())
+            .id();
+        let wood = app.world().get::<Children>(wood).unwrap()[0];
+        let new_direction = Vec3::new(1.0, 0.3, 0.0).normalize();
+        let ricochet = |surface: Entity, impact_point: Vec3| RicochetEvent {
+            projectile: Entity::PLACEHOLDER,
+            impact_point,
+            new_direction,
+            new_speed: 600.0,
+            surface,
+            respawned: None,
+        };
+        app.world_mut().write_message(ricochet(steel, Vec3::ZERO));
+        app.world_mut().write_message(ricochet(wood, Vec3::new(0.0, 0.0, -50.0)));
+        app.update();
+
+        let effects: Vec<Transform> = app
+            .world_mut()
+            .query::<(&Transform, &ImpactDecal)>()
+            .iter(app.world())
+            .map(|(transform, _)| *transform)
+            .collect();
+        let (sparks, chips): (Vec<&Transform>, Vec<&Transform>) =
+            effects.iter().partition(|transform| transform.translation.z > -25.0);
+
+        // A full-speed ricochet off metal throws a full spray of streaks
+        assert_eq!(sparks.len(), 12);
+        for spark in &sparks {
+            let axis = spark.rotation * Vec3::Y;
+            assert!(axis.dot(new_direction) > 0.9);
+            // Downrange of the impact point, along the outgoing direction
+            assert!(spark.translation.dot(new_direction) > 0.0);
+            // Streaks on the tracer cylinder (radius 0.02, height 1) are longer than wide
+            assert!(spark.scale.y > 0.04 * spark.scale.x);
+        }
+
+        // Wood sheds a smaller puff of chips instead
+        assert!(!chips.is_empty() && chips.len() < sparks.len());
+        assert!(chips.iter().all(|chip| chip.scale.x == chip.scale.y));
+    }
+
+    #[test]
+    fn test_laser_dot_follows_hit_point() {
+        use crate::components::{LaserDot, LaserSight};
+        use avian3d::prelude::*;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            bevy::asset::AssetPlugin::default(),
+            bevy::mesh::MeshPlugin,
+            bevy::scene::ScenePlugin,
+            PhysicsPlugins::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .init_asset::<StandardMaterial>()
+        .init_resource::<crate::resources::BallisticsAssets>()
+        .add_systems(Update, update_laser_sights)
+        .add_observer(despawn_laser_dot);
+
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(10.0, 10.0, 1.0),
+            Transform::from_xyz(0.0, 0.0, -20.0),
+        ));
+        let muzzle = app
+            .world_mut()
+            .spawn((Transform::default(), LaserSight::default()))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let dot = app.world().get::<LaserSight>(muzzle).unwrap().dot.unwrap();
+        let dot_transform = app.world().get::<Transform>(dot).unwrap();
+        assert_eq!(*app.world().get::<Visibility>(dot).unwrap(), Visibility::Visible);
+        assert!(dot_transform.translation.distance(Vec3::new(0.0, 0.0, -19.5)) < 0.01);
+
+        // Only one dot is ever spawned
+        let dots = app
+            .world_mut()
+            .query_filtered::<Entity, With<LaserDot>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(dots, 1);
+
+        // Aim away from the wall: the dot hides
+        app.world_mut()
+            .get_mut::<Transform>(muzzle)
+            .unwrap()
+            .rotate_y(std::f32::consts::PI);
+        app.update();
+        app.update();
+        assert_eq!(*app.world().get::<Visibility>(dot).unwrap(), Visibility::Hidden);
+
+        // Retuning the sight restyles the same dot
+        {
+            let mut sight = app.world_mut().get_mut::<LaserSight>(muzzle).unwrap();
+            sight.color = Color::srgb(0.0, 1.0, 0.0);
+            sight.render_layers = bevy::camera::visibility::RenderLayers::layer(2);
+        }
+        app.update();
+        assert_eq!(app.world().get::<LaserSight>(muzzle).unwrap().dot, Some(dot));
+        let material = app.world().get::<MeshMaterial3d<StandardMaterial>>(dot).unwrap();
+        let material = app.world().resource::<Assets<StandardMaterial>>().get(&material.0).unwrap();
+        assert_eq!(material.base_color, Color::srgb(0.0, 1.0, 0.0));
+        assert_eq!(
+            *app.world().get::<bevy::camera::visibility::RenderLayers>(dot).unwrap(),
+            bevy::camera::visibility::RenderLayers::layer(2)
+        );
+
+        // Taking the sight off removes its dot
+        app.world_mut().entity_mut(muzzle).remove::<LaserSight>();
+        app.update();
+        assert!(app.world().get_entity(dot).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "dim2"))]
+mod tests_2d {
+    use super::*;
+
+    #[test]
+    fn test_sprite_tracer_and_decal_in_2d() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<TracerPool>();
+        world.init_resource::<DecalPool>();
+
+        let (tracer, decal) = world
+            .run_system_once(
+                |mut commands: Commands,
+                 mut tracers: ResMut<TracerPool>,
+                 mut decals: ResMut<DecalPool>| {
+                    let tracer = spawn_tracer_2d(
+                        &mut commands,
+                        &mut tracers,
+                        Vec2::new(10.0, 5.0),
+                        Vec2::Y,
+                        600.0,
+                        &tracer_config::TracerSettings::default(),
+                    );
+                    let decal = spawn_impact_decal_2d(
+                        &mut commands,
+                        &mut decals,
+                        Vec2::new(50.0, 0.0),
+                        Vec2::NEG_X,
+                        HitEffectType::Dust,
+                    );
+                    (tracer, decal)
+                },
+            )
+            .unwrap();
+
+        assert!(world.get::<Sprite>(tracer).is_some());
+        assert!(world.get::<BulletTracer>(tracer).unwrap().lifetime > 0.0);
+        let transform = world.get::<Transform>(tracer).unwrap();
+        assert!((transform.rotation * Vec3::X).distance(Vec3::Y) < 1e-5);
+        assert_eq!(transform.translation, Vec3::new(10.0, 5.0, 0.0));
+
+        assert!(world.get::<Sprite>(decal).is_some());
+        assert!(world.get::<ImpactDecal>(decal).is_some());
+        let transform = world.get::<Transform>(decal).unwrap();
+        assert!((transform.rotation * Vec3::Y).distance(Vec3::NEG_X) < 1e-5);
+    }
+
+    #[test]
+    fn test_penetration_spawns_sprite_effects() {
+        let mut app = App::new();
+        app.add_message::<PenetrationEvent>()
+            .init_resource::<DecalPool>()
+            .add_systems(Update, spawn_penetration_effects_2d);
+
+        app.world_mut().write_message(PenetrationEvent {
+            projectile: Entity::PLACEHOLDER,
+            entry_point: Vec3::new(100.0, 0.0, 0.0),
+            exit_point: Vec3::new(110.0, 0.0, 0.0),
+            target: Entity::PLACEHOLDER,
+            remaining_power: 600.0,
+        });
+        app.world_mut().write_message(PenetrationEvent {
+            projectile: Entity::PLACEHOLDER,
+            entry_point: Vec3::new(0.0, 100.0, 0.0),
+            exit_point: Vec3::new(0.0, 110.0, 0.0),
+            target: Entity::PLACEHOLDER,
+            remaining_power: 0.0,
+        });
+        app.update();
+
+        let effects: Vec<(Vec3, Vec2)> = app
+            .world_mut()
+            .query::<(&Transform, &Sprite, &ImpactDecal)>()
+            .iter(app.world())
+            .map(|(transform, sprite, _)| (transform.translation, sprite.custom_size.unwrap()))
+            .collect();
+        assert_eq!(effects.len(), 4);
 
+        // Two sprites per event; a spent round's exit puff is smaller
+        let size_at = |point: Vec3| effects.iter().find(|(p, _)| *p == point).unwrap().1;
+        assert!(size_at(Vec3::new(0.0, 110.0, 0.0)).x < size_at(Vec3::new(110.0, 0.0, 0.0)).x);
+    }
+
+    #[test]
+    fn test_ricochet_sprays_sprites_along_new_direction() {
+        let mut app = App::new();
+        app.add_message::<RicochetEvent>()
+            .init_resource::<DecalPool>()
+            .add_systems(Update, spawn_ricochet_effects_2d);
+
+        app.world_mut().write_message(RicochetEvent {
+            projectile: Entity::PLACEHOLDER,
+            impact_point: Vec3::ZERO,
+            new_direction: Vec3::X,
+            new_speed: RICOCHET_EFFECT_REFERENCE_SPEED,
+            surface: Entity::PLACEHOLDER,
+            respawned: None,
+        });
+        app.update();
+
+        // A full-speed spark spray, every sprite inside the cone and none of it 3D
+        let (max_count, cone, _) = RicochetSpray::shape(HitEffectType::Sparks);
+        let positions: Vec<Vec3> = app
+            .world_mut()
+            .query_filtered::<&Transform, (With<Sprite>, With<ImpactDecal>, Without<Mesh3d>)>()
+            .iter(app.world())
+            .map(|transform| transform.translation)
+            .collect();
+        assert_eq!(positions.len(), max_count as usize);
+        assert!(positions.iter().all(|p| p.z == 0.0 && p.truncate().angle_to(Vec2::X).abs() <= cone + 1e-5));
+    }
+}
@@ -2,6 +2,11 @@
 
 use bevy::prelude::*;
 
+use crate::components::{
+    Accuracy, FalloffCurve, NetProjectile, Payload, ProjectileLogic, SurfaceMaterial, Weapon,
+};
+use crate::systems::accuracy::{apply_spread_to_direction, SpreadRng};
+
 /// Physics model for projectile simulation.
 /// 
 /// Defines the integration method used for simulating projectile physics.
@@ -56,30 +61,121 @@ pub enum WeaponCategory {
     Explosive,
 }
 
+impl WeaponCategory {
+    /// Create a plausible starting loadout for this category.
+    ///
+    /// Each category maps to a distinct trigger behavior:
+    /// - Firearm: automatic rifle, `Impact` + `Kinetic`
+    /// - Projectile: bow, `Sticky` + `Kinetic`
+    /// - Throwable: frag grenade, `Timed` + `Explosive`
+    /// - Explosive: rocket launcher, `Proximity` + `Explosive`
+    ///
+    /// The tuple is a valid bundle, but `Weapon` and `Accuracy` usually live on the
+    /// shooter while `ProjectileLogic` and `Payload` go on each spawned projectile.
+    ///
+    /// # Returns
+    /// A tuple of (Weapon, Accuracy, ProjectileLogic, Payload) for this category
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_bullet_dynamics::types::WeaponCategory;
+    ///
+    /// let (weapon, accuracy, logic, payload) = WeaponCategory::Throwable.default_bundle();
+    /// ```
+    pub fn default_bundle(&self) -> (Weapon, Accuracy, ProjectileLogic, Payload) {
+        use crate::systems::{accuracy::presets as accuracy_presets, logic::presets as logic_presets};
+
+        match self {
+            Self::Firearm => (
+                Weapon {
+                    fire_rate: 10.0,
+                    automatic: true,
+                    ..Default::default()
+                },
+                accuracy_presets::rifle(),
+                ProjectileLogic::Impact,
+                Payload::Kinetic { damage: 35.0 },
+            ),
+            Self::Projectile => (
+                Weapon {
+                    fire_rate: 1.0,
+                    ..Default::default()
+                },
+                accuracy_presets::bow(),
+                ProjectileLogic::Sticky,
+                Payload::Kinetic { damage: 45.0 },
+            ),
+            Self::Throwable => {
+                let (logic, payload) = logic_presets::frag_grenade();
+                (
+                    Weapon {
+                        fire_rate: 1.0,
+                        ..Default::default()
+                    },
+                    Accuracy {
+                        base_spread: 0.02,
+                        bloom_per_shot: 0.0,
+                        recovery_rate: 0.0,
+                        ..Default::default()
+                    },
+                    logic,
+                    payload,
+                )
+            }
+            Self::Explosive => (
+                Weapon {
+                    fire_rate: 0.5,
+                    ..Default::default()
+                },
+                Accuracy {
+                    base_spread: 0.005,
+                    bloom_per_shot: 0.01,
+                    ..Default::default()
+                },
+                ProjectileLogic::Proximity { range: 2.0 },
+                Payload::Explosive {
+                    damage: 200.0,
+                    radius: 6.0,
+                    falloff: FalloffCurve::Power(1.0),
+                },
+            ),
+        }
+    }
+}
+
 /// Hit result from raycasting.
 /// 
 /// Contains information about a successful raycast hit, including the hit entity,
 /// world position, surface normal, and distance from origin.
 /// 
+/// For compound bodies `entity` is the root body while `collider_entity` is the
+/// child collider that was actually struck (e.g. a head hitbox). For a standalone
+/// collider both are the same entity.
+/// 
+/// The struct is `#[non_exhaustive]`, so other crates can't build it with a
+/// struct literal or destructure it without `..`. Build one with
+/// [`HitResult::new`] and the `with_*` methods instead; new fields can then be
+/// added later without breaking callers again.
+/// 
 /// # Fields
-/// * `entity` - The entity that was hit by the raycast
+/// * `entity` - The entity that was hit by the raycast (root body)
 /// * `point` - World-space coordinates of the hit point
 /// * `normal` - Surface normal vector at the hit point
 /// * `distance` - Distance from the ray origin to the hit point
+/// * `collider_entity` - The leaf collider that was hit
+/// * `surface` - Surface material of the hit, if the collider or its body has one
 /// 
 /// # Example
 /// ```
 /// use bevy::prelude::*;
 /// use bevy_bullet_dynamics::types::HitResult;
 /// 
-/// let hit_result = HitResult {
-///     entity: Entity::PLACEHOLDER,
-///     point: Vec3::ZERO,
-///     normal: Vec3::Y,
-///     distance: 10.0,
-/// };
+/// let hit_result = HitResult::new(Entity::PLACEHOLDER, Vec3::ZERO, Vec3::Y, 10.0);
+/// assert_eq!(hit_result.collider_entity, hit_result.entity);
+/// assert!(hit_result.surface.is_none());
 /// ```
 #[derive(Clone)]
+#[non_exhaustive]
 pub struct HitResult {
     /// Hit entity
     pub entity: Entity,
@@ -89,6 +185,57 @@ pub struct HitResult {
     pub normal: Vec3,
     /// Distance from ray origin
     pub distance: f32,
+    /// Leaf collider that was hit (same as `entity` for standalone colliders)
+    pub collider_entity: Entity,
+    /// Surface material at the hit, None if untagged
+    pub surface: Option<SurfaceMaterial>,
+}
+
+impl HitResult {
+    /// Creates a hit on a standalone collider with no surface material.
+    /// 
+    /// # Arguments
+    /// * `entity` - The entity that was hit
+    /// * `point` - World-space coordinates of the hit point
+    /// * `normal` - Surface normal vector at the hit point
+    /// * `distance` - Distance from the ray origin to the hit point
+    /// 
+    /// # Returns
+    /// A new HitResult with `collider_entity` set to `entity`
+    pub fn new(entity: Entity, point: Vec3, normal: Vec3, distance: f32) -> Self {
+        Self {
+            entity,
+            point,
+            normal,
+            distance,
+            collider_entity: entity,
+            surface: None,
+        }
+    }
+
+    /// Sets the leaf collider that was hit.
+    /// 
+    /// # Arguments
+    /// * `collider` - Child collider entity of the hit body
+    /// 
+    /// # Returns
+    /// The modified HitResult instance for method chaining
+    pub fn with_collider(mut self, collider: Entity) -> Self {
+        self.collider_entity = collider;
+        self
+    }
+
+    /// Sets the surface material of the hit.
+    /// 
+    /// # Arguments
+    /// * `surface` - Surface material, or None for untagged surfaces
+    /// 
+    /// # Returns
+    /// The modified HitResult instance for method chaining
+    pub fn with_surface(mut self, surface: Option<SurfaceMaterial>) -> Self {
+        self.surface = surface;
+        self
+    }
 }
 
 /// Spatial query abstraction for 2D/3D support.
@@ -279,3 +426,310 @@ impl ProjectileSpawnParams {
         self
     }
 }
+
+/// Reasons a projectile is rejected by [`Projectile::validate`].
+///
+/// NaN values fail the same checks as the out-of-range ones.
+///
+/// [`Projectile::validate`]: crate::components::Projectile::validate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BallisticsError {
+    /// Velocity has a NaN or infinite component
+    NonFiniteVelocity(Vec3),
+    /// Mass is zero, negative or not finite; drag would divide by it
+    NonPositiveMass(f32),
+    /// Reference area is negative or not finite
+    NegativeReferenceArea(f32),
+}
+
+impl std::fmt::Display for BallisticsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonFiniteVelocity(velocity) => {
+                write!(f, "projectile velocity {velocity} is not finite")
+            }
+            Self::NonPositiveMass(mass) => {
+                write!(f, "projectile mass {mass} kg must be positive and finite")
+            }
+            Self::NegativeReferenceArea(area) => {
+                write!(f, "projectile reference area {area} m² must be non-negative and finite")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BallisticsError {}
+
+/// Independent random sub-streams of a [`ProjectileRng`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RngStream {
+    /// Pellet spread at the muzzle
+    Spread,
+    /// Ricochet probability rolls
+    Ricochet,
+    /// Fragment scatter directions
+    Fragmentation,
+    /// Round-to-round muzzle velocity variation
+    MuzzleVelocity,
+    /// Cluster submunition scatter
+    Submunition,
+    /// Identity of the clone a `RicochetMode::Respawn` ricochet continues with
+    Respawn,
+    /// Jitter of the shards a `Fragmentation` burst spawns
+    Shard,
+}
+
+impl RngStream {
+    /// Key mixed into the projectile seed. Keys are fixed per stream and must never
+    /// be renumbered: that is what keeps a stream stable when others come and go.
+    const fn key(self) -> u64 {
+        match self {
+            Self::Spread => 0,
+            Self::Ricochet => 0x5249_434F_4348_4554,
+            Self::Fragmentation => 0x4652_4147_4D45_4E54,
+            Self::MuzzleVelocity => 0x4D55_5A5A_4C45_5645,
+            Self::Submunition => 0x5355_424D_554E_4954,
+            Self::Respawn => 0x5245_5350_4157_4E53,
+            Self::Shard => 0x5348_5241_504E_454C,
+        }
+    }
+}
+
+/// Deterministic per-projectile randomness, derived from one seed.
+/// 
+/// Every random effect draws from its own [`RngStream`], and each draw is addressed
+/// by an index (pellet, ricochet or fragment number) instead of by call order. Adding,
+/// removing or reordering random effects therefore never shifts another effect's
+/// outcomes, and two peers holding the same [`NetProjectile`] get bit-identical results.
+/// 
+/// The spread stream uses the seed unchanged, so it matches
+/// `apply_spread_to_direction(seed + pellet)`.
+/// 
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_bullet_dynamics::types::ProjectileRng;
+/// 
+/// let rng = ProjectileRng::new(42);
+/// let pellet = rng.spread_direction(Vec3::NEG_Z, 0.05, 0);
+/// assert_eq!(pellet, ProjectileRng::new(42).spread_direction(Vec3::NEG_Z, 0.05, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProjectileRng {
+    seed: u64,
+}
+
+impl ProjectileRng {
+    /// Creates the streams for `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// The seed all streams are derived from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Seed of one sub-stream, scrambled with SplitMix64 so streams are independent.
+    pub fn stream_seed(&self, stream: RngStream) -> u64 {
+        match stream.key() {
+            0 => self.seed,
+            key => SpreadRng::new(self.seed ^ key).next_u64(),
+        }
+    }
+
+    fn draw(&self, stream: RngStream, index: u64) -> SpreadRng {
+        SpreadRng::new(self.stream_seed(stream).wrapping_add(index))
+    }
+
+    /// Direction of pellet `pellet` inside the spread cone.
+    /// 
+    /// # Arguments
+    /// * `base_direction` - Aim direction before spread
+    /// * `spread_angle` - Maximum spread angle in radians
+    /// * `pellet` - Pellet index within the shot
+    pub fn spread_direction(&self, base_direction: Vec3, spread_angle: f32, pellet: u32) -> Vec3 {
+        apply_spread_to_direction(
+            base_direction,
+            spread_angle,
+            self.stream_seed(RngStream::Spread).wrapping_add(pellet as u64),
+        )
+    }
+
+    /// Direction of cluster submunition `submunition` inside a cone of
+    /// `spread_angle` radians around `base_direction`.
+    pub fn submunition_direction(&self, base_direction: Vec3, spread_angle: f32, submunition: u32) -> Vec3 {
+        apply_spread_to_direction(
+            base_direction,
+            spread_angle,
+            self.stream_seed(RngStream::Submunition).wrapping_add(submunition as u64),
+        )
+    }
+
+    /// Uniform roll in `[0, 1)` for the projectile's `ricochet`-th ricochet check.
+    pub fn ricochet_roll(&self, ricochet: u8) -> f32 {
+        self.draw(RngStream::Ricochet, ricochet as u64).next_unit()
+    }
+
+    /// Seed for the round that continues the projectile's `ricochet`-th ricochet
+    /// under `RicochetMode::Respawn`. Also derives the clone's `NetworkId` from the
+    /// original's, so every peer names the respawned leg the same without talking.
+    pub fn respawn_seed(&self, ricochet: u8) -> u64 {
+        self.draw(RngStream::Respawn, ricochet as u64).next_u64()
+    }
+
+    /// Normally distributed muzzle velocity deviation with standard deviation `sd` (m/s).
+    pub fn muzzle_velocity_offset(&self, sd: f32) -> f32 {
+        self.draw(RngStream::MuzzleVelocity, 0).next_normal_pair().0 * sd
+    }
+
+    /// Uniformly distributed unit direction for fragment `fragment`.
+    pub fn fragment_direction(&self, fragment: u32) -> Vec3 {
+        let mut rng = self.draw(RngStream::Fragmentation, fragment as u64);
+        let z = rng.next_unit() * 2.0 - 1.0;
+        let azimuth = rng.next_unit() * std::f32::consts::TAU;
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+        Vec3::new(radius * azimuth.cos(), radius * azimuth.sin(), z)
+    }
+
+    /// Jitter for fragment `fragment` around its nominal direction.
+    ///
+    /// Drawn from the [`RngStream::Shard`] stream, apart from
+    /// [`ProjectileRng::fragment_direction`].
+    ///
+    /// # Arguments
+    /// * `fragment` - Fragment index
+    /// * `base_direction` - Nominal (unit) direction of the fragment
+    /// * `max_angle` - Maximum tilt off `base_direction`, in radians
+    /// * `speed_jitter` - Maximum relative speed change
+    ///
+    /// # Returns
+    /// The tilted direction, uniform over the cone, and a speed factor in
+    /// `[1 - speed_jitter, 1 + speed_jitter)`
+    pub fn fragment_jitter(
+        &self,
+        fragment: u32,
+        base_direction: Vec3,
+        max_angle: f32,
+        speed_jitter: f32,
+    ) -> (Vec3, f32) {
+        let mut rng = self.draw(RngStream::Shard, fragment as u64);
+        let cos_tilt = 1.0 - rng.next_unit() * (1.0 - max_angle.cos());
+        let azimuth = rng.next_unit() * std::f32::consts::TAU;
+        let sin_tilt = (1.0 - cos_tilt * cos_tilt).max(0.0).sqrt();
+        let local = Vec3::new(sin_tilt * azimuth.cos(), sin_tilt * azimuth.sin(), cos_tilt);
+        let direction = Quat::from_rotation_arc(Vec3::Z, base_direction) * local;
+        let speed_factor = 1.0 + speed_jitter * (rng.next_unit() * 2.0 - 1.0);
+        (direction, speed_factor)
+    }
+
+    /// Jitter for fragment `fragment` within the XY plane, for 2D bursts.
+    ///
+    /// Like [`ProjectileRng::fragment_jitter`], but the tilt is a turn about the
+    /// Z axis, so a direction in the plane stays in it.
+    ///
+    /// # Arguments
+    /// * `fragment` - Fragment index
+    /// * `base_direction` - Nominal (unit) direction of the fragment, in the XY plane
+    /// * `max_angle` - Maximum turn off `base_direction`, in radians
+    /// * `speed_jitter` - Maximum relative speed change
+    ///
+    /// # Returns
+    /// The turned direction, uniform over the arc, and a speed factor in
+    /// `[1 - speed_jitter, 1 + speed_jitter)`
+    pub fn fragment_jitter_2d(
+        &self,
+        fragment: u32,
+        base_direction: Vec3,
+        max_angle: f32,
+        speed_jitter: f32,
+    ) -> (Vec3, f32) {
+        let mut rng = self.draw(RngStream::Shard, fragment as u64);
+        let turn = (rng.next_unit() * 2.0 - 1.0) * max_angle;
+        let direction = Vec2::from_angle(turn).rotate(base_direction.truncate()).extend(0.0);
+        let speed_factor = 1.0 + speed_jitter * (rng.next_unit() * 2.0 - 1.0);
+        (direction, speed_factor)
+    }
+}
+
+impl From<&NetProjectile> for ProjectileRng {
+    fn from(net: &NetProjectile) -> Self {
+        Self::new(net.spread_seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projectile_rng_streams_reproducible() {
+        let net = NetProjectile {
+            owner_id: 7,
+            timestamp: 12.5,
+            spread_seed: 0xDEAD_BEEF,
+        };
+
+        // Everything a projectile rolls, in the order one client consumes it
+        let run = |rng: ProjectileRng| {
+            let spread: Vec<Vec3> = (0..8).map(|p| rng.spread_direction(Vec3::NEG_Z, 0.05, p)).collect();
+            let ricochets: Vec<f32> = (0..3).map(|r| rng.ricochet_roll(r)).collect();
+            let fragments: Vec<Vec3> = (0..16).map(|f| rng.fragment_direction(f)).collect();
+            (spread, ricochets, fragments)
+        };
+        let client_a = run(ProjectileRng::from(&net));
+        let client_b = run(ProjectileRng::from(&net));
+        assert_eq!(client_a, client_b);
+
+        // A client without spread (or rolling in another order) sees the same rolls
+        let rng = ProjectileRng::from(&net);
+        let ricochets_only: Vec<f32> = (0..3).rev().map(|r| rng.ricochet_roll(r)).rev().collect();
+        assert_eq!(ricochets_only, client_a.1);
+
+        // Streams are independent of each other and of the legacy spread seed
+        assert_eq!(client_a.0[3], apply_spread_to_direction(Vec3::NEG_Z, 0.05, 0xDEAD_BEEF + 3));
+        let seeds = [
+            RngStream::Spread,
+            RngStream::Ricochet,
+            RngStream::Fragmentation,
+            RngStream::MuzzleVelocity,
+            RngStream::Submunition,
+            RngStream::Respawn,
+            RngStream::Shard,
+        ]
+        .map(|stream| rng.stream_seed(stream));
+        for (i, a) in seeds.iter().enumerate() {
+            assert!(seeds[i + 1..].iter().all(|b| a != b));
+        }
+        assert!(client_a.1.iter().all(|roll| (0.0..1.0).contains(roll)));
+        assert!(client_a.2.iter().all(|dir| (dir.length() - 1.0).abs() < 1e-5));
+
+        // 2D shard jitter turns within the plane and within its arc
+        for shard in 0..16 {
+            let (direction, speed) = rng.fragment_jitter_2d(shard, Vec3::X, 0.3, 0.1);
+            assert_eq!(direction.z, 0.0);
+            assert!(direction.angle_between(Vec3::X) <= 0.3 + 1e-5);
+            assert!((0.9..1.1).contains(&speed));
+        }
+    }
+
+    #[test]
+    fn test_category_default_bundles() {
+        let (weapon, _, logic, payload) = WeaponCategory::Firearm.default_bundle();
+        assert!(weapon.automatic);
+        assert!(matches!(logic, ProjectileLogic::Impact));
+        assert!(matches!(payload, Payload::Kinetic { .. }));
+
+        let (_, _, logic, payload) = WeaponCategory::Projectile.default_bundle();
+        assert!(matches!(logic, ProjectileLogic::Sticky));
+        assert!(matches!(payload, Payload::Kinetic { .. }));
+
+        let (_, _, logic, payload) = WeaponCategory::Throwable.default_bundle();
+        assert!(matches!(logic, ProjectileLogic::Timed { .. }));
+        assert!(matches!(payload, Payload::Explosive { .. }));
+
+        let (_, _, logic, payload) = WeaponCategory::Explosive.default_bundle();
+        assert!(matches!(logic, ProjectileLogic::Proximity { .. }));
+        assert!(matches!(payload, Payload::Explosive { .. }));
+    }
+}